@@ -63,12 +63,13 @@ impl ControllerPlatform {
 
     /// The path prefix for the Integration API.
     ///
-    /// On UniFi OS devices: `/proxy/network/integration`
-    /// On standalone / cloud: `/integration`
+    /// On UniFi OS devices and through the cloud connector (which proxies
+    /// to a UniFi OS console): `/proxy/network/integration`
+    /// On standalone: `/integration`
     pub fn integration_prefix(&self) -> &'static str {
         match self {
-            Self::UnifiOs => "/proxy/network/integration",
-            Self::ClassicController | Self::Cloud => "/integration",
+            Self::UnifiOs | Self::Cloud => "/proxy/network/integration",
+            Self::ClassicController => "/integration",
         }
     }
 
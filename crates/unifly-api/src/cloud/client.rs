@@ -0,0 +1,231 @@
+// Async HTTP client for the UniFi Site Manager API (api.ui.com).
+//
+// Base path: /v1/ (early-access endpoints under /ea/)
+// Auth: X-API-KEY header (cloud API key)
+
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Method, StatusCode};
+use secrecy::ExposeSecret;
+use serde::de::DeserializeOwned;
+use tracing::debug;
+use url::Url;
+
+use super::types;
+use crate::Error;
+use crate::transport::{RetryPolicy, body_preview, rate_limited};
+
+/// Hosts, sites and devices per page when following `nextToken`.
+const PAGE_SIZE: &str = "200";
+
+// ── Error response shape from the Site Manager API ───────────────────
+
+#[derive(serde::Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    message: Option<String>,
+}
+
+// ── Client ───────────────────────────────────────────────────────────
+
+/// Async client for the UniFi Site Manager API.
+///
+/// Every call is a read, so the whole surface goes through the retry
+/// policy.
+#[derive(Clone)]
+pub struct SiteManagerClient {
+    http: reqwest::Client,
+    base_url: Url,
+    retry: RetryPolicy,
+}
+
+impl SiteManagerClient {
+    // ── Constructors ─────────────────────────────────────────────────
+
+    /// Build from a cloud API key and transport config.
+    ///
+    /// `base_url` is normally [`SITE_MANAGER_URL`](super::SITE_MANAGER_URL).
+    pub fn from_api_key(
+        base_url: &str,
+        api_key: &secrecy::SecretString,
+        transport: &crate::TransportConfig,
+    ) -> Result<Self, Error> {
+        let mut headers = HeaderMap::new();
+        let mut key_value =
+            HeaderValue::from_str(api_key.expose_secret()).map_err(|e| Error::Authentication {
+                message: format!("invalid API key header value: {e}"),
+            })?;
+        key_value.set_sensitive(true);
+        headers.insert("X-API-KEY", key_value);
+
+        let http = transport.build_client_with_headers(headers)?;
+        Ok(Self {
+            http,
+            base_url: Self::normalize_base_url(base_url)?,
+            retry: transport.retry.clone(),
+        })
+    }
+
+    /// Wrap an existing `reqwest::Client` (caller manages auth headers).
+    pub fn from_reqwest(base_url: &str, http: reqwest::Client) -> Result<Self, Error> {
+        Ok(Self {
+            http,
+            base_url: Self::normalize_base_url(base_url)?,
+            retry: RetryPolicy::default(),
+        })
+    }
+
+    /// Replace the retry policy.
+    #[must_use]
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    fn normalize_base_url(raw: &str) -> Result<Url, Error> {
+        let mut url = Url::parse(raw)?;
+        let path = url.path().trim_end_matches('/').to_owned();
+        url.set_path(&format!("{path}/"));
+        Ok(url)
+    }
+
+    /// The connector URL that proxies requests to console `host_id`.
+    pub fn connector_url(&self, host_id: &str) -> Result<Url, Error> {
+        super::connector_url(self.base_url.as_str(), host_id)
+    }
+
+    // ── Requests ─────────────────────────────────────────────────────
+
+    async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+    ) -> Result<types::Envelope<T>, Error> {
+        let url = self
+            .base_url
+            .join(path)
+            .expect("path should be valid relative URL");
+        debug!("GET {url} params={params:?}");
+
+        self.retry
+            .run(&Method::GET, || async {
+                let resp = self.http.get(url.clone()).query(params).send().await?;
+                self.handle_response(resp).await
+            })
+            .await
+    }
+
+    /// Follow `nextToken` until the last page and collect every item.
+    async fn get_all<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+    ) -> Result<Vec<T>, Error> {
+        let mut all = Vec::new();
+        let mut next_token: Option<String> = None;
+
+        loop {
+            let mut page_params = params.to_vec();
+            page_params.push(("pageSize", PAGE_SIZE.into()));
+            if let Some(token) = next_token.take() {
+                page_params.push(("nextToken", token));
+            }
+
+            let page: types::Envelope<Vec<T>> = self.get(path, &page_params).await?;
+            all.extend(page.data);
+
+            match page.next_token {
+                Some(token) if !token.is_empty() => next_token = Some(token),
+                _ => break,
+            }
+        }
+
+        Ok(all)
+    }
+
+    async fn handle_response<T: DeserializeOwned>(
+        &self,
+        resp: reqwest::Response,
+    ) -> Result<T, Error> {
+        let status = resp.status();
+        if status == StatusCode::UNAUTHORIZED {
+            return Err(Error::InvalidApiKey);
+        }
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(rate_limited(resp.headers()));
+        }
+
+        let body = resp.text().await?;
+        if !status.is_success() {
+            let message = serde_json::from_str::<ErrorResponse>(&body)
+                .ok()
+                .and_then(|e| e.message)
+                .unwrap_or_else(|| {
+                    if body.is_empty() {
+                        status.to_string()
+                    } else {
+                        body.clone()
+                    }
+                });
+            return Err(Error::SiteManager {
+                message,
+                status: status.as_u16(),
+            });
+        }
+
+        serde_json::from_str(&body).map_err(|e| {
+            let preview = body_preview(&body);
+            Error::Deserialization {
+                message: format!("{e} (body preview: {preview:?})"),
+                body,
+            }
+        })
+    }
+
+    // ━━ Public API ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+    // ── Hosts ────────────────────────────────────────────────────────
+
+    /// Every host the API key can reach.
+    pub async fn list_hosts(&self) -> Result<Vec<types::HostResponse>, Error> {
+        self.get_all("v1/hosts", &[]).await
+    }
+
+    pub async fn get_host(&self, host_id: &str) -> Result<types::HostResponse, Error> {
+        let host: types::Envelope<_> = self.get(&format!("v1/hosts/{host_id}"), &[]).await?;
+        Ok(host.data)
+    }
+
+    // ── Sites ────────────────────────────────────────────────────────
+
+    /// Every Network site across all reachable hosts.
+    pub async fn list_sites(&self) -> Result<Vec<types::SiteResponse>, Error> {
+        self.get_all("v1/sites", &[]).await
+    }
+
+    // ── Devices ──────────────────────────────────────────────────────
+
+    /// Devices grouped by host. An empty `host_ids` means every host.
+    pub async fn list_devices(
+        &self,
+        host_ids: &[String],
+    ) -> Result<Vec<types::HostDevicesResponse>, Error> {
+        let params: Vec<_> = host_ids
+            .iter()
+            .map(|id| ("hostIds[]", id.clone()))
+            .collect();
+        self.get_all("v1/devices", &params).await
+    }
+
+    // ── ISP metrics ──────────────────────────────────────────────────
+
+    /// WAN metrics for every site, sampled at `interval`.
+    pub async fn get_isp_metrics(
+        &self,
+        interval: types::IspMetricInterval,
+    ) -> Result<Vec<types::IspMetricsResponse>, Error> {
+        let metrics: types::Envelope<_> = self
+            .get(&format!("ea/isp-metrics/{}", interval.as_str()), &[])
+            .await?;
+        Ok(metrics.data)
+    }
+}
@@ -0,0 +1,32 @@
+// Site Manager API client for UniFi's cloud (api.ui.com).
+//
+// Lists the hosts (consoles), sites and devices an API key can reach,
+// plus ISP metrics. Uses X-API-KEY authentication and `{ data, nextToken }`
+// JSON envelopes. A host found here is reachable through the cloud
+// connector proxy at `/v1/connector/consoles/{hostId}/`.
+
+pub mod client;
+pub mod types;
+
+pub use client::SiteManagerClient;
+
+use url::Url;
+
+use crate::Error;
+
+/// Base URL of the public Site Manager API.
+pub const SITE_MANAGER_URL: &str = "https://api.ui.com";
+
+/// The cloud connector URL that proxies requests to console `host_id`.
+///
+/// The console's own API paths (e.g. `/proxy/network/integration/v1/sites`)
+/// are appended to this URL. A `base_url` that already points at a
+/// connector is returned as-is.
+pub fn connector_url(base_url: &str, host_id: &str) -> Result<Url, Error> {
+    let mut url = Url::parse(base_url)?;
+    let path = url.path().trim_end_matches('/').to_owned();
+    if !path.contains("/connector/consoles/") {
+        url.set_path(&format!("{path}/v1/connector/consoles/{host_id}"));
+    }
+    Ok(url)
+}
@@ -0,0 +1,239 @@
+//! Site Manager API response types (api.ui.com, `/v1/` and `/ea/`).
+//!
+//! Field names use camelCase via `#[serde(rename_all = "camelCase")]`.
+//! Nested state that varies by console model is kept as opaque JSON.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// ── Envelope ─────────────────────────────────────────────────────────
+
+/// Wrapper returned by every Site Manager endpoint.
+///
+/// List endpoints are cursor-paginated: `next_token` is set while more
+/// pages remain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Envelope<T> {
+    pub data: T,
+    #[serde(default)]
+    pub next_token: Option<String>,
+    #[serde(default)]
+    pub trace_id: Option<String>,
+}
+
+// ── Hosts ────────────────────────────────────────────────────────────
+
+/// A console or self-hosted Network Server — from `GET /v1/hosts`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HostResponse {
+    /// Host ID, used for the connector proxy and device filters.
+    pub id: String,
+    #[serde(default)]
+    pub hardware_id: Option<String>,
+    /// `console` or `network-server`.
+    #[serde(rename = "type", default)]
+    pub host_type: Option<String>,
+    #[serde(default)]
+    pub ip_address: Option<String>,
+    #[serde(default)]
+    pub owner: bool,
+    #[serde(default)]
+    pub is_blocked: bool,
+    #[serde(default)]
+    pub registration_time: Option<String>,
+    #[serde(default)]
+    pub last_connection_state_change: Option<String>,
+    #[serde(default)]
+    pub latest_backup_time: Option<String>,
+    /// State reported by the console (name, firmware, connection state).
+    #[serde(default)]
+    pub reported_state: Value,
+    #[serde(default)]
+    pub user_data: Value,
+}
+
+impl HostResponse {
+    fn reported(&self, key: &str) -> Option<&str> {
+        self.reported_state.get(key).and_then(Value::as_str)
+    }
+
+    /// Display name, falling back to the hostname.
+    pub fn name(&self) -> Option<&str> {
+        self.reported("name").or_else(|| self.reported("hostname"))
+    }
+
+    /// Connection state, e.g. `connected` or `disconnected`.
+    pub fn state(&self) -> Option<&str> {
+        self.reported("state")
+    }
+
+    /// Console firmware version.
+    pub fn version(&self) -> Option<&str> {
+        self.reported("version")
+    }
+}
+
+// ── Sites ────────────────────────────────────────────────────────────
+
+/// A Network site on some host — from `GET /v1/sites`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SiteResponse {
+    pub site_id: String,
+    pub host_id: String,
+    #[serde(default)]
+    pub meta: SiteMeta,
+    /// Counts and ISP info; shape varies by firmware.
+    #[serde(default)]
+    pub statistics: Value,
+    /// `admin`, `readonly`, ...
+    #[serde(default)]
+    pub permission: Option<String>,
+    #[serde(default)]
+    pub is_owner: bool,
+}
+
+/// Descriptive fields of a [`SiteResponse`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SiteMeta {
+    /// Internal site name (`default` for the first site).
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Human-readable description shown in the UI.
+    #[serde(default)]
+    pub desc: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub gateway_mac: Option<String>,
+}
+
+// ── Devices ──────────────────────────────────────────────────────────
+
+/// Devices managed by one host — from `GET /v1/devices`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HostDevicesResponse {
+    pub host_id: String,
+    #[serde(default)]
+    pub host_name: Option<String>,
+    #[serde(default)]
+    pub devices: Vec<DeviceResponse>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// A single device as seen by Site Manager.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceResponse {
+    pub id: String,
+    #[serde(default)]
+    pub mac: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default)]
+    pub shortname: Option<String>,
+    #[serde(default)]
+    pub ip: Option<String>,
+    /// `network`, `protect`, `access`, ...
+    #[serde(default)]
+    pub product_line: Option<String>,
+    /// `online`, `offline`, `updating`, ...
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub firmware_status: Option<String>,
+    #[serde(default)]
+    pub is_console: bool,
+    #[serde(default)]
+    pub is_managed: bool,
+    #[serde(default)]
+    pub startup_time: Option<String>,
+    #[serde(default)]
+    pub adoption_time: Option<String>,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+// ── ISP metrics ──────────────────────────────────────────────────────
+
+/// Sampling interval for ISP metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IspMetricInterval {
+    /// 5-minute samples, kept for 24 hours.
+    FiveMinutes,
+    /// Hourly samples, kept for 30 days.
+    Hourly,
+}
+
+impl IspMetricInterval {
+    /// Path segment used by the API (`5m` / `1h`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::FiveMinutes => "5m",
+            Self::Hourly => "1h",
+        }
+    }
+}
+
+/// WAN metrics of one site — from `GET /ea/isp-metrics/{type}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IspMetricsResponse {
+    pub metric_type: String,
+    pub host_id: String,
+    pub site_id: String,
+    #[serde(default)]
+    pub periods: Vec<IspMetricsPeriod>,
+}
+
+/// One sample in an [`IspMetricsResponse`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IspMetricsPeriod {
+    pub metric_time: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub data: IspMetricsData,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IspMetricsData {
+    #[serde(default)]
+    pub wan: Option<WanMetrics>,
+}
+
+/// WAN link quality for one sample period.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WanMetrics {
+    #[serde(default)]
+    pub avg_latency: Option<f64>,
+    #[serde(default)]
+    pub max_latency: Option<f64>,
+    #[serde(default)]
+    pub packet_loss: Option<f64>,
+    #[serde(rename = "download_kbps", default)]
+    pub download_kbps: Option<f64>,
+    #[serde(rename = "upload_kbps", default)]
+    pub upload_kbps: Option<f64>,
+    /// Uptime percentage for the period.
+    #[serde(default)]
+    pub uptime: Option<f64>,
+    /// Downtime in seconds.
+    #[serde(default)]
+    pub downtime: Option<f64>,
+    #[serde(default)]
+    pub isp_name: Option<String>,
+    #[serde(default)]
+    pub isp_asn: Option<String>,
+}
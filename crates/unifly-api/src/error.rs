@@ -3,7 +3,8 @@ use thiserror::Error;
 /// Top-level error type for the `unifly-api` crate.
 ///
 /// Covers every failure mode across all API surfaces:
-/// authentication, transport, Integration API, Legacy API, WebSocket, and cloud.
+/// authentication, transport, Integration API, Legacy API, v2 API, WebSocket,
+/// and cloud.
 /// `unifly-core` maps these into user-facing diagnostics.
 #[derive(Debug, Error)]
 pub enum Error {
@@ -50,6 +51,10 @@ pub enum Error {
     #[error("Rate limited -- retry after {retry_after_secs}s")]
     RateLimited { retry_after_secs: u64 },
 
+    /// Error response from the Site Manager API (api.ui.com).
+    #[error("Site Manager API error (HTTP {status}): {message}")]
+    SiteManager { message: String, status: u16 },
+
     // ── Integration API ─────────────────────────────────────────────
     /// Structured error from the Integration API.
     #[error("Integration API error (HTTP {status}): {message}")]
@@ -64,6 +69,15 @@ pub enum Error {
     #[error("Legacy API error: {message}")]
     LegacyApi { message: String },
 
+    // ── V2 API ──────────────────────────────────────────────────────
+    /// Error from the v2 internal API (`/v2/api/site/{site}/...`).
+    #[error("V2 API error (HTTP {status}): {message}")]
+    V2Api {
+        message: String,
+        code: Option<String>,
+        status: u16,
+    },
+
     // ── WebSocket ───────────────────────────────────────────────────
     /// WebSocket connection failed.
     #[error("WebSocket connection failed: {0}")]
@@ -104,7 +118,9 @@ impl Error {
     pub fn is_not_found(&self) -> bool {
         match self {
             Self::Transport(e) => e.status() == Some(reqwest::StatusCode::NOT_FOUND),
-            Self::Integration { status: 404, .. } => true,
+            Self::Integration { status: 404, .. }
+            | Self::SiteManager { status: 404, .. }
+            | Self::V2Api { status: 404, .. } => true,
             _ => false,
         }
     }
@@ -112,7 +128,7 @@ impl Error {
     /// Extract the API error code, if available.
     pub fn api_error_code(&self) -> Option<&str> {
         match self {
-            Self::Integration { code, .. } => code.as_deref(),
+            Self::Integration { code, .. } | Self::V2Api { code, .. } => code.as_deref(),
             _ => None,
         }
     }
@@ -6,6 +6,7 @@
 use std::future::Future;
 
 use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
 use secrecy::ExposeSecret;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
@@ -15,6 +16,7 @@ use uuid::Uuid;
 
 use super::types;
 use crate::Error;
+use crate::transport::{RetryPolicy, body_preview, rate_limited};
 
 // ── Error response shape from the Integration API ────────────────────
 
@@ -36,6 +38,7 @@ struct ErrorResponse {
 pub struct IntegrationClient {
     http: reqwest::Client,
     base_url: Url,
+    retry: RetryPolicy,
 }
 
 impl IntegrationClient {
@@ -63,17 +66,34 @@ impl IntegrationClient {
         let http = transport.build_client_with_headers(headers)?;
         let base_url = Self::normalize_base_url(base_url, platform)?;
 
-        Ok(Self { http, base_url })
+        Ok(Self {
+            http,
+            base_url,
+            retry: transport.retry.clone(),
+        })
     }
 
     /// Wrap an existing `reqwest::Client` (caller manages auth headers).
+    ///
+    /// Uses the default [`RetryPolicy`]; see [`with_retry`](Self::with_retry).
     pub fn from_reqwest(
         base_url: &str,
         http: reqwest::Client,
         platform: crate::ControllerPlatform,
     ) -> Result<Self, Error> {
         let base_url = Self::normalize_base_url(base_url, platform)?;
-        Ok(Self { http, base_url })
+        Ok(Self {
+            http,
+            base_url,
+            retry: RetryPolicy::default(),
+        })
+    }
+
+    /// Replace the retry policy.
+    #[must_use]
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
     }
 
     /// Build the base URL with correct platform prefix + `/integration/`.
@@ -112,7 +132,9 @@ impl IntegrationClient {
         let url = self.url(path);
         debug!("GET {url}");
 
-        let resp = self.http.get(url).send().await?;
+        let resp = self
+            .send(Method::GET, || self.http.get(url.clone()))
+            .await?;
         self.handle_response(resp).await
     }
 
@@ -124,7 +146,9 @@ impl IntegrationClient {
         let url = self.url(path);
         debug!("GET {url} params={params:?}");
 
-        let resp = self.http.get(url).query(params).send().await?;
+        let resp = self
+            .send(Method::GET, || self.http.get(url.clone()).query(params))
+            .await?;
         self.handle_response(resp).await
     }
 
@@ -136,7 +160,9 @@ impl IntegrationClient {
         let url = self.url(path);
         debug!("POST {url}");
 
-        let resp = self.http.post(url).json(body).send().await?;
+        let resp = self
+            .send(Method::POST, || self.http.post(url.clone()).json(body))
+            .await?;
         self.handle_response(resp).await
     }
 
@@ -148,7 +174,9 @@ impl IntegrationClient {
         let url = self.url(path);
         debug!("POST {url}");
 
-        let resp = self.http.post(url).json(body).send().await?;
+        let resp = self
+            .send(Method::POST, || self.http.post(url.clone()).json(body))
+            .await?;
         self.handle_empty(resp).await
     }
 
@@ -160,7 +188,9 @@ impl IntegrationClient {
         let url = self.url(path);
         debug!("PUT {url}");
 
-        let resp = self.http.put(url).json(body).send().await?;
+        let resp = self
+            .send(Method::PUT, || self.http.put(url.clone()).json(body))
+            .await?;
         self.handle_response(resp).await
     }
 
@@ -172,7 +202,9 @@ impl IntegrationClient {
         let url = self.url(path);
         debug!("PATCH {url}");
 
-        let resp = self.http.patch(url).json(body).send().await?;
+        let resp = self
+            .send(Method::PATCH, || self.http.patch(url.clone()).json(body))
+            .await?;
         self.handle_response(resp).await
     }
 
@@ -180,7 +212,9 @@ impl IntegrationClient {
         let url = self.url(path);
         debug!("DELETE {url}");
 
-        let resp = self.http.delete(url).send().await?;
+        let resp = self
+            .send(Method::DELETE, || self.http.delete(url.clone()))
+            .await?;
         self.handle_empty(resp).await
     }
 
@@ -188,7 +222,9 @@ impl IntegrationClient {
         let url = self.url(path);
         debug!("DELETE {url}");
 
-        let resp = self.http.delete(url).send().await?;
+        let resp = self
+            .send(Method::DELETE, || self.http.delete(url.clone()))
+            .await?;
         self.handle_response(resp).await
     }
 
@@ -200,10 +236,33 @@ impl IntegrationClient {
         let url = self.url(path);
         debug!("DELETE {url} params={params:?}");
 
-        let resp = self.http.delete(url).query(params).send().await?;
+        let resp = self
+            .send(Method::DELETE, || {
+                self.http.delete(url.clone()).query(params)
+            })
+            .await?;
         self.handle_response(resp).await
     }
 
+    /// Send a request built by `build`, retrying transient failures
+    /// (connect errors, timeouts, HTTP 429) per the retry policy.
+    async fn send(
+        &self,
+        method: Method,
+        build: impl Fn() -> RequestBuilder + Sync,
+    ) -> Result<Response, Error> {
+        let build = &build;
+        self.retry
+            .run(&method, || async move {
+                let resp = build().send().await?;
+                if resp.status() == StatusCode::TOO_MANY_REQUESTS {
+                    return Err(rate_limited(resp.headers()));
+                }
+                Ok(resp)
+            })
+            .await
+    }
+
     // ── Response handling ────────────────────────────────────────────
 
     async fn handle_response<T: DeserializeOwned>(
@@ -214,7 +273,7 @@ impl IntegrationClient {
         if status.is_success() {
             let body = resp.text().await?;
             serde_json::from_str(&body).map_err(|e| {
-                let preview = &body[..body.len().min(200)];
+                let preview = body_preview(&body);
                 Error::Deserialization {
                     message: format!("{e} (body preview: {preview:?})"),
                     body,
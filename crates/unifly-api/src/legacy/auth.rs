@@ -22,6 +22,20 @@ impl LegacyClient {
     /// - UniFi OS: `POST /api/auth/login`
     /// - Standalone: `POST /api/login`
     pub async fn login(&self, username: &str, password: &SecretString) -> Result<(), Error> {
+        self.login_with_2fa(username, password, None).await
+    }
+
+    /// Authenticate, supplying a two-factor code for accounts with MFA.
+    ///
+    /// The code goes out as `ubic_2fa_token` on standalone controllers and
+    /// as `token` on UniFi OS. Without a code, a controller that demands
+    /// one yields [`Error::TwoFactorRequired`] so the caller can prompt.
+    pub async fn login_with_2fa(
+        &self,
+        username: &str,
+        password: &SecretString,
+        token: Option<&SecretString>,
+    ) -> Result<(), Error> {
         let login_path = self
             .platform()
             .login_path()
@@ -36,10 +50,17 @@ impl LegacyClient {
 
         debug!("logging in at {}", url);
 
-        let body = json!({
+        let mut body = json!({
             "username": username,
             "password": password.expose_secret(),
         });
+        if let Some(token) = token {
+            let field = match self.platform() {
+                ControllerPlatform::UnifiOs => "token",
+                _ => "ubic_2fa_token",
+            };
+            body[field] = json!(token.expose_secret());
+        }
 
         let resp = self
             .http()
@@ -52,6 +73,9 @@ impl LegacyClient {
         let status = resp.status();
         if !status.is_success() {
             let body = resp.text().await.unwrap_or_default();
+            if token.is_none() && requests_2fa(&body) {
+                return Err(Error::TwoFactorRequired);
+            }
             return Err(Error::Authentication {
                 message: format!("login failed (HTTP {status}): {body}"),
             });
@@ -68,6 +92,7 @@ impl LegacyClient {
             self.set_csrf_token(token.to_owned());
         }
 
+        self.mark_logged_in();
         debug!("login successful");
         Ok(())
     }
@@ -145,3 +170,12 @@ impl LegacyClient {
         }
     }
 }
+
+/// Whether a failed login response asks for a second factor.
+///
+/// Standalone controllers answer `api.err.Ubic2faTokenRequired`; UniFi OS
+/// answers with an `MFA_AUTH_REQUIRED` code.
+fn requests_2fa(body: &str) -> bool {
+    let lower = body.to_ascii_lowercase();
+    lower.contains("2fatokenrequired") || lower.contains("mfa_auth_required")
+}
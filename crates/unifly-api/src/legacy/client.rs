@@ -5,9 +5,12 @@
 // (devices, clients, etc.) are implemented as inherent methods via
 // separate files to keep this module focused on transport mechanics.
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 
+use reqwest::Method;
 use reqwest::cookie::{CookieStore, Jar};
+use secrecy::SecretString;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use tracing::{debug, trace};
@@ -16,7 +19,7 @@ use url::Url;
 use crate::auth::ControllerPlatform;
 use crate::error::Error;
 use crate::legacy::models::LegacyResponse;
-use crate::transport::TransportConfig;
+use crate::transport::{RetryPolicy, TransportConfig, body_preview, rate_limited};
 
 /// UniFi OS wraps some errors as `{"error":{"code":N,"message":"..."}}` with HTTP 200.
 #[derive(serde::Deserialize)]
@@ -30,6 +33,25 @@ struct UnifiOsErrorInner {
     message: Option<String>,
 }
 
+/// Source of a fresh two-factor code for an automatic re-login.
+pub type TokenSource = Arc<dyn Fn() -> Option<SecretString> + Send + Sync>;
+
+/// Callback run with the client after an automatic re-login succeeds.
+pub type SessionHook = Arc<dyn Fn(&LegacyClient) + Send + Sync>;
+
+/// Credentials a [`LegacyClient`] keeps to renew an expired session.
+#[derive(Clone)]
+pub struct Relogin {
+    pub username: String,
+    pub password: SecretString,
+    /// Called for a two-factor code on every re-login, if the account needs
+    /// one. When it returns `None` the re-login fails with
+    /// [`Error::TwoFactorRequired`] instead of trying without a code.
+    pub token: Option<TokenSource>,
+    /// Called after every successful re-login, e.g. to save the new session.
+    pub on_renewed: Option<SessionHook>,
+}
+
 /// Raw HTTP client for the UniFi controller's legacy API.
 ///
 /// Handles the `{ data: [], meta: { rc, msg } }` envelope, site-scoped
@@ -48,6 +70,14 @@ pub struct LegacyClient {
     csrf_token: Arc<RwLock<Option<String>>>,
     /// Cookie jar reference for extracting session cookies (e.g. for WebSocket auth).
     cookie_jar: Option<Arc<Jar>>,
+    /// Credentials for renewing the session on `SessionExpired`.
+    relogin: Option<Arc<Relogin>>,
+    /// Serializes re-logins so concurrent requests hitting an expired
+    /// session trigger a single login.
+    relogin_lock: Arc<tokio::sync::Mutex<()>>,
+    /// Bumped on every successful login.
+    session_epoch: Arc<AtomicU64>,
+    retry: RetryPolicy,
 }
 
 impl LegacyClient {
@@ -77,6 +107,10 @@ impl LegacyClient {
             platform,
             csrf_token: Arc::new(RwLock::new(None)),
             cookie_jar,
+            relogin: None,
+            relogin_lock: Arc::default(),
+            session_epoch: Arc::default(),
+            retry: config.retry,
         })
     }
 
@@ -97,14 +131,37 @@ impl LegacyClient {
             platform,
             csrf_token: Arc::new(RwLock::new(None)),
             cookie_jar: None,
+            relogin: None,
+            relogin_lock: Arc::default(),
+            session_epoch: Arc::default(),
+            retry: RetryPolicy::default(),
         }
     }
 
+    /// Replace the retry policy.
+    #[must_use]
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
     /// The current site identifier.
     pub fn site(&self) -> &str {
         &self.site
     }
 
+    /// A client for another site on the same controller.
+    ///
+    /// Shares the HTTP client, cookie jar and CSRF token, so the clone is
+    /// already authenticated -- no second login is needed.
+    #[must_use]
+    pub fn with_site(&self, site: impl Into<String>) -> Self {
+        Self {
+            site: site.into(),
+            ..self.clone()
+        }
+    }
+
     /// The underlying HTTP client (for auth flows that need direct access).
     pub fn http(&self) -> &reqwest::Client {
         &self.http
@@ -130,8 +187,64 @@ impl LegacyClient {
         cookies.to_str().ok().map(String::from)
     }
 
+    /// Resume a session captured earlier with [`cookie_header`](Self::cookie_header)
+    /// and [`csrf_token`](Self::csrf_token), skipping the login.
+    ///
+    /// Requires a cookie jar (clients built with [`new`](Self::new) have one).
+    /// Whether the session is still valid only shows on the next request.
+    pub fn restore_session(&self, cookie_header: &str, csrf_token: Option<String>) {
+        if let Some(jar) = &self.cookie_jar {
+            for cookie in cookie_header
+                .split(';')
+                .map(str::trim)
+                .filter(|c| !c.is_empty())
+            {
+                jar.add_cookie_str(cookie, &self.base_url);
+            }
+        }
+        *self.csrf_token.write().expect("CSRF lock poisoned") = csrf_token;
+    }
+
+    /// Keep credentials so an expired session is renewed transparently.
+    ///
+    /// Once set, a request answered with [`Error::SessionExpired`] logs in
+    /// again and is retried once.
+    pub fn set_relogin(&mut self, relogin: Relogin) {
+        self.relogin = Some(Arc::new(relogin));
+    }
+
+    /// Record a successful login (see [`renew_session`](Self::renew_session)).
+    pub(crate) fn mark_logged_in(&self) {
+        self.session_epoch.fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Log in again after `SessionExpired`, unless another request already
+    /// did so since `seen_epoch`.
+    async fn renew_session(&self, relogin: &Relogin, seen_epoch: u64) -> Result<(), Error> {
+        let _guard = self.relogin_lock.lock().await;
+        if self.session_epoch.load(Ordering::Acquire) != seen_epoch {
+            return Ok(());
+        }
+        debug!("session expired, logging in again");
+        let token = match &relogin.token {
+            Some(source) => Some(source().ok_or(Error::TwoFactorRequired)?),
+            None => None,
+        };
+        self.login_with_2fa(&relogin.username, &relogin.password, token.as_ref())
+            .await?;
+        if let Some(hook) = &relogin.on_renewed {
+            hook(self);
+        }
+        Ok(())
+    }
+
     // ── CSRF token management ─────────────────────────────────────────
 
+    /// The current CSRF token, if the controller issued one.
+    pub fn csrf_token(&self) -> Option<String> {
+        self.csrf_token.read().expect("CSRF lock poisoned").clone()
+    }
+
     /// Store a CSRF token (captured from login response headers).
     pub(crate) fn set_csrf_token(&self, token: String) {
         debug!("storing CSRF token");
@@ -154,7 +267,7 @@ impl LegacyClient {
     }
 
     /// Apply the stored CSRF token to a request builder.
-    fn apply_csrf(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+    pub(crate) fn apply_csrf(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
         let guard = self.csrf_token.read().expect("CSRF lock poisoned");
         match guard.as_deref() {
             Some(token) => builder.header("X-CSRF-Token", token),
@@ -193,9 +306,7 @@ impl LegacyClient {
     pub(crate) async fn get<T: DeserializeOwned>(&self, url: Url) -> Result<Vec<T>, Error> {
         debug!("GET {}", url);
 
-        let resp = self.http.get(url).send().await.map_err(Error::Transport)?;
-
-        self.parse_envelope(resp).await
+        self.send(Method::GET, || self.http.get(url.clone())).await
     }
 
     /// Send a POST request with JSON body and unwrap the legacy envelope.
@@ -206,14 +317,13 @@ impl LegacyClient {
     ) -> Result<Vec<T>, Error> {
         debug!("POST {}", url);
 
-        let builder = self.apply_csrf(self.http.post(url).json(body));
-        let resp = builder.send().await.map_err(Error::Transport)?;
-
-        self.parse_envelope(resp).await
+        self.send(Method::POST, || {
+            self.apply_csrf(self.http.post(url.clone()).json(body))
+        })
+        .await
     }
 
     /// Send a PUT request with JSON body and unwrap the legacy envelope.
-    #[allow(dead_code)]
     pub(crate) async fn put<T: DeserializeOwned>(
         &self,
         url: Url,
@@ -221,88 +331,147 @@ impl LegacyClient {
     ) -> Result<Vec<T>, Error> {
         debug!("PUT {}", url);
 
-        let builder = self.apply_csrf(self.http.put(url).json(body));
-        let resp = builder.send().await.map_err(Error::Transport)?;
-
-        self.parse_envelope(resp).await
+        self.send(Method::PUT, || {
+            self.apply_csrf(self.http.put(url.clone()).json(body))
+        })
+        .await
     }
 
     /// Send a DELETE request and unwrap the legacy envelope.
-    #[allow(dead_code)]
     pub(crate) async fn delete<T: DeserializeOwned>(&self, url: Url) -> Result<Vec<T>, Error> {
         debug!("DELETE {}", url);
 
-        let builder = self.apply_csrf(self.http.delete(url));
-        let resp = builder.send().await.map_err(Error::Transport)?;
+        self.send(Method::DELETE, || {
+            self.apply_csrf(self.http.delete(url.clone()))
+        })
+        .await
+    }
 
-        self.parse_envelope(resp).await
+    /// Send a request built by `build` and unwrap the envelope.
+    async fn send<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        build: impl Fn() -> reqwest::RequestBuilder + Sync,
+    ) -> Result<Vec<T>, Error> {
+        let raw = self.send_raw(method, build).await?;
+        parse_envelope(&raw)
     }
 
-    /// Parse the `{ meta, data }` envelope, returning `data` on success
-    /// or an `Error::LegacyApi` if `meta.rc != "ok"`.
+    /// Send a request built by `build` and return the response body
+    /// undecoded, for API surfaces that don't use the legacy envelope.
     ///
-    /// Also handles UniFi OS error responses that use a different shape:
-    /// `{"error": {"code": 403, "message": "..."}}` (returned with HTTP 200).
-    async fn parse_envelope<T: DeserializeOwned>(
+    /// Transient failures are retried per the retry policy. When the
+    /// session has expired and re-login credentials are set, logs in again
+    /// and sends a freshly built request (picking up the new CSRF token).
+    pub(crate) async fn send_raw(
         &self,
-        resp: reqwest::Response,
-    ) -> Result<Vec<T>, Error> {
+        method: Method,
+        build: impl Fn() -> reqwest::RequestBuilder + Sync,
+    ) -> Result<RawResponse, Error> {
+        let epoch = self.session_epoch.load(Ordering::Acquire);
+        match (self.send_with_retry(&method, &build).await, &self.relogin) {
+            (Err(Error::SessionExpired), Some(relogin)) => {
+                self.renew_session(relogin, epoch).await?;
+                self.send_with_retry(&method, &build).await
+            }
+            (result, _) => result,
+        }
+    }
+
+    async fn send_with_retry(
+        &self,
+        method: &Method,
+        build: &(impl Fn() -> reqwest::RequestBuilder + Sync),
+    ) -> Result<RawResponse, Error> {
+        self.retry
+            .run(method, || async move {
+                let resp = build().send().await.map_err(Error::Transport)?;
+                self.read_response(resp).await
+            })
+            .await
+    }
+
+    /// Apply the session-level checks shared by every API surface and
+    /// read the body.
+    ///
+    /// Rotates the CSRF token, maps HTTP 401 (and the UniFi OS
+    /// `{"error":{"code":401}}` shape returned with HTTP 200) to
+    /// `Error::SessionExpired` and HTTP 429 to `Error::RateLimited`.
+    /// Any other status is left for the caller to interpret.
+    async fn read_response(&self, resp: reqwest::Response) -> Result<RawResponse, Error> {
         let status = resp.status();
 
         // Capture any CSRF token rotation before consuming the response.
         self.update_csrf_from_response(resp.headers());
 
         if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(Error::Authentication {
-                message: "session expired or invalid credentials".into(),
-            });
-        }
-
-        if status == reqwest::StatusCode::FORBIDDEN {
-            return Err(Error::LegacyApi {
-                message: "insufficient permissions (HTTP 403)".into(),
-            });
+            return Err(Error::SessionExpired);
         }
 
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(Error::LegacyApi {
-                message: format!("HTTP {status}: {}", &body[..body.len().min(200)]),
-            });
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(rate_limited(resp.headers()));
         }
 
         let body = resp.text().await.map_err(Error::Transport)?;
 
         // UniFi OS sometimes returns `{"error":{"code":N,"message":"..."}}` with HTTP 200.
-        if let Ok(wrapper) = serde_json::from_str::<UnifiOsError>(&body) {
-            if let Some(err) = wrapper.error {
-                let msg = err.message.unwrap_or_default();
-                return Err(if err.code == 401 {
-                    Error::Authentication { message: msg }
-                } else {
-                    Error::LegacyApi {
-                        message: format!("UniFi OS error {}: {msg}", err.code),
-                    }
-                });
+        if status.is_success() {
+            if let Ok(wrapper) = serde_json::from_str::<UnifiOsError>(&body) {
+                if let Some(err) = wrapper.error {
+                    let msg = err.message.unwrap_or_default();
+                    return Err(if err.code == 401 {
+                        Error::SessionExpired
+                    } else {
+                        Error::LegacyApi {
+                            message: format!("UniFi OS error {}: {msg}", err.code),
+                        }
+                    });
+                }
             }
         }
 
-        let envelope: LegacyResponse<T> = serde_json::from_str(&body).map_err(|e| {
-            let preview = &body[..body.len().min(200)];
-            Error::Deserialization {
-                message: format!("{e} (body preview: {preview:?})"),
-                body: body.clone(),
-            }
-        })?;
-
-        match envelope.meta.rc.as_str() {
-            "ok" => Ok(envelope.data),
-            _ => Err(Error::LegacyApi {
-                message: envelope
-                    .meta
-                    .msg
-                    .unwrap_or_else(|| format!("rc={}", envelope.meta.rc)),
-            }),
+        Ok(RawResponse { status, body })
+    }
+}
+
+/// A response that passed the session-level checks, body not yet decoded.
+pub(crate) struct RawResponse {
+    pub status: reqwest::StatusCode,
+    pub body: String,
+}
+
+/// Parse the `{ meta, data }` envelope, returning `data` on success
+/// or an `Error::LegacyApi` if `meta.rc != "ok"`.
+fn parse_envelope<T: DeserializeOwned>(raw: &RawResponse) -> Result<Vec<T>, Error> {
+    let RawResponse { status, body } = raw;
+
+    if *status == reqwest::StatusCode::FORBIDDEN {
+        return Err(Error::LegacyApi {
+            message: "insufficient permissions (HTTP 403)".into(),
+        });
+    }
+
+    if !status.is_success() {
+        return Err(Error::LegacyApi {
+            message: format!("HTTP {status}: {}", body_preview(body)),
+        });
+    }
+
+    let envelope: LegacyResponse<T> = serde_json::from_str(body).map_err(|e| {
+        let preview = body_preview(body);
+        Error::Deserialization {
+            message: format!("{e} (body preview: {preview:?})"),
+            body: body.clone(),
         }
+    })?;
+
+    match envelope.meta.rc.as_str() {
+        "ok" => Ok(envelope.data),
+        _ => Err(Error::LegacyApi {
+            message: envelope
+                .meta
+                .msg
+                .unwrap_or_else(|| format!("rc={}", envelope.meta.rc)),
+        }),
     }
 }
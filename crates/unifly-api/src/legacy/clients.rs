@@ -1,14 +1,17 @@
 // Legacy API client (station) endpoints
 //
-// Client management via stat/sta (read) and cmd/stamgr (commands).
-// Covers listing, blocking, kicking, forgetting, and guest authorization.
+// Client management via stat/sta (read), cmd/stamgr (commands) and
+// rest/user (persistent per-client settings). Covers listing, blocking,
+// kicking, forgetting, guest authorization, aliases, notes, and DHCP
+// reservations, plus known-client (stat/alluser) and connection session
+// (stat/session) history.
 
 use serde_json::json;
 use tracing::debug;
 
 use crate::error::Error;
 use crate::legacy::client::LegacyClient;
-use crate::legacy::models::LegacyClientEntry;
+use crate::legacy::models::{LegacyClientEntry, LegacySession, LegacyUserEntry};
 
 impl LegacyClient {
     /// List all currently connected clients (stations).
@@ -20,6 +23,51 @@ impl LegacyClient {
         self.get(url).await
     }
 
+    /// List every client the controller has seen, connected or not.
+    ///
+    /// `POST /api/s/{site}/stat/alluser` with `{"type": "all", "conn": "all", "within": hours}`
+    ///
+    /// `within` limits results to clients seen in the last N hours; the
+    /// controller's default (one year) applies when `None`.
+    pub async fn list_known_clients(
+        &self,
+        within: Option<u32>,
+    ) -> Result<Vec<LegacyUserEntry>, Error> {
+        let url = self.site_url("stat/alluser");
+        debug!(?within, "listing known clients");
+        let mut body = json!({ "type": "all", "conn": "all" });
+        if let Some(hours) = within {
+            body.as_object_mut()
+                .expect("json! macro always produces an object")
+                .insert("within".into(), json!(hours));
+        }
+        self.post(url, &body).await
+    }
+
+    /// List a client's connection sessions between `start` and `end`
+    /// (seconds since the epoch).
+    ///
+    /// `POST /api/s/{site}/stat/session` with `{"type": "all", "start", "end", "mac"}`
+    pub async fn list_client_sessions(
+        &self,
+        mac: &str,
+        start: i64,
+        end: i64,
+    ) -> Result<Vec<LegacySession>, Error> {
+        let url = self.site_url("stat/session");
+        debug!(mac, start, end, "listing client sessions");
+        self.post(
+            url,
+            &json!({
+                "type": "all",
+                "start": start,
+                "end": end,
+                "mac": mac,
+            }),
+        )
+        .await
+    }
+
     /// Block a client by MAC address.
     ///
     /// `POST /api/s/{site}/cmd/stamgr` with `{"cmd": "block-sta", "mac": "..."}`
@@ -152,4 +200,35 @@ impl LegacyClient {
             .await?;
         Ok(())
     }
+
+    /// Look up the persistent record for a client by MAC address.
+    ///
+    /// Works for offline clients too. Returns `None` if the controller
+    /// has never seen the MAC.
+    ///
+    /// `GET /api/s/{site}/stat/user/{mac}`
+    pub async fn get_client_user(&self, mac: &str) -> Result<Option<LegacyUserEntry>, Error> {
+        let url = self.site_url(&format!("stat/user/{mac}"));
+        debug!(mac, "fetching client user record");
+        let users: Vec<LegacyUserEntry> = self.get(url).await?;
+        Ok(users.into_iter().next())
+    }
+
+    /// Replace a client's persistent record with the whole object in
+    /// `body` (e.g. the `get_client_user` record with `name`, `note` or
+    /// `use_fixedip` / `fixed_ip` changed).
+    ///
+    /// `PUT /api/s/{site}/rest/user/{user_id}`
+    pub async fn update_client_user(
+        &self,
+        user_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<LegacyUserEntry, Error> {
+        let url = self.site_url(&format!("rest/user/{user_id}"));
+        debug!(user_id, "updating client user record");
+        let mut updated: Vec<LegacyUserEntry> = self.put(url, body).await?;
+        updated.pop().ok_or_else(|| Error::LegacyApi {
+            message: "controller returned no client record".into(),
+        })
+    }
 }
@@ -1,7 +1,9 @@
 // Legacy API device endpoints
 //
-// Device management via stat/device (read) and cmd/devmgr (commands).
-// Covers listing, adoption, restart, firmware upgrade, and LED locate.
+// Device management via stat/device (read), cmd/devmgr (commands) and
+// rest/device (configuration). Covers listing, adoption, restart,
+// firmware upgrade, LED locate, and per-device settings such as
+// port overrides.
 
 use serde_json::json;
 use tracing::debug;
@@ -136,4 +138,25 @@ impl LegacyClient {
             .await?;
         Ok(())
     }
+
+    /// Update a device's stored configuration. Only the top-level fields
+    /// present in `body` change (e.g. `port_overrides`, `radio_table`);
+    /// array fields are replaced wholesale.
+    ///
+    /// Unlike other `rest/` PUTs this one merges, so callers send just the
+    /// fields they change: the device record from `stat/device` is mostly
+    /// live state (`port_table`, `stat`, `uptime`, ...) that must not be
+    /// written back as configuration.
+    ///
+    /// `PUT /api/s/{site}/rest/device/{device_id}`
+    pub async fn update_device(
+        &self,
+        device_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<(), Error> {
+        let url = self.site_url(&format!("rest/device/{device_id}"));
+        debug!(device_id, "updating device configuration");
+        let _: Vec<serde_json::Value> = self.put(url, body).await?;
+        Ok(())
+    }
 }
@@ -1,14 +1,14 @@
 // Legacy API event and alarm endpoints
 //
 // Events (stat/event) and alarms (stat/alarm) with archive support
-// via cmd/evtmgr.
+// via cmd/evtmgr, plus threat management detections (stat/ips/event).
 
 use serde_json::json;
 use tracing::debug;
 
 use crate::error::Error;
 use crate::legacy::client::LegacyClient;
-use crate::legacy::models::{LegacyAlarm, LegacyEvent};
+use crate::legacy::models::{LegacyAlarm, LegacyEvent, LegacyIpsEvent};
 
 impl LegacyClient {
     /// List recent events.
@@ -27,6 +27,20 @@ impl LegacyClient {
         self.get(url).await
     }
 
+    /// List IDS/IPS detections between `start` and `end` (milliseconds
+    /// since the epoch).
+    ///
+    /// `POST /api/s/{site}/stat/ips/event`
+    pub async fn list_ips_events(
+        &self,
+        start: i64,
+        end: i64,
+    ) -> Result<Vec<LegacyIpsEvent>, Error> {
+        let url = self.site_url("stat/ips/event");
+        debug!(start, end, "listing IPS events");
+        self.post(url, &json!({ "start": start, "end": end })).await
+    }
+
     /// List active alarms.
     ///
     /// `GET /api/s/{site}/stat/alarm`
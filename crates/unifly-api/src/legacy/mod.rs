@@ -10,8 +10,15 @@ pub mod clients;
 pub mod devices;
 pub mod events;
 pub mod models;
+pub mod networks;
+pub mod port_forwards;
+pub mod port_profiles;
+pub mod routing;
+pub mod settings;
 pub mod sites;
 pub mod stats;
 pub mod system;
+pub mod user_groups;
+pub mod wireless;
 
-pub use client::LegacyClient;
+pub use client::{LegacyClient, Relogin, SessionHook, TokenSource};
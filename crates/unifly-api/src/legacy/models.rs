@@ -72,11 +72,138 @@ pub struct LegacyDevice {
     pub user_num_sta: Option<i32>,
     #[serde(default, rename = "guest-num_sta")]
     pub guest_num_sta: Option<i32>,
+    /// Live per-port state (switches and gateways).
+    #[serde(default)]
+    pub port_table: Vec<LegacyPortTableEntry>,
+    /// Per-port configuration overrides, written back via `rest/device`.
+    #[serde(default)]
+    pub port_overrides: Vec<LegacyPortOverride>,
+    /// Per-band radio configuration (APs), written back via `rest/device`.
+    #[serde(default)]
+    pub radio_table: Vec<LegacyRadioConfig>,
+    /// Live per-radio state (APs).
+    #[serde(default)]
+    pub radio_table_stats: Vec<LegacyRadioStats>,
     /// Catch-all for undocumented fields.
     #[serde(flatten)]
     pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+/// Live port state nested inside `LegacyDevice.port_table`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyPortTableEntry {
+    #[serde(default)]
+    pub port_idx: Option<u32>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub up: Option<bool>,
+    #[serde(default)]
+    pub enable: Option<bool>,
+    /// Negotiated link speed in Mbps.
+    #[serde(default)]
+    pub speed: Option<u32>,
+    /// Physical media, e.g. `"GE"`, `"SFP+"`.
+    #[serde(default)]
+    pub media: Option<String>,
+    /// Whether the port is PoE-capable.
+    #[serde(default)]
+    pub port_poe: Option<bool>,
+    #[serde(default)]
+    pub poe_enable: Option<bool>,
+    /// `"auto"`, `"off"`, `"pasv24"` or `"passthrough"`.
+    #[serde(default)]
+    pub poe_mode: Option<String>,
+    #[serde(default)]
+    pub poe_caps: Option<u32>,
+    #[serde(default)]
+    pub portconf_id: Option<String>,
+    #[serde(default)]
+    pub native_networkconf_id: Option<String>,
+    /// `"all"`, `"native"`, `"customize"` or `"disabled"`.
+    #[serde(default)]
+    pub forward: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// One entry of `LegacyDevice.port_overrides`.
+///
+/// The whole array is replaced on write, so unset fields are omitted
+/// rather than sent as `null` and unknown fields round-trip via `extra`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LegacyPortOverride {
+    pub port_idx: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub portconf_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub native_networkconf_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poe_mode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forward: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// One entry of `LegacyDevice.radio_table`.
+///
+/// Like `port_overrides`, the whole array is replaced on write. `channel`,
+/// `ht` and `tx_power` arrive as numbers or strings depending on firmware,
+/// so they are kept as raw JSON values.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LegacyRadioConfig {
+    /// Band: `ng` (2.4 GHz), `na` (5 GHz) or `6e`.
+    pub radio: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Channel number or `"auto"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<serde_json::Value>,
+    /// Channel width in MHz.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ht: Option<serde_json::Value>,
+    /// `"auto"`, `"high"`, `"medium"`, `"low"` or `"custom"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_power_mode: Option<String>,
+    /// Transmit power in dBm, used when `tx_power_mode` is `"custom"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_power: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_rssi_enabled: Option<bool>,
+    /// Clients below this signal (dBm) are disconnected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_rssi: Option<i32>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Live radio state nested inside `LegacyDevice.radio_table_stats`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyRadioStats {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub radio: Option<String>,
+    /// Channel in use (resolved when configured as auto).
+    #[serde(default)]
+    pub channel: Option<u32>,
+    /// Transmit power in use, in dBm.
+    #[serde(default)]
+    pub tx_power: Option<i32>,
+    /// Channel utilization, as a percentage.
+    #[serde(default)]
+    pub cu_total: Option<f64>,
+    #[serde(default)]
+    pub tx_retries: Option<f64>,
+    #[serde(default)]
+    pub tx_packets: Option<f64>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
 /// System statistics nested inside `LegacyDevice`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SysStats {
@@ -178,11 +305,153 @@ pub struct LegacyClientEntry {
     pub sw_mac: Option<String>,
     #[serde(default)]
     pub sw_port: Option<i32>,
+    /// Whether a DHCP reservation is active.
+    #[serde(default)]
+    pub use_fixedip: Option<bool>,
+    /// Reserved IP (meaningful only when `use_fixedip` is set).
+    #[serde(default)]
+    pub fixed_ip: Option<String>,
+    #[serde(default)]
+    pub note: Option<String>,
+    /// User group (`rest/usergroup` ID) the client belongs to.
+    #[serde(default)]
+    pub usergroup_id: Option<String>,
+    /// Catch-all for undocumented fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// ── Known client (user) ──────────────────────────────────────────────
+
+/// Persistent client record from `stat/user` / `rest/user` /
+/// `stat/alluser`.
+///
+/// Unlike `LegacyClientEntry` (a live station), this exists for every
+/// client the controller has ever seen and carries the user-set fields:
+/// alias, note, and DHCP reservation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyUserEntry {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub mac: String,
+    /// User-assigned alias.
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub hostname: Option<String>,
+    #[serde(default)]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub use_fixedip: Option<bool>,
+    #[serde(default)]
+    pub fixed_ip: Option<String>,
+    /// Network (`rest/networkconf` ID) the reservation belongs to.
+    #[serde(default)]
+    pub network_id: Option<String>,
+    /// User group (`rest/usergroup` ID); empty or absent means the default group.
+    #[serde(default)]
+    pub usergroup_id: Option<String>,
+    #[serde(default)]
+    pub oui: Option<String>,
+    #[serde(default)]
+    pub is_wired: Option<bool>,
+    #[serde(default)]
+    pub is_guest: Option<bool>,
+    #[serde(default)]
+    pub blocked: Option<bool>,
+    /// Unix epoch seconds.
+    #[serde(default)]
+    pub first_seen: Option<i64>,
+    /// Unix epoch seconds.
+    #[serde(default)]
+    pub last_seen: Option<i64>,
+    /// Catch-all for undocumented fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// ── Client session ───────────────────────────────────────────────────
+
+/// One connection of a client from `stat/session`: when it associated,
+/// how long it stayed, and where (AP or switch port).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacySession {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub mac: String,
+    /// Unix epoch seconds.
+    #[serde(default)]
+    pub assoc_time: Option<i64>,
+    /// Unix epoch seconds; absent while the session is still open.
+    #[serde(default)]
+    pub disassoc_time: Option<i64>,
+    /// Seconds.
+    #[serde(default)]
+    pub duration: Option<i64>,
+    #[serde(default)]
+    pub is_wired: Option<bool>,
+    #[serde(default)]
+    pub hostname: Option<String>,
+    #[serde(default)]
+    pub ip: Option<String>,
+    #[serde(default)]
+    pub ap_mac: Option<String>,
+    #[serde(default)]
+    pub essid: Option<String>,
+    #[serde(default)]
+    pub sw_mac: Option<String>,
+    #[serde(default)]
+    pub sw_port: Option<i32>,
+    #[serde(default)]
+    pub tx_bytes: Option<i64>,
+    #[serde(default)]
+    pub rx_bytes: Option<i64>,
     /// Catch-all for undocumented fields.
     #[serde(flatten)]
     pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+// ── Network config ───────────────────────────────────────────────────
+
+/// Network definition from `rest/networkconf`. Only the identifying
+/// fields are typed; everything else lands in `extra`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyNetworkConf {
+    #[serde(rename = "_id")]
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub purpose: Option<String>,
+    #[serde(default)]
+    pub vlan: Option<u16>,
+    /// Gateway address with prefix, e.g. `192.168.1.1/24`.
+    #[serde(default)]
+    pub ip_subnet: Option<String>,
+    /// Catch-all for undocumented fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// ── Port profile ─────────────────────────────────────────────────────
+
+/// Switch port profile from `rest/portconf`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyPortConf {
+    #[serde(rename = "_id")]
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub forward: Option<String>,
+    #[serde(default)]
+    pub native_networkconf_id: Option<String>,
+    #[serde(default)]
+    pub poe_mode: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
 // ── Site ─────────────────────────────────────────────────────────────
 
 /// Site object from `/api/self/sites`.
@@ -222,6 +491,49 @@ pub struct LegacyEvent {
     pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+// ── IPS event ────────────────────────────────────────────────────────
+
+/// Threat management (IDS/IPS) detection from `stat/ips/event`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyIpsEvent {
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Milliseconds since the epoch.
+    #[serde(default)]
+    pub timestamp: Option<i64>,
+    #[serde(default)]
+    pub src_ip: Option<String>,
+    #[serde(default)]
+    pub src_port: Option<u16>,
+    #[serde(default)]
+    pub src_mac: Option<String>,
+    #[serde(default, rename = "srcipCountry")]
+    pub src_country: Option<String>,
+    #[serde(default)]
+    pub dest_ip: Option<String>,
+    #[serde(default)]
+    pub dest_port: Option<u16>,
+    #[serde(default, rename = "dstipCountry")]
+    pub dest_country: Option<String>,
+    #[serde(default)]
+    pub proto: Option<String>,
+    #[serde(default)]
+    pub inner_alert_signature: Option<String>,
+    #[serde(default)]
+    pub inner_alert_signature_id: Option<u64>,
+    #[serde(default)]
+    pub inner_alert_category: Option<String>,
+    /// `allowed` or `blocked`.
+    #[serde(default)]
+    pub inner_alert_action: Option<String>,
+    /// 1 (high) to 3 (low).
+    #[serde(default)]
+    pub inner_alert_severity: Option<u8>,
+    /// Catch-all for undocumented fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
 // ── Alarm ────────────────────────────────────────────────────────────
 
 /// Alarm object from `stat/alarm`.
@@ -241,3 +553,227 @@ pub struct LegacyAlarm {
     #[serde(flatten)]
     pub extra: serde_json::Map<String, serde_json::Value>,
 }
+
+// ── Site setting ─────────────────────────────────────────────────────
+
+/// One section of the site settings from `get/setting`, identified by
+/// `key` (`ntp`, `mgmt`, `guest_access`, ...). The section's fields vary
+/// by key and controller version, so they all land in `fields`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacySetting {
+    #[serde(rename = "_id", default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub key: String,
+    #[serde(flatten)]
+    pub fields: serde_json::Map<String, serde_json::Value>,
+}
+
+// ── User group ───────────────────────────────────────────────────────
+
+/// Bandwidth profile from `rest/usergroup`. Rates are in Kbps; `-1`
+/// means unlimited.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyUserGroup {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub qos_rate_max_down: Option<i64>,
+    #[serde(default)]
+    pub qos_rate_max_up: Option<i64>,
+    /// Catch-all for undocumented fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// ── Port Forward ─────────────────────────────────────────────────────
+
+/// Port-forward rule from `rest/portforward`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyPortForward {
+    #[serde(rename = "_id")]
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// `tcp`, `udp`, or `tcp_udp`.
+    #[serde(default)]
+    pub proto: Option<String>,
+    /// WAN interface: `wan`, `wan2`, or `both`.
+    #[serde(default)]
+    pub pfwd_interface: Option<String>,
+    /// Allowed source address/CIDR, or `any`.
+    #[serde(default)]
+    pub src: Option<String>,
+    /// External port or range (e.g. `8080`, `5000-5010`).
+    #[serde(default)]
+    pub dst_port: Option<String>,
+    /// Internal forward target IP.
+    #[serde(default)]
+    pub fwd: Option<String>,
+    /// Internal forward port or range.
+    #[serde(default)]
+    pub fwd_port: Option<String>,
+    #[serde(default)]
+    pub log: Option<bool>,
+    /// Catch-all for undocumented fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// ── Static Route ─────────────────────────────────────────────────────
+
+/// Routing entry from `rest/routing`. Static routes have
+/// `type == "static-route"`; field names carry a `static-route_` prefix.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyStaticRoute {
+    #[serde(rename = "_id")]
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// Entry type, `static-route` for static routes.
+    #[serde(rename = "type", default)]
+    pub route_kind: Option<String>,
+    /// `nexthop-route`, `interface-route`, or `blackhole`.
+    #[serde(rename = "static-route_type", default)]
+    pub static_route_type: Option<String>,
+    /// Destination network in CIDR notation.
+    #[serde(rename = "static-route_network", default)]
+    pub static_route_network: Option<String>,
+    /// Gateway IP for `nexthop-route` entries.
+    #[serde(rename = "static-route_nexthop", default)]
+    pub static_route_nexthop: Option<String>,
+    /// Egress interface (e.g. `WAN1`) for `interface-route` entries.
+    #[serde(rename = "static-route_interface", default)]
+    pub static_route_interface: Option<String>,
+    /// Administrative distance.
+    #[serde(rename = "static-route_distance", default)]
+    pub static_route_distance: Option<u32>,
+    /// Catch-all for undocumented fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// ── Rogue / neighbor AP ──────────────────────────────────────────────
+
+/// Foreign access point heard by one of our APs, from `stat/rogueap`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyRogueAp {
+    pub bssid: String,
+    #[serde(default)]
+    pub essid: Option<String>,
+    #[serde(default)]
+    pub channel: Option<u32>,
+    /// Centre frequency in MHz.
+    #[serde(default)]
+    pub freq: Option<u32>,
+    /// Radio band: `ng` (2.4 GHz), `na` (5 GHz) or `6e`.
+    #[serde(default)]
+    pub radio: Option<String>,
+    /// Signal above the noise floor, in dB.
+    #[serde(default)]
+    pub rssi: Option<i32>,
+    /// Received signal strength in dBm.
+    #[serde(default)]
+    pub signal: Option<i32>,
+    #[serde(default)]
+    pub security: Option<String>,
+    /// MAC of our AP that heard it.
+    #[serde(default)]
+    pub ap_mac: Option<String>,
+    /// Vendor OUI name.
+    #[serde(default)]
+    pub oui: Option<String>,
+    /// Set when the BSSID was also seen on our wired network.
+    #[serde(default)]
+    pub is_rogue: Option<bool>,
+    #[serde(default)]
+    pub is_ubnt: Option<bool>,
+    /// Epoch seconds.
+    #[serde(default)]
+    pub last_seen: Option<i64>,
+    /// Catch-all for undocumented fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// ── Spectrum scan ────────────────────────────────────────────────────
+
+/// Spectrum scan state and results for one AP, from `stat/spectrum-scan/{mac}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacySpectrumScan {
+    #[serde(default)]
+    pub mac: Option<String>,
+    /// `true` while a scan is still running.
+    #[serde(default)]
+    pub spectrum_scanning: Option<bool>,
+    /// One entry per scanned channel; empty until a scan has completed.
+    #[serde(default)]
+    pub spectrum_table: Vec<LegacySpectrumEntry>,
+    /// Catch-all for undocumented fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Per-channel measurement within a spectrum scan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacySpectrumEntry {
+    pub channel: u32,
+    /// Centre frequency in MHz.
+    #[serde(default)]
+    pub freq: Option<u32>,
+    /// Channel width in MHz.
+    #[serde(default)]
+    pub width: Option<u32>,
+    /// Airtime in use, as a percentage.
+    #[serde(default)]
+    pub utilization: Option<f64>,
+    /// Airtime lost to non-WiFi interference, as a percentage.
+    #[serde(default)]
+    pub interference: Option<f64>,
+    /// Interference sources the AP classified (e.g. `microwave`, `bluetooth`).
+    #[serde(default)]
+    pub interference_type: Vec<String>,
+    /// Catch-all for undocumented fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// ── Regulatory channels ──────────────────────────────────────────────
+
+/// Channels the site's configured country allows, from `stat/current-channel`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegacyChannelPlan {
+    /// ISO country code, e.g. `US`.
+    #[serde(default)]
+    pub key: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// 2.4 GHz channels.
+    #[serde(default)]
+    pub channels_ng: Vec<u32>,
+    /// 2.4 GHz channels usable at 40 MHz.
+    #[serde(default)]
+    pub channels_ng_40: Vec<u32>,
+    /// 5 GHz channels.
+    #[serde(default)]
+    pub channels_na: Vec<u32>,
+    /// 5 GHz DFS channels.
+    #[serde(default)]
+    pub channels_na_dfs: Vec<u32>,
+    #[serde(default)]
+    pub channels_na_40: Vec<u32>,
+    #[serde(default)]
+    pub channels_na_80: Vec<u32>,
+    #[serde(default)]
+    pub channels_na_160: Vec<u32>,
+    /// 6 GHz channels.
+    #[serde(default)]
+    pub channels_6e: Vec<u32>,
+    /// Catch-all for undocumented fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
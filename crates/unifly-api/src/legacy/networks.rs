@@ -0,0 +1,22 @@
+// Legacy API network configuration endpoints
+//
+// Read-only access to rest/networkconf. Network management goes through
+// the Integration API; this exists because Legacy endpoints that take a
+// `network_id` (e.g. DHCP reservations) expect the networkconf `_id`.
+
+use tracing::debug;
+
+use crate::error::Error;
+use crate::legacy::client::LegacyClient;
+use crate::legacy::models::LegacyNetworkConf;
+
+impl LegacyClient {
+    /// List all network definitions.
+    ///
+    /// `GET /api/s/{site}/rest/networkconf`
+    pub async fn list_network_conf(&self) -> Result<Vec<LegacyNetworkConf>, Error> {
+        let url = self.site_url("rest/networkconf");
+        debug!("listing network configuration");
+        self.get(url).await
+    }
+}
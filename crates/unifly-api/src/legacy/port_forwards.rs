@@ -0,0 +1,85 @@
+// Legacy API port-forward endpoints
+//
+// CRUD on rest/portforward. Port forwards have no Integration API
+// equivalent, so these are the only way to manage them.
+
+use tracing::debug;
+
+use crate::error::Error;
+use crate::legacy::client::LegacyClient;
+use crate::legacy::models::LegacyPortForward;
+
+impl LegacyClient {
+    /// List all port-forward rules.
+    ///
+    /// `GET /api/s/{site}/rest/portforward`
+    pub async fn list_port_forwards(&self) -> Result<Vec<LegacyPortForward>, Error> {
+        let url = self.site_url("rest/portforward");
+        debug!("listing port forwards");
+        self.get(url).await
+    }
+
+    /// Create a port-forward rule.
+    ///
+    /// `POST /api/s/{site}/rest/portforward`
+    pub async fn create_port_forward(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<LegacyPortForward, Error> {
+        let url = self.site_url("rest/portforward");
+        debug!("creating port forward");
+        let mut created: Vec<LegacyPortForward> = self.post(url, body).await?;
+        created.pop().ok_or_else(|| Error::LegacyApi {
+            message: "controller returned no port forward".into(),
+        })
+    }
+
+    /// Replace a port-forward rule with `body`. The controller takes the
+    /// whole object, so start from the current rule and change fields on it.
+    ///
+    /// `PUT /api/s/{site}/rest/portforward/{id}`
+    pub async fn update_port_forward(
+        &self,
+        id: &str,
+        body: &serde_json::Value,
+    ) -> Result<LegacyPortForward, Error> {
+        let url = self.site_url(&format!("rest/portforward/{id}"));
+        debug!(id, "updating port forward");
+        let mut updated: Vec<LegacyPortForward> = self.put(url, body).await?;
+        updated.pop().ok_or_else(|| Error::LegacyApi {
+            message: "controller returned no port forward".into(),
+        })
+    }
+
+    /// Enable or disable a port-forward rule.
+    ///
+    /// Re-sends the current rule with `enabled` changed, since a PUT
+    /// replaces the whole object.
+    ///
+    /// `PUT /api/s/{site}/rest/portforward/{id}`
+    pub async fn set_port_forward_enabled(&self, id: &str, enabled: bool) -> Result<(), Error> {
+        let mut rule = self
+            .list_port_forwards()
+            .await?
+            .into_iter()
+            .find(|p| p.id == id)
+            .ok_or_else(|| Error::LegacyApi {
+                message: format!("port forward {id} not found"),
+            })?;
+        rule.enabled = Some(enabled);
+        let url = self.site_url(&format!("rest/portforward/{id}"));
+        debug!(id, enabled, "toggling port forward");
+        let _: Vec<serde_json::Value> = self.put(url, &rule).await?;
+        Ok(())
+    }
+
+    /// Delete a port-forward rule.
+    ///
+    /// `DELETE /api/s/{site}/rest/portforward/{id}`
+    pub async fn delete_port_forward(&self, id: &str) -> Result<(), Error> {
+        let url = self.site_url(&format!("rest/portforward/{id}"));
+        debug!(id, "deleting port forward");
+        let _: Vec<serde_json::Value> = self.delete(url).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+// Legacy API switch port profile endpoints
+//
+// Read-only access to rest/portconf. Profiles are assigned to individual
+// ports through `port_overrides` on the device (see `update_device`).
+
+use tracing::debug;
+
+use crate::error::Error;
+use crate::legacy::client::LegacyClient;
+use crate::legacy::models::LegacyPortConf;
+
+impl LegacyClient {
+    /// List all switch port profiles.
+    ///
+    /// `GET /api/s/{site}/rest/portconf`
+    pub async fn list_port_profiles(&self) -> Result<Vec<LegacyPortConf>, Error> {
+        let url = self.site_url("rest/portconf");
+        debug!("listing port profiles");
+        self.get(url).await
+    }
+}
@@ -0,0 +1,62 @@
+// Legacy API routing endpoints
+//
+// CRUD on rest/routing, which holds the gateway's user-defined static
+// routes. There is no Integration API equivalent.
+
+use tracing::debug;
+
+use crate::error::Error;
+use crate::legacy::client::LegacyClient;
+use crate::legacy::models::LegacyStaticRoute;
+
+impl LegacyClient {
+    /// List all static routes.
+    ///
+    /// `GET /api/s/{site}/rest/routing`
+    pub async fn list_static_routes(&self) -> Result<Vec<LegacyStaticRoute>, Error> {
+        let url = self.site_url("rest/routing");
+        debug!("listing static routes");
+        self.get(url).await
+    }
+
+    /// Create a static route.
+    ///
+    /// `POST /api/s/{site}/rest/routing`
+    pub async fn create_static_route(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<LegacyStaticRoute, Error> {
+        let url = self.site_url("rest/routing");
+        debug!("creating static route");
+        let mut created: Vec<LegacyStaticRoute> = self.post(url, body).await?;
+        created.pop().ok_or_else(|| Error::LegacyApi {
+            message: "controller returned no static route".into(),
+        })
+    }
+
+    /// Replace a static route with the whole object in `body`.
+    ///
+    /// `PUT /api/s/{site}/rest/routing/{id}`
+    pub async fn update_static_route(
+        &self,
+        id: &str,
+        body: &serde_json::Value,
+    ) -> Result<LegacyStaticRoute, Error> {
+        let url = self.site_url(&format!("rest/routing/{id}"));
+        debug!(id, "updating static route");
+        let mut updated: Vec<LegacyStaticRoute> = self.put(url, body).await?;
+        updated.pop().ok_or_else(|| Error::LegacyApi {
+            message: "controller returned no static route".into(),
+        })
+    }
+
+    /// Delete a static route.
+    ///
+    /// `DELETE /api/s/{site}/rest/routing/{id}`
+    pub async fn delete_static_route(&self, id: &str) -> Result<(), Error> {
+        let url = self.site_url(&format!("rest/routing/{id}"));
+        debug!(id, "deleting static route");
+        let _: Vec<serde_json::Value> = self.delete(url).await?;
+        Ok(())
+    }
+}
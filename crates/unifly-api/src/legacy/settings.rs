@@ -0,0 +1,40 @@
+// Legacy API site-settings endpoints
+//
+// Site-wide configuration (NTP, country, SNMP, syslog, management,
+// guest access, ...) lives in one document per section key. Reads return
+// every section at once; writes replace a single section.
+
+use tracing::debug;
+
+use crate::error::Error;
+use crate::legacy::client::LegacyClient;
+use crate::legacy::models::LegacySetting;
+
+impl LegacyClient {
+    /// Fetch every settings section for the site.
+    ///
+    /// `GET /api/s/{site}/get/setting`
+    pub async fn get_settings(&self) -> Result<Vec<LegacySetting>, Error> {
+        let url = self.site_url("get/setting");
+        debug!("fetching site settings");
+        self.get(url).await
+    }
+
+    /// Replace one settings section. `body` should be the full section
+    /// as returned by [`get_settings`](Self::get_settings), with changes
+    /// applied.
+    ///
+    /// `POST /api/s/{site}/set/setting/{key}`
+    pub async fn set_setting(
+        &self,
+        key: &str,
+        body: &serde_json::Value,
+    ) -> Result<LegacySetting, Error> {
+        let url = self.site_url(&format!("set/setting/{key}"));
+        debug!(key, "updating site setting");
+        let mut updated: Vec<LegacySetting> = self.post(url, body).await?;
+        updated.pop().ok_or_else(|| Error::LegacyApi {
+            message: format!("controller returned no '{key}' setting"),
+        })
+    }
+}
@@ -0,0 +1,62 @@
+// Legacy API user-group endpoints
+//
+// CRUD on rest/usergroup. User groups cap per-client download and upload
+// rates; clients join one through the `usergroup_id` on their user record.
+
+use tracing::debug;
+
+use crate::error::Error;
+use crate::legacy::client::LegacyClient;
+use crate::legacy::models::LegacyUserGroup;
+
+impl LegacyClient {
+    /// List all user groups, including the built-in `Default` group.
+    ///
+    /// `GET /api/s/{site}/rest/usergroup`
+    pub async fn list_user_groups(&self) -> Result<Vec<LegacyUserGroup>, Error> {
+        let url = self.site_url("rest/usergroup");
+        debug!("listing user groups");
+        self.get(url).await
+    }
+
+    /// Create a user group.
+    ///
+    /// `POST /api/s/{site}/rest/usergroup`
+    pub async fn create_user_group(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<LegacyUserGroup, Error> {
+        let url = self.site_url("rest/usergroup");
+        debug!("creating user group");
+        let mut created: Vec<LegacyUserGroup> = self.post(url, body).await?;
+        created.pop().ok_or_else(|| Error::LegacyApi {
+            message: "controller returned no user group".into(),
+        })
+    }
+
+    /// Replace a user group with the whole object in `body`.
+    ///
+    /// `PUT /api/s/{site}/rest/usergroup/{id}`
+    pub async fn update_user_group(
+        &self,
+        id: &str,
+        body: &serde_json::Value,
+    ) -> Result<LegacyUserGroup, Error> {
+        let url = self.site_url(&format!("rest/usergroup/{id}"));
+        debug!(id, "updating user group");
+        let mut updated: Vec<LegacyUserGroup> = self.put(url, body).await?;
+        updated.pop().ok_or_else(|| Error::LegacyApi {
+            message: "controller returned no user group".into(),
+        })
+    }
+
+    /// Delete a user group. Its clients fall back to the default group.
+    ///
+    /// `DELETE /api/s/{site}/rest/usergroup/{id}`
+    pub async fn delete_user_group(&self, id: &str) -> Result<(), Error> {
+        let url = self.site_url(&format!("rest/usergroup/{id}"));
+        debug!(id, "deleting user group");
+        let _: Vec<serde_json::Value> = self.delete(url).await?;
+        Ok(())
+    }
+}
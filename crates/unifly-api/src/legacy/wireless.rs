@@ -0,0 +1,68 @@
+// Legacy API wireless survey endpoints
+//
+// Neighboring and rogue access points heard by our APs (stat/rogueap),
+// RF spectrum scans run on AP radios (cmd/devmgr, stat/spectrum-scan) and
+// the regulatory channel plan for the site's country (stat/current-channel).
+
+use serde_json::json;
+use tracing::debug;
+
+use crate::error::Error;
+use crate::legacy::client::LegacyClient;
+use crate::legacy::models::{LegacyChannelPlan, LegacyRogueAp, LegacySpectrumScan};
+
+impl LegacyClient {
+    /// List foreign access points heard by the site's APs.
+    ///
+    /// `POST /api/s/{site}/stat/rogueap` with `{"within": hours}`
+    ///
+    /// `within` limits results to APs seen in the last N hours; the
+    /// controller's default (24) applies when `None`.
+    pub async fn list_rogue_aps(&self, within: Option<u32>) -> Result<Vec<LegacyRogueAp>, Error> {
+        let url = self.site_url("stat/rogueap");
+        debug!(?within, "listing rogue aps");
+        let body = within.map_or_else(|| json!({}), |hours| json!({ "within": hours }));
+        self.post(url, &body).await
+    }
+
+    /// Start an RF spectrum scan on an AP. The AP stops serving clients
+    /// on the scanned radios for a few minutes while it runs.
+    ///
+    /// `POST /api/s/{site}/cmd/devmgr` with `{"cmd": "spectrum-scan", "mac": "..."}`
+    pub async fn start_spectrum_scan(&self, mac: &str) -> Result<(), Error> {
+        let url = self.site_url("cmd/devmgr");
+        debug!(mac, "starting spectrum scan");
+        let _: Vec<serde_json::Value> = self
+            .post(
+                url,
+                &json!({
+                    "cmd": "spectrum-scan",
+                    "mac": mac.to_lowercase(),
+                }),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Fetch the state and latest results of an AP's spectrum scan.
+    ///
+    /// `GET /api/s/{site}/stat/spectrum-scan/{mac}`
+    ///
+    /// Returns `None` if the controller has no scan record for the AP.
+    pub async fn get_spectrum_scan(&self, mac: &str) -> Result<Option<LegacySpectrumScan>, Error> {
+        let url = self.site_url(&format!("stat/spectrum-scan/{}", mac.to_lowercase()));
+        debug!(mac, "fetching spectrum scan results");
+        let scans: Vec<LegacySpectrumScan> = self.get(url).await?;
+        Ok(scans.into_iter().next())
+    }
+
+    /// Fetch the channels allowed by the site's configured country.
+    ///
+    /// `GET /api/s/{site}/stat/current-channel`
+    pub async fn get_channel_plan(&self) -> Result<Option<LegacyChannelPlan>, Error> {
+        let url = self.site_url("stat/current-channel");
+        debug!("fetching regulatory channel plan");
+        let plans: Vec<LegacyChannelPlan> = self.get(url).await?;
+        Ok(plans.into_iter().next())
+    }
+}
@@ -1,7 +1,7 @@
 //! Async Rust client for UniFi controller APIs.
 //!
 //! This crate provides the HTTP transport layer for communicating with UniFi
-//! Network controllers. It supports two distinct API surfaces:
+//! Network controllers. It supports four distinct API surfaces:
 //!
 //! - **Integration API** ([`IntegrationClient`]) — RESTful OpenAPI-based interface
 //!   authenticated via `X-API-KEY` header. Primary surface for CRUD operations on
@@ -11,7 +11,15 @@
 //!   `/api/s/{site}/`. Used for data not yet exposed by the Integration API: events,
 //!   traffic stats, admin users, DPI data, system info, and real-time WebSocket events.
 //!
-//! Both clients share a common [`TransportConfig`] for reqwest-based HTTP transport
+//! - **V2 API** ([`V2Client`]) — Internal endpoints under `/v2/api/site/{site}/`,
+//!   sharing the legacy session. Newer features (traffic rules and routes, content
+//!   filtering, system logs) exist only here. Responses are bare JSON.
+//!
+//! - **Site Manager API** ([`SiteManagerClient`]) — Cloud API at `api.ui.com`,
+//!   authenticated with a cloud API key. Lists the hosts, sites and devices the
+//!   key can reach; hosts are then reachable through the cloud connector proxy.
+//!
+//! All clients share a common [`TransportConfig`] for reqwest-based HTTP transport
 //! with configurable TLS ([`TlsMode`]: system CA, custom PEM, or danger-accept for
 //! self-signed controllers) and timeout settings.
 //!
@@ -20,16 +28,21 @@
 //! responses into canonical domain types.
 
 pub mod auth;
+pub mod cloud;
 pub mod error;
 pub mod integration;
 pub mod legacy;
 pub mod transport;
+pub mod v2;
 pub mod websocket;
 
 pub use auth::{AuthStrategy, ControllerPlatform, Credentials};
+pub use cloud::SiteManagerClient;
 pub use error::Error;
 pub use integration::IntegrationClient;
 pub use integration::types as integration_types;
 pub use legacy::LegacyClient;
 pub use legacy::models as legacy_models;
-pub use transport::{TlsMode, TransportConfig};
+pub use transport::{RetryPolicy, TlsMode, TransportConfig};
+pub use v2::V2Client;
+pub use v2::models as v2_models;
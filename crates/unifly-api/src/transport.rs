@@ -1,13 +1,19 @@
 // Shared transport configuration for building reqwest::Client instances.
 //
-// Both Legacy and Integration clients share TLS, timeout, and cookie
-// settings through this module, avoiding duplicated builder logic.
+// Both Legacy and Integration clients share TLS, timeout, cookie and
+// retry settings through this module, avoiding duplicated builder logic.
 
+use std::future::Future;
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use reqwest::Method;
 use reqwest::cookie::Jar;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use tracing::debug;
+
+use crate::error::Error;
 
 /// TLS verification mode (api-level mirror of core's TlsVerification).
 #[derive(Debug, Clone)]
@@ -20,12 +26,132 @@ pub enum TlsMode {
     DangerAcceptInvalid,
 }
 
+/// Retry policy for transient HTTP failures (see [`Error::is_transient`]).
+///
+/// Exponential backoff with jitter; a rate-limited response waits at least
+/// as long as the server's `Retry-After`, up to `max_delay`. Only safe
+/// methods (GET, HEAD) are retried unless `retry_writes` is set.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; 0 disables retrying. Default: 3.
+    pub max_retries: u32,
+
+    /// Delay before the first retry, doubling for each one after. Default: 500ms.
+    pub initial_delay: Duration,
+
+    /// Upper bound on the backoff delay. Default: 10s.
+    pub max_delay: Duration,
+
+    /// Also retry POST/PUT/PATCH/DELETE. Only safe when repeating a write
+    /// that may have reached the controller is harmless. Default: false.
+    pub retry_writes: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            retry_writes: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn disabled() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Whether requests with `method` are retried at all.
+    pub fn applies_to(&self, method: &Method) -> bool {
+        self.max_retries > 0 && (method.is_safe() || self.retry_writes)
+    }
+
+    /// Delay before retry number `attempt` (0-based) after `error`.
+    ///
+    /// `delay = min(initial * 2^attempt, max)`, half of it randomized so
+    /// concurrent clients spread out; never shorter than `Retry-After`
+    /// unless that exceeds `max`.
+    pub fn delay(&self, attempt: u32, error: &Error) -> Duration {
+        let base = self
+            .initial_delay
+            .saturating_mul(2_u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let half = base / 2;
+        let backoff = half + half.mul_f64(jitter());
+        match error {
+            Error::RateLimited { retry_after_secs } => {
+                backoff.max(Duration::from_secs(*retry_after_secs).min(self.max_delay))
+            }
+            _ => backoff,
+        }
+    }
+
+    /// Run `op`, retrying transient failures according to this policy.
+    pub async fn run<T, F, Fut>(&self, method: &Method, mut op: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match op().await {
+                Err(e)
+                    if e.is_transient()
+                        && attempt < self.max_retries
+                        && self.applies_to(method) =>
+                {
+                    let delay = self.delay(attempt, &e);
+                    attempt += 1;
+                    debug!(%method, attempt, ?delay, error = %e, "retrying transient failure");
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// A fraction in `[0, 1)` taken from the clock's sub-second nanos.
+/// Not random in any strong sense, but enough to de-synchronize retries,
+/// even on clocks with only microsecond resolution.
+fn jitter() -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.subsec_nanos());
+    f64::from(nanos) / 1_000_000_000.0
+}
+
+/// The first 200 characters of a response body, for error messages.
+pub(crate) fn body_preview(body: &str) -> &str {
+    body.char_indices()
+        .nth(200)
+        .map_or(body, |(end, _)| &body[..end])
+}
+
+/// Build the error for an HTTP 429 response from its `Retry-After` header
+/// (delay in seconds; the HTTP-date form counts as no hint).
+pub(crate) fn rate_limited(headers: &HeaderMap) -> Error {
+    let retry_after_secs = headers
+        .get(RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(0);
+    Error::RateLimited { retry_after_secs }
+}
+
 /// Shared transport configuration for building HTTP clients.
 #[derive(Debug, Clone)]
 pub struct TransportConfig {
     pub tls: TlsMode,
     pub timeout: Duration,
     pub cookie_jar: Option<Arc<Jar>>,
+    pub retry: RetryPolicy,
 }
 
 impl Default for TransportConfig {
@@ -34,6 +160,7 @@ impl Default for TransportConfig {
             tls: TlsMode::DangerAcceptInvalid,
             timeout: Duration::from_secs(30),
             cookie_jar: None,
+            retry: RetryPolicy::default(),
         }
     }
 }
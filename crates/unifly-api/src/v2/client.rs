@@ -0,0 +1,172 @@
+// V2 API HTTP client
+//
+// Thin layer over `LegacyClient`: the v2 endpoints sit behind the same
+// session cookie, CSRF token and platform prefix, so requests go through
+// the legacy transport (retry, re-login, CSRF rotation) and only the body
+// decoding differs. V2 responses are plain JSON with no envelope.
+
+use reqwest::Method;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tracing::debug;
+use url::Url;
+
+use crate::error::Error;
+use crate::legacy::LegacyClient;
+use crate::legacy::client::RawResponse;
+use crate::transport::body_preview;
+
+/// Error body returned by v2 endpoints with a non-2xx status:
+/// `{"errorCode": 400, "code": "api.err.InvalidPayload", "message": "..."}`.
+#[derive(serde::Deserialize)]
+struct V2ErrorBody {
+    #[serde(default)]
+    code: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+/// Raw HTTP client for the controller's v2 internal API.
+///
+/// Built from an authenticated [`LegacyClient`] and shares its session,
+/// CSRF token and retry policy -- log in through the legacy client first.
+/// Site-scoped paths resolve to `{base}{prefix}/v2/api/site/{site}/{path}`.
+#[derive(Clone)]
+pub struct V2Client {
+    legacy: LegacyClient,
+}
+
+impl V2Client {
+    /// Wrap a legacy client. The two stay in sync: a re-login or CSRF
+    /// rotation on either is seen by both.
+    pub fn new(legacy: LegacyClient) -> Self {
+        Self { legacy }
+    }
+
+    /// The legacy client carrying the session.
+    pub fn legacy(&self) -> &LegacyClient {
+        &self.legacy
+    }
+
+    /// The current site identifier.
+    pub fn site(&self) -> &str {
+        self.legacy.site()
+    }
+
+    /// A client for another site on the same controller, sharing the session.
+    #[must_use]
+    pub fn with_site(&self, site: impl Into<String>) -> Self {
+        Self {
+            legacy: self.legacy.with_site(site),
+        }
+    }
+
+    // ── URL builders ─────────────────────────────────────────────────
+
+    /// Build a site-scoped URL: `{base}{prefix}/v2/api/site/{site}/{path}`
+    pub(crate) fn site_url(&self, path: &str) -> Url {
+        let prefix = self.legacy.platform().legacy_prefix().unwrap_or("");
+        let base = self.legacy.base_url().as_str().trim_end_matches('/');
+        let prefix = prefix.trim_end_matches('/');
+        let full = format!("{base}{prefix}/v2/api/site/{}/{path}", self.site());
+        Url::parse(&full).expect("invalid v2 URL")
+    }
+
+    // ── Request helpers ──────────────────────────────────────────────
+
+    /// Send a GET request and decode the JSON body.
+    pub(crate) async fn get<T: DeserializeOwned>(&self, url: Url) -> Result<T, Error> {
+        debug!("GET {}", url);
+
+        let http = self.legacy.http();
+        let raw = self
+            .legacy
+            .send_raw(Method::GET, || http.get(url.clone()))
+            .await?;
+        decode(&raw)
+    }
+
+    /// Send a POST request with JSON body and decode the JSON response.
+    pub(crate) async fn post<T: DeserializeOwned>(
+        &self,
+        url: Url,
+        body: &(impl Serialize + Sync),
+    ) -> Result<T, Error> {
+        debug!("POST {}", url);
+
+        let http = self.legacy.http();
+        let raw = self
+            .legacy
+            .send_raw(Method::POST, || {
+                self.legacy.apply_csrf(http.post(url.clone()).json(body))
+            })
+            .await?;
+        decode(&raw)
+    }
+
+    /// Send a PUT request with JSON body and decode the JSON response.
+    pub(crate) async fn put<T: DeserializeOwned>(
+        &self,
+        url: Url,
+        body: &(impl Serialize + Sync),
+    ) -> Result<T, Error> {
+        debug!("PUT {}", url);
+
+        let http = self.legacy.http();
+        let raw = self
+            .legacy
+            .send_raw(Method::PUT, || {
+                self.legacy.apply_csrf(http.put(url.clone()).json(body))
+            })
+            .await?;
+        decode(&raw)
+    }
+
+    /// Send a DELETE request. The response body, often empty, is ignored.
+    pub(crate) async fn delete(&self, url: Url) -> Result<(), Error> {
+        debug!("DELETE {}", url);
+
+        let http = self.legacy.http();
+        let raw = self
+            .legacy
+            .send_raw(Method::DELETE, || {
+                self.legacy.apply_csrf(http.delete(url.clone()))
+            })
+            .await?;
+        check_status(&raw)
+    }
+}
+
+/// Map a non-2xx response to `Error::V2Api`, using the v2 error body
+/// when the controller sent one.
+fn check_status(raw: &RawResponse) -> Result<(), Error> {
+    let RawResponse { status, body } = raw;
+    if status.is_success() {
+        return Ok(());
+    }
+
+    let parsed = serde_json::from_str::<V2ErrorBody>(body).ok();
+    let code = parsed.as_ref().and_then(|e| e.code.clone());
+    let message = parsed
+        .and_then(|e| e.message)
+        .or_else(|| code.clone())
+        .unwrap_or_else(|| format!("HTTP {status}: {}", body_preview(body)));
+    Err(Error::V2Api {
+        message,
+        code,
+        status: status.as_u16(),
+    })
+}
+
+/// Check the status, then decode the body as `T`.
+fn decode<T: DeserializeOwned>(raw: &RawResponse) -> Result<T, Error> {
+    check_status(raw)?;
+    let body = &raw.body;
+    serde_json::from_str(body).map_err(|e| {
+        let preview = body_preview(body);
+        Error::Deserialization {
+            message: format!("{e} (body preview: {preview:?})"),
+            body: body.clone(),
+        }
+    })
+}
@@ -0,0 +1,34 @@
+// V2 API content-filtering endpoints
+//
+// Read and update content filtering profiles (category blocking, safe
+// search, allow/block lists) scoped to networks or clients.
+
+use tracing::debug;
+
+use crate::error::Error;
+use crate::v2::client::V2Client;
+use crate::v2::models::V2ContentFilter;
+
+impl V2Client {
+    /// List content filtering profiles.
+    ///
+    /// `GET /v2/api/site/{site}/content-filtering`
+    pub async fn list_content_filters(&self) -> Result<Vec<V2ContentFilter>, Error> {
+        let url = self.site_url("content-filtering");
+        debug!("listing content filters");
+        self.get(url).await
+    }
+
+    /// Replace a content filtering profile with the full object in `body`.
+    ///
+    /// `PUT /v2/api/site/{site}/content-filtering/{id}`
+    pub async fn update_content_filter(
+        &self,
+        id: &str,
+        body: &serde_json::Value,
+    ) -> Result<V2ContentFilter, Error> {
+        let url = self.site_url(&format!("content-filtering/{id}"));
+        debug!(id, "updating content filter");
+        self.put(url, body).await
+    }
+}
@@ -0,0 +1,16 @@
+// V2 API client modules
+//
+// Hand-written client for the controller's internal v2 endpoints under
+// `/v2/api/site/{site}/` (`/proxy/network/v2/...` on UniFi OS). Newer
+// features -- traffic rules and routes, content filtering, system logs --
+// exist only here. Responses are bare JSON, not the legacy envelope.
+
+pub mod client;
+pub mod content_filtering;
+pub mod models;
+pub mod port_forwards;
+pub mod system_log;
+pub mod traffic_routes;
+pub mod traffic_rules;
+
+pub use client::V2Client;
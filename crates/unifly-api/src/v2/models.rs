@@ -0,0 +1,187 @@
+// V2 API response types
+//
+// Models for the controller's v2 internal API. Responses are bare JSON
+// arrays or objects. As with the legacy models, fields are optional and
+// everything not modelled lands in `extra`, so a full object can be read,
+// edited and PUT back without dropping fields.
+
+use serde::{Deserialize, Serialize};
+
+// ── Targets ──────────────────────────────────────────────────────────
+
+/// A device or network a traffic rule or route applies to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V2TargetDevice {
+    /// `CLIENT`, `NETWORK` or `ALL_CLIENTS`.
+    #[serde(rename = "type", default)]
+    pub target_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_mac: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+}
+
+// ── Traffic Rule ─────────────────────────────────────────────────────
+
+/// Traffic rule from `trafficrules`: block, allow or rate-limit traffic
+/// by app, domain, IP or region.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V2TrafficRule {
+    #[serde(rename = "_id")]
+    pub id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub enabled: bool,
+    /// `BLOCK`, `ALLOW` or `ALLOW_WITH_RATE_LIMIT`.
+    #[serde(default)]
+    pub action: Option<String>,
+    /// `INTERNET`, `APP`, `APP_CATEGORY`, `DOMAIN`, `IP`, `REGION` or `LOCAL_NETWORK`.
+    #[serde(default)]
+    pub matching_target: Option<String>,
+    #[serde(default)]
+    pub target_devices: Vec<V2TargetDevice>,
+    /// Catch-all for undocumented fields (schedule, domains, app ids, ...).
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// ── Traffic Route ────────────────────────────────────────────────────
+
+/// Policy-based route from `trafficroutes`: send matching traffic out a
+/// specific WAN or VPN interface.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V2TrafficRoute {
+    #[serde(rename = "_id")]
+    pub id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub enabled: bool,
+    /// `INTERNET`, `DOMAIN`, `IP` or `REGION`.
+    #[serde(default)]
+    pub matching_target: Option<String>,
+    /// Network (WAN or VPN client) the traffic leaves through.
+    #[serde(default)]
+    pub network_id: Option<String>,
+    #[serde(default)]
+    pub next_hop: Option<String>,
+    /// Drop matching traffic when the route's interface is down.
+    #[serde(default)]
+    pub kill_switch_enabled: Option<bool>,
+    #[serde(default)]
+    pub target_devices: Vec<V2TargetDevice>,
+    /// Catch-all for undocumented fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// ── Content Filtering ────────────────────────────────────────────────
+
+/// Content filtering profile from `content-filtering`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V2ContentFilter {
+    #[serde(rename = "_id")]
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub enabled: bool,
+    /// Blocked categories, e.g. `ADVERTISEMENT`, `GAMBLING`.
+    #[serde(default)]
+    pub categories: Vec<String>,
+    #[serde(default)]
+    pub network_ids: Vec<String>,
+    #[serde(default)]
+    pub client_macs: Vec<String>,
+    #[serde(default)]
+    pub allow_list: Vec<String>,
+    #[serde(default)]
+    pub block_list: Vec<String>,
+    /// Catch-all for undocumented fields (safe search, schedule, ...).
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// ── Port Forward ─────────────────────────────────────────────────────
+
+/// Port-forward rule from `portforward` on newer releases.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V2PortForward {
+    #[serde(rename = "_id")]
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// `tcp`, `udp`, or `tcp_udp`.
+    #[serde(default)]
+    pub proto: Option<String>,
+    /// External port or range (e.g. `8080`, `5000-5010`).
+    #[serde(default)]
+    pub dst_port: Option<String>,
+    /// Internal forward target IP.
+    #[serde(default)]
+    pub fwd: Option<String>,
+    /// Internal forward port or range.
+    #[serde(default)]
+    pub fwd_port: Option<String>,
+    /// Catch-all for undocumented fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// ── System Log ───────────────────────────────────────────────────────
+
+/// Page and time window for a `system-log` query.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct V2SystemLogQuery {
+    /// Window start, Unix milliseconds.
+    pub timestamp_from: i64,
+    /// Window end, Unix milliseconds.
+    pub timestamp_to: i64,
+    /// Zero-based page index.
+    pub page_number: u32,
+    pub page_size: u32,
+}
+
+/// One page of `system-log` results.
+#[derive(Debug, Clone, Deserialize)]
+pub struct V2SystemLogPage {
+    #[serde(default)]
+    pub data: Vec<V2SystemLogEntry>,
+    #[serde(default)]
+    pub page_number: u32,
+    #[serde(default)]
+    pub total_element_count: u64,
+    #[serde(default)]
+    pub total_page_count: u32,
+}
+
+/// A single system log entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct V2SystemLogEntry {
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Unix milliseconds.
+    #[serde(default)]
+    pub timestamp: Option<i64>,
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub subcategory: Option<String>,
+    /// Event key, e.g. `DEVICE_ADOPTED`.
+    #[serde(default)]
+    pub key: Option<String>,
+    /// `INFO`, `LOW`, `MEDIUM`, `HIGH` or `VERY_HIGH`.
+    #[serde(default)]
+    pub severity: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Catch-all for undocumented fields (parameters, raw message, ...).
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -0,0 +1,55 @@
+// V2 API port-forward endpoints
+//
+// CRUD on portforward for newer releases. Older controllers only have
+// the legacy `rest/portforward` endpoints (see `LegacyClient`).
+
+use tracing::debug;
+
+use crate::error::Error;
+use crate::v2::client::V2Client;
+use crate::v2::models::V2PortForward;
+
+impl V2Client {
+    /// List all port-forward rules.
+    ///
+    /// `GET /v2/api/site/{site}/portforward`
+    pub async fn list_port_forwards(&self) -> Result<Vec<V2PortForward>, Error> {
+        let url = self.site_url("portforward");
+        debug!("listing port forwards");
+        self.get(url).await
+    }
+
+    /// Create a port-forward rule.
+    ///
+    /// `POST /v2/api/site/{site}/portforward`
+    pub async fn create_port_forward(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<V2PortForward, Error> {
+        let url = self.site_url("portforward");
+        debug!("creating port forward");
+        self.post(url, body).await
+    }
+
+    /// Replace a port-forward rule with the full object in `body`.
+    ///
+    /// `PUT /v2/api/site/{site}/portforward/{id}`
+    pub async fn update_port_forward(
+        &self,
+        id: &str,
+        body: &serde_json::Value,
+    ) -> Result<V2PortForward, Error> {
+        let url = self.site_url(&format!("portforward/{id}"));
+        debug!(id, "updating port forward");
+        self.put(url, body).await
+    }
+
+    /// Delete a port-forward rule.
+    ///
+    /// `DELETE /v2/api/site/{site}/portforward/{id}`
+    pub async fn delete_port_forward(&self, id: &str) -> Result<(), Error> {
+        let url = self.site_url(&format!("portforward/{id}"));
+        debug!(id, "deleting port forward");
+        self.delete(url).await
+    }
+}
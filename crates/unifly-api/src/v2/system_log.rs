@@ -0,0 +1,28 @@
+// V2 API system-log endpoints
+//
+// Paged queries over the controller's system log (the "System Log" view
+// in the Network app), which supersedes stat/event on newer releases.
+
+use tracing::debug;
+
+use crate::error::Error;
+use crate::v2::client::V2Client;
+use crate::v2::models::{V2SystemLogPage, V2SystemLogQuery};
+
+impl V2Client {
+    /// Fetch one page of system log entries in `category`.
+    ///
+    /// `category` is `all`, `critical`, `devices`, `clients`, `triggers`,
+    /// `updates`, `admin-activity` or `security`.
+    ///
+    /// `POST /v2/api/site/{site}/system-log/{category}`
+    pub async fn list_system_log(
+        &self,
+        category: &str,
+        query: &V2SystemLogQuery,
+    ) -> Result<V2SystemLogPage, Error> {
+        let url = self.site_url(&format!("system-log/{category}"));
+        debug!(category, page = query.page_number, "fetching system log");
+        self.post(url, query).await
+    }
+}
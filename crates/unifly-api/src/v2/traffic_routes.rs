@@ -0,0 +1,55 @@
+// V2 API traffic-route endpoints
+//
+// CRUD on trafficroutes (policy-based routing through a chosen WAN or
+// VPN client network).
+
+use tracing::debug;
+
+use crate::error::Error;
+use crate::v2::client::V2Client;
+use crate::v2::models::V2TrafficRoute;
+
+impl V2Client {
+    /// List all traffic routes.
+    ///
+    /// `GET /v2/api/site/{site}/trafficroutes`
+    pub async fn list_traffic_routes(&self) -> Result<Vec<V2TrafficRoute>, Error> {
+        let url = self.site_url("trafficroutes");
+        debug!("listing traffic routes");
+        self.get(url).await
+    }
+
+    /// Create a traffic route.
+    ///
+    /// `POST /v2/api/site/{site}/trafficroutes`
+    pub async fn create_traffic_route(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<V2TrafficRoute, Error> {
+        let url = self.site_url("trafficroutes");
+        debug!("creating traffic route");
+        self.post(url, body).await
+    }
+
+    /// Replace a traffic route with the full object in `body`.
+    ///
+    /// `PUT /v2/api/site/{site}/trafficroutes/{id}`
+    pub async fn update_traffic_route(
+        &self,
+        id: &str,
+        body: &serde_json::Value,
+    ) -> Result<V2TrafficRoute, Error> {
+        let url = self.site_url(&format!("trafficroutes/{id}"));
+        debug!(id, "updating traffic route");
+        self.put(url, body).await
+    }
+
+    /// Delete a traffic route.
+    ///
+    /// `DELETE /v2/api/site/{site}/trafficroutes/{id}`
+    pub async fn delete_traffic_route(&self, id: &str) -> Result<(), Error> {
+        let url = self.site_url(&format!("trafficroutes/{id}"));
+        debug!(id, "deleting traffic route");
+        self.delete(url).await
+    }
+}
@@ -0,0 +1,56 @@
+// V2 API traffic-rule endpoints
+//
+// CRUD on trafficrules. Traffic rules replaced the legacy firewall app
+// blocking and only exist on the v2 surface.
+
+use tracing::debug;
+
+use crate::error::Error;
+use crate::v2::client::V2Client;
+use crate::v2::models::V2TrafficRule;
+
+impl V2Client {
+    /// List all traffic rules.
+    ///
+    /// `GET /v2/api/site/{site}/trafficrules`
+    pub async fn list_traffic_rules(&self) -> Result<Vec<V2TrafficRule>, Error> {
+        let url = self.site_url("trafficrules");
+        debug!("listing traffic rules");
+        self.get(url).await
+    }
+
+    /// Create a traffic rule.
+    ///
+    /// `POST /v2/api/site/{site}/trafficrules`
+    pub async fn create_traffic_rule(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<V2TrafficRule, Error> {
+        let url = self.site_url("trafficrules");
+        debug!("creating traffic rule");
+        self.post(url, body).await
+    }
+
+    /// Replace a traffic rule. The controller expects the full object, so
+    /// read it, edit it, and send it back whole.
+    ///
+    /// `PUT /v2/api/site/{site}/trafficrules/{id}`
+    pub async fn update_traffic_rule(
+        &self,
+        id: &str,
+        body: &serde_json::Value,
+    ) -> Result<V2TrafficRule, Error> {
+        let url = self.site_url(&format!("trafficrules/{id}"));
+        debug!(id, "updating traffic rule");
+        self.put(url, body).await
+    }
+
+    /// Delete a traffic rule.
+    ///
+    /// `DELETE /v2/api/site/{site}/trafficrules/{id}`
+    pub async fn delete_traffic_rule(&self, id: &str) -> Result<(), Error> {
+        let url = self.site_url(&format!("trafficrules/{id}"));
+        debug!(id, "deleting traffic rule");
+        self.delete(url).await
+    }
+}
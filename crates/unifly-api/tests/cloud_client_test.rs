@@ -0,0 +1,256 @@
+#![allow(clippy::unwrap_used)]
+// Integration tests for `SiteManagerClient` using wiremock.
+
+use serde_json::json;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use unifly_api::cloud::types::IspMetricInterval;
+use unifly_api::cloud::{self, SiteManagerClient};
+use unifly_api::{Error, TransportConfig};
+
+// ── Helpers ─────────────────────────────────────────────────────────
+
+async fn setup() -> (MockServer, SiteManagerClient) {
+    let server = MockServer::start().await;
+    let client = SiteManagerClient::from_reqwest(&server.uri(), reqwest::Client::new()).unwrap();
+    (server, client)
+}
+
+fn host(id: &str, name: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "hardwareId": "eae0f123-0000-5000-8000-000000000000",
+        "type": "console",
+        "ipAddress": "203.0.113.10",
+        "owner": true,
+        "isBlocked": false,
+        "reportedState": { "name": name, "state": "connected", "version": "4.1.13" },
+        "userData": {}
+    })
+}
+
+// ── Hosts ───────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_list_hosts_follows_next_token() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/hosts"))
+        .and(query_param("nextToken", "page-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [host("HOST-B:2", "Branch")],
+            "httpStatusCode": 200,
+            "traceId": "b"
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/hosts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [host("HOST-A:1", "HQ")],
+            "httpStatusCode": 200,
+            "traceId": "a",
+            "nextToken": "page-2"
+        })))
+        .mount(&server)
+        .await;
+
+    let hosts = client.list_hosts().await.unwrap();
+
+    assert_eq!(hosts.len(), 2);
+    assert_eq!(hosts[0].id, "HOST-A:1");
+    assert_eq!(hosts[0].name(), Some("HQ"));
+    assert_eq!(hosts[0].state(), Some("connected"));
+    assert_eq!(hosts[0].version(), Some("4.1.13"));
+    assert_eq!(hosts[1].name(), Some("Branch"));
+}
+
+#[tokio::test]
+async fn test_api_key_header_sent() {
+    let server = MockServer::start().await;
+    let client = SiteManagerClient::from_api_key(
+        &server.uri(),
+        &"cloud-key".to_owned().into(),
+        &TransportConfig::default(),
+    )
+    .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/hosts/HOST-A:1"))
+        .and(header("X-API-KEY", "cloud-key"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "data": host("HOST-A:1", "HQ") })),
+        )
+        .mount(&server)
+        .await;
+
+    let host = client.get_host("HOST-A:1").await.unwrap();
+
+    assert_eq!(host.host_type.as_deref(), Some("console"));
+    assert!(host.owner);
+}
+
+// ── Sites / devices / metrics ───────────────────────────────────────
+
+#[tokio::test]
+async fn test_list_sites() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/sites"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [{
+                "siteId": "661900ae6aec8f548d49fd54",
+                "hostId": "HOST-A:1",
+                "meta": { "name": "default", "desc": "Default", "timezone": "Europe/Berlin" },
+                "statistics": { "counts": { "totalDevice": 4 } },
+                "permission": "admin",
+                "isOwner": true
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let sites = client.list_sites().await.unwrap();
+
+    assert_eq!(sites.len(), 1);
+    assert_eq!(sites[0].host_id, "HOST-A:1");
+    assert_eq!(sites[0].meta.desc.as_deref(), Some("Default"));
+    assert_eq!(sites[0].permission.as_deref(), Some("admin"));
+}
+
+#[tokio::test]
+async fn test_list_devices_filters_by_host() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/devices"))
+        .and(query_param("hostIds[]", "HOST-A:1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [{
+                "hostId": "HOST-A:1",
+                "hostName": "HQ",
+                "updatedAt": "2026-01-01T00:00:00Z",
+                "devices": [{
+                    "id": "F4E2C6000001",
+                    "mac": "F4E2C6000001",
+                    "name": "Office AP",
+                    "model": "U7 Pro",
+                    "ip": "192.168.1.20",
+                    "productLine": "network",
+                    "status": "online",
+                    "version": "7.0.76",
+                    "firmwareStatus": "upToDate",
+                    "isConsole": false,
+                    "isManaged": true
+                }]
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let groups = client.list_devices(&["HOST-A:1".into()]).await.unwrap();
+
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].host_name.as_deref(), Some("HQ"));
+    assert_eq!(groups[0].devices[0].name.as_deref(), Some("Office AP"));
+    assert_eq!(groups[0].devices[0].status.as_deref(), Some("online"));
+}
+
+#[tokio::test]
+async fn test_get_isp_metrics() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/ea/isp-metrics/1h"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [{
+                "metricType": "1h",
+                "hostId": "HOST-A:1",
+                "siteId": "661900ae6aec8f548d49fd54",
+                "periods": [{
+                    "metricTime": "2026-01-01T00:00:00Z",
+                    "version": "1",
+                    "data": { "wan": {
+                        "avgLatency": 12,
+                        "download_kbps": 250_000,
+                        "upload_kbps": 40_000,
+                        "packetLoss": 0,
+                        "uptime": 100,
+                        "ispName": "Example ISP"
+                    } }
+                }]
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let metrics = client
+        .get_isp_metrics(IspMetricInterval::Hourly)
+        .await
+        .unwrap();
+
+    let wan = metrics[0].periods[0].data.wan.as_ref().unwrap();
+    assert_eq!(wan.download_kbps, Some(250_000.0));
+    assert_eq!(wan.isp_name.as_deref(), Some("Example ISP"));
+}
+
+// ── Errors ──────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_error_401_invalid_key() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+
+    let result = client.list_hosts().await;
+
+    assert!(
+        matches!(result, Err(Error::InvalidApiKey)),
+        "expected InvalidApiKey, got: {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_error_404_host() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/hosts/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "code": "not_found",
+            "httpStatusCode": 404,
+            "message": "host not found"
+        })))
+        .mount(&server)
+        .await;
+
+    let err = client.get_host("missing").await.unwrap_err();
+
+    assert!(err.is_not_found());
+    assert_eq!(
+        err.to_string(),
+        "Site Manager API error (HTTP 404): host not found"
+    );
+}
+
+// ── Connector ───────────────────────────────────────────────────────
+
+#[test]
+fn test_connector_url() {
+    let url = cloud::connector_url(cloud::SITE_MANAGER_URL, "HOST-A:1").unwrap();
+    assert_eq!(
+        url.as_str(),
+        "https://api.ui.com/v1/connector/consoles/HOST-A:1"
+    );
+
+    // Already a connector URL: unchanged
+    let again = cloud::connector_url(url.as_str(), "other").unwrap();
+    assert_eq!(again, url);
+}
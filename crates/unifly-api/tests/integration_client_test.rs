@@ -2,6 +2,7 @@
 // Integration tests for `IntegrationClient` using wiremock.
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 use serde_json::json;
 use uuid::Uuid;
@@ -11,7 +12,7 @@ use wiremock::{Mock, MockServer, ResponseTemplate};
 use unifly_api::integration_types::{
     DeviceDetailsResponse, NetworkCreateUpdate, NetworkDetailsResponse, Page, SiteResponse,
 };
-use unifly_api::{ControllerPlatform, Error, IntegrationClient};
+use unifly_api::{ControllerPlatform, Error, IntegrationClient, RetryPolicy};
 
 // ── Helpers ─────────────────────────────────────────────────────────
 
@@ -305,3 +306,76 @@ async fn test_error_500_server_error() {
         other => panic!("expected Integration 500 error, got: {other:?}"),
     }
 }
+
+// ── Retry tests ─────────────────────────────────────────────────────
+
+fn fast_retry() -> RetryPolicy {
+    RetryPolicy {
+        initial_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(5),
+        ..RetryPolicy::default()
+    }
+}
+
+#[tokio::test]
+async fn test_get_retries_after_rate_limit() {
+    let (server, client) = setup().await;
+    let client = client.with_retry(fast_retry());
+
+    Mock::given(method("GET"))
+        .and(path("/integration/v1/sites"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/integration/v1/sites"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "offset": 0, "limit": 25, "count": 0, "totalCount": 0, "data": []
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let page: Page<SiteResponse> = client.list_sites(0, 25).await.unwrap();
+
+    assert_eq!(page.total_count, 0);
+}
+
+#[tokio::test]
+async fn test_post_not_retried_by_default() {
+    let (server, client) = setup().await;
+    let client = client.with_retry(fast_retry());
+
+    let site_id = Uuid::new_v4();
+
+    Mock::given(method("POST"))
+        .and(path(format!("/integration/v1/sites/{site_id}/networks")))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "7"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let req = NetworkCreateUpdate {
+        name: "Guest".into(),
+        enabled: true,
+        management: "USER_DEFINED".into(),
+        vlan_id: 20,
+        dhcp_guarding: None,
+        extra: HashMap::new(),
+    };
+
+    let result = client.create_network(&site_id, &req).await;
+
+    assert!(
+        matches!(
+            result,
+            Err(Error::RateLimited {
+                retry_after_secs: 7
+            })
+        ),
+        "expected RateLimited, got: {result:?}"
+    );
+}
@@ -1,12 +1,16 @@
 #![allow(clippy::unwrap_used)]
 // Integration tests for `LegacyClient` using wiremock.
 
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
 use serde_json::json;
 use url::Url;
-use wiremock::matchers::{method, path, query_param};
+use wiremock::matchers::{body_json, body_partial_json, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
-use unifly_api::{ControllerPlatform, Error, LegacyClient};
+use unifly_api::{ControllerPlatform, Error, LegacyClient, RetryPolicy};
 
 // ── Helpers ─────────────────────────────────────────────────────────
 
@@ -61,6 +65,48 @@ async fn test_login_failure() {
     );
 }
 
+#[tokio::test]
+async fn test_login_requires_2fa() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/login"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "meta": { "rc": "error", "msg": "api.err.Ubic2faTokenRequired" },
+            "data": []
+        })))
+        .mount(&server)
+        .await;
+
+    let secret: secrecy::SecretString = "test-password".to_string().into();
+    let result = client.login("admin", &secret).await;
+
+    assert!(
+        matches!(result, Err(Error::TwoFactorRequired)),
+        "expected TwoFactorRequired error, got: {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_login_with_2fa_token() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/login"))
+        .and(body_partial_json(json!({ "ubic_2fa_token": "123456" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let secret: secrecy::SecretString = "test-password".to_string().into();
+    let token: secrecy::SecretString = "123456".to_string().into();
+    client
+        .login_with_2fa("admin", &secret, Some(&token))
+        .await
+        .unwrap();
+}
+
 // ── Device tests ────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -96,6 +142,37 @@ async fn test_list_devices() {
     assert_eq!(devices[0].state, 1);
 }
 
+#[tokio::test]
+async fn test_with_site_targets_other_site() {
+    let (server, client) = setup().await;
+
+    let envelope = json!({
+        "meta": { "rc": "ok" },
+        "data": [{
+            "_id": "dev-branch",
+            "mac": "11:22:33:44:55:66",
+            "type": "uap",
+            "state": 1,
+            "site_id": "site-branch"
+        }]
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/api/s/branch/stat/device"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&envelope))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let branch = client.with_site("branch");
+    assert_eq!(branch.site(), "branch");
+    assert_eq!(client.site(), "default");
+
+    let devices = branch.list_devices().await.unwrap();
+    assert_eq!(devices.len(), 1);
+    assert_eq!(devices[0].site_id.as_deref(), Some("site-branch"));
+}
+
 // ── Event tests ─────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -160,6 +237,681 @@ async fn test_list_events_with_limit() {
     assert_eq!(events.len(), 1);
 }
 
+#[tokio::test]
+async fn test_list_ips_events() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("POST"))
+        .and(path(site_path("stat/ips/event")))
+        .and(body_json(json!({ "start": 1000, "end": 2000 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": { "rc": "ok" },
+            "data": [{
+                "_id": "ips1",
+                "timestamp": 1500,
+                "src_ip": "203.0.113.9",
+                "src_port": 51515,
+                "srcipCountry": "NL",
+                "dest_ip": "10.0.0.20",
+                "dest_port": 22,
+                "proto": "TCP",
+                "inner_alert_signature": "ET SCAN Potential SSH Scan",
+                "inner_alert_signature_id": 2_001_219,
+                "inner_alert_category": "Attempted Information Leak",
+                "inner_alert_action": "blocked",
+                "inner_alert_severity": 2,
+                "catname": "scan"
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let events = client.list_ips_events(1000, 2000).await.unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].src_country.as_deref(), Some("NL"));
+    assert_eq!(events[0].dest_port, Some(22));
+    assert_eq!(events[0].inner_alert_action.as_deref(), Some("blocked"));
+    assert_eq!(events[0].inner_alert_signature_id, Some(2_001_219));
+    assert!(events[0].extra.contains_key("catname"));
+}
+
+// ── Client record tests ─────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_update_client_user_fixed_ip() {
+    let (server, client) = setup().await;
+
+    let user = json!({
+        "meta": { "rc": "ok" },
+        "data": [{
+            "_id": "u1",
+            "mac": "aa:bb:cc:dd:ee:ff",
+            "name": "NAS",
+            "use_fixedip": false,
+            "first_seen": 1_700_000_000
+        }]
+    });
+    let body = json!({
+        "_id": "u1",
+        "mac": "aa:bb:cc:dd:ee:ff",
+        "name": "NAS",
+        "use_fixedip": true,
+        "fixed_ip": "192.168.1.50",
+        "network_id": "net1",
+        "first_seen": 1_700_000_000
+    });
+    let updated = json!({
+        "meta": { "rc": "ok" },
+        "data": [body.clone()]
+    });
+
+    Mock::given(method("GET"))
+        .and(path(site_path("stat/user/aa:bb:cc:dd:ee:ff")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&user))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(site_path("rest/user/u1")))
+        .and(body_json(&body))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&updated))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = client
+        .get_client_user("aa:bb:cc:dd:ee:ff")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.id, "u1");
+    assert_eq!(record.name.as_deref(), Some("NAS"));
+    assert_eq!(record.first_seen, Some(1_700_000_000));
+
+    let record = client.update_client_user(&record.id, &body).await.unwrap();
+    assert_eq!(record.use_fixedip, Some(true));
+    assert_eq!(record.fixed_ip.as_deref(), Some("192.168.1.50"));
+}
+
+#[tokio::test]
+async fn test_get_client_user_unknown_mac() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(site_path("stat/user/00:00:00:00:00:01")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "meta": { "rc": "ok" }, "data": [] })),
+        )
+        .mount(&server)
+        .await;
+
+    let record = client.get_client_user("00:00:00:00:00:01").await.unwrap();
+    assert!(record.is_none());
+}
+
+#[tokio::test]
+async fn test_list_known_clients_within_hours() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("POST"))
+        .and(path(site_path("stat/alluser")))
+        .and(body_json(
+            json!({ "type": "all", "conn": "all", "within": 168 }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": { "rc": "ok" },
+            "data": [{
+                "_id": "u1",
+                "mac": "aa:bb:cc:00:00:10",
+                "hostname": "printer",
+                "oui": "HP",
+                "is_wired": true,
+                "first_seen": 1_690_000_000,
+                "last_seen": 1_700_000_000,
+                "use_fixedip": true,
+                "fixed_ip": "192.168.1.20"
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let known = client.list_known_clients(Some(168)).await.unwrap();
+
+    assert_eq!(known.len(), 1);
+    assert_eq!(known[0].is_wired, Some(true));
+    assert_eq!(known[0].last_seen, Some(1_700_000_000));
+    assert_eq!(known[0].fixed_ip.as_deref(), Some("192.168.1.20"));
+}
+
+#[tokio::test]
+async fn test_list_client_sessions() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("POST"))
+        .and(path(site_path("stat/session")))
+        .and(body_json(json!({
+            "type": "all",
+            "start": 1000,
+            "end": 2000,
+            "mac": "aa:bb:cc:00:00:10"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": { "rc": "ok" },
+            "data": [{
+                "_id": "s1",
+                "mac": "aa:bb:cc:00:00:10",
+                "assoc_time": 1100,
+                "disassoc_time": 1700,
+                "duration": 600,
+                "ap_mac": "11:22:33:44:55:66",
+                "essid": "Office",
+                "ip": "192.168.1.20",
+                "tx_bytes": 1024,
+                "rx_bytes": 2048,
+                "roam_count": 1
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sessions = client
+        .list_client_sessions("aa:bb:cc:00:00:10", 1000, 2000)
+        .await
+        .unwrap();
+
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].duration, Some(600));
+    assert_eq!(sessions[0].ap_mac.as_deref(), Some("11:22:33:44:55:66"));
+    assert_eq!(sessions[0].essid.as_deref(), Some("Office"));
+    assert!(sessions[0].extra.contains_key("roam_count"));
+}
+
+// ── Port forward tests ──────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_list_port_forwards() {
+    let (server, client) = setup().await;
+
+    let envelope = json!({
+        "meta": { "rc": "ok" },
+        "data": [{
+            "_id": "pf1",
+            "name": "Plex",
+            "enabled": true,
+            "proto": "tcp",
+            "pfwd_interface": "wan",
+            "src": "any",
+            "dst_port": "32400",
+            "fwd": "10.0.0.20",
+            "fwd_port": "32400",
+            "log": false,
+            "site_id": "s1"
+        }]
+    });
+
+    Mock::given(method("GET"))
+        .and(path(site_path("rest/portforward")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&envelope))
+        .mount(&server)
+        .await;
+
+    let rules = client.list_port_forwards().await.unwrap();
+
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].name.as_deref(), Some("Plex"));
+    assert_eq!(rules[0].fwd.as_deref(), Some("10.0.0.20"));
+    assert_eq!(rules[0].dst_port.as_deref(), Some("32400"));
+    assert!(rules[0].extra.contains_key("site_id"));
+}
+
+#[tokio::test]
+async fn test_create_and_toggle_port_forward() {
+    let (server, client) = setup().await;
+
+    let created = json!({
+        "meta": { "rc": "ok" },
+        "data": [{ "_id": "pf2", "name": "SSH", "enabled": true, "dst_port": "2222" }]
+    });
+
+    Mock::given(method("POST"))
+        .and(path(site_path("rest/portforward")))
+        .and(body_partial_json(
+            json!({ "name": "SSH", "dst_port": "2222" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&created))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(site_path("rest/portforward")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&created))
+        .mount(&server)
+        .await;
+
+    // Toggling re-sends the whole rule, not just `enabled`.
+    Mock::given(method("PUT"))
+        .and(path(site_path("rest/portforward/pf2")))
+        .and(body_partial_json(
+            json!({ "_id": "pf2", "name": "SSH", "enabled": false, "dst_port": "2222" }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "meta": { "rc": "ok" }, "data": [] })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let rule = client
+        .create_port_forward(&json!({ "name": "SSH", "dst_port": "2222" }))
+        .await
+        .unwrap();
+    assert_eq!(rule.id, "pf2");
+
+    client.set_port_forward_enabled("pf2", false).await.unwrap();
+}
+
+// ── User group tests ────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_user_group_crud() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(site_path("rest/usergroup")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": { "rc": "ok" },
+            "data": [
+                { "_id": "g0", "name": "Default", "qos_rate_max_down": -1, "qos_rate_max_up": -1, "attr_no_delete": true },
+                { "_id": "g1", "name": "Kids", "qos_rate_max_down": 10000, "qos_rate_max_up": 2000 }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(site_path("rest/usergroup/g1")))
+        .and(body_json(
+            json!({ "_id": "g1", "name": "Kids", "qos_rate_max_down": 5000, "qos_rate_max_up": 2000 }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": { "rc": "ok" },
+            "data": [{ "_id": "g1", "name": "Kids", "qos_rate_max_down": 5000, "qos_rate_max_up": 2000 }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path(site_path("rest/usergroup/g1")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "meta": { "rc": "ok" }, "data": [] })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let groups = client.list_user_groups().await.unwrap();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].qos_rate_max_down, Some(-1));
+    assert!(groups[0].extra.contains_key("attr_no_delete"));
+    assert_eq!(groups[1].qos_rate_max_up, Some(2000));
+
+    // PUT replaces the group, so the body carries every field.
+    let updated = client
+        .update_user_group(
+            "g1",
+            &json!({ "_id": "g1", "name": "Kids", "qos_rate_max_down": 5000, "qos_rate_max_up": 2000 }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.qos_rate_max_down, Some(5000));
+
+    client.delete_user_group("g1").await.unwrap();
+}
+
+// ── Site setting tests ──────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_get_and_set_settings() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(site_path("get/setting")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": { "rc": "ok" },
+            "data": [
+                { "_id": "s1", "key": "ntp", "setting_preference": "auto", "site_id": "x" },
+                { "_id": "s2", "key": "mgmt", "led_enabled": true, "x_ssh_enabled": false }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(site_path("set/setting/mgmt")))
+        .and(body_partial_json(json!({ "x_ssh_enabled": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": { "rc": "ok" },
+            "data": [{ "_id": "s2", "key": "mgmt", "led_enabled": true, "x_ssh_enabled": true }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let settings = client.get_settings().await.unwrap();
+    assert_eq!(settings.len(), 2);
+    assert_eq!(settings[0].key, "ntp");
+    assert_eq!(settings[0].id.as_deref(), Some("s1"));
+    assert_eq!(settings[0].fields["setting_preference"], "auto");
+
+    let updated = client
+        .set_setting(
+            "mgmt",
+            &json!({ "key": "mgmt", "led_enabled": true, "x_ssh_enabled": true }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.fields["x_ssh_enabled"], true);
+}
+
+// ── Static route tests ──────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_list_static_routes() {
+    let (server, client) = setup().await;
+
+    let envelope = json!({
+        "meta": { "rc": "ok" },
+        "data": [{
+            "_id": "rt1",
+            "name": "Lab",
+            "enabled": true,
+            "type": "static-route",
+            "static-route_type": "nexthop-route",
+            "static-route_network": "10.50.0.0/16",
+            "static-route_nexthop": "192.168.1.2",
+            "static-route_distance": 1,
+            "site_id": "s1"
+        }]
+    });
+
+    Mock::given(method("GET"))
+        .and(path(site_path("rest/routing")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&envelope))
+        .mount(&server)
+        .await;
+
+    let routes = client.list_static_routes().await.unwrap();
+
+    assert_eq!(routes.len(), 1);
+    assert_eq!(routes[0].route_kind.as_deref(), Some("static-route"));
+    assert_eq!(
+        routes[0].static_route_network.as_deref(),
+        Some("10.50.0.0/16")
+    );
+    assert_eq!(
+        routes[0].static_route_nexthop.as_deref(),
+        Some("192.168.1.2")
+    );
+    assert_eq!(routes[0].static_route_distance, Some(1));
+    assert!(routes[0].extra.contains_key("site_id"));
+}
+
+#[tokio::test]
+async fn test_create_and_delete_static_route() {
+    let (server, client) = setup().await;
+
+    let body = json!({
+        "name": "Blackhole",
+        "type": "static-route",
+        "static-route_type": "blackhole",
+        "static-route_network": "203.0.113.0/24"
+    });
+    let created = json!({
+        "meta": { "rc": "ok" },
+        "data": [{ "_id": "rt2", "name": "Blackhole", "static-route_type": "blackhole" }]
+    });
+
+    Mock::given(method("POST"))
+        .and(path(site_path("rest/routing")))
+        .and(body_json(&body))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&created))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path(site_path("rest/routing/rt2")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "meta": { "rc": "ok" }, "data": [] })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let route = client.create_static_route(&body).await.unwrap();
+    assert_eq!(route.id, "rt2");
+    assert_eq!(route.static_route_type.as_deref(), Some("blackhole"));
+
+    client.delete_static_route("rt2").await.unwrap();
+}
+
+// ── Rogue AP tests ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_list_rogue_aps_within_hours() {
+    let (server, client) = setup().await;
+
+    let envelope = json!({
+        "meta": { "rc": "ok" },
+        "data": [{
+            "bssid": "f0:9f:c2:00:00:99",
+            "essid": "Neighbor-5G",
+            "channel": 36,
+            "freq": 5180,
+            "radio": "na",
+            "rssi": 28,
+            "signal": -67,
+            "security": "wpapsk",
+            "ap_mac": "74:ac:b9:00:00:01",
+            "is_rogue": false,
+            "last_seen": 1_767_225_600,
+            "age": 12
+        }]
+    });
+
+    Mock::given(method("POST"))
+        .and(path(site_path("stat/rogueap")))
+        .and(body_json(json!({ "within": 6 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&envelope))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let aps = client.list_rogue_aps(Some(6)).await.unwrap();
+
+    assert_eq!(aps.len(), 1);
+    assert_eq!(aps[0].essid.as_deref(), Some("Neighbor-5G"));
+    assert_eq!(aps[0].channel, Some(36));
+    assert_eq!(aps[0].signal, Some(-67));
+    assert_eq!(aps[0].ap_mac.as_deref(), Some("74:ac:b9:00:00:01"));
+    assert!(aps[0].extra.contains_key("age"));
+}
+
+// ── Spectrum scan tests ─────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_start_and_get_spectrum_scan() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("POST"))
+        .and(path(site_path("cmd/devmgr")))
+        .and(body_json(
+            json!({ "cmd": "spectrum-scan", "mac": "74:ac:b9:00:00:01" }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "meta": { "rc": "ok" }, "data": [] })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(site_path("stat/spectrum-scan/74:ac:b9:00:00:01")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": { "rc": "ok" },
+            "data": [{
+                "mac": "74:ac:b9:00:00:01",
+                "spectrum_scanning": false,
+                "spectrum_table": [
+                    { "channel": 1, "freq": 2412, "width": 20, "utilization": 42.5,
+                      "interference": 7.0, "interference_type": ["bluetooth"] },
+                    { "channel": 36, "freq": 5180, "width": 80, "utilization": 12.0 }
+                ]
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .start_spectrum_scan("74:AC:B9:00:00:01")
+        .await
+        .unwrap();
+    let scan = client
+        .get_spectrum_scan("74:AC:B9:00:00:01")
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(scan.spectrum_scanning, Some(false));
+    assert_eq!(scan.spectrum_table.len(), 2);
+    assert_eq!(scan.spectrum_table[0].utilization, Some(42.5));
+    assert_eq!(scan.spectrum_table[0].interference_type, vec!["bluetooth"]);
+    assert_eq!(
+        scan.spectrum_table[1].interference_type,
+        Vec::<String>::new()
+    );
+}
+
+#[tokio::test]
+async fn test_get_channel_plan() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(site_path("stat/current-channel")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": { "rc": "ok" },
+            "data": [{
+                "code": "840",
+                "key": "US",
+                "name": "United States",
+                "channels_ng": [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+                "channels_na": [36, 40, 44, 48, 149, 153, 157, 161, 165],
+                "channels_na_80": [36, 40, 44, 48, 149, 153, 157, 161]
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let plan = client.get_channel_plan().await.unwrap().unwrap();
+
+    assert_eq!(plan.key.as_deref(), Some("US"));
+    assert_eq!(plan.channels_ng.len(), 11);
+    assert!(plan.channels_na.contains(&165));
+    assert_eq!(plan.channels_6e, Vec::<u32>::new());
+    assert!(plan.extra.contains_key("code"));
+}
+
+// ── Switch port tests ───────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_list_devices_parses_port_table_and_overrides() {
+    let (server, client) = setup().await;
+
+    let body = json!({
+        "meta": { "rc": "ok" },
+        "data": [{
+            "_id": "dev1",
+            "mac": "aa:bb:cc:dd:ee:01",
+            "type": "usw",
+            "port_table": [
+                { "port_idx": 1, "name": "Port 1", "up": true, "speed": 1000, "poe_mode": "auto" },
+                { "port_idx": 2, "name": "Uplink", "up": false, "media": "SFP+" }
+            ],
+            "port_overrides": [
+                { "port_idx": 2, "name": "Uplink", "portconf_id": "pc1", "stp_port_mode": true }
+            ]
+        }]
+    });
+
+    Mock::given(method("GET"))
+        .and(path(site_path("stat/device")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&body))
+        .mount(&server)
+        .await;
+
+    let devices = client.list_devices().await.unwrap();
+    let device = &devices[0];
+    assert_eq!(device.port_table.len(), 2);
+    assert_eq!(device.port_table[0].speed, Some(1000));
+    assert_eq!(device.port_table[1].media.as_deref(), Some("SFP+"));
+
+    let ov = &device.port_overrides[0];
+    assert_eq!(ov.port_idx, 2);
+    assert_eq!(ov.portconf_id.as_deref(), Some("pc1"));
+
+    // Unknown override fields must survive a round trip, and unset ones
+    // must not be sent back as null.
+    let written = serde_json::to_value(ov).unwrap();
+    assert_eq!(written["stp_port_mode"], json!(true));
+    assert!(written.get("poe_mode").is_none());
+}
+
+#[tokio::test]
+async fn test_list_port_profiles_and_update_device() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(site_path("rest/portconf")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": { "rc": "ok" },
+            "data": [
+                { "_id": "pc1", "name": "All", "forward": "all" },
+                { "_id": "pc2", "name": "Cameras", "forward": "native", "native_networkconf_id": "net3" }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let overrides = json!({
+        "port_overrides": [{ "port_idx": 4, "portconf_id": "pc2" }]
+    });
+    Mock::given(method("PUT"))
+        .and(path(site_path("rest/device/dev1")))
+        .and(body_json(&overrides))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "meta": { "rc": "ok" }, "data": [{ "_id": "dev1" }] })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let profiles = client.list_port_profiles().await.unwrap();
+    assert_eq!(profiles.len(), 2);
+    assert_eq!(profiles[1].name.as_deref(), Some("Cameras"));
+    assert_eq!(profiles[1].native_networkconf_id.as_deref(), Some("net3"));
+
+    client.update_device("dev1", &overrides).await.unwrap();
+}
+
 // ── Error tests ─────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -173,15 +925,120 @@ async fn test_session_expired() {
 
     let result = client.list_devices().await;
 
-    match result {
-        Err(Error::Authentication { ref message }) => {
-            assert!(
-                message.contains("session expired") || message.contains("insufficient permissions"),
-                "expected auth error message, got: {message}"
-            );
-        }
-        other => panic!("expected Authentication error, got: {other:?}"),
-    }
+    assert!(
+        matches!(result, Err(Error::SessionExpired)),
+        "expected SessionExpired error, got: {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_session_expired_logs_in_again() {
+    let (server, mut client) = setup().await;
+    let renewed = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&renewed);
+    client.set_relogin(unifly_api::legacy::Relogin {
+        username: "admin".into(),
+        password: "test-password".to_string().into(),
+        token: None,
+        on_renewed: Some(Arc::new(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        })),
+    });
+
+    Mock::given(method("GET"))
+        .and(path(site_path("stat/device")))
+        .respond_with(ResponseTemplate::new(401))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/login"))
+        .and(body_partial_json(json!({ "username": "admin" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(site_path("stat/device")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": { "rc": "ok" },
+            "data": []
+        })))
+        .mount(&server)
+        .await;
+
+    let devices = client.list_devices().await.unwrap();
+    assert!(devices.is_empty());
+    assert_eq!(renewed.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_relogin_without_two_factor_code_fails_fast() {
+    let (server, mut client) = setup().await;
+    client.set_relogin(unifly_api::legacy::Relogin {
+        username: "admin".into(),
+        password: "test-password".to_string().into(),
+        token: Some(Arc::new(|| None)),
+        on_renewed: None,
+    });
+
+    Mock::given(method("GET"))
+        .and(path(site_path("stat/device")))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let result = client.list_devices().await;
+    assert!(
+        matches!(result, Err(Error::TwoFactorRequired)),
+        "expected TwoFactorRequired, got: {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_get_retries_after_rate_limit() {
+    let (server, client) = setup().await;
+    let client = client.with_retry(RetryPolicy {
+        initial_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(5),
+        ..RetryPolicy::default()
+    });
+
+    // An hour-long Retry-After is capped at max_delay.
+    Mock::given(method("GET"))
+        .and(path(site_path("stat/device")))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "3600"))
+        .up_to_n_times(2)
+        .expect(2)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(site_path("stat/device")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": { "rc": "ok" },
+            "data": []
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let devices = tokio::time::timeout(Duration::from_secs(5), client.list_devices())
+        .await
+        .expect("retry waited for Retry-After instead of max_delay")
+        .unwrap();
+    assert!(devices.is_empty());
 }
 
 #[tokio::test]
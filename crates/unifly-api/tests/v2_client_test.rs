@@ -0,0 +1,361 @@
+#![allow(clippy::unwrap_used)]
+// Integration tests for `V2Client` using wiremock.
+
+use serde_json::json;
+use url::Url;
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use unifly_api::v2_models::V2SystemLogQuery;
+use unifly_api::{ControllerPlatform, Error, LegacyClient, V2Client};
+
+// ── Helpers ─────────────────────────────────────────────────────────
+
+async fn setup_on(platform: ControllerPlatform) -> (MockServer, V2Client) {
+    let server = MockServer::start().await;
+    let base_url = Url::parse(&server.uri()).unwrap();
+    let legacy =
+        LegacyClient::with_client(reqwest::Client::new(), base_url, "default".into(), platform);
+    (server, V2Client::new(legacy))
+}
+
+async fn setup() -> (MockServer, V2Client) {
+    setup_on(ControllerPlatform::ClassicController).await
+}
+
+fn site_path(suffix: &str) -> String {
+    format!("/v2/api/site/default/{suffix}")
+}
+
+// ── URL / session tests ─────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_unifi_os_uses_proxy_prefix() {
+    let (server, client) = setup_on(ControllerPlatform::UnifiOs).await;
+
+    Mock::given(method("GET"))
+        .and(path("/proxy/network/v2/api/site/default/trafficrules"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let rules = client.list_traffic_rules().await.unwrap();
+    assert!(rules.is_empty());
+}
+
+#[tokio::test]
+async fn test_with_site_targets_other_site() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/api/site/branch/trafficroutes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let branch = client.with_site("branch");
+    assert_eq!(branch.site(), "branch");
+    branch.list_traffic_routes().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_writes_send_csrf_token() {
+    let (server, client) = setup().await;
+    client
+        .legacy()
+        .restore_session("TOKEN=abc", Some("csrf-123".into()));
+
+    Mock::given(method("DELETE"))
+        .and(path(site_path("trafficrules/r1")))
+        .and(header("X-CSRF-Token", "csrf-123"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.delete_traffic_rule("r1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_csrf_rotation_shared_with_legacy() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(site_path("content-filtering")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-Updated-CSRF-Token", "rotated")
+                .set_body_json(json!([])),
+        )
+        .mount(&server)
+        .await;
+
+    client.list_content_filters().await.unwrap();
+    assert_eq!(client.legacy().csrf_token().as_deref(), Some("rotated"));
+}
+
+// ── Traffic rule tests ──────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_list_traffic_rules() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(site_path("trafficrules")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "_id": "r1",
+                "description": "Block socials",
+                "enabled": true,
+                "action": "BLOCK",
+                "matching_target": "APP",
+                "app_ids": [1, 2],
+                "target_devices": [
+                    { "type": "CLIENT", "client_mac": "aa:bb:cc:dd:ee:ff" }
+                ]
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let rules = client.list_traffic_rules().await.unwrap();
+    assert_eq!(rules.len(), 1);
+    let rule = &rules[0];
+    assert_eq!(rule.id, "r1");
+    assert!(rule.enabled);
+    assert_eq!(rule.action.as_deref(), Some("BLOCK"));
+    assert_eq!(rule.matching_target.as_deref(), Some("APP"));
+    assert_eq!(
+        rule.target_devices[0].client_mac.as_deref(),
+        Some("aa:bb:cc:dd:ee:ff")
+    );
+    assert_eq!(rule.extra.get("app_ids"), Some(&json!([1, 2])));
+}
+
+#[tokio::test]
+async fn test_create_traffic_rule_sends_body() {
+    let (server, client) = setup().await;
+    let body = json!({
+        "description": "Kids bedtime",
+        "enabled": true,
+        "action": "BLOCK",
+        "matching_target": "INTERNET",
+        "target_devices": [{ "type": "CLIENT", "client_mac": "aa:bb:cc:dd:ee:ff" }]
+    });
+
+    Mock::given(method("POST"))
+        .and(path(site_path("trafficrules")))
+        .and(body_json(&body))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "_id": "r2",
+            "description": "Kids bedtime",
+            "enabled": true,
+            "action": "BLOCK",
+            "matching_target": "INTERNET"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let created = client.create_traffic_rule(&body).await.unwrap();
+    assert_eq!(created.id, "r2");
+    assert_eq!(created.description.as_deref(), Some("Kids bedtime"));
+}
+
+// ── Content filtering / port forward tests ──────────────────────────
+
+#[tokio::test]
+async fn test_update_content_filter() {
+    let (server, client) = setup().await;
+    let body = json!({ "_id": "cf1", "name": "Kids", "enabled": true, "categories": ["GAMBLING"] });
+
+    Mock::given(method("PUT"))
+        .and(path(site_path("content-filtering/cf1")))
+        .and(body_json(&body))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&body))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let filter = client.update_content_filter("cf1", &body).await.unwrap();
+    assert_eq!(filter.name.as_deref(), Some("Kids"));
+    assert_eq!(filter.categories, vec!["GAMBLING".to_string()]);
+}
+
+#[tokio::test]
+async fn test_list_port_forwards() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(site_path("portforward")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "_id": "pf1", "name": "web", "enabled": true, "proto": "tcp",
+              "dst_port": "443", "fwd": "10.0.0.5", "fwd_port": "8443" }
+        ])))
+        .mount(&server)
+        .await;
+
+    let forwards = client.list_port_forwards().await.unwrap();
+    assert_eq!(forwards.len(), 1);
+    assert_eq!(forwards[0].fwd.as_deref(), Some("10.0.0.5"));
+    assert_eq!(forwards[0].fwd_port.as_deref(), Some("8443"));
+}
+
+// ── System log tests ────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_list_system_log_page() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("POST"))
+        .and(path(site_path("system-log/critical")))
+        .and(body_json(json!({
+            "timestampFrom": 1_700_000_000_000_i64,
+            "timestampTo": 1_700_086_400_000_i64,
+            "pageNumber": 0,
+            "pageSize": 50
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [
+                {
+                    "id": "log1",
+                    "timestamp": 1_700_000_500_000_i64,
+                    "category": "DEVICES",
+                    "key": "DEVICE_OFFLINE",
+                    "severity": "HIGH",
+                    "title": "Device offline",
+                    "parameters": { "DEVICE": { "name": "Office AP" } }
+                }
+            ],
+            "page_number": 0,
+            "total_element_count": 1,
+            "total_page_count": 1
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let query = V2SystemLogQuery {
+        timestamp_from: 1_700_000_000_000,
+        timestamp_to: 1_700_086_400_000,
+        page_number: 0,
+        page_size: 50,
+    };
+    let page = client.list_system_log("critical", &query).await.unwrap();
+    assert_eq!(page.total_element_count, 1);
+    assert_eq!(page.total_page_count, 1);
+    let entry = &page.data[0];
+    assert_eq!(entry.key.as_deref(), Some("DEVICE_OFFLINE"));
+    assert_eq!(entry.severity.as_deref(), Some("HIGH"));
+    assert_eq!(entry.timestamp, Some(1_700_000_500_000));
+    assert!(entry.extra.contains_key("parameters"));
+}
+
+// ── Error handling tests ────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_v2_error_body() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("POST"))
+        .and(path(site_path("trafficroutes")))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "errorCode": 400,
+            "code": "api.err.InvalidPayload",
+            "message": "next_hop is required"
+        })))
+        .mount(&server)
+        .await;
+
+    let result = client.create_traffic_route(&json!({})).await;
+    match result {
+        Err(Error::V2Api {
+            message,
+            code,
+            status,
+        }) => {
+            assert_eq!(message, "next_hop is required");
+            assert_eq!(code.as_deref(), Some("api.err.InvalidPayload"));
+            assert_eq!(status, 400);
+        }
+        other => panic!("expected V2Api error, got: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_not_found_without_error_body() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("DELETE"))
+        .and(path(site_path("portforward/missing")))
+        .respond_with(ResponseTemplate::new(404).set_body_string("Not Found"))
+        .mount(&server)
+        .await;
+
+    let err = client.delete_port_forward("missing").await.unwrap_err();
+    assert!(err.is_not_found(), "expected not found, got: {err:?}");
+    assert!(err.api_error_code().is_none());
+}
+
+#[tokio::test]
+async fn test_error_body_preview_keeps_multibyte_chars_whole() {
+    let (server, client) = setup().await;
+
+    // Byte 200 falls inside the first "é".
+    let body = format!("{}{}", "x".repeat(199), "é".repeat(50));
+    Mock::given(method("GET"))
+        .and(path(site_path("trafficrules")))
+        .respond_with(ResponseTemplate::new(502).set_body_string(body))
+        .mount(&server)
+        .await;
+
+    match client.list_traffic_rules().await {
+        Err(Error::V2Api {
+            message, status, ..
+        }) => {
+            assert_eq!(status, 502);
+            assert!(message.ends_with("xé"), "unexpected message: {message}");
+        }
+        other => panic!("expected V2Api error, got: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_unauthorized_is_session_expired() {
+    let (server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(site_path("trafficrules")))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+
+    let result = client.list_traffic_rules().await;
+    assert!(
+        matches!(result, Err(Error::SessionExpired)),
+        "expected SessionExpired, got: {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_unexpected_shape_is_deserialization_error() {
+    let (server, client) = setup().await;
+
+    // An enveloped legacy-style body where v2 expects a bare array.
+    Mock::given(method("GET"))
+        .and(path(site_path("trafficrules")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": { "rc": "ok" },
+            "data": []
+        })))
+        .mount(&server)
+        .await;
+
+    let result = client.list_traffic_rules().await;
+    assert!(
+        matches!(result, Err(Error::Deserialization { .. })),
+        "expected Deserialization error, got: {result:?}"
+    );
+}
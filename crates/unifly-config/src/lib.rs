@@ -5,7 +5,9 @@
 //! depend on this crate — the CLI adds `GlobalOpts`-aware wrappers on top.
 
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 
 use directories::ProjectDirs;
@@ -13,11 +15,15 @@ use figment::{
     Figment,
     providers::{Env, Format, Serialized, Toml},
 };
-use secrecy::SecretString;
+use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use unifly_core::{AuthCredentials, ControllerConfig, TlsVerification};
+use unifly_core::history::HistoryConfig;
+use unifly_core::{
+    AuthCredentials, CachedSession, ControllerConfig, MacAddress, RetryConfig, SessionCache,
+    TlsVerification, TwoFactor,
+};
 
 // ── Error ───────────────────────────────────────────────────────────
 
@@ -37,6 +43,9 @@ pub enum ConfigError {
 
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
+
+    #[error("keyring error: {0}")]
+    Keyring(#[from] keyring::Error),
 }
 
 impl From<figment::Error> for ConfigError {
@@ -118,7 +127,7 @@ pub struct Profile {
     #[serde(default = "default_site")]
     pub site: String,
 
-    /// Auth mode: "integration", "legacy", or "hybrid".
+    /// Auth mode: "integration", "legacy", "hybrid", or "cloud".
     #[serde(default = "default_auth_mode")]
     pub auth_mode: String,
 
@@ -134,6 +143,9 @@ pub struct Profile {
     /// Password for legacy auth (plaintext — prefer keyring).
     pub password: Option<String>,
 
+    /// Console host ID for cloud auth (see `unifly cloud hosts`).
+    pub host_id: Option<String>,
+
     /// Path to custom CA certificate.
     pub ca_cert: Option<PathBuf>,
 
@@ -142,6 +154,59 @@ pub struct Profile {
 
     /// Override timeout.
     pub timeout: Option<u64>,
+
+    /// Retries for transient HTTP failures (default 3; 0 disables).
+    pub retries: Option<u32>,
+
+    /// Also retry writes, not just reads (default false).
+    pub retry_writes: Option<bool>,
+
+    /// Record live stats to disk while the TUI or exporter runs (default false).
+    pub history: Option<bool>,
+
+    /// Days of recorded stats to keep (default 7; 0 keeps everything).
+    pub history_retention_days: Option<u32>,
+
+    /// Free-form labels for grouping profiles (e.g., in `fleet status`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// BSSIDs of neighboring APs flagged as known rogues (TUI neighbor view).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub known_rogue_aps: Vec<String>,
+}
+
+impl Profile {
+    /// The profile's retry policy, defaults filled in.
+    pub fn retry_config(&self) -> RetryConfig {
+        let defaults = RetryConfig::default();
+        RetryConfig {
+            max_retries: self.retries.unwrap_or(defaults.max_retries),
+            retry_writes: self.retry_writes.unwrap_or(defaults.retry_writes),
+            ..defaults
+        }
+    }
+
+    /// Whether `bssid` has been flagged as a known rogue AP.
+    pub fn is_known_rogue(&self, bssid: &MacAddress) -> bool {
+        self.known_rogue_aps
+            .iter()
+            .any(|known| MacAddress::new(known) == *bssid)
+    }
+
+    /// Flag or unflag `bssid` as a known rogue AP.
+    pub fn set_known_rogue(&mut self, bssid: &MacAddress, known: bool) {
+        self.known_rogue_aps
+            .retain(|flagged| MacAddress::new(flagged) != *bssid);
+        if known {
+            self.known_rogue_aps.push(bssid.to_string());
+        }
+    }
+
+    /// Whether the profile carries every one of `tags`.
+    pub fn has_tags(&self, tags: &[String]) -> bool {
+        tags.iter().all(|t| self.tags.iter().any(|own| own == t))
+    }
 }
 
 fn default_site() -> String {
@@ -151,6 +216,21 @@ fn default_auth_mode() -> String {
     "integration".into()
 }
 
+impl Config {
+    /// Profiles carrying every one of `tags` (all profiles when empty),
+    /// sorted by name.
+    pub fn profiles_tagged(&self, tags: &[String]) -> Vec<(&str, &Profile)> {
+        let mut selected: Vec<_> = self
+            .profiles
+            .iter()
+            .filter(|(_, p)| p.has_tags(tags))
+            .map(|(name, p)| (name.as_str(), p))
+            .collect();
+        selected.sort_by_key(|(name, _)| *name);
+        selected
+    }
+}
+
 // ── Config file path ────────────────────────────────────────────────
 
 /// Resolve the config file path via XDG / platform conventions.
@@ -172,6 +252,130 @@ fn dirs_fallback() -> PathBuf {
     p
 }
 
+/// Directory holding per-profile Legacy session caches.
+fn session_cache_dir() -> PathBuf {
+    ProjectDirs::from("com", "unifly", "unifly").map_or_else(
+        || {
+            let mut p = PathBuf::from(std::env::var("HOME").unwrap_or_else(|_| ".".into()));
+            p.push(".cache");
+            p.push("unifly");
+            p.push("sessions");
+            p
+        },
+        |dirs| dirs.cache_dir().join("sessions"),
+    )
+}
+
+/// Directory holding a profile's recorded stats history.
+pub fn history_dir(profile_name: &str) -> PathBuf {
+    ProjectDirs::from("com", "unifly", "unifly")
+        .map_or_else(
+            || {
+                let mut p = PathBuf::from(std::env::var("HOME").unwrap_or_else(|_| ".".into()));
+                p.push(".local");
+                p.push("share");
+                p.push("unifly");
+                p
+            },
+            |dirs| dirs.data_dir().to_path_buf(),
+        )
+        .join("history")
+        .join(profile_name)
+}
+
+/// The profile's history recorder settings, or `None` when recording is off.
+pub fn history_config(profile: &Profile, profile_name: &str) -> Option<HistoryConfig> {
+    if !profile.history.unwrap_or(false) {
+        return None;
+    }
+    let mut config = HistoryConfig::new(history_dir(profile_name));
+    if let Some(days) = profile.history_retention_days {
+        config.retention_days = days;
+    }
+    Some(config)
+}
+
+// ── Session cache ───────────────────────────────────────────────────
+
+/// Legacy session cache for one profile: a TOML file in the user cache
+/// directory, readable by the owner only (0600 on Unix).
+///
+/// Best-effort: an unreadable file means a fresh login, a failed write
+/// means the next run logs in again.
+#[derive(Debug, Clone)]
+pub struct FileSessionCache {
+    path: PathBuf,
+}
+
+impl FileSessionCache {
+    pub fn for_profile(profile_name: &str) -> Self {
+        Self {
+            path: session_cache_dir().join(format!("{}.toml", file_stem(profile_name))),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn write(&self, session: &CachedSession) -> Result<(), ConfigError> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+            options.mode(0o600);
+            // `mode` only applies on creation; tighten files left over
+            // with looser permissions.
+            if self.path.exists() {
+                std::fs::set_permissions(&self.path, std::fs::Permissions::from_mode(0o600))?;
+            }
+        }
+        let mut file = options.open(&self.path)?;
+        file.write_all(toml::to_string(session)?.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl SessionCache for FileSessionCache {
+    fn load(&self) -> Option<CachedSession> {
+        let text = std::fs::read_to_string(&self.path).ok()?;
+        toml::from_str(&text).ok()
+    }
+
+    fn store(&self, session: &CachedSession) {
+        let _ = self.write(session);
+    }
+}
+
+/// A profile name as a file name: everything but ASCII letters, digits,
+/// `-` and `_` is percent-escaped, so names with `/` or `..` stay inside
+/// the cache directory and distinct names never share a file.
+fn file_stem(profile_name: &str) -> String {
+    profile_name
+        .bytes()
+        .map(|byte| {
+            if byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_' {
+                char::from(byte).to_string()
+            } else {
+                format!("%{byte:02X}")
+            }
+        })
+        .collect()
+}
+
+/// The session cache for a profile that logs in to the Legacy API
+/// (`legacy` / `hybrid`); `None` for integration and cloud profiles.
+pub fn session_cache(profile: &Profile, profile_name: &str) -> Option<Arc<dyn SessionCache>> {
+    if matches!(profile.auth_mode.as_str(), "integration" | "cloud") {
+        return None;
+    }
+    Some(Arc::new(FileSessionCache::for_profile(profile_name)))
+}
+
 // ── Config loading ──────────────────────────────────────────────────
 
 /// Load the full Config from file + environment.
@@ -268,6 +472,25 @@ pub fn resolve_legacy_credentials(
     })
 }
 
+/// Resolve the TOTP secret for two-factor Legacy login from the keyring.
+///
+/// Only profiles that log in to the Legacy API (`legacy` / `hybrid`) use
+/// one; `None` when the profile has none stored.
+pub fn resolve_totp_secret(profile: &Profile, profile_name: &str) -> Option<SecretString> {
+    if matches!(profile.auth_mode.as_str(), "integration" | "cloud") {
+        return None;
+    }
+    let entry = keyring::Entry::new("unifly", &format!("{profile_name}/totp-secret")).ok()?;
+    entry.get_password().ok().map(SecretString::from)
+}
+
+/// Store a TOTP secret in the keyring, where [`resolve_totp_secret`] finds it.
+pub fn store_totp_secret(profile_name: &str, secret: &SecretString) -> Result<(), ConfigError> {
+    let entry = keyring::Entry::new("unifly", &format!("{profile_name}/totp-secret"))?;
+    entry.set_password(secret.expose_secret())?;
+    Ok(())
+}
+
 /// Resolve `AuthCredentials` from a profile's `auth_mode` field.
 pub fn resolve_auth(profile: &Profile, profile_name: &str) -> Result<AuthCredentials, ConfigError> {
     match profile.auth_mode.as_str() {
@@ -288,9 +511,18 @@ pub fn resolve_auth(profile: &Profile, profile_name: &str) -> Result<AuthCredent
                 password,
             })
         }
+        "cloud" => {
+            let api_key = resolve_api_key(profile, profile_name)?;
+            Ok(AuthCredentials::Cloud {
+                api_key,
+                host_id: profile.host_id.clone().unwrap_or_default(),
+            })
+        }
         other => Err(ConfigError::Validation {
             field: "auth_mode".into(),
-            reason: format!("expected 'integration', 'legacy', or 'hybrid', got '{other}'"),
+            reason: format!(
+                "expected 'integration', 'legacy', 'hybrid', or 'cloud', got '{other}'"
+            ),
         }),
     }
 }
@@ -329,11 +561,35 @@ pub fn profile_to_controller_config(
         site: profile.site.clone(),
         tls,
         timeout,
+        retry: profile.retry_config(),
         refresh_interval_secs: 10,
         websocket_enabled: true,
         polling_interval_secs: 10,
         bandwidth_poll_interval: Duration::from_millis(500),
         client_poll_interval: Duration::from_secs(2),
         device_stats_poll_interval: Duration::from_secs(2),
+        two_factor: resolve_totp_secret(profile, profile_name).map(TwoFactor::TotpSecret),
+        session_cache: session_cache(profile, profile_name),
+        history: history_config(profile, profile_name),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::FileSessionCache;
+
+    #[test]
+    fn session_cache_paths_stay_in_the_cache_dir() {
+        let dir = FileSessionCache::for_profile("office").path().to_path_buf();
+        let dir = dir.parent().expect("cache dir");
+        for (name, file) in [
+            ("office", "office.toml"),
+            ("../office", "%2E%2E%2Foffice.toml"),
+            ("a/b", "a%2Fb.toml"),
+            ("a%2Fb", "a%252Fb.toml"),
+        ] {
+            let path = FileSessionCache::for_profile(name).path().to_path_buf();
+            assert_eq!(path, dir.join(file), "profile {name:?}");
+        }
+    }
+}
@@ -0,0 +1,351 @@
+// ── Rule evaluation ──
+//
+// Pure state machine: feed it events, alarm listings and device
+// snapshots, get back the alerts that survive dedup and rate limiting.
+// All time comes in through `now` so tests can drive it directly.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+use super::rules::{AlertRule, AlertRules, AlertSource};
+use crate::model::{
+    Alarm, Device, DeviceState, EntityId, Event, EventCategory, EventSeverity, MacAddress,
+};
+
+/// What produced an alert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+    Event,
+    Alarm,
+    DeviceOffline,
+    DeviceRecovered,
+}
+
+/// An alert ready for delivery.
+#[derive(Debug, Clone, Serialize)]
+pub struct Alert {
+    pub rule: String,
+    pub kind: AlertKind,
+    pub timestamp: DateTime<Utc>,
+    pub severity: EventSeverity,
+    pub category: EventCategory,
+    pub message: String,
+    pub device_mac: Option<MacAddress>,
+    pub client_mac: Option<MacAddress>,
+    pub raw_key: Option<String>,
+    /// Notifier names from the rule.
+    #[serde(skip)]
+    pub notify: Vec<String>,
+}
+
+impl Alert {
+    /// One-line human-readable form.
+    pub fn summary(&self) -> String {
+        format!("[{:?}] {}: {}", self.severity, self.rule, self.message)
+    }
+}
+
+/// Fields shared by events and alarms that rules filter on.
+struct Candidate<'a> {
+    from_alarm: bool,
+    category: EventCategory,
+    severity: EventSeverity,
+    raw_key: Option<&'a str>,
+    macs: [Option<&'a MacAddress>; 2],
+}
+
+/// Rule durations are user input; saturate instead of overflowing.
+fn secs(value: u64) -> Duration {
+    i64::try_from(value)
+        .ok()
+        .and_then(Duration::try_seconds)
+        .unwrap_or(Duration::MAX)
+}
+
+fn is_offline(state: DeviceState) -> bool {
+    matches!(
+        state,
+        DeviceState::Offline | DeviceState::ConnectionInterrupted | DeviceState::Isolated
+    )
+}
+
+/// Evaluates [`AlertRules`] against the controller's streams.
+pub struct AlertEngine {
+    rules: Vec<AlertRule>,
+    /// Normalized `macs` filter per rule.
+    rule_macs: Vec<Vec<MacAddress>>,
+    /// Alarm ids seen in the previous listing; `None` until primed.
+    seen_alarms: Option<HashSet<EntityId>>,
+    /// When each currently-offline device was first seen offline.
+    offline_since: HashMap<MacAddress, DateTime<Utc>>,
+    /// (rule index, device) pairs that already fired an offline alert.
+    offline_alerted: HashSet<(usize, MacAddress)>,
+    /// Last delivery per (rule index, dedup key), for cooldowns.
+    last_fired: HashMap<(usize, String), DateTime<Utc>>,
+    /// Recent delivery times per rule, for `max_per_hour`.
+    recent: Vec<VecDeque<DateTime<Utc>>>,
+}
+
+impl AlertEngine {
+    pub fn new(rules: AlertRules) -> Self {
+        let rule_macs = rules
+            .rules
+            .iter()
+            .map(|r| r.macs.iter().map(MacAddress::new).collect())
+            .collect();
+        let recent = vec![VecDeque::new(); rules.rules.len()];
+        Self {
+            rules: rules.rules,
+            rule_macs,
+            seen_alarms: None,
+            offline_since: HashMap::new(),
+            offline_alerted: HashSet::new(),
+            last_fired: HashMap::new(),
+            recent,
+        }
+    }
+
+    /// Evaluate one live event.
+    pub fn on_event(&mut self, event: &Event, now: DateTime<Utc>) -> Vec<Alert> {
+        let candidate = Candidate {
+            from_alarm: false,
+            category: event.category,
+            severity: event.severity,
+            raw_key: event.raw_key.as_deref(),
+            macs: [event.device_mac.as_ref(), event.client_mac.as_ref()],
+        };
+        let key = format!(
+            "{}|{}|{}",
+            event.raw_key.as_deref().unwrap_or(&event.event_type),
+            event.device_mac.as_ref().map_or("", MacAddress::as_str),
+            event.client_mac.as_ref().map_or("", MacAddress::as_str),
+        );
+
+        let mut alerts = Vec::new();
+        for idx in self.matching(&candidate) {
+            if self.admit(idx, &key, now) {
+                alerts.push(self.alert(
+                    idx,
+                    AlertKind::Event,
+                    event.timestamp,
+                    &candidate,
+                    (
+                        event.message.clone(),
+                        event.device_mac.clone(),
+                        event.client_mac.clone(),
+                    ),
+                ));
+            }
+        }
+        alerts
+    }
+
+    /// Evaluate a full alarm listing. Only alarms that were not in the
+    /// previous listing can fire; the first listing just primes that set
+    /// so already-open alarms do not flood the notifiers on startup.
+    pub fn on_alarms(&mut self, alarms: &[Alarm], now: DateTime<Utc>) -> Vec<Alert> {
+        let open: Vec<&Alarm> = alarms.iter().filter(|a| !a.archived).collect();
+        let current: HashSet<EntityId> = open.iter().map(|a| a.id.clone()).collect();
+        let Some(seen) = self.seen_alarms.replace(current) else {
+            return Vec::new();
+        };
+
+        let mut alerts = Vec::new();
+        for alarm in open.into_iter().filter(|a| !seen.contains(&a.id)) {
+            let candidate = Candidate {
+                from_alarm: true,
+                category: alarm.category,
+                severity: alarm.severity,
+                raw_key: alarm.raw_key.as_deref(),
+                macs: [alarm.device_mac.as_ref(), None],
+            };
+            let key = format!("alarm|{}", alarm.id);
+            for idx in self.matching(&candidate) {
+                if self.admit(idx, &key, now) {
+                    alerts.push(self.alert(
+                        idx,
+                        AlertKind::Alarm,
+                        alarm.timestamp,
+                        &candidate,
+                        (alarm.message.clone(), alarm.device_mac.clone(), None),
+                    ));
+                }
+            }
+        }
+        alerts
+    }
+
+    /// Evaluate a device snapshot for offline / recovered transitions.
+    pub fn on_devices(&mut self, devices: &[Arc<Device>], now: DateTime<Utc>) -> Vec<Alert> {
+        let mut alerts = Vec::new();
+        for device in devices {
+            let label = device.name.as_deref().map_or_else(
+                || device.mac.to_string(),
+                |n| format!("{n} ({})", device.mac),
+            );
+
+            if !is_offline(device.state) {
+                if self.offline_since.remove(&device.mac).is_some() {
+                    alerts.extend(self.recovered(device, &label, now));
+                }
+                continue;
+            }
+
+            let since = *self.offline_since.entry(device.mac.clone()).or_insert(now);
+            for idx in 0..self.rules.len() {
+                let rule = &self.rules[idx];
+                let Some(threshold) = rule.device_offline_secs else {
+                    continue;
+                };
+                let threshold = secs(threshold);
+                if !self.device_matches(idx, device)
+                    || now - since < threshold
+                    || self.offline_alerted.contains(&(idx, device.mac.clone()))
+                {
+                    continue;
+                }
+                let key = format!("offline|{}", device.mac);
+                if !self.admit(idx, &key, now) {
+                    continue;
+                }
+                self.offline_alerted.insert((idx, device.mac.clone()));
+                let rule = &self.rules[idx];
+                alerts.push(Alert {
+                    rule: rule.name.clone(),
+                    kind: AlertKind::DeviceOffline,
+                    timestamp: now,
+                    severity: EventSeverity::Warning,
+                    category: EventCategory::Device,
+                    message: format!(
+                        "{label} has been offline for {}s",
+                        (now - since).num_seconds()
+                    ),
+                    device_mac: Some(device.mac.clone()),
+                    client_mac: None,
+                    raw_key: None,
+                    notify: rule.notify.clone(),
+                });
+            }
+        }
+        alerts
+    }
+
+    fn recovered(&mut self, device: &Device, label: &str, now: DateTime<Utc>) -> Vec<Alert> {
+        let mut alerts = Vec::new();
+        for idx in 0..self.rules.len() {
+            if !self.offline_alerted.remove(&(idx, device.mac.clone()))
+                || !self.rules[idx].notify_recovery
+            {
+                continue;
+            }
+            let rule = &self.rules[idx];
+            alerts.push(Alert {
+                rule: rule.name.clone(),
+                kind: AlertKind::DeviceRecovered,
+                timestamp: now,
+                severity: EventSeverity::Info,
+                category: EventCategory::Device,
+                message: format!("{label} is back online"),
+                device_mac: Some(device.mac.clone()),
+                client_mac: None,
+                raw_key: None,
+                notify: rule.notify.clone(),
+            });
+        }
+        alerts
+    }
+
+    /// Indices of event rules whose filters all accept `c`.
+    fn matching(&self, c: &Candidate<'_>) -> Vec<usize> {
+        self.rules
+            .iter()
+            .enumerate()
+            .filter(|(idx, rule)| {
+                let source_ok = match rule.source {
+                    AlertSource::Any => true,
+                    AlertSource::Events => !c.from_alarm,
+                    AlertSource::Alarms => c.from_alarm,
+                };
+                source_ok
+                    && !rule.is_device_state_rule()
+                    && (rule.categories.is_empty() || rule.categories.contains(&c.category))
+                    && rule.min_severity.is_none_or(|min| c.severity >= min)
+                    && (rule.raw_keys.is_empty()
+                        || c.raw_key
+                            .is_some_and(|k| rule.raw_keys.iter().any(|r| r == k)))
+                    && (self.rule_macs[*idx].is_empty()
+                        || c.macs
+                            .iter()
+                            .flatten()
+                            .any(|m| self.rule_macs[*idx].contains(m)))
+            })
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    fn device_matches(&self, idx: usize, device: &Device) -> bool {
+        let rule = &self.rules[idx];
+        (rule.device_types.is_empty() || rule.device_types.contains(&device.device_type))
+            && (self.rule_macs[idx].is_empty() || self.rule_macs[idx].contains(&device.mac))
+    }
+
+    /// Apply the rule's cooldown and hourly cap; records the delivery
+    /// when admitted.
+    fn admit(&mut self, idx: usize, key: &str, now: DateTime<Utc>) -> bool {
+        let rule = &self.rules[idx];
+        let cooldown = secs(rule.cooldown_secs);
+        let dedup_key = (idx, key.to_owned());
+        if self
+            .last_fired
+            .get(&dedup_key)
+            .is_some_and(|last| now - *last < cooldown)
+        {
+            return false;
+        }
+
+        let recent = &mut self.recent[idx];
+        while recent
+            .front()
+            .is_some_and(|t| now - *t >= Duration::hours(1))
+        {
+            recent.pop_front();
+        }
+        if rule
+            .max_per_hour
+            .is_some_and(|max| recent.len() >= usize::try_from(max).unwrap_or(usize::MAX))
+        {
+            return false;
+        }
+
+        recent.push_back(now);
+        self.last_fired.insert(dedup_key, now);
+        true
+    }
+
+    fn alert(
+        &self,
+        idx: usize,
+        kind: AlertKind,
+        timestamp: DateTime<Utc>,
+        c: &Candidate<'_>,
+        (message, device_mac, client_mac): (String, Option<MacAddress>, Option<MacAddress>),
+    ) -> Alert {
+        let rule = &self.rules[idx];
+        Alert {
+            rule: rule.name.clone(),
+            kind,
+            timestamp,
+            severity: c.severity,
+            category: c.category,
+            message,
+            device_mac,
+            client_mac,
+            raw_key: c.raw_key.map(str::to_owned),
+            notify: rule.notify.clone(),
+        }
+    }
+}
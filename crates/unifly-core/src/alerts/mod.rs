@@ -0,0 +1,233 @@
+// ── Alert rules ──
+//
+// Rule-driven alerting for `unifly watch-alerts`: an `AlertEngine`
+// matches live events, new alarms and device offline/recovery
+// transitions against `AlertRules`, applying per-rule cooldowns and
+// hourly caps; `Notifiers` delivers the resulting alerts to webhooks,
+// Slack-compatible endpoints or shell commands.
+
+mod engine;
+mod notify;
+mod rules;
+
+pub use engine::{Alert, AlertEngine, AlertKind};
+pub use notify::{Notifiers, slack_payload};
+pub use rules::{AlertRule, AlertRules, AlertSource, NotifierKind, NotifierSpec};
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+
+    use super::*;
+    use crate::model::{Alarm, Device, EntityId, Event, EventCategory, EventSeverity};
+
+    fn t0() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap()
+    }
+
+    fn rules(rule_list: serde_json::Value) -> AlertRules {
+        let mut doc = serde_json::json!({
+            "notifier": [{ "name": "ops", "type": "webhook", "url": "http://localhost/hook" }],
+        });
+        doc["rule"] = rule_list;
+        let rules: AlertRules = serde_json::from_value(doc).unwrap();
+        rules.validate().unwrap();
+        rules
+    }
+
+    fn event(key: &str, severity: &str, device_mac: &str) -> Event {
+        serde_json::from_value(serde_json::json!({
+            "id": null,
+            "timestamp": t0(),
+            "category": "Device",
+            "severity": severity,
+            "event_type": key,
+            "message": format!("{key} happened"),
+            "device_mac": device_mac,
+            "client_mac": null,
+            "site_id": null,
+            "raw_key": key,
+        }))
+        .unwrap()
+    }
+
+    fn alarm(id: &str, key: &str) -> Alarm {
+        Alarm {
+            id: EntityId::from(id),
+            timestamp: t0(),
+            category: EventCategory::Device,
+            severity: EventSeverity::Warning,
+            message: format!("{key} raised"),
+            archived: false,
+            device_mac: None,
+            site_id: None,
+            raw_key: Some(key.into()),
+        }
+    }
+
+    fn ap(state: &str) -> Arc<Device> {
+        Arc::new(
+            serde_json::from_value(serde_json::json!({
+                "id": "dev1",
+                "mac": "aa:bb:cc:00:00:01",
+                "ip": null,
+                "wan_ipv6": null,
+                "name": "Lobby AP",
+                "model": "U6-Lite",
+                "device_type": "AccessPoint",
+                "state": state,
+                "firmware_version": null,
+                "firmware_updatable": false,
+                "adopted_at": null,
+                "provisioned_at": null,
+                "last_seen": null,
+                "serial": null,
+                "supported": true,
+                "ports": [],
+                "radios": [],
+                "uplink_device_id": null,
+                "uplink_device_mac": null,
+                "has_switching": false,
+                "has_access_point": true,
+                "stats": {
+                    "uptime_secs": null,
+                    "cpu_utilization_pct": null,
+                    "memory_utilization_pct": null,
+                    "load_average_1m": null,
+                    "load_average_5m": null,
+                    "load_average_15m": null,
+                    "uplink_bandwidth": null,
+                    "last_heartbeat": null,
+                    "next_heartbeat": null
+                },
+                "client_count": null,
+                "site_id": null,
+                "origin": null
+            }))
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn validate_rejects_unknown_notifier() {
+        let doc: AlertRules = serde_json::from_value(serde_json::json!({
+            "rule": [{ "name": "r", "notify": ["nobody"] }],
+        }))
+        .unwrap();
+        assert!(doc.validate().is_err());
+    }
+
+    #[test]
+    fn event_filters_match_key_severity_and_mac() {
+        let mut engine = AlertEngine::new(rules(serde_json::json!([{
+            "name": "ap-lost",
+            "notify": ["ops"],
+            "raw_keys": ["EVT_AP_Lost_Contact"],
+            "min_severity": "Warning",
+            "macs": ["AA-BB-CC-00-00-01"],
+        }])));
+
+        let hit = event("EVT_AP_Lost_Contact", "Warning", "aa:bb:cc:00:00:01");
+        let alerts = engine.on_event(&hit, t0());
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].rule, "ap-lost");
+        assert_eq!(alerts[0].notify, vec!["ops"]);
+
+        let minor = event("EVT_AP_Lost_Contact", "Info", "aa:bb:cc:00:00:02");
+        assert!(engine.on_event(&minor, t0()).is_empty());
+        let other_key = event("EVT_AP_Connected", "Warning", "aa:bb:cc:00:00:01");
+        assert!(engine.on_event(&other_key, t0()).is_empty());
+        let other_mac = event("EVT_AP_Lost_Contact", "Warning", "aa:bb:cc:00:00:09");
+        assert!(engine.on_event(&other_mac, t0()).is_empty());
+    }
+
+    #[test]
+    fn cooldown_and_hourly_cap_suppress_repeats() {
+        let mut engine = AlertEngine::new(rules(serde_json::json!([{
+            "name": "any",
+            "notify": ["ops"],
+            "cooldown_secs": 60,
+            "max_per_hour": 2,
+        }])));
+        let a = event("EVT_A", "Info", "aa:bb:cc:00:00:01");
+        let b = event("EVT_B", "Info", "aa:bb:cc:00:00:01");
+        let c = event("EVT_C", "Info", "aa:bb:cc:00:00:01");
+
+        assert_eq!(engine.on_event(&a, t0()).len(), 1);
+        assert!(engine.on_event(&a, t0() + Duration::seconds(30)).is_empty());
+        assert_eq!(engine.on_event(&b, t0() + Duration::seconds(30)).len(), 1);
+        // Cap reached: a new key is still dropped within the hour.
+        assert!(engine.on_event(&c, t0() + Duration::minutes(5)).is_empty());
+        assert_eq!(engine.on_event(&c, t0() + Duration::minutes(61)).len(), 1);
+    }
+
+    #[test]
+    fn first_alarm_listing_only_primes() {
+        let mut engine = AlertEngine::new(rules(serde_json::json!([{
+            "name": "alarms",
+            "notify": ["ops"],
+            "source": "alarms",
+        }])));
+        let old = alarm("a1", "EVT_GW_WANTransition");
+        assert!(
+            engine
+                .on_alarms(std::slice::from_ref(&old), t0())
+                .is_empty()
+        );
+
+        let new = alarm("a2", "EVT_AP_Lost_Contact");
+        let alerts = engine.on_alarms(&[old, new], t0());
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, AlertKind::Alarm);
+        assert_eq!(alerts[0].raw_key.as_deref(), Some("EVT_AP_Lost_Contact"));
+
+        // Alarms-only rules ignore live events.
+        let live = event("EVT_AP_Lost_Contact", "Warning", "aa:bb:cc:00:00:01");
+        assert!(engine.on_event(&live, t0()).is_empty());
+    }
+
+    #[test]
+    fn offline_device_fires_after_threshold_and_recovers() {
+        let mut engine = AlertEngine::new(rules(serde_json::json!([{
+            "name": "ap-down",
+            "notify": ["ops"],
+            "device_offline_secs": 120,
+            "device_types": ["AccessPoint"],
+            "notify_recovery": true,
+        }])));
+
+        assert!(engine.on_devices(&[ap("Offline")], t0()).is_empty());
+        let early = t0() + Duration::seconds(60);
+        assert!(engine.on_devices(&[ap("Offline")], early).is_empty());
+
+        let late = t0() + Duration::seconds(150);
+        let alerts = engine.on_devices(&[ap("Offline")], late);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, AlertKind::DeviceOffline);
+        assert!(alerts[0].message.contains("Lobby AP"));
+        // Still offline: no repeat.
+        assert!(engine.on_devices(&[ap("Offline")], late).is_empty());
+
+        let back = engine.on_devices(&[ap("Online")], late + Duration::seconds(10));
+        assert_eq!(back.len(), 1);
+        assert_eq!(back[0].kind, AlertKind::DeviceRecovered);
+    }
+
+    #[test]
+    fn slack_payload_is_a_text_message() {
+        let mut engine = AlertEngine::new(rules(serde_json::json!([{
+            "name": "all",
+            "notify": ["ops"],
+        }])));
+        let alert = engine
+            .on_event(&event("EVT_X", "Error", "aa:bb:cc:00:00:01"), t0())
+            .remove(0);
+        assert_eq!(
+            slack_payload(&alert),
+            serde_json::json!({ "text": "[Error] all: EVT_X happened" })
+        );
+    }
+}
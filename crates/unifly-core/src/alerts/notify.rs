@@ -0,0 +1,161 @@
+// ── Alert delivery ──
+//
+// Webhook and Slack notifiers share one reqwest client; command
+// notifiers run through the platform shell on a blocking thread.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::process::{Command as Process, Stdio};
+use std::time::Duration;
+
+use super::engine::Alert;
+use super::rules::{NotifierKind, NotifierSpec};
+use crate::error::CoreError;
+
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn failed(message: impl Into<String>) -> CoreError {
+    CoreError::OperationFailed {
+        message: message.into(),
+    }
+}
+
+/// Body posted to Slack-compatible incoming webhooks.
+pub fn slack_payload(alert: &Alert) -> serde_json::Value {
+    serde_json::json!({ "text": alert.summary() })
+}
+
+/// The configured notifiers, addressed by name.
+pub struct Notifiers {
+    http: reqwest::Client,
+    targets: HashMap<String, NotifierKind>,
+}
+
+impl Notifiers {
+    pub fn new(specs: &[NotifierSpec]) -> Result<Self, CoreError> {
+        let http = reqwest::Client::builder()
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .map_err(|e| failed(format!("cannot build HTTP client: {e}")))?;
+        let targets = specs
+            .iter()
+            .map(|s| (s.name.clone(), s.kind.clone()))
+            .collect();
+        Ok(Self { http, targets })
+    }
+
+    /// Deliver `alert` to every notifier the rule names. Returns the
+    /// notifiers that failed, with their errors.
+    pub async fn deliver(&self, alert: &Alert) -> Vec<(String, CoreError)> {
+        let mut failures = Vec::new();
+        for name in &alert.notify {
+            let result = match self.targets.get(name) {
+                Some(kind) => self.send(kind, alert).await,
+                None => Err(failed(format!("unknown notifier '{name}'"))),
+            };
+            if let Err(e) = result {
+                failures.push((name.clone(), e));
+            }
+        }
+        failures
+    }
+
+    async fn send(&self, kind: &NotifierKind, alert: &Alert) -> Result<(), CoreError> {
+        match kind {
+            NotifierKind::Webhook { url } => {
+                let body = serde_json::to_value(alert)
+                    .map_err(|e| CoreError::Internal(format!("cannot encode alert: {e}")))?;
+                self.post(url, &body).await
+            }
+            NotifierKind::Slack { url } => self.post(url, &slack_payload(alert)).await,
+            NotifierKind::Command { command } => run_command(command, alert).await,
+        }
+    }
+
+    async fn post(&self, url: &str, body: &serde_json::Value) -> Result<(), CoreError> {
+        let resp = self
+            .http
+            .post(url)
+            .json(body)
+            .send()
+            .await
+            .map_err(|e| failed(format!("POST {url}: {e}")))?;
+        let status = resp.status();
+        if status.is_success() {
+            Ok(())
+        } else {
+            Err(failed(format!("POST {url}: HTTP {status}")))
+        }
+    }
+}
+
+/// Run `command` through the shell, alert JSON on stdin.
+async fn run_command(command: &str, alert: &Alert) -> Result<(), CoreError> {
+    let payload = serde_json::to_vec(alert)
+        .map_err(|e| CoreError::Internal(format!("cannot encode alert: {e}")))?;
+    let env = [
+        ("UNIFLY_ALERT_RULE", alert.rule.clone()),
+        ("UNIFLY_ALERT_KIND", json_name(&alert.kind)),
+        ("UNIFLY_ALERT_SEVERITY", format!("{:?}", alert.severity)),
+        ("UNIFLY_ALERT_MESSAGE", alert.message.clone()),
+        (
+            "UNIFLY_ALERT_DEVICE_MAC",
+            alert
+                .device_mac
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+        ),
+        (
+            "UNIFLY_ALERT_CLIENT_MAC",
+            alert
+                .client_mac
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+        ),
+        (
+            "UNIFLY_ALERT_KEY",
+            alert.raw_key.clone().unwrap_or_default(),
+        ),
+    ];
+    let command = command.to_owned();
+
+    tokio::task::spawn_blocking(move || {
+        let (shell, flag) = if cfg!(windows) {
+            ("cmd", "/C")
+        } else {
+            ("sh", "-c")
+        };
+        let mut child = Process::new(shell)
+            .arg(flag)
+            .arg(&command)
+            .envs(env)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+            .map_err(|e| failed(format!("cannot run '{command}': {e}")))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            // A command that ignores stdin may close it early; that is fine.
+            let _ = stdin.write_all(&payload);
+        }
+        let status = child
+            .wait()
+            .map_err(|e| failed(format!("'{command}': {e}")))?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(failed(format!("'{command}' exited with {status}")))
+        }
+    })
+    .await
+    .map_err(|e| CoreError::Internal(format!("notifier task failed: {e}")))?
+}
+
+/// Serialized name of a unit enum variant, e.g. `device_offline`.
+fn json_name<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_owned))
+        .unwrap_or_default()
+}
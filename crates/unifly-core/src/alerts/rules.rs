@@ -0,0 +1,147 @@
+// ── Alert rule documents ──
+//
+// Serde schema for the files consumed by `unifly watch-alerts`: a list
+// of named notifiers and a list of rules, each rule pointing at the
+// notifiers it delivers to.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::CoreError;
+use crate::model::{DeviceType, EventCategory, EventSeverity};
+
+fn default_cooldown_secs() -> u64 {
+    300
+}
+
+/// A complete rules document.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AlertRules {
+    #[serde(default, rename = "notifier")]
+    pub notifiers: Vec<NotifierSpec>,
+    #[serde(default, rename = "rule")]
+    pub rules: Vec<AlertRule>,
+}
+
+/// A named delivery target.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotifierSpec {
+    pub name: String,
+    #[serde(flatten)]
+    pub kind: NotifierKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NotifierKind {
+    /// POST the alert as JSON.
+    Webhook { url: String },
+    /// POST a Slack-compatible `{"text": ...}` message (also accepted by
+    /// Mattermost, Rocket.Chat and Discord's `/slack` endpoint).
+    Slack { url: String },
+    /// Run through the shell with the alert JSON on stdin and
+    /// `UNIFLY_ALERT_*` variables in the environment.
+    Command { command: String },
+}
+
+/// Which stream an event rule watches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertSource {
+    #[default]
+    Any,
+    Events,
+    Alarms,
+}
+
+/// One rule. With `device_offline_secs` set it is a device state rule;
+/// otherwise it matches events and alarms. Every filter left empty
+/// matches everything.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AlertRule {
+    pub name: String,
+    /// Notifier names to deliver to.
+    pub notify: Vec<String>,
+
+    #[serde(default)]
+    pub source: AlertSource,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<EventCategory>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_severity: Option<EventSeverity>,
+    /// Event or alarm keys, e.g. `EVT_AP_Lost_Contact`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub raw_keys: Vec<String>,
+    /// Device or client MACs, any common notation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub macs: Vec<String>,
+
+    /// Fire once a device has been offline this long.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_offline_secs: Option<u64>,
+    /// Limit a device state rule to these device types.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub device_types: Vec<DeviceType>,
+    /// Also alert when an offline device comes back.
+    #[serde(default)]
+    pub notify_recovery: bool,
+
+    /// Identical alerts from this rule within the window are dropped.
+    #[serde(default = "default_cooldown_secs")]
+    pub cooldown_secs: u64,
+    /// At most this many alerts from this rule per rolling hour.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_per_hour: Option<u32>,
+}
+
+impl AlertRule {
+    pub fn is_device_state_rule(&self) -> bool {
+        self.device_offline_secs.is_some()
+    }
+}
+
+impl AlertRules {
+    /// Check cross-references the schema cannot express.
+    pub fn validate(&self) -> Result<(), CoreError> {
+        let invalid = |message: String| Err(CoreError::ValidationFailed { message });
+
+        let mut names = HashSet::new();
+        for notifier in &self.notifiers {
+            if !names.insert(notifier.name.as_str()) {
+                return invalid(format!("duplicate notifier '{}'", notifier.name));
+            }
+        }
+
+        for rule in &self.rules {
+            if rule.notify.is_empty() {
+                return invalid(format!("rule '{}' has no notifiers", rule.name));
+            }
+            if let Some(missing) = rule.notify.iter().find(|n| !names.contains(n.as_str())) {
+                return invalid(format!(
+                    "rule '{}' refers to unknown notifier '{missing}'",
+                    rule.name
+                ));
+            }
+            if rule.is_device_state_rule() {
+                if !rule.categories.is_empty()
+                    || rule.min_severity.is_some()
+                    || !rule.raw_keys.is_empty()
+                {
+                    return invalid(format!(
+                        "rule '{}': device_offline_secs cannot be combined with event filters",
+                        rule.name
+                    ));
+                }
+            } else if !rule.device_types.is_empty() || rule.notify_recovery {
+                return invalid(format!(
+                    "rule '{}': device_types and notify_recovery need device_offline_secs",
+                    rule.name
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+// ── Site Manager (cloud) ──
+//
+// Lists what a cloud API key can reach via api.ui.com: hosts (consoles),
+// their sites and devices. A host found here can be managed like a local
+// controller -- `SiteManager::controller_config` builds a config that
+// connects through the cloud connector proxy.
+
+use serde::Serialize;
+use unifly_api::cloud::SiteManagerClient;
+use unifly_api::cloud::types::{DeviceResponse, HostDevicesResponse, HostResponse, SiteResponse};
+
+use crate::config::{AuthCredentials, ControllerConfig};
+use crate::controller::build_transport;
+use crate::error::CoreError;
+
+pub use unifly_api::cloud::SITE_MANAGER_URL;
+
+/// A console or Network Server reachable with the API key.
+#[derive(Debug, Clone, Serialize)]
+pub struct CloudHost {
+    /// Host ID -- what `host_id` in a cloud profile refers to.
+    pub id: String,
+    pub name: Option<String>,
+    /// `console` or `network-server`.
+    pub host_type: Option<String>,
+    pub ip_address: Option<String>,
+    /// Connection state, e.g. `connected`.
+    pub state: Option<String>,
+    pub version: Option<String>,
+    pub owner: bool,
+    pub is_blocked: bool,
+    pub last_connection_state_change: Option<String>,
+}
+
+impl From<HostResponse> for CloudHost {
+    fn from(h: HostResponse) -> Self {
+        Self {
+            name: h.name().map(String::from),
+            state: h.state().map(String::from),
+            version: h.version().map(String::from),
+            id: h.id,
+            host_type: h.host_type,
+            ip_address: h.ip_address,
+            owner: h.owner,
+            is_blocked: h.is_blocked,
+            last_connection_state_change: h.last_connection_state_change,
+        }
+    }
+}
+
+/// A Network site on one of the hosts.
+#[derive(Debug, Clone, Serialize)]
+pub struct CloudSite {
+    pub site_id: String,
+    pub host_id: String,
+    /// Internal site name (`default` for the first site).
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub timezone: Option<String>,
+    pub permission: Option<String>,
+    pub is_owner: bool,
+}
+
+impl From<SiteResponse> for CloudSite {
+    fn from(s: SiteResponse) -> Self {
+        Self {
+            site_id: s.site_id,
+            host_id: s.host_id,
+            name: s.meta.name,
+            description: s.meta.desc,
+            timezone: s.meta.timezone,
+            permission: s.permission,
+            is_owner: s.is_owner,
+        }
+    }
+}
+
+/// A device as reported by Site Manager, tagged with its host.
+#[derive(Debug, Clone, Serialize)]
+pub struct CloudDevice {
+    pub id: String,
+    pub host_id: String,
+    pub host_name: Option<String>,
+    pub name: Option<String>,
+    pub mac: Option<String>,
+    pub model: Option<String>,
+    pub ip: Option<String>,
+    pub product_line: Option<String>,
+    pub status: Option<String>,
+    pub version: Option<String>,
+    pub firmware_status: Option<String>,
+    pub is_console: bool,
+}
+
+impl CloudDevice {
+    fn from_group(host_id: &str, host_name: Option<&str>, d: DeviceResponse) -> Self {
+        Self {
+            id: d.id,
+            host_id: host_id.to_owned(),
+            host_name: host_name.map(String::from),
+            name: d.name,
+            mac: d.mac,
+            model: d.model,
+            ip: d.ip,
+            product_line: d.product_line,
+            status: d.status,
+            version: d.version,
+            firmware_status: d.firmware_status,
+            is_console: d.is_console,
+        }
+    }
+}
+
+fn flatten_devices(groups: Vec<HostDevicesResponse>) -> Vec<CloudDevice> {
+    groups
+        .into_iter()
+        .flat_map(|g| {
+            let HostDevicesResponse {
+                host_id,
+                host_name,
+                devices,
+                ..
+            } = g;
+            devices
+                .into_iter()
+                .map(move |d| CloudDevice::from_group(&host_id, host_name.as_deref(), d))
+        })
+        .collect()
+}
+
+/// Site Manager session for one cloud API key.
+///
+/// Built from a [`ControllerConfig`] with [`AuthCredentials::Cloud`]
+/// auth, whose URL is the Site Manager base (normally
+/// [`SITE_MANAGER_URL`]). The `host_id` is not needed for listing.
+pub struct SiteManager {
+    client: SiteManagerClient,
+    config: ControllerConfig,
+}
+
+impl SiteManager {
+    pub fn new(config: ControllerConfig) -> Result<Self, CoreError> {
+        let AuthCredentials::Cloud { ref api_key, .. } = config.auth else {
+            return Err(CoreError::Config {
+                message: "Site Manager requires cloud auth (auth_mode = \"cloud\")".into(),
+            });
+        };
+        let client = SiteManagerClient::from_api_key(
+            config.url.as_str(),
+            api_key,
+            &build_transport(&config),
+        )?;
+        Ok(Self { client, config })
+    }
+
+    pub async fn hosts(&self) -> Result<Vec<CloudHost>, CoreError> {
+        let hosts = self.client.list_hosts().await?;
+        Ok(hosts.into_iter().map(CloudHost::from).collect())
+    }
+
+    pub async fn sites(&self) -> Result<Vec<CloudSite>, CoreError> {
+        let sites = self.client.list_sites().await?;
+        Ok(sites.into_iter().map(CloudSite::from).collect())
+    }
+
+    /// Devices on `host_ids` (every host when empty).
+    pub async fn devices(&self, host_ids: &[String]) -> Result<Vec<CloudDevice>, CoreError> {
+        let groups = self.client.list_devices(host_ids).await?;
+        Ok(flatten_devices(groups))
+    }
+
+    /// A config that connects a [`Controller`](crate::Controller) to
+    /// `host_id` through the cloud connector, targeting `site`.
+    pub fn controller_config(&self, host_id: &str, site: &str) -> ControllerConfig {
+        let AuthCredentials::Cloud { ref api_key, .. } = self.config.auth else {
+            unreachable!("SiteManager::new only accepts cloud auth");
+        };
+        ControllerConfig {
+            auth: AuthCredentials::Cloud {
+                api_key: api_key.clone(),
+                host_id: host_id.to_owned(),
+            },
+            site: site.to_owned(),
+            ..self.config.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn devices_are_flattened_with_their_host() {
+        let groups: Vec<HostDevicesResponse> = serde_json::from_value(serde_json::json!([
+            { "hostId": "A:1", "hostName": "HQ", "devices": [{ "id": "d1" }, { "id": "d2" }] },
+            { "hostId": "B:2", "devices": [{ "id": "d3", "status": "offline" }] }
+        ]))
+        .expect("valid fixture");
+
+        let devices = flatten_devices(groups);
+
+        assert_eq!(devices.len(), 3);
+        assert_eq!(devices[1].host_id, "A:1");
+        assert_eq!(devices[1].host_name.as_deref(), Some("HQ"));
+        assert_eq!(devices[2].host_id, "B:2");
+        assert_eq!(devices[2].status.as_deref(), Some("offline"));
+    }
+
+    #[test]
+    fn controller_config_targets_host() {
+        let manager = SiteManager::new(ControllerConfig {
+            url: SITE_MANAGER_URL.parse().expect("valid URL"),
+            auth: AuthCredentials::Cloud {
+                api_key: "key".to_owned().into(),
+                host_id: String::new(),
+            },
+            ..ControllerConfig::default()
+        })
+        .expect("cloud auth");
+
+        let config = manager.controller_config("A:1", "default");
+
+        assert!(matches!(
+            config.auth,
+            AuthCredentials::Cloud { ref host_id, .. } if host_id == "A:1"
+        ));
+        assert_eq!(config.site, "default");
+    }
+
+    #[test]
+    fn non_cloud_auth_is_rejected() {
+        let result = SiteManager::new(ControllerConfig::default());
+        assert!(matches!(result, Err(CoreError::Config { .. })));
+    }
+}
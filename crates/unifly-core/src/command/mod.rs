@@ -6,18 +6,24 @@
 
 pub mod requests;
 
+use std::net::Ipv4Addr;
+
 use crate::error::CoreError;
 use crate::model::{
     AclRule, Client, Device, DnsPolicy, EntityId, FirewallPolicy, FirewallZone, MacAddress,
-    Network, TrafficMatchingList, Voucher, WifiBroadcast,
+    Network, PortForward, RadioBand, StaticRoute, TrafficMatchingList, UserGroup, Voucher,
+    WifiBroadcast,
 };
 
 pub use requests::{
     CreateAclRuleRequest, CreateDnsPolicyRequest, CreateFirewallPolicyRequest,
-    CreateFirewallZoneRequest, CreateNetworkRequest, CreateTrafficMatchingListRequest,
+    CreateFirewallZoneRequest, CreateNetworkRequest, CreatePortForwardRequest,
+    CreateStaticRouteRequest, CreateTrafficMatchingListRequest, CreateUserGroupRequest,
     CreateVouchersRequest, CreateWifiBroadcastRequest, UpdateAclRuleRequest,
-    UpdateDnsPolicyRequest, UpdateFirewallPolicyRequest, UpdateFirewallZoneRequest,
-    UpdateNetworkRequest, UpdateTrafficMatchingListRequest, UpdateWifiBroadcastRequest,
+    UpdateDeviceRadioRequest, UpdateDnsPolicyRequest, UpdateFirewallPolicyRequest,
+    UpdateFirewallZoneRequest, UpdateNetworkRequest, UpdatePortForwardRequest, UpdatePortRequest,
+    UpdateStaticRouteRequest, UpdateTrafficMatchingListRequest, UpdateUserGroupRequest,
+    UpdateWifiBroadcastRequest,
 };
 
 /// A command envelope sent through the command channel.
@@ -53,10 +59,26 @@ pub enum Command {
         mac: MacAddress,
     },
     SpeedtestDevice,
+    /// Start an RF spectrum scan on an AP's radios (Legacy).
+    SpectrumScan {
+        mac: MacAddress,
+    },
     PowerCyclePort {
         device_id: EntityId,
         port_idx: u32,
     },
+    /// Apply a per-port override (profile, native VLAN, PoE, name, enable).
+    UpdatePort {
+        device_id: EntityId,
+        port_idx: u32,
+        update: UpdatePortRequest,
+    },
+    /// Change one band's radio settings on an AP (Legacy `radio_table`).
+    UpdateDeviceRadio {
+        device_id: EntityId,
+        band: RadioBand,
+        update: UpdateDeviceRadioRequest,
+    },
 
     // ── Client operations ────────────────────────────────────────────
     BlockClient {
@@ -81,6 +103,28 @@ pub enum Command {
     UnauthorizeGuest {
         client_id: EntityId,
     },
+    /// Pin (or, with `ip: None`, release) a client's DHCP reservation.
+    /// `network_id` is required when setting one.
+    SetClientFixedIp {
+        mac: MacAddress,
+        ip: Option<Ipv4Addr>,
+        network_id: Option<EntityId>,
+    },
+    /// Set the client's display alias; `None` clears it.
+    SetClientAlias {
+        mac: MacAddress,
+        alias: Option<String>,
+    },
+    /// Set the client's note; `None` clears it.
+    SetClientNote {
+        mac: MacAddress,
+        note: Option<String>,
+    },
+    /// Move a client into a user group; `None` returns it to the default group.
+    AssignClientGroup {
+        mac: MacAddress,
+        group_id: Option<EntityId>,
+    },
 
     // ── Network CRUD ─────────────────────────────────────────────────
     CreateNetwork(CreateNetworkRequest),
@@ -163,6 +207,40 @@ pub enum Command {
         id: EntityId,
     },
 
+    // ── Port forwards (Legacy) ───────────────────────────────────────
+    CreatePortForward(CreatePortForwardRequest),
+    UpdatePortForward {
+        id: EntityId,
+        update: UpdatePortForwardRequest,
+    },
+    SetPortForwardEnabled {
+        id: EntityId,
+        enabled: bool,
+    },
+    DeletePortForward {
+        id: EntityId,
+    },
+
+    // ── User groups (Legacy) ─────────────────────────────────────────
+    CreateUserGroup(CreateUserGroupRequest),
+    UpdateUserGroup {
+        id: EntityId,
+        update: UpdateUserGroupRequest,
+    },
+    DeleteUserGroup {
+        id: EntityId,
+    },
+
+    // ── Static routes (Legacy) ───────────────────────────────────────
+    CreateStaticRoute(CreateStaticRouteRequest),
+    UpdateStaticRoute {
+        id: EntityId,
+        update: UpdateStaticRouteRequest,
+    },
+    DeleteStaticRoute {
+        id: EntityId,
+    },
+
     // ── Hotspot / Vouchers ───────────────────────────────────────────
     CreateVouchers(CreateVouchersRequest),
     DeleteVoucher {
@@ -173,6 +251,13 @@ pub enum Command {
     },
 
     // ── System (Legacy) ──────────────────────────────────────────────
+    /// Change fields in one site settings section. Keys are validated
+    /// with [`crate::settings::parse_value`] before the command is built.
+    UpdateSiteSettings {
+        /// Legacy section key (`ntp`, `mgmt`, `rsyslogd`, ...).
+        section: String,
+        changes: serde_json::Map<String, serde_json::Value>,
+    },
     ArchiveAlarm {
         id: EntityId,
     },
@@ -218,4 +303,7 @@ pub enum CommandResult {
     DnsPolicy(DnsPolicy),
     Vouchers(Vec<Voucher>),
     TrafficMatchingList(TrafficMatchingList),
+    PortForward(PortForward),
+    StaticRoute(StaticRoute),
+    UserGroup(UserGroup),
 }
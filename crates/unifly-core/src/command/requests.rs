@@ -6,7 +6,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::model::{
-    DnsPolicyType, EntityId, FirewallAction, NetworkManagement, NetworkPurpose, WifiSecurityMode,
+    DnsPolicyType, EntityId, FirewallAction, NetworkManagement, NetworkPurpose, PoeMode,
+    PortForwardProtocol, RadioChannel, StaticRouteType, TxPower, WifiSecurityMode,
 };
 
 // ── Network ────────────────────────────────────────────────────────
@@ -260,6 +261,147 @@ fn default_traffic_list_type() -> String {
     "IPV4".into()
 }
 
+// ── Port Forwards ──────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePortForwardRequest {
+    pub name: String,
+    pub enabled: bool,
+    pub protocol: PortForwardProtocol,
+    /// `wan`, `wan2`, or `both`. Defaults to `wan`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wan_interface: Option<String>,
+    /// Allowed source address or CIDR. Defaults to any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_address: Option<String>,
+    pub wan_port: String,
+    pub forward_ip: String,
+    /// Defaults to `wan_port`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_port: Option<String>,
+    pub logging: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdatePortForwardRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<PortForwardProtocol>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wan_interface: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wan_port: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_ip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_port: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logging: Option<bool>,
+}
+
+// ── User Groups ────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateUserGroupRequest {
+    pub name: String,
+    /// Per-client download cap in Kbps; `None` or `0` is unlimited.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_limit_kbps: Option<u32>,
+    /// Per-client upload cap in Kbps; `None` or `0` is unlimited.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upload_limit_kbps: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateUserGroupRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// New download cap in Kbps; `0` removes the cap.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_limit_kbps: Option<u32>,
+    /// New upload cap in Kbps; `0` removes the cap.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upload_limit_kbps: Option<u32>,
+}
+
+// ── Static Routes ──────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateStaticRouteRequest {
+    pub name: String,
+    pub enabled: bool,
+    pub route_type: StaticRouteType,
+    /// Destination network in CIDR notation.
+    pub destination: String,
+    /// Gateway IP; required for `NextHop` routes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_hop: Option<String>,
+    /// Egress interface; required for `Interface` routes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface: Option<String>,
+    /// Administrative distance. Defaults to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateStaticRouteRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route_type: Option<StaticRouteType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_hop: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<u32>,
+}
+
+// ── Switch ports ───────────────────────────────────────────────────
+
+/// Per-port override on a switch. `None` fields are left unchanged.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdatePortRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Port profile (`PortProfile::id`) to assign.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_id: Option<EntityId>,
+    /// Network to carry untagged on the port.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub native_network_id: Option<EntityId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poe_mode: Option<PoeMode>,
+}
+
+/// Radio settings for one band of an AP. `None` fields are left unchanged.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateDeviceRadioRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<RadioChannel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_width_mhz: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_power: Option<TxPower>,
+    /// Minimum client signal in dBm; setting it also enables the check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_rssi_dbm: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_rssi_enabled: Option<bool>,
+}
+
 // ── Vouchers ───────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
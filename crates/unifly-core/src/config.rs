@@ -4,9 +4,16 @@
 // They carry credential data and connection tuning, but never touch disk.
 // The CLI/TUI constructs a `ControllerConfig` and hands it in.
 
-use secrecy::SecretString;
+use std::sync::Arc;
+
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize};
+use totp_rs::{Algorithm, Secret, TOTP};
 use url::Url;
 
+use crate::error::CoreError;
+use crate::history::HistoryConfig;
+
 /// How to authenticate with a controller.
 ///
 /// Named `AuthCredentials` (not `AuthMethod`) to avoid collision with
@@ -37,6 +44,69 @@ pub enum AuthCredentials {
     },
 }
 
+/// Second factor for Legacy login on accounts with MFA enforced.
+#[derive(Debug, Clone)]
+pub enum TwoFactor {
+    /// A one-time code, typed in by the user. Only good for one login.
+    Code(SecretString),
+    /// Base32 TOTP secret; a fresh code is derived for every login, so
+    /// reconnects need no user interaction.
+    TotpSecret(SecretString),
+}
+
+impl TwoFactor {
+    /// The code to send with the next login.
+    pub fn current_code(&self) -> Result<SecretString, CoreError> {
+        match self {
+            Self::Code(code) => Ok(code.clone()),
+            Self::TotpSecret(secret) => totp_code(secret),
+        }
+    }
+}
+
+/// Derive the current 6-digit, 30-second TOTP code (RFC 6238, SHA-1).
+fn totp_code(secret: &SecretString) -> Result<SecretString, CoreError> {
+    let normalized: String = secret
+        .expose_secret()
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '=')
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    let bytes = Secret::Encoded(normalized)
+        .to_bytes()
+        .map_err(|_| CoreError::Config {
+            message: "TOTP secret is not valid base32".into(),
+        })?;
+    let totp = TOTP::new_unchecked(Algorithm::SHA1, 6, 1, 30, bytes);
+    let code = totp.generate_current().map_err(|e| CoreError::Config {
+        message: format!("cannot derive TOTP code: {e}"),
+    })?;
+    Ok(SecretString::from(code))
+}
+
+/// A Legacy API session saved between runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedSession {
+    /// Controller URL the session belongs to.
+    pub url: String,
+    pub username: String,
+    /// `Cookie` header value carrying the session cookie(s).
+    pub cookie: String,
+    pub csrf_token: Option<String>,
+}
+
+/// Storage for a Legacy session, so short-lived processes (one CLI run
+/// each) can skip the login. Core only calls it; where the session is
+/// kept is up to the implementor.
+pub trait SessionCache: Send + Sync + std::fmt::Debug {
+    fn load(&self) -> Option<CachedSession>;
+    fn store(&self, session: &CachedSession);
+}
+
+/// Site name that makes a [`Controller`](crate::Controller) load every site
+/// on the controller instead of a single one.
+pub const ALL_SITES: &str = "all";
+
 /// TLS verification strategy.
 #[derive(Debug, Clone, Default)]
 pub enum TlsVerification {
@@ -62,6 +132,33 @@ impl PartialEq for TlsVerification {
 
 impl Eq for TlsVerification {}
 
+/// Retry behavior for transient HTTP failures (timeouts, refused
+/// connections, rate limiting).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Retries after the first attempt; 0 disables retrying.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubling for each one after
+    /// (with jitter, and never shorter than a server's `Retry-After`).
+    pub initial_delay: std::time::Duration,
+    /// Upper bound on the backoff delay.
+    pub max_delay: std::time::Duration,
+    /// Also retry writes. Reads are always retried; a write may already
+    /// have been applied when its response was lost.
+    pub retry_writes: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_delay: std::time::Duration::from_millis(500),
+            max_delay: std::time::Duration::from_secs(10),
+            retry_writes: false,
+        }
+    }
+}
+
 /// Configuration for connecting to a single controller.
 ///
 /// Built by CLI/TUI, passed to `Controller` -- core never reads config files.
@@ -71,12 +168,15 @@ pub struct ControllerConfig {
     pub url: Url,
     /// Authentication method and credentials.
     pub auth: AuthCredentials,
-    /// Site to operate on (defaults to "default").
+    /// Site to operate on (defaults to "default"). [`ALL_SITES`] loads
+    /// every site; entities then carry their `site_id`.
     pub site: String,
     /// TLS verification strategy.
     pub tls: TlsVerification,
     /// Request timeout.
     pub timeout: std::time::Duration,
+    /// Retry policy for transient HTTP failures.
+    pub retry: RetryConfig,
     /// How often to perform a full refresh (seconds). 0 = never.
     pub refresh_interval_secs: u64,
     /// Enable WebSocket event stream.
@@ -90,6 +190,13 @@ pub struct ControllerConfig {
     /// How often to poll per-device statistics (CPU, memory, etc.) via the
     /// Integration API. Zero = disabled (relies on WebSocket only).
     pub device_stats_poll_interval: std::time::Duration,
+    /// Second factor for Legacy login, when the account requires one.
+    pub two_factor: Option<TwoFactor>,
+    /// Where to resume and save the Legacy session. When set, disconnecting
+    /// keeps the session alive instead of logging out.
+    pub session_cache: Option<Arc<dyn SessionCache>>,
+    /// Record live stats to disk while connected. `None` = off.
+    pub history: Option<HistoryConfig>,
 }
 
 impl Default for ControllerConfig {
@@ -105,12 +212,40 @@ impl Default for ControllerConfig {
             site: "default".into(),
             tls: TlsVerification::default(),
             timeout: std::time::Duration::from_secs(30),
+            retry: RetryConfig::default(),
             refresh_interval_secs: 300,
             websocket_enabled: true,
             polling_interval_secs: 10,
             bandwidth_poll_interval: std::time::Duration::from_millis(500),
             client_poll_interval: std::time::Duration::from_secs(2),
             device_stats_poll_interval: std::time::Duration::from_secs(5),
+            two_factor: None,
+            session_cache: None,
+            history: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn totp_secret_yields_six_digit_code() {
+        // RFC 6238 SHA-1 test key, lower-cased and space-separated the way
+        // authenticator apps often display it.
+        let secret = "gezd gnbv gy3t qojq gezd gnbv gy3t qojq".to_owned();
+        let code = TwoFactor::TotpSecret(secret.into())
+            .current_code()
+            .expect("valid secret");
+        let code = code.expose_secret();
+        assert_eq!(code.len(), 6);
+        assert!(code.chars().all(|c| c.is_ascii_digit()));
+    }
+
+    #[test]
+    fn totp_secret_rejects_invalid_base32() {
+        let result = TwoFactor::TotpSecret("not base32!".to_owned().into()).current_code();
+        assert!(matches!(result, Err(CoreError::Config { .. })));
+    }
+}
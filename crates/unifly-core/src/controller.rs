@@ -9,30 +9,47 @@ use std::net::{Ipv4Addr, Ipv6Addr};
 use std::sync::Arc;
 use std::time::Duration;
 
+use chrono::{DateTime, Utc};
+use futures_util::StreamExt;
 use tokio::sync::{Mutex, broadcast, mpsc, watch};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 
-use crate::command::{Command, CommandEnvelope, CommandResult};
-use crate::config::{AuthCredentials, ControllerConfig, TlsVerification};
+use crate::command::{
+    Command, CommandEnvelope, CommandResult, UpdateDeviceRadioRequest, UpdatePortRequest,
+};
+use crate::config::{
+    ALL_SITES, AuthCredentials, CachedSession, ControllerConfig, SessionCache, TlsVerification,
+    TwoFactor,
+};
+use crate::convert::legacy_number;
 use crate::error::CoreError;
 use crate::model::{
-    AclRule, Admin, Alarm, Client, Country, Device, DnsPolicy, DpiApplication, DpiCategory,
-    EntityId, Event, FirewallAction, FirewallPolicy, FirewallZone, HealthSummary, MacAddress,
-    Network, NetworkManagement, NetworkPurpose, RadiusProfile, Site, SysInfo, SystemInfo,
-    TrafficMatchingList, Voucher, VpnServer, VpnTunnel, WanInterface, WifiBroadcast,
+    AclRule, Admin, Alarm, Client, ClientSession, Country, Device, DnsPolicy, DpiApplication,
+    DpiCategory, EntityId, Event, FirewallAction, FirewallPolicy, FirewallZone, HealthSummary,
+    KnownClient, MacAddress, NeighborAp, Network, NetworkManagement, NetworkPurpose, PortForward,
+    PortProfile, RadioBand, RadioChannel, RadiusProfile, Site, SpectrumScan, StaticRoute,
+    StaticRouteType, SysInfo, SystemInfo, ThreatEvent, TrafficMatchingList, TxPower, UserGroup,
+    Voucher, VpnServer, VpnTunnel, WanInterface, WifiBroadcast,
 };
 use crate::model::device::DeviceStatsUpdate;
+use crate::settings::SiteSettings;
 use crate::store::DataStore;
 use crate::stream::EntityStream;
 
-use unifly_api::transport::{TlsMode, TransportConfig};
+use unifly_api::legacy::models::{
+    LegacyChannelPlan, LegacyDevice, LegacyPortOverride, LegacyRadioConfig,
+};
+use unifly_api::legacy::{Relogin, SessionHook, TokenSource};
+use unifly_api::transport::{RetryPolicy, TlsMode, TransportConfig};
 use unifly_api::websocket::{ReconnectConfig, WebSocketHandle};
 use unifly_api::{IntegrationClient, LegacyClient};
 
 const COMMAND_CHANNEL_SIZE: usize = 64;
 const EVENT_CHANNEL_SIZE: usize = 256;
+/// How many sites the all-sites refresh loads at once.
+const SITE_REFRESH_CONCURRENCY: usize = 4;
 
 // ── ConnectionState ──────────────────────────────────────────────
 
@@ -71,7 +88,11 @@ struct ControllerInner {
     cancel_child: Mutex<CancellationToken>,
     legacy_client: Mutex<Option<LegacyClient>>,
     integration_client: Mutex<Option<IntegrationClient>>,
-    /// Resolved Integration API site UUID (populated on connect).
+    /// Site currently loaded — starts as `config.site`, changed by
+    /// [`Controller::switch_site`].
+    active_site: watch::Sender<String>,
+    /// Resolved Integration API site UUID (populated on connect; `None`
+    /// when all sites are loaded).
     site_id: Mutex<Option<uuid::Uuid>>,
     /// WebSocket event stream handle (populated on connect if enabled).
     ws_handle: Mutex<Option<WebSocketHandle>>,
@@ -95,6 +116,7 @@ impl Controller {
         let cancel = CancellationToken::new();
         let cancel_child = cancel.child_token();
         let (stats_tx, stats_rx) = mpsc::unbounded_channel();
+        let (active_site, _) = watch::channel(config.site.clone());
 
         Self {
             inner: Arc::new(ControllerInner {
@@ -109,6 +131,7 @@ impl Controller {
                 legacy_client: Mutex::new(None),
                 integration_client: Mutex::new(None),
                 warnings: Mutex::new(Vec::new()),
+                active_site,
                 site_id: Mutex::new(None),
                 ws_handle: Mutex::new(None),
                 task_handles: Mutex::new(Vec::new()),
@@ -128,6 +151,21 @@ impl Controller {
         &self.inner.store
     }
 
+    /// The site currently loaded (initially `config.site`).
+    pub fn active_site(&self) -> String {
+        self.inner.active_site.borrow().clone()
+    }
+
+    /// Subscribe to site switches.
+    pub fn active_site_changes(&self) -> watch::Receiver<String> {
+        self.inner.active_site.subscribe()
+    }
+
+    /// Whether every site on the controller is loaded ([`ALL_SITES`]).
+    pub fn is_all_sites(&self) -> bool {
+        *self.inner.active_site.borrow() == ALL_SITES
+    }
+
     // ── Connection lifecycle ─────────────────────────────────────
 
     /// Connect to the controller.
@@ -148,6 +186,7 @@ impl Controller {
 
         let config = &self.inner.config;
         let transport = build_transport(config);
+        let site = self.active_site();
 
         match &config.auth {
             AuthCredentials::ApiKey(api_key) => {
@@ -164,16 +203,16 @@ impl Controller {
                 )?;
 
                 // Resolve site UUID from Integration API
-                let site_id = resolve_site_id(&integration, &config.site).await?;
-                debug!(site_id = %site_id, "resolved Integration API site UUID");
+                let site_id = resolve_active_site_id(&integration, &site).await?;
+                debug!(site_id = ?site_id, "resolved Integration API site UUID");
 
                 *self.inner.integration_client.lock().await = Some(integration);
-                *self.inner.site_id.lock().await = Some(site_id);
+                *self.inner.site_id.lock().await = site_id;
 
                 // Also set up Legacy client for event streams and supplementary data.
                 // API key auth may not work with Legacy API on all controllers,
                 // so we swallow errors here — it's optional.
-                match setup_legacy_client(config, &transport).await {
+                match setup_legacy_client(config, &site, &transport).await {
                     Ok(client) => {
                         *self.inner.legacy_client.lock().await = Some(client);
                         debug!("legacy client available as supplement");
@@ -188,13 +227,9 @@ impl Controller {
                 let platform = LegacyClient::detect_platform(&config.url).await?;
                 debug!(?platform, "detected controller platform");
 
-                let client = LegacyClient::new(
-                    config.url.clone(),
-                    config.site.clone(),
-                    platform,
-                    &transport,
-                )?;
-                client.login(username, password).await?;
+                let mut client =
+                    LegacyClient::new(config.url.clone(), site.clone(), platform, &transport)?;
+                start_legacy_session(&mut client, username, password, config).await?;
                 debug!("session authentication successful");
 
                 *self.inner.legacy_client.lock().await = Some(client);
@@ -216,34 +251,31 @@ impl Controller {
                     platform,
                 )?;
 
-                let site_id = resolve_site_id(&integration, &config.site).await?;
-                debug!(site_id = %site_id, "resolved Integration API site UUID");
+                let site_id = resolve_active_site_id(&integration, &site).await?;
+                debug!(site_id = ?site_id, "resolved Integration API site UUID");
 
                 *self.inner.integration_client.lock().await = Some(integration);
-                *self.inner.site_id.lock().await = Some(site_id);
+                *self.inner.site_id.lock().await = site_id;
 
                 // Legacy API client — attempt login but degrade gracefully
                 // if it fails. The Integration API is the primary surface;
                 // Legacy adds events, stats, and admin ops.
-                match LegacyClient::new(
-                    config.url.clone(),
-                    config.site.clone(),
-                    platform,
-                    &transport,
-                ) {
-                    Ok(client) => match client.login(username, password).await {
-                        Ok(()) => {
-                            debug!("legacy session authentication successful (hybrid)");
-                            *self.inner.legacy_client.lock().await = Some(client);
-                        }
-                        Err(e) => {
-                            let msg = format!(
-                                "Legacy login failed: {e} — events, health stats, and client traffic will be unavailable"
-                            );
-                            warn!("{msg}");
-                            self.inner.warnings.lock().await.push(msg);
+                match LegacyClient::new(config.url.clone(), site.clone(), platform, &transport) {
+                    Ok(mut client) => {
+                        match start_legacy_session(&mut client, username, password, config).await {
+                            Ok(()) => {
+                                debug!("legacy session authentication successful (hybrid)");
+                                *self.inner.legacy_client.lock().await = Some(client);
+                            }
+                            Err(e) => {
+                                let msg = format!(
+                                    "Legacy login failed: {e} — events, health stats, and client traffic will be unavailable"
+                                );
+                                warn!("{msg}");
+                                self.inner.warnings.lock().await.push(msg);
+                            }
                         }
-                    },
+                    }
                     Err(e) => {
                         let msg = format!("Legacy client setup failed: {e}");
                         warn!("{msg}");
@@ -252,24 +284,34 @@ impl Controller {
                 }
             }
             AuthCredentials::Cloud { api_key, host_id } => {
+                if host_id.is_empty() {
+                    return Err(CoreError::Config {
+                        message: "cloud auth needs the host ID of a console \
+                                  (list them with: unifly cloud hosts)"
+                            .into(),
+                    });
+                }
+
+                // Requests reach the console through the connector proxy.
+                let connector = unifly_api::cloud::connector_url(config.url.as_str(), host_id)?;
                 let integration = IntegrationClient::from_api_key(
-                    config.url.as_str(),
+                    connector.as_str(),
                     api_key,
                     &transport,
                     unifly_api::ControllerPlatform::Cloud,
                 )?;
 
-                let site_id = if let Ok(uuid) = uuid::Uuid::parse_str(&config.site) {
-                    uuid
-                } else if let Ok(uuid) = uuid::Uuid::parse_str(host_id) {
-                    uuid
+                let site_id = if site == ALL_SITES {
+                    None
+                } else if let Ok(uuid) = uuid::Uuid::parse_str(&site) {
+                    Some(uuid)
                 } else {
-                    resolve_site_id(&integration, &config.site).await?
+                    Some(resolve_site_id(&integration, &site).await?)
                 };
-                debug!(site_id = %site_id, "resolved cloud Integration API site UUID");
+                debug!(site_id = ?site_id, "resolved cloud Integration API site UUID");
 
                 *self.inner.integration_client.lock().await = Some(integration);
-                *self.inner.site_id.lock().await = Some(site_id);
+                *self.inner.site_id.lock().await = site_id;
 
                 let msg =
                     "Cloud auth mode active: Legacy API and WebSocket features are unavailable"
@@ -341,6 +383,15 @@ impl Controller {
             handles.push(tokio::spawn(client_daily_usage_task(ctrl, cancel)));
         }
 
+        // Opt-in on-disk stats history
+        if let Some(history) = config.history.clone() {
+            let ctrl = self.clone();
+            let cancel = child.clone();
+            handles.push(tokio::spawn(crate::history::record_task(
+                ctrl, history, cancel,
+            )));
+        }
+
         // WebSocket event stream
         if config.websocket_enabled {
             self.spawn_websocket(&child, &mut handles).await;
@@ -368,7 +419,11 @@ impl Controller {
             return;
         };
 
-        let ws_path = ws_path_template.replace("{site}", &self.inner.config.site);
+        if self.is_all_sites() {
+            debug!("all sites loaded — WebSocket is per-site (skipping)");
+            return;
+        }
+        let ws_path = ws_path_template.replace("{site}", &self.active_site());
         let base_url = &self.inner.config.url;
         let scheme = if base_url.scheme() == "https" {
             "wss"
@@ -480,13 +535,17 @@ impl Controller {
             let _ = handle.await;
         }
 
-        // Logout if session-based (Credentials or Hybrid both have active sessions)
+        // Logout if session-based (Credentials or Hybrid both have active
+        // sessions). With a session cache the session is kept alive for the
+        // next run instead.
         if matches!(
             self.inner.config.auth,
             AuthCredentials::Credentials { .. } | AuthCredentials::Hybrid { .. }
         ) {
             if let Some(ref client) = *self.inner.legacy_client.lock().await {
-                if let Err(e) = client.logout().await {
+                if self.inner.config.session_cache.is_some() {
+                    save_legacy_session(client, &self.inner.config);
+                } else if let Err(e) = client.logout().await {
                     warn!(error = %e, "logout failed (non-fatal)");
                 }
             }
@@ -521,8 +580,15 @@ impl Controller {
     /// Pulls devices, clients, and events from the Legacy API, converts
     /// them to domain types, and applies them to the store. Events are
     /// broadcast through the event channel (not stored).
+    ///
+    /// When all sites are loaded this delegates to the all-sites refresh,
+    /// which covers devices, clients, networks and WiFi for every site.
     #[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
     pub async fn full_refresh(&self) -> Result<(), CoreError> {
+        if self.is_all_sites() {
+            return self.refresh_all_sites().await;
+        }
+
         let integration_guard = self.inner.integration_client.lock().await;
         let site_id = *self.inner.site_id.lock().await;
 
@@ -578,7 +644,7 @@ impl Controller {
                 network_count = network_ids.len(),
                 "fetching network details"
             );
-            let networks: Vec<Network> = {
+            let mut networks: Vec<Network> = {
                 let futs = network_ids.into_iter().map(|nid| async move {
                     match integration.get_network(&sid, &nid).await {
                         Ok(detail) => Some(Network::from(detail)),
@@ -594,7 +660,8 @@ impl Controller {
                     .flatten()
                     .collect()
             };
-            let wifi: Vec<WifiBroadcast> = wifi_res?.into_iter().map(WifiBroadcast::from).collect();
+            let mut wifi: Vec<WifiBroadcast> =
+                wifi_res?.into_iter().map(WifiBroadcast::from).collect();
             let policies: Vec<FirewallPolicy> = unwrap_or_empty("firewall/policies", policies_res);
             let zones: Vec<FirewallZone> = unwrap_or_empty("firewall/zones", zones_res);
             let sites: Vec<Site> = sites_res?.into_iter().map(Site::from).collect();
@@ -675,52 +742,67 @@ impl Controller {
                 guard.as_ref().cloned()
             };
 
-            let (legacy_events, legacy_health, legacy_devices): (
-                Vec<Event>,
-                Vec<HealthSummary>,
-                Vec<unifly_api::legacy::models::LegacyDevice>,
-            ) = match legacy_clone {
-                Some(legacy) => {
-                    let (events_res, health_res, devices_res) = tokio::join!(
-                        legacy.list_events(Some(100)),
-                        legacy.get_health(),
-                        legacy.list_devices(),
-                    );
+            let (legacy_events, legacy_health, legacy_devices, port_forwards, static_routes) =
+                match legacy_clone {
+                    Some(legacy) => {
+                        let (events_res, health_res, devices_res, pf_res, routes_res) = tokio::join!(
+                            legacy.list_events(Some(100)),
+                            legacy.get_health(),
+                            legacy.list_devices(),
+                            legacy.list_port_forwards(),
+                            legacy.list_static_routes(),
+                        );
 
-                    let events = match events_res {
-                        Ok(raw) => {
-                            let evts: Vec<Event> = raw.into_iter().map(Event::from).collect();
-                            for evt in &evts {
-                                let _ = self.inner.event_tx.send(Arc::new(evt.clone()));
+                        let events = match events_res {
+                            Ok(raw) => {
+                                let evts: Vec<Event> = raw.into_iter().map(Event::from).collect();
+                                for evt in &evts {
+                                    let _ = self.inner.event_tx.send(Arc::new(evt.clone()));
+                                }
+                                evts
                             }
-                            evts
-                        }
-                        Err(e) => {
-                            warn!(error = %e, "legacy event fetch failed (non-fatal)");
-                            Vec::new()
-                        }
-                    };
+                            Err(e) => {
+                                warn!(error = %e, "legacy event fetch failed (non-fatal)");
+                                Vec::new()
+                            }
+                        };
 
-                    let health = match health_res {
-                        Ok(raw) => convert_health_summaries(raw),
-                        Err(e) => {
-                            warn!(error = %e, "legacy health fetch failed (non-fatal)");
-                            Vec::new()
-                        }
-                    };
+                        let health = match health_res {
+                            Ok(raw) => convert_health_summaries(raw),
+                            Err(e) => {
+                                warn!(error = %e, "legacy health fetch failed (non-fatal)");
+                                Vec::new()
+                            }
+                        };
 
-                    let ld = match devices_res {
-                        Ok(raw) => raw,
-                        Err(e) => {
-                            warn!(error = %e, "legacy device fetch failed (non-fatal)");
-                            Vec::new()
-                        }
-                    };
+                        let ld = match devices_res {
+                            Ok(raw) => raw,
+                            Err(e) => {
+                                warn!(error = %e, "legacy device fetch failed (non-fatal)");
+                                Vec::new()
+                            }
+                        };
 
-                    (events, health, ld)
-                }
-                None => (Vec::new(), Vec::new(), Vec::new()),
-            };
+                        let pf: Vec<PortForward> = match pf_res {
+                            Ok(raw) => raw.into_iter().map(PortForward::from).collect(),
+                            Err(e) => {
+                                warn!(error = %e, "legacy port forward fetch failed (non-fatal)");
+                                Vec::new()
+                            }
+                        };
+
+                        let routes = match routes_res {
+                            Ok(raw) => static_routes_from(raw),
+                            Err(e) => {
+                                warn!(error = %e, "legacy static route fetch failed (non-fatal)");
+                                Vec::new()
+                            }
+                        };
+
+                        (events, health, ld, pf, routes)
+                    }
+                    None => (Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new()),
+                };
 
             // Merge Legacy device num_sta (client counts) into Integration devices
             if !legacy_devices.is_empty() {
@@ -777,6 +859,16 @@ impl Controller {
                                     sys.load_15.as_deref().and_then(|v| v.parse().ok());
                             }
                         }
+                        // Integration API doesn't report ports or radios; take them from Legacy.
+                        if device.ports.is_empty() {
+                            device.ports = crate::convert::legacy_ports(ld);
+                        }
+                        if device.radios.is_empty() {
+                            device.radios = crate::convert::legacy_radios(ld);
+                        }
+                        if device.firmware_target.is_none() {
+                            device.firmware_target = crate::convert::legacy_firmware_target(ld);
+                        }
                         // Fill uptime from Legacy when Integration didn't provide it
                         if device.stats.uptime_secs.is_none() {
                             device.stats.uptime_secs =
@@ -786,6 +878,17 @@ impl Controller {
                 }
             }
 
+            let site = EntityId::Uuid(sid);
+            for device in &mut devices {
+                device.site_id = Some(site.clone());
+            }
+            for network in &mut networks {
+                network.site_id = Some(site.clone());
+            }
+            for broadcast in &mut wifi {
+                broadcast.site_id = Some(site.clone());
+            }
+
             // Push health to DataStore
             if !legacy_health.is_empty() {
                 self.inner
@@ -808,6 +911,8 @@ impl Controller {
                     sites,
                     events: legacy_events,
                     traffic_matching_lists,
+                    port_forwards,
+                    static_routes,
                 });
         } else {
             // ── Legacy-only path ─────────────────────────────────
@@ -818,13 +923,31 @@ impl Controller {
                 .as_ref()
                 .ok_or(CoreError::ControllerDisconnected)?;
 
-            let (devices_res, events_res) = tokio::join!(
+            let (devices_res, events_res, pf_res, routes_res, sites_res) = tokio::join!(
                 legacy.list_devices(),
                 legacy.list_events(Some(100)),
+                legacy.list_port_forwards(),
+                legacy.list_static_routes(),
+                legacy.list_sites(),
             );
 
             let devices: Vec<Device> = devices_res?.into_iter().map(Device::from).collect();
             let events: Vec<Event> = events_res?.into_iter().map(Event::from).collect();
+            let port_forwards: Vec<PortForward> = match pf_res {
+                Ok(raw) => raw.into_iter().map(PortForward::from).collect(),
+                Err(e) => {
+                    warn!(error = %e, "legacy port forward fetch failed (non-fatal)");
+                    Vec::new()
+                }
+            };
+            let static_routes: Vec<StaticRoute> = match routes_res {
+                Ok(raw) => static_routes_from(raw),
+                Err(e) => {
+                    warn!(error = %e, "legacy static route fetch failed (non-fatal)");
+                    Vec::new()
+                }
+            };
+            let sites: Vec<Site> = unwrap_or_empty("self/sites", sites_res);
 
             drop(legacy_guard);
 
@@ -843,9 +966,11 @@ impl Controller {
                     acls: Vec::new(),
                     dns: Vec::new(),
                     vouchers: Vec::new(),
-                    sites: Vec::new(),
+                    sites,
                     events,
                     traffic_matching_lists: Vec::new(),
+                    port_forwards,
+                    static_routes,
                 });
         }
 
@@ -858,6 +983,164 @@ impl Controller {
         Ok(())
     }
 
+    /// All-sites variant of [`full_refresh()`](Self::full_refresh).
+    ///
+    /// Loads the site list, then the inventory of every site a few sites
+    /// at a time. Each entity is tagged with its `site_id`; a site that
+    /// fails to load is logged and left empty rather than failing the
+    /// whole refresh. Clients are keyed by site and MAC, so a client seen
+    /// on two sites is listed once per site. Per-site configuration
+    /// (firewall, DNS, port forwards, ...) is not aggregated.
+    async fn refresh_all_sites(&self) -> Result<(), CoreError> {
+        let integration = self.inner.integration_client.lock().await.clone();
+        let legacy = self.inner.legacy_client.lock().await.clone();
+
+        let mut sites: Vec<Site> = if let Some(ref integration) = integration {
+            integration
+                .paginate_all(50, |off, lim| integration.list_sites(off, lim))
+                .await?
+                .into_iter()
+                .map(Site::from)
+                .collect()
+        } else {
+            let legacy = legacy.as_ref().ok_or(CoreError::ControllerDisconnected)?;
+            legacy
+                .list_sites()
+                .await?
+                .into_iter()
+                .map(Site::from)
+                .collect()
+        };
+        info!(site_count = sites.len(), "loading all sites");
+
+        let mut inventories = Vec::with_capacity(sites.len());
+        for batch in sites.chunks(SITE_REFRESH_CONCURRENCY) {
+            let futs = batch
+                .iter()
+                .map(|site| load_site(integration.as_ref(), legacy.as_ref(), site));
+            inventories.extend(futures_util::future::join_all(futs).await);
+        }
+
+        let mut all = SiteInventory::default();
+        for (site, inventory) in sites.iter_mut().zip(inventories) {
+            site.device_count = inventory.devices.len().try_into().ok();
+            site.client_count = inventory.clients.len().try_into().ok();
+            all.devices.extend(inventory.devices);
+            all.clients.extend(inventory.clients);
+            all.networks.extend(inventory.networks);
+            all.wifi.extend(inventory.wifi);
+        }
+
+        self.inner
+            .store
+            .apply_integration_snapshot(crate::store::RefreshSnapshot {
+                devices: all.devices,
+                networks: all.networks,
+                wifi: all.wifi,
+                policies: Vec::new(),
+                zones: Vec::new(),
+                acls: Vec::new(),
+                dns: Vec::new(),
+                vouchers: Vec::new(),
+                sites,
+                events: Vec::new(),
+                traffic_matching_lists: Vec::new(),
+                port_forwards: Vec::new(),
+                static_routes: Vec::new(),
+            });
+        self.inner.store.apply_site_clients(all.clients);
+
+        debug!(
+            devices = self.inner.store.device_count(),
+            clients = self.inner.store.client_count(),
+            "all-sites refresh complete"
+        );
+        Ok(())
+    }
+
+    /// Fetch the active site's clients from the Legacy API and replace the
+    /// client collection with them.
+    async fn poll_clients(&self) -> Result<(), CoreError> {
+        let legacy = self
+            .inner
+            .legacy_client
+            .lock()
+            .await
+            .clone()
+            .ok_or(CoreError::ControllerDisconnected)?;
+        let site_id = *self.inner.site_id.lock().await;
+
+        let clients = legacy
+            .list_clients()
+            .await?
+            .into_iter()
+            .map(|raw| {
+                let mut client = Client::from(raw);
+                // Match the site ids used for devices when the
+                // Integration API is in play.
+                if let Some(sid) = site_id {
+                    client.site_id = Some(EntityId::Uuid(sid));
+                }
+                client
+            })
+            .collect();
+        self.inner.store.apply_clients(clients);
+        Ok(())
+    }
+
+    /// Switch to another site on the same controller without logging in
+    /// again.
+    ///
+    /// Re-resolves the Integration API site UUID, points the Legacy client
+    /// at the new site, drops the previous site's clients and health, then
+    /// reloads the store and restarts the WebSocket stream. Pass
+    /// [`ALL_SITES`] to load every site.
+    pub async fn switch_site(&self, site: &str) -> Result<(), CoreError> {
+        if site == self.active_site() {
+            return Ok(());
+        }
+        let all_sites = site == ALL_SITES;
+
+        let site_id = {
+            let guard = self.inner.integration_client.lock().await;
+            match guard.as_ref() {
+                Some(integration) => resolve_active_site_id(integration, site).await?,
+                None => None,
+            }
+        };
+
+        if let Some(handle) = self.inner.ws_handle.lock().await.take() {
+            handle.shutdown();
+        }
+        {
+            let mut guard = self.inner.legacy_client.lock().await;
+            let switched = guard.as_ref().map(|legacy| legacy.with_site(site));
+            *guard = switched;
+        }
+        *self.inner.site_id.lock().await = site_id;
+        self.inner.active_site.send_replace(site.to_owned());
+        info!(site, "switched site");
+
+        self.inner.store.clients.clear();
+        self.inner
+            .store
+            .site_health
+            .send_modify(|h| *h = Arc::new(Vec::new()));
+        self.full_refresh().await?;
+
+        if !all_sites {
+            if let Err(e) = self.poll_clients().await {
+                debug!(error = %e, "initial client fetch after site switch failed");
+            }
+            if self.inner.config.websocket_enabled {
+                let child = self.inner.cancel_child.lock().await.clone();
+                let mut handles = self.inner.task_handles.lock().await;
+                self.spawn_websocket(&child, &mut handles).await;
+            }
+        }
+        Ok(())
+    }
+
     // ── Command execution ────────────────────────────────────────
 
     /// Execute a command against the controller.
@@ -865,9 +1148,7 @@ impl Controller {
     /// Sends the command through the internal channel to the command
     /// processor task and awaits the result.
     pub async fn execute(&self, cmd: Command) -> Result<CommandResult, CoreError> {
-        if *self.inner.connection_state.borrow() != ConnectionState::Connected {
-            return Err(CoreError::ControllerDisconnected);
-        }
+        self.check_can_execute()?;
 
         let (tx, rx) = tokio::sync::oneshot::channel();
 
@@ -884,6 +1165,36 @@ impl Controller {
         rx.await.map_err(|_| CoreError::ControllerDisconnected)?
     }
 
+    /// Execute several commands with at most `concurrency` in flight.
+    ///
+    /// Meant for bulk operations: unlike [`execute`](Self::execute) these
+    /// run side by side rather than through the command queue. Per-command
+    /// results come back in the order of `cmds`.
+    pub async fn execute_many(
+        &self,
+        cmds: Vec<Command>,
+        concurrency: usize,
+    ) -> Result<Vec<Result<CommandResult, CoreError>>, CoreError> {
+        self.check_can_execute()?;
+        Ok(futures_util::stream::iter(cmds)
+            .map(|cmd| route_command(self, cmd))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await)
+    }
+
+    fn check_can_execute(&self) -> Result<(), CoreError> {
+        if *self.inner.connection_state.borrow() != ConnectionState::Connected {
+            return Err(CoreError::ControllerDisconnected);
+        }
+        if self.is_all_sites() {
+            return Err(CoreError::ValidationFailed {
+                message: "commands apply to a single site; select one instead of 'all'".into(),
+            });
+        }
+        Ok(())
+    }
+
     // ── One-shot convenience ─────────────────────────────────────
 
     /// One-shot: connect, run closure, disconnect.
@@ -956,6 +1267,14 @@ impl Controller {
         self.inner.store.vouchers_snapshot()
     }
 
+    pub fn port_forwards_snapshot(&self) -> Arc<Vec<Arc<PortForward>>> {
+        self.inner.store.port_forwards_snapshot()
+    }
+
+    pub fn static_routes_snapshot(&self) -> Arc<Vec<Arc<StaticRoute>>> {
+        self.inner.store.static_routes_snapshot()
+    }
+
     pub fn sites_snapshot(&self) -> Arc<Vec<Arc<Site>>> {
         self.inner.store.sites_snapshot()
     }
@@ -1006,6 +1325,14 @@ impl Controller {
         self.inner.store.subscribe_vouchers()
     }
 
+    pub fn port_forwards(&self) -> EntityStream<PortForward> {
+        self.inner.store.subscribe_port_forwards()
+    }
+
+    pub fn static_routes(&self) -> EntityStream<StaticRoute> {
+        self.inner.store.subscribe_static_routes()
+    }
+
     pub fn sites(&self) -> EntityStream<Site> {
         self.inner.store.subscribe_sites()
     }
@@ -1497,6 +1824,133 @@ impl Controller {
         Ok(raw.into_iter().map(Alarm::from).collect())
     }
 
+    /// Fetch IDS/IPS detections between `start` and `end` from the Legacy
+    /// API, newest first.
+    pub async fn list_threat_events(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<ThreatEvent>, CoreError> {
+        let guard = self.inner.legacy_client.lock().await;
+        let legacy = require_legacy(&guard)?;
+        let raw = legacy
+            .list_ips_events(start.timestamp_millis(), end.timestamp_millis())
+            .await?;
+        let mut events: Vec<ThreatEvent> = raw.into_iter().map(ThreatEvent::from).collect();
+        events.sort_by_key(|e| std::cmp::Reverse(e.timestamp));
+        Ok(events)
+    }
+
+    /// Fetch foreign access points heard by the site's APs in the last
+    /// `within_hours` (controller default 24) from the Legacy API.
+    pub async fn list_neighbor_aps(
+        &self,
+        within_hours: Option<u32>,
+    ) -> Result<Vec<NeighborAp>, CoreError> {
+        let guard = self.inner.legacy_client.lock().await;
+        let legacy = require_legacy(&guard)?;
+        let raw = legacy.list_rogue_aps(within_hours).await?;
+        Ok(raw.into_iter().map(NeighborAp::from).collect())
+    }
+
+    /// Fetch the latest spectrum scan for an AP from the Legacy API.
+    /// Returns `None` if the AP has never been scanned.
+    pub async fn get_spectrum_scan(
+        &self,
+        mac: &MacAddress,
+    ) -> Result<Option<SpectrumScan>, CoreError> {
+        let guard = self.inner.legacy_client.lock().await;
+        let legacy = require_legacy(&guard)?;
+        let raw = legacy.get_spectrum_scan(mac.as_str()).await?;
+        Ok(raw.map(|r| SpectrumScan {
+            device_mac: mac.clone(),
+            ..SpectrumScan::from(r)
+        }))
+    }
+
+    /// Fetch switch port profiles from the Legacy API.
+    pub async fn list_port_profiles(&self) -> Result<Vec<PortProfile>, CoreError> {
+        let guard = self.inner.legacy_client.lock().await;
+        let legacy = require_legacy(&guard)?;
+        let raw = legacy.list_port_profiles().await?;
+        Ok(raw.into_iter().map(PortProfile::from).collect())
+    }
+
+    /// Networks keyed by their Legacy `networkconf` id, which is what
+    /// switch ports and port profiles reference (`Port::native_network_id`).
+    ///
+    /// Store networks carry Integration API ids when an API key is in
+    /// use, so they are matched to the legacy records by name.
+    pub async fn networks_by_legacy_id(
+        &self,
+    ) -> Result<HashMap<EntityId, Arc<Network>>, CoreError> {
+        let conf = {
+            let guard = self.inner.legacy_client.lock().await;
+            let legacy = require_legacy(&guard)?;
+            legacy.list_network_conf().await?
+        };
+        let networks = self.inner.store.networks_snapshot();
+        Ok(conf
+            .into_iter()
+            .filter_map(|c| {
+                let id = EntityId::from(c.id.as_str());
+                let network = networks.iter().find(|n| {
+                    n.id == id || (n.id.as_uuid().is_some() && c.name.as_deref() == Some(&n.name))
+                })?;
+                Some((id, Arc::clone(network)))
+            })
+            .collect())
+    }
+
+    /// Fetch every site settings section from the Legacy API.
+    pub async fn get_site_settings(&self) -> Result<SiteSettings, CoreError> {
+        let guard = self.inner.legacy_client.lock().await;
+        let legacy = require_legacy(&guard)?;
+        let raw = legacy.get_settings().await?;
+        Ok(SiteSettings::from(raw))
+    }
+
+    /// Fetch user groups (bandwidth profiles) from the Legacy API.
+    pub async fn list_user_groups(&self) -> Result<Vec<UserGroup>, CoreError> {
+        let guard = self.inner.legacy_client.lock().await;
+        let legacy = require_legacy(&guard)?;
+        let raw = legacy.list_user_groups().await?;
+        Ok(raw.into_iter().map(UserGroup::from).collect())
+    }
+
+    /// Fetch every client the controller has seen in the last
+    /// `within_hours` (controller default one year), connected or not,
+    /// from the Legacy API. Most recently seen first.
+    pub async fn list_known_clients(
+        &self,
+        within_hours: Option<u32>,
+    ) -> Result<Vec<KnownClient>, CoreError> {
+        let guard = self.inner.legacy_client.lock().await;
+        let legacy = require_legacy(&guard)?;
+        let raw = legacy.list_known_clients(within_hours).await?;
+        let mut known: Vec<KnownClient> = raw.into_iter().map(KnownClient::from).collect();
+        known.sort_by_key(|k| std::cmp::Reverse(k.last_seen));
+        Ok(known)
+    }
+
+    /// Fetch a client's connection sessions between `start` and `end`
+    /// from the Legacy API, newest first.
+    pub async fn list_client_sessions(
+        &self,
+        mac: &MacAddress,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<ClientSession>, CoreError> {
+        let guard = self.inner.legacy_client.lock().await;
+        let legacy = require_legacy(&guard)?;
+        let raw = legacy
+            .list_client_sessions(mac.as_str(), start.timestamp(), end.timestamp())
+            .await?;
+        let mut sessions: Vec<ClientSession> = raw.into_iter().map(ClientSession::from).collect();
+        sessions.sort_by_key(|s| std::cmp::Reverse(s.connected_at));
+        Ok(sessions)
+    }
+
     /// Fetch controller system info.
     ///
     /// Prefers the Integration API (`GET /v1/info`) when available,
@@ -1805,6 +2259,9 @@ async fn health_poll_task(controller: Controller, period: Duration, cancel: Canc
             () = cancel.cancelled() => break,
             _ = interval.tick() => {
                 tracing::debug!("health_poll_task tick");
+                if controller.is_all_sites() {
+                    continue;
+                }
                 // Clone legacy client early and release lock before async call
                 let legacy = {
                     let guard = controller.inner.legacy_client.lock().await;
@@ -1871,45 +2328,14 @@ async fn client_poll_task(controller: Controller, period: Duration, cancel: Canc
             () = cancel.cancelled() => break,
             _ = interval.tick() => {
                 tracing::debug!("client_poll_task tick (fallback)");
-                let legacy = {
-                    let guard = controller.inner.legacy_client.lock().await;
-                    match &*guard {
-                        Some(l) => l.clone(),
-                        None => {
-                            debug!("client_poll: no legacy client — will retry");
-                            continue;
-                        }
-                    }
-                };
-
-                match legacy.list_clients().await {
-                    Ok(raw) => {
-                        let clients: Vec<Client> = raw.into_iter().map(Client::from).collect();
-
-                        let items: Vec<(String, EntityId, Client)> = clients
-                            .into_iter()
-                            .map(|c| {
-                                let key = c.mac.as_str().to_owned();
-                                let id = c.id.clone();
-                                (key, id, c)
-                            })
-                            .collect();
-                        let col = &controller.inner.store.clients;
-                        let incoming_keys: std::collections::HashSet<String> =
-                            items.iter().map(|(k, _, _)| k.clone()).collect();
-                        for (key, id, entity) in items {
-                            col.upsert_silent(key, id, entity);
-                        }
-                        let stale: Vec<String> = col.keys()
-                            .into_iter()
-                            .filter(|k| !incoming_keys.contains(k))
-                            .collect();
-                        for key in &stale {
-                            col.remove(key);
-                        }
-                        if stale.is_empty() {
-                            col.flush();
-                        }
+                // All-sites clients come from the periodic full refresh.
+                if controller.is_all_sites() {
+                    continue;
+                }
+                match controller.poll_clients().await {
+                    Ok(()) => {}
+                    Err(CoreError::ControllerDisconnected) => {
+                        debug!("client_poll: no legacy client — will retry");
                     }
                     Err(e) => {
                         debug!(error = %e, "client_poll: fetch failed");
@@ -2055,7 +2481,7 @@ async fn device_stats_poll_task(
 
 /// Periodically fetch monthly WAN usage from daily site stats.
 async fn monthly_stats_task(controller: Controller, cancel: CancellationToken) {
-    use chrono::{Datelike, Utc};
+    use chrono::Datelike;
 
     // Fetch once immediately, then every 60 seconds
     let mut interval = tokio::time::interval(Duration::from_secs(60));
@@ -2065,6 +2491,9 @@ async fn monthly_stats_task(controller: Controller, cancel: CancellationToken) {
             biased;
             () = cancel.cancelled() => break,
             _ = interval.tick() => {
+                if controller.is_all_sites() {
+                    continue;
+                }
                 // Clone and release lock before API call
                 let legacy = {
                     let guard = controller.inner.legacy_client.lock().await;
@@ -2125,8 +2554,6 @@ async fn monthly_stats_task(controller: Controller, cancel: CancellationToken) {
 /// writes new report rows every few minutes.
 #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation, clippy::as_conversions)]
 async fn client_daily_usage_task(controller: Controller, cancel: CancellationToken) {
-    use chrono::Utc;
-
     let mut interval = tokio::time::interval(Duration::from_secs(60));
 
     loop {
@@ -2134,6 +2561,9 @@ async fn client_daily_usage_task(controller: Controller, cancel: CancellationTok
             biased;
             () = cancel.cancelled() => break,
             _ = interval.tick() => {
+                if controller.is_all_sites() {
+                    continue;
+                }
                 // Clone and release lock before API call
                 let legacy = {
                     let guard = controller.inner.legacy_client.lock().await;
@@ -2182,6 +2612,79 @@ async fn client_daily_usage_task(controller: Controller, cancel: CancellationTok
     }
 }
 
+/// Inventory of one site, as loaded by the all-sites refresh.
+#[derive(Default)]
+struct SiteInventory {
+    devices: Vec<Device>,
+    clients: Vec<Client>,
+    networks: Vec<Network>,
+    wifi: Vec<WifiBroadcast>,
+}
+
+/// Load one site's devices, clients, networks and WiFi broadcasts.
+///
+/// Uses the Integration API when the site has a UUID, the Legacy API
+/// (pointed at the site's internal name) otherwise. Clients prefer the
+/// Legacy API, which reports far more detail. Failures are non-fatal.
+async fn load_site(
+    integration: Option<&IntegrationClient>,
+    legacy: Option<&LegacyClient>,
+    site: &Site,
+) -> SiteInventory {
+    let page_limit = 200;
+    let legacy = legacy.map(|l| l.with_site(site.internal_name.clone()));
+    let mut inventory = SiteInventory::default();
+
+    let integration_site = match (integration, &site.id) {
+        (Some(integration), EntityId::Uuid(sid)) => Some((integration, *sid)),
+        _ => None,
+    };
+
+    if let Some((integration, sid)) = integration_site {
+        let (devices_res, networks_res, wifi_res) = tokio::join!(
+            integration.paginate_all(page_limit, |off, lim| {
+                integration.list_devices(&sid, off, lim)
+            }),
+            integration.paginate_all(page_limit, |off, lim| {
+                integration.list_networks(&sid, off, lim)
+            }),
+            integration.paginate_all(page_limit, |off, lim| {
+                integration.list_wifi_broadcasts(&sid, off, lim)
+            }),
+        );
+        inventory.devices = unwrap_or_empty("devices", devices_res);
+        inventory.networks = unwrap_or_empty("networks", networks_res);
+        inventory.wifi = unwrap_or_empty("wifi/broadcasts", wifi_res);
+    } else if let Some(ref legacy) = legacy {
+        inventory.devices = unwrap_or_empty("stat/device", legacy.list_devices().await);
+    }
+
+    if let Some(ref legacy) = legacy {
+        inventory.clients = unwrap_or_empty("stat/sta", legacy.list_clients().await);
+    } else if let Some((integration, sid)) = integration_site {
+        let clients_res = integration
+            .paginate_all(page_limit, |off, lim| {
+                integration.list_clients(&sid, off, lim)
+            })
+            .await;
+        inventory.clients = unwrap_or_empty("clients", clients_res);
+    }
+
+    for device in &mut inventory.devices {
+        device.site_id = Some(site.id.clone());
+    }
+    for client in &mut inventory.clients {
+        client.site_id = Some(site.id.clone());
+    }
+    for network in &mut inventory.networks {
+        network.site_id = Some(site.id.clone());
+    }
+    for broadcast in &mut inventory.wifi {
+        broadcast.site_id = Some(site.id.clone());
+    }
+    inventory
+}
+
 /// Periodically refresh data from the controller.
 async fn refresh_task(controller: Controller, interval_secs: u64, cancel: CancellationToken) {
     let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
@@ -2228,9 +2731,10 @@ async fn command_processor_task(controller: Controller, mut rx: mpsc::Receiver<C
 async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandResult, CoreError> {
     let store = &controller.inner.store;
 
-    // Acquire both clients for routing decisions
-    let integration_guard = controller.inner.integration_client.lock().await;
-    let legacy_guard = controller.inner.legacy_client.lock().await;
+    // Work on clones so a command doesn't hold the client locks while its
+    // requests are in flight; `execute_many` runs several side by side.
+    let integration_client = controller.inner.integration_client.lock().await.clone();
+    let legacy_client = controller.inner.legacy_client.lock().await.clone();
     let site_id = *controller.inner.site_id.lock().await;
 
     match cmd {
@@ -2239,22 +2743,22 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
             mac,
             ignore_device_limit,
         } => {
-            if let (Some(ic), Some(sid)) = (integration_guard.as_ref(), site_id) {
+            if let (Some(ic), Some(sid)) = (integration_client.as_ref(), site_id) {
                 ic.adopt_device(&sid, mac.as_str(), ignore_device_limit)
                     .await?;
             } else {
-                let legacy = require_legacy(&legacy_guard)?;
+                let legacy = require_legacy(&legacy_client)?;
                 legacy.adopt_device(mac.as_str()).await?;
             }
             Ok(CommandResult::Ok)
         }
 
         Command::RestartDevice { id } => {
-            if let (Some(ic), Some(sid)) = (integration_guard.as_ref(), site_id) {
+            if let (Some(ic), Some(sid)) = (integration_client.as_ref(), site_id) {
                 let device_uuid = require_uuid(&id)?;
                 ic.device_action(&sid, &device_uuid, "RESTART").await?;
             } else {
-                let legacy = require_legacy(&legacy_guard)?;
+                let legacy = require_legacy(&legacy_client)?;
                 let mac = device_mac(store, &id)?;
                 legacy.restart_device(mac.as_str()).await?;
             }
@@ -2262,7 +2766,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         }
 
         Command::LocateDevice { mac, enable } => {
-            if let (Some(ic), Some(sid)) = (integration_guard.as_ref(), site_id) {
+            if let (Some(ic), Some(sid)) = (integration_client.as_ref(), site_id) {
                 let device =
                     store
                         .device_by_mac(&mac)
@@ -2273,14 +2777,14 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
                 let action = if enable { "LOCATE_ON" } else { "LOCATE_OFF" };
                 ic.device_action(&sid, &device_uuid, action).await?;
             } else {
-                let legacy = require_legacy(&legacy_guard)?;
+                let legacy = require_legacy(&legacy_client)?;
                 legacy.locate_device(mac.as_str(), enable).await?;
             }
             Ok(CommandResult::Ok)
         }
 
         Command::UpgradeDevice { mac, firmware_url } => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy
                 .upgrade_device(mac.as_str(), firmware_url.as_deref())
                 .await?;
@@ -2288,54 +2792,134 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         }
 
         Command::RemoveDevice { id } => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "RemoveDevice")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "RemoveDevice")?;
             let device_uuid = require_uuid(&id)?;
             ic.remove_device(&sid, &device_uuid).await?;
             Ok(CommandResult::Ok)
         }
 
         Command::ProvisionDevice { mac } => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy.provision_device(mac.as_str()).await?;
             Ok(CommandResult::Ok)
         }
         Command::SpeedtestDevice => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy.speedtest().await?;
             Ok(CommandResult::Ok)
         }
+        Command::SpectrumScan { mac } => {
+            let legacy = require_legacy(&legacy_client)?;
+            legacy.start_spectrum_scan(mac.as_str()).await?;
+            Ok(CommandResult::Ok)
+        }
 
         Command::PowerCyclePort {
             device_id,
             port_idx,
         } => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "PowerCyclePort")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "PowerCyclePort")?;
             let device_uuid = require_uuid(&device_id)?;
             ic.port_action(&sid, &device_uuid, port_idx, "POWER_CYCLE")
                 .await?;
             Ok(CommandResult::Ok)
         }
 
-        // ── Client operations ────────────────────────────────────
-        Command::BlockClient { mac } => {
-            if let (Some(ic), Some(sid)) = (integration_guard.as_ref(), site_id) {
-                let client =
+        Command::UpdatePort {
+            device_id,
+            port_idx,
+            update,
+        } => {
+            let legacy = require_legacy(&legacy_client)?;
+            let device =
+                store
+                    .device_by_id(&device_id)
+                    .ok_or_else(|| CoreError::DeviceNotFound {
+                        identifier: device_id.to_string(),
+                    })?;
+            let ld = legacy
+                .get_device(device.mac.as_str())
+                .await?
+                .ok_or_else(|| CoreError::DeviceNotFound {
+                    identifier: device.mac.to_string(),
+                })?;
+            let mut native_conf_id = None;
+            if let Some(ref id) = update.native_network_id {
+                let network =
                     store
-                        .client_by_mac(&mac)
-                        .ok_or_else(|| CoreError::ClientNotFound {
-                            identifier: mac.to_string(),
+                        .network_by_id(id)
+                        .ok_or_else(|| CoreError::NetworkNotFound {
+                            identifier: id.to_string(),
                         })?;
-                let client_uuid = require_uuid(&client.id)?;
-                ic.client_action(&sid, &client_uuid, "BLOCK").await?;
-            } else {
-                let legacy = require_legacy(&legacy_guard)?;
-                legacy.block_client(mac.as_str()).await?;
+                native_conf_id = Some(legacy_network_id(legacy, &network).await?);
             }
+            // rest/device merges top-level fields, so the whole
+            // `port_overrides` array is all that needs to go back.
+            let mut overrides = ld.port_overrides.clone();
+            apply_port_override(&ld, &mut overrides, port_idx, &update, native_conf_id)?;
+            legacy
+                .update_device(&ld.id, &serde_json::json!({ "port_overrides": overrides }))
+                .await?;
             Ok(CommandResult::Ok)
         }
 
-        Command::UnblockClient { mac } => {
-            if let (Some(ic), Some(sid)) = (integration_guard.as_ref(), site_id) {
+        Command::UpdateDeviceRadio {
+            device_id,
+            band,
+            update,
+        } => {
+            let legacy = require_legacy(&legacy_client)?;
+            let device =
+                store
+                    .device_by_id(&device_id)
+                    .ok_or_else(|| CoreError::DeviceNotFound {
+                        identifier: device_id.to_string(),
+                    })?;
+            let ld = legacy
+                .get_device(device.mac.as_str())
+                .await?
+                .ok_or_else(|| CoreError::DeviceNotFound {
+                    identifier: device.mac.to_string(),
+                })?;
+            let plan = if update.channel.is_some() || update.channel_width_mhz.is_some() {
+                let plan = legacy.get_channel_plan().await?;
+                if let (Some(plan), Some(_)) = (&plan, &integration_client) {
+                    check_site_country(plan, &controller.list_countries().await?)?;
+                }
+                plan
+            } else {
+                None
+            };
+            // rest/device merges top-level fields, so the whole
+            // `radio_table` array is all that needs to go back.
+            let mut radios = ld.radio_table.clone();
+            apply_radio_update(&mut radios, band, &update, plan.as_ref())?;
+            legacy
+                .update_device(&ld.id, &serde_json::json!({ "radio_table": radios }))
+                .await?;
+            Ok(CommandResult::Ok)
+        }
+
+        // ── Client operations ────────────────────────────────────
+        Command::BlockClient { mac } => {
+            if let (Some(ic), Some(sid)) = (integration_client.as_ref(), site_id) {
+                let client =
+                    store
+                        .client_by_mac(&mac)
+                        .ok_or_else(|| CoreError::ClientNotFound {
+                            identifier: mac.to_string(),
+                        })?;
+                let client_uuid = require_uuid(&client.id)?;
+                ic.client_action(&sid, &client_uuid, "BLOCK").await?;
+            } else {
+                let legacy = require_legacy(&legacy_client)?;
+                legacy.block_client(mac.as_str()).await?;
+            }
+            Ok(CommandResult::Ok)
+        }
+
+        Command::UnblockClient { mac } => {
+            if let (Some(ic), Some(sid)) = (integration_client.as_ref(), site_id) {
                 let client =
                     store
                         .client_by_mac(&mac)
@@ -2345,14 +2929,14 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
                 let client_uuid = require_uuid(&client.id)?;
                 ic.client_action(&sid, &client_uuid, "UNBLOCK").await?;
             } else {
-                let legacy = require_legacy(&legacy_guard)?;
+                let legacy = require_legacy(&legacy_client)?;
                 legacy.unblock_client(mac.as_str()).await?;
             }
             Ok(CommandResult::Ok)
         }
 
         Command::KickClient { mac } => {
-            if let (Some(ic), Some(sid)) = (integration_guard.as_ref(), site_id) {
+            if let (Some(ic), Some(sid)) = (integration_client.as_ref(), site_id) {
                 let client =
                     store
                         .client_by_mac(&mac)
@@ -2362,18 +2946,79 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
                 let client_uuid = require_uuid(&client.id)?;
                 ic.client_action(&sid, &client_uuid, "RECONNECT").await?;
             } else {
-                let legacy = require_legacy(&legacy_guard)?;
+                let legacy = require_legacy(&legacy_client)?;
                 legacy.kick_client(mac.as_str()).await?;
             }
             Ok(CommandResult::Ok)
         }
 
         Command::ForgetClient { mac } => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy.forget_client(mac.as_str()).await?;
             Ok(CommandResult::Ok)
         }
 
+        Command::SetClientFixedIp {
+            mac,
+            ip,
+            network_id,
+        } => {
+            let legacy = require_legacy(&legacy_client)?;
+            let reservation = match ip {
+                Some(ip) => {
+                    let network_id = network_id.ok_or_else(|| CoreError::ValidationFailed {
+                        message: "a network is required to reserve an IP".into(),
+                    })?;
+                    let network = store.network_by_id(&network_id).ok_or_else(|| {
+                        CoreError::NetworkNotFound {
+                            identifier: network_id.to_string(),
+                        }
+                    })?;
+                    validate_fixed_ip(&network, ip)?;
+                    let conf_id = legacy_network_id(legacy, &network).await?;
+                    Some((ip, conf_id))
+                }
+                None => None,
+            };
+            let (user_id, mut body) = client_user(legacy, &mac).await?;
+            body.insert("use_fixedip".into(), reservation.is_some().into());
+            if let Some((ip, conf_id)) = reservation {
+                body.insert("fixed_ip".into(), ip.to_string().into());
+                body.insert("network_id".into(), conf_id.into());
+            }
+            legacy.update_client_user(&user_id, &body.into()).await?;
+            Ok(CommandResult::Ok)
+        }
+
+        Command::SetClientAlias { mac, alias } => {
+            let legacy = require_legacy(&legacy_client)?;
+            let (user_id, mut body) = client_user(legacy, &mac).await?;
+            body.insert("name".into(), alias.unwrap_or_default().into());
+            legacy.update_client_user(&user_id, &body.into()).await?;
+            Ok(CommandResult::Ok)
+        }
+
+        Command::SetClientNote { mac, note } => {
+            let legacy = require_legacy(&legacy_client)?;
+            let (user_id, mut body) = client_user(legacy, &mac).await?;
+            body.insert("noted".into(), note.is_some().into());
+            body.insert("note".into(), note.unwrap_or_default().into());
+            legacy.update_client_user(&user_id, &body.into()).await?;
+            Ok(CommandResult::Ok)
+        }
+
+        Command::AssignClientGroup { mac, group_id } => {
+            let legacy = require_legacy(&legacy_client)?;
+            let (user_id, mut body) = client_user(legacy, &mac).await?;
+            // An empty ID puts the client back in the default group.
+            body.insert(
+                "usergroup_id".into(),
+                group_id.map(|id| id.to_string()).unwrap_or_default().into(),
+            );
+            legacy.update_client_user(&user_id, &body.into()).await?;
+            Ok(CommandResult::Ok)
+        }
+
         Command::AuthorizeGuest {
             client_id,
             time_limit_minutes,
@@ -2381,8 +3026,8 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
             rx_rate_kbps,
             tx_rate_kbps,
         } => {
-            let legacy = require_legacy(&legacy_guard)?;
-            let mac = client_mac(store, &client_id)?;
+            let legacy = require_legacy(&legacy_client)?;
+            let mac = known_client_mac(store, legacy, &client_id).await?;
             let minutes = time_limit_minutes.unwrap_or(60);
             #[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
             {
@@ -2400,41 +3045,41 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         }
 
         Command::UnauthorizeGuest { client_id } => {
-            let legacy = require_legacy(&legacy_guard)?;
-            let mac = client_mac(store, &client_id)?;
+            let legacy = require_legacy(&legacy_client)?;
+            let mac = known_client_mac(store, legacy, &client_id).await?;
             legacy.unauthorize_guest(mac.as_str()).await?;
             Ok(CommandResult::Ok)
         }
 
         // ── Alarm operations ─────────────────────────────────────
         Command::ArchiveAlarm { id } => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy.archive_alarm(&id.to_string()).await?;
             Ok(CommandResult::Ok)
         }
 
         Command::ArchiveAllAlarms => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy.archive_all_alarms().await?;
             Ok(CommandResult::Ok)
         }
 
         // ── Backup operations ────────────────────────────────────
         Command::CreateBackup => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy.create_backup().await?;
             Ok(CommandResult::Ok)
         }
 
         Command::DeleteBackup { filename } => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy.delete_backup(&filename).await?;
             Ok(CommandResult::Ok)
         }
 
         // ── Network CRUD (Integration API) ───────────────────────
         Command::CreateNetwork(req) => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "CreateNetwork")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "CreateNetwork")?;
             let crate::command::CreateNetworkRequest {
                 name,
                 vlan_id,
@@ -2526,7 +3171,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         }
 
         Command::UpdateNetwork { id, update } => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "UpdateNetwork")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "UpdateNetwork")?;
             let uuid = require_uuid(&id)?;
             // Fetch existing to merge partial update
             let existing = ic.get_network(&sid, &uuid).await?;
@@ -2564,7 +3209,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         }
 
         Command::DeleteNetwork { id, force: _ } => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "DeleteNetwork")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "DeleteNetwork")?;
             let uuid = require_uuid(&id)?;
             ic.delete_network(&sid, &uuid).await?;
             Ok(CommandResult::Ok)
@@ -2573,7 +3218,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         // ── WiFi Broadcast CRUD ──────────────────────────────────
         Command::CreateWifiBroadcast(req) => {
             let (ic, sid) =
-                require_integration(&integration_guard, site_id, "CreateWifiBroadcast")?;
+                require_integration(&integration_client, site_id, "CreateWifiBroadcast")?;
             let mut extra = serde_json::Map::new();
             extra.insert("ssid".into(), serde_json::Value::String(req.ssid));
             let security_mode = match req.security_mode {
@@ -2629,7 +3274,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
 
         Command::UpdateWifiBroadcast { id, update } => {
             let (ic, sid) =
-                require_integration(&integration_guard, site_id, "UpdateWifiBroadcast")?;
+                require_integration(&integration_client, site_id, "UpdateWifiBroadcast")?;
             let uuid = require_uuid(&id)?;
             let existing = ic.get_wifi_broadcast(&sid, &uuid).await?;
 
@@ -2692,7 +3337,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
 
         Command::DeleteWifiBroadcast { id, force: _ } => {
             let (ic, sid) =
-                require_integration(&integration_guard, site_id, "DeleteWifiBroadcast")?;
+                require_integration(&integration_client, site_id, "DeleteWifiBroadcast")?;
             let uuid = require_uuid(&id)?;
             ic.delete_wifi_broadcast(&sid, &uuid).await?;
             Ok(CommandResult::Ok)
@@ -2701,7 +3346,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         // ── Firewall Policy CRUD ─────────────────────────────────
         Command::CreateFirewallPolicy(req) => {
             let (ic, sid) =
-                require_integration(&integration_guard, site_id, "CreateFirewallPolicy")?;
+                require_integration(&integration_client, site_id, "CreateFirewallPolicy")?;
             let action_str = match req.action {
                 FirewallAction::Allow => "ALLOW",
                 FirewallAction::Block => "DROP",
@@ -2726,7 +3371,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
 
         Command::UpdateFirewallPolicy { id, update } => {
             let (ic, sid) =
-                require_integration(&integration_guard, site_id, "UpdateFirewallPolicy")?;
+                require_integration(&integration_client, site_id, "UpdateFirewallPolicy")?;
             let uuid = require_uuid(&id)?;
             let existing = ic.get_firewall_policy(&sid, &uuid).await?;
 
@@ -2810,7 +3455,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
 
         Command::DeleteFirewallPolicy { id } => {
             let (ic, sid) =
-                require_integration(&integration_guard, site_id, "DeleteFirewallPolicy")?;
+                require_integration(&integration_client, site_id, "DeleteFirewallPolicy")?;
             let uuid = require_uuid(&id)?;
             ic.delete_firewall_policy(&sid, &uuid).await?;
             Ok(CommandResult::Ok)
@@ -2818,7 +3463,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
 
         Command::PatchFirewallPolicy { id, enabled } => {
             let (ic, sid) =
-                require_integration(&integration_guard, site_id, "PatchFirewallPolicy")?;
+                require_integration(&integration_client, site_id, "PatchFirewallPolicy")?;
             let uuid = require_uuid(&id)?;
             let body = unifly_api::integration_types::FirewallPolicyPatch {
                 enabled: Some(enabled),
@@ -2833,7 +3478,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
             ordered_ids,
         } => {
             let (ic, sid) =
-                require_integration(&integration_guard, site_id, "ReorderFirewallPolicies")?;
+                require_integration(&integration_client, site_id, "ReorderFirewallPolicies")?;
             let uuids: Result<Vec<uuid::Uuid>, _> = ordered_ids.iter().map(require_uuid).collect();
             let body = unifly_api::integration_types::FirewallPolicyOrdering {
                 before_system_defined: uuids?,
@@ -2845,7 +3490,8 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
 
         // ── Firewall Zone CRUD ───────────────────────────────────
         Command::CreateFirewallZone(req) => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "CreateFirewallZone")?;
+            let (ic, sid) =
+                require_integration(&integration_client, site_id, "CreateFirewallZone")?;
             let network_uuids: Result<Vec<uuid::Uuid>, _> =
                 req.network_ids.iter().map(require_uuid).collect();
             let body = unifly_api::integration_types::FirewallZoneCreateUpdate {
@@ -2857,7 +3503,8 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         }
 
         Command::UpdateFirewallZone { id, update } => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "UpdateFirewallZone")?;
+            let (ic, sid) =
+                require_integration(&integration_client, site_id, "UpdateFirewallZone")?;
             let uuid = require_uuid(&id)?;
             let existing = ic.get_firewall_zone(&sid, &uuid).await?;
             let network_ids = if let Some(ids) = update.network_ids {
@@ -2875,7 +3522,8 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         }
 
         Command::DeleteFirewallZone { id } => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "DeleteFirewallZone")?;
+            let (ic, sid) =
+                require_integration(&integration_client, site_id, "DeleteFirewallZone")?;
             let uuid = require_uuid(&id)?;
             ic.delete_firewall_zone(&sid, &uuid).await?;
             Ok(CommandResult::Ok)
@@ -2883,7 +3531,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
 
         // ── ACL Rule CRUD ────────────────────────────────────────
         Command::CreateAclRule(req) => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "CreateAclRule")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "CreateAclRule")?;
             let action_str = match req.action {
                 FirewallAction::Allow => "ALLOW",
                 FirewallAction::Block => "BLOCK",
@@ -2928,7 +3576,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         }
 
         Command::UpdateAclRule { id, update } => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "UpdateAclRule")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "UpdateAclRule")?;
             let uuid = require_uuid(&id)?;
             let existing = ic.get_acl_rule(&sid, &uuid).await?;
             let action_str = match update.action {
@@ -2952,14 +3600,14 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         }
 
         Command::DeleteAclRule { id } => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "DeleteAclRule")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "DeleteAclRule")?;
             let uuid = require_uuid(&id)?;
             ic.delete_acl_rule(&sid, &uuid).await?;
             Ok(CommandResult::Ok)
         }
 
         Command::ReorderAclRules { ordered_ids } => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "ReorderAclRules")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "ReorderAclRules")?;
             let uuids: Result<Vec<uuid::Uuid>, _> = ordered_ids.iter().map(require_uuid).collect();
             let body = unifly_api::integration_types::AclRuleOrdering {
                 ordered_acl_rule_ids: uuids?,
@@ -2970,7 +3618,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
 
         // ── DNS Policy CRUD ──────────────────────────────────────
         Command::CreateDnsPolicy(req) => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "CreateDnsPolicy")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "CreateDnsPolicy")?;
             let policy_type_str = match req.policy_type {
                 crate::model::DnsPolicyType::ARecord => "A",
                 crate::model::DnsPolicyType::AaaaRecord => "AAAA",
@@ -3021,7 +3669,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         }
 
         Command::UpdateDnsPolicy { id, update } => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "UpdateDnsPolicy")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "UpdateDnsPolicy")?;
             let uuid = require_uuid(&id)?;
             let existing = ic.get_dns_policy(&sid, &uuid).await?;
             let mut fields: serde_json::Map<String, serde_json::Value> =
@@ -3075,7 +3723,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         }
 
         Command::DeleteDnsPolicy { id } => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "DeleteDnsPolicy")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "DeleteDnsPolicy")?;
             let uuid = require_uuid(&id)?;
             ic.delete_dns_policy(&sid, &uuid).await?;
             Ok(CommandResult::Ok)
@@ -3084,7 +3732,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         // ── Traffic Matching List CRUD ───────────────────────────
         Command::CreateTrafficMatchingList(req) => {
             let (ic, sid) =
-                require_integration(&integration_guard, site_id, "CreateTrafficMatchingList")?;
+                require_integration(&integration_client, site_id, "CreateTrafficMatchingList")?;
             let mut fields = serde_json::Map::new();
             fields.insert(
                 "entries".into(),
@@ -3109,7 +3757,7 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
 
         Command::UpdateTrafficMatchingList { id, update } => {
             let (ic, sid) =
-                require_integration(&integration_guard, site_id, "UpdateTrafficMatchingList")?;
+                require_integration(&integration_client, site_id, "UpdateTrafficMatchingList")?;
             let uuid = require_uuid(&id)?;
             let existing = ic.get_traffic_matching_list(&sid, &uuid).await?;
             let mut fields = serde_json::Map::new();
@@ -3142,15 +3790,196 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
 
         Command::DeleteTrafficMatchingList { id } => {
             let (ic, sid) =
-                require_integration(&integration_guard, site_id, "DeleteTrafficMatchingList")?;
+                require_integration(&integration_client, site_id, "DeleteTrafficMatchingList")?;
             let uuid = require_uuid(&id)?;
             ic.delete_traffic_matching_list(&sid, &uuid).await?;
             Ok(CommandResult::Ok)
         }
 
+        // ── Port forwards (Legacy) ───────────────────────────────
+        Command::CreatePortForward(req) => {
+            let legacy = require_legacy(&legacy_client)?;
+            let forward_port = req.forward_port.unwrap_or_else(|| req.wan_port.clone());
+            let body = serde_json::json!({
+                "name": req.name,
+                "enabled": req.enabled,
+                "proto": req.protocol.as_legacy_str(),
+                "pfwd_interface": req.wan_interface.unwrap_or_else(|| "wan".into()),
+                "src": req.source_address.unwrap_or_else(|| "any".into()),
+                "dst_port": req.wan_port,
+                "fwd": req.forward_ip,
+                "fwd_port": forward_port,
+                "log": req.logging,
+            });
+            let created = legacy.create_port_forward(&body).await?;
+            Ok(CommandResult::PortForward(PortForward::from(created)))
+        }
+
+        Command::UpdatePortForward { id, update } => {
+            let legacy = require_legacy(&legacy_client)?;
+            let id_str = id.to_string();
+            let (_, mut body) = current_rest_object(
+                legacy.list_port_forwards().await?,
+                &id_str,
+                |p| &p.id,
+                "port forward",
+            )?;
+            let mut set = |key: &str, value: serde_json::Value| {
+                body.insert(key.into(), value);
+            };
+            if let Some(name) = update.name {
+                set("name", name.into());
+            }
+            if let Some(enabled) = update.enabled {
+                set("enabled", enabled.into());
+            }
+            if let Some(protocol) = update.protocol {
+                set("proto", protocol.as_legacy_str().into());
+            }
+            if let Some(iface) = update.wan_interface {
+                set("pfwd_interface", iface.into());
+            }
+            if let Some(src) = update.source_address {
+                set("src", src.into());
+            }
+            if let Some(port) = update.wan_port {
+                set("dst_port", port.into());
+            }
+            if let Some(ip) = update.forward_ip {
+                set("fwd", ip.into());
+            }
+            if let Some(port) = update.forward_port {
+                set("fwd_port", port.into());
+            }
+            if let Some(log) = update.logging {
+                set("log", log.into());
+            }
+            let updated = legacy.update_port_forward(&id_str, &body.into()).await?;
+            Ok(CommandResult::PortForward(PortForward::from(updated)))
+        }
+
+        Command::SetPortForwardEnabled { id, enabled } => {
+            let legacy = require_legacy(&legacy_client)?;
+            legacy
+                .set_port_forward_enabled(&id.to_string(), enabled)
+                .await?;
+            Ok(CommandResult::Ok)
+        }
+
+        Command::DeletePortForward { id } => {
+            let legacy = require_legacy(&legacy_client)?;
+            legacy.delete_port_forward(&id.to_string()).await?;
+            Ok(CommandResult::Ok)
+        }
+
+        // ── User groups (Legacy) ─────────────────────────────────
+        Command::CreateUserGroup(req) => {
+            let legacy = require_legacy(&legacy_client)?;
+            let body = serde_json::json!({
+                "name": req.name,
+                "qos_rate_max_down": legacy_rate_limit(req.download_limit_kbps),
+                "qos_rate_max_up": legacy_rate_limit(req.upload_limit_kbps),
+            });
+            let created = legacy.create_user_group(&body).await?;
+            Ok(CommandResult::UserGroup(UserGroup::from(created)))
+        }
+
+        Command::UpdateUserGroup { id, update } => {
+            let legacy = require_legacy(&legacy_client)?;
+            let id_str = id.to_string();
+            let (_, mut body) = current_rest_object(
+                legacy.list_user_groups().await?,
+                &id_str,
+                |g| &g.id,
+                "user group",
+            )?;
+            if let Some(name) = update.name {
+                body.insert("name".into(), name.into());
+            }
+            if let Some(down) = update.download_limit_kbps {
+                body.insert(
+                    "qos_rate_max_down".into(),
+                    legacy_rate_limit(Some(down)).into(),
+                );
+            }
+            if let Some(up) = update.upload_limit_kbps {
+                body.insert("qos_rate_max_up".into(), legacy_rate_limit(Some(up)).into());
+            }
+            let updated = legacy.update_user_group(&id_str, &body.into()).await?;
+            Ok(CommandResult::UserGroup(UserGroup::from(updated)))
+        }
+
+        Command::DeleteUserGroup { id } => {
+            let legacy = require_legacy(&legacy_client)?;
+            legacy.delete_user_group(&id.to_string()).await?;
+            Ok(CommandResult::Ok)
+        }
+
+        // ── Static routes (Legacy) ───────────────────────────────
+        Command::CreateStaticRoute(req) => {
+            let legacy = require_legacy(&legacy_client)?;
+            let mut body = serde_json::Map::new();
+            body.insert("name".into(), req.name.into());
+            body.insert("enabled".into(), req.enabled.into());
+            body.insert("type".into(), "static-route".into());
+            body.insert(
+                "static-route_distance".into(),
+                req.distance.unwrap_or(1).into(),
+            );
+            set_static_route_target(
+                &mut body,
+                req.route_type,
+                &req.destination,
+                req.next_hop.as_deref(),
+                req.interface.as_deref(),
+            )?;
+            let created = legacy.create_static_route(&body.into()).await?;
+            Ok(CommandResult::StaticRoute(StaticRoute::from(created)))
+        }
+
+        Command::UpdateStaticRoute { id, update } => {
+            let legacy = require_legacy(&legacy_client)?;
+            let id_str = id.to_string();
+            let (existing, mut body) = current_rest_object(
+                legacy.list_static_routes().await?,
+                &id_str,
+                |r| &r.id,
+                "static route",
+            )?;
+            let current = StaticRoute::from(existing);
+
+            if let Some(name) = update.name {
+                body.insert("name".into(), name.into());
+            }
+            if let Some(enabled) = update.enabled {
+                body.insert("enabled".into(), enabled.into());
+            }
+            if let Some(distance) = update.distance {
+                body.insert("static-route_distance".into(), distance.into());
+            }
+            set_static_route_target(
+                &mut body,
+                update.route_type.unwrap_or(current.route_type),
+                update
+                    .destination
+                    .as_deref()
+                    .unwrap_or(&current.destination),
+                update.next_hop.as_deref().or(current.next_hop.as_deref()),
+                update.interface.as_deref().or(current.interface.as_deref()),
+            )?;
+            let updated = legacy.update_static_route(&id_str, &body.into()).await?;
+            Ok(CommandResult::StaticRoute(StaticRoute::from(updated)))
+        }
+
+        Command::DeleteStaticRoute { id } => {
+            let legacy = require_legacy(&legacy_client)?;
+            legacy.delete_static_route(&id.to_string()).await?;
+            Ok(CommandResult::Ok)
+        }
+
         // ── Voucher management ───────────────────────────────────
         Command::CreateVouchers(req) => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "CreateVouchers")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "CreateVouchers")?;
             #[allow(clippy::as_conversions, clippy::cast_possible_wrap)]
             let body = unifly_api::integration_types::VoucherCreateRequest {
                 name: req.name.unwrap_or_else(|| "Voucher".into()),
@@ -3167,41 +3996,62 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         }
 
         Command::DeleteVoucher { id } => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "DeleteVoucher")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "DeleteVoucher")?;
             let uuid = require_uuid(&id)?;
             ic.delete_voucher(&sid, &uuid).await?;
             Ok(CommandResult::Ok)
         }
 
         Command::PurgeVouchers { filter } => {
-            let (ic, sid) = require_integration(&integration_guard, site_id, "PurgeVouchers")?;
+            let (ic, sid) = require_integration(&integration_client, site_id, "PurgeVouchers")?;
             ic.purge_vouchers(&sid, &filter).await?;
             Ok(CommandResult::Ok)
         }
 
         // ── System administration ────────────────────────────────
+        Command::UpdateSiteSettings { section, changes } => {
+            let legacy = require_legacy(&legacy_client)?;
+            // set/setting replaces the whole section, so start from the current one.
+            let existing = legacy
+                .get_settings()
+                .await?
+                .into_iter()
+                .find(|s| s.key == section)
+                .ok_or_else(|| CoreError::NotFound {
+                    entity_type: "settings section".into(),
+                    identifier: section.clone(),
+                })?;
+            let mut body =
+                serde_json::to_value(&existing).map_err(|e| CoreError::Internal(e.to_string()))?;
+            if let Some(obj) = body.as_object_mut() {
+                obj.extend(changes);
+            }
+            legacy.set_setting(&section, &body).await?;
+            Ok(CommandResult::Ok)
+        }
+
         Command::CreateSite { name, description } => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy.create_site(&name, &description).await?;
             Ok(CommandResult::Ok)
         }
         Command::DeleteSite { name } => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy.delete_site(&name).await?;
             Ok(CommandResult::Ok)
         }
         Command::InviteAdmin { name, email, role } => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy.invite_admin(&name, &email, &role).await?;
             Ok(CommandResult::Ok)
         }
         Command::RevokeAdmin { id } => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy.revoke_admin(&id.to_string()).await?;
             Ok(CommandResult::Ok)
         }
         Command::UpdateAdmin { id, role } => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy
                 .update_admin(&id.to_string(), role.as_deref())
                 .await?;
@@ -3209,12 +4059,12 @@ async fn route_command(controller: &Controller, cmd: Command) -> Result<CommandR
         }
 
         Command::RebootController => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy.reboot_controller().await?;
             Ok(CommandResult::Ok)
         }
         Command::PoweroffController => {
-            let legacy = require_legacy(&legacy_guard)?;
+            let legacy = require_legacy(&legacy_client)?;
             legacy.poweroff_controller().await?;
             Ok(CommandResult::Ok)
         }
@@ -3306,11 +4156,17 @@ fn convert_health_summaries(raw: Vec<serde_json::Value>) -> Vec<HealthSummary> {
 }
 
 /// Build a [`TransportConfig`] from the controller configuration.
-fn build_transport(config: &ControllerConfig) -> TransportConfig {
+pub(crate) fn build_transport(config: &ControllerConfig) -> TransportConfig {
     TransportConfig {
         tls: tls_to_transport(&config.tls),
         timeout: config.timeout,
         cookie_jar: None, // LegacyClient::new adds one automatically
+        retry: RetryPolicy {
+            max_retries: config.retry.max_retries,
+            initial_delay: config.retry.initial_delay,
+            max_delay: config.retry.max_delay,
+            retry_writes: config.retry.retry_writes,
+        },
     }
 }
 
@@ -3370,13 +4226,120 @@ async fn resolve_site_id(
         })
 }
 
+/// Like [`resolve_site_id`], but `None` when all sites are loaded.
+async fn resolve_active_site_id(
+    client: &IntegrationClient,
+    site_name: &str,
+) -> Result<Option<uuid::Uuid>, CoreError> {
+    if site_name == ALL_SITES {
+        return Ok(None);
+    }
+    resolve_site_id(client, site_name).await.map(Some)
+}
+
+/// Log in to the Legacy API, adding the configured second factor if any.
+async fn legacy_login(
+    client: &LegacyClient,
+    username: &str,
+    password: &secrecy::SecretString,
+    config: &ControllerConfig,
+) -> Result<(), CoreError> {
+    let code = config
+        .two_factor
+        .as_ref()
+        .map(crate::config::TwoFactor::current_code)
+        .transpose()?;
+    client
+        .login_with_2fa(username, password, code.as_ref())
+        .await?;
+    Ok(())
+}
+
+/// Start a Legacy session: resume the cached one when it belongs to this
+/// controller and user, log in otherwise.
+///
+/// Either way the client keeps the credentials, so an expired session is
+/// renewed on the first request that hits `SessionExpired`.
+async fn start_legacy_session(
+    client: &mut LegacyClient,
+    username: &str,
+    password: &secrecy::SecretString,
+    config: &ControllerConfig,
+) -> Result<(), CoreError> {
+    // A one-time code was spent on the first login, so an expired session
+    // needs a new one from the user; only a TOTP secret can mint it.
+    let token = config.two_factor.clone().map(|two_factor| -> TokenSource {
+        match two_factor {
+            TwoFactor::TotpSecret(_) => Arc::new(move || two_factor.current_code().ok()),
+            TwoFactor::Code(_) => Arc::new(|| None),
+        }
+    });
+    // Save a renewed session right away; waiting for `disconnect` loses it
+    // when the process is killed, and the next run logs in again.
+    let on_renewed = config.session_cache.clone().map(|cache| -> SessionHook {
+        let url = config.url.to_string();
+        let username = username.to_owned();
+        Arc::new(move |client: &LegacyClient| {
+            store_legacy_session(client, cache.as_ref(), &url, &username);
+        })
+    });
+    client.set_relogin(Relogin {
+        username: username.to_owned(),
+        password: password.clone(),
+        token,
+        on_renewed,
+    });
+
+    let cached = config.session_cache.as_ref().and_then(|cache| cache.load());
+    if let Some(session) = cached.filter(|s| s.url == config.url.as_str() && s.username == username)
+    {
+        debug!("resuming cached legacy session");
+        client.restore_session(&session.cookie, session.csrf_token);
+        return Ok(());
+    }
+
+    legacy_login(client, username, password, config).await?;
+    save_legacy_session(client, config);
+    Ok(())
+}
+
+/// Write the client's current session to the configured cache, if any.
+fn save_legacy_session(client: &LegacyClient, config: &ControllerConfig) {
+    let Some(cache) = &config.session_cache else {
+        return;
+    };
+    let username = match &config.auth {
+        AuthCredentials::Credentials { username, .. }
+        | AuthCredentials::Hybrid { username, .. } => username,
+        AuthCredentials::ApiKey(_) | AuthCredentials::Cloud { .. } => return,
+    };
+    store_legacy_session(client, cache.as_ref(), config.url.as_str(), username);
+}
+
+fn store_legacy_session(
+    client: &LegacyClient,
+    cache: &dyn SessionCache,
+    url: &str,
+    username: &str,
+) {
+    if let Some(cookie) = client.cookie_header() {
+        cache.store(&CachedSession {
+            url: url.to_owned(),
+            username: username.to_owned(),
+            cookie,
+            csrf_token: client.csrf_token(),
+        });
+    }
+}
+
 /// Try to set up a Legacy client (best-effort for API key auth).
 async fn setup_legacy_client(
     config: &ControllerConfig,
+    site: &str,
     transport: &TransportConfig,
 ) -> Result<LegacyClient, CoreError> {
     let platform = LegacyClient::detect_platform(&config.url).await?;
-    let client = LegacyClient::new(config.url.clone(), config.site.clone(), platform, transport)?;
+    let client = LegacyClient::new(config.url.clone(), site.to_owned(), platform, transport)?;
     Ok(client)
 }
 
@@ -3404,6 +4367,70 @@ fn parse_ipv4_cidr(cidr: &str) -> Result<(Ipv4Addr, u8), CoreError> {
     Ok((host_ip, prefix_len))
 }
 
+/// Convert `rest/routing` entries, keeping only user-defined static routes.
+fn static_routes_from(raw: Vec<unifly_api::legacy::models::LegacyStaticRoute>) -> Vec<StaticRoute> {
+    raw.into_iter()
+        .filter(|r| r.route_kind.as_deref().is_none_or(|k| k == "static-route"))
+        .map(StaticRoute::from)
+        .collect()
+}
+
+/// Validate a static route's target and write the `static-route_*` fields
+/// into a `rest/routing` body.
+///
+/// The destination must be a network address (no host bits set). Fields
+/// that don't apply to `route_type` are removed, so switching a route's
+/// type doesn't leave a stale next hop or interface behind.
+fn set_static_route_target(
+    body: &mut serde_json::Map<String, serde_json::Value>,
+    route_type: StaticRouteType,
+    destination: &str,
+    next_hop: Option<&str>,
+    interface: Option<&str>,
+) -> Result<(), CoreError> {
+    let (network, prefix_len) = parse_ipv4_cidr(destination)?;
+    let mask = u32::MAX
+        .checked_shl(32 - u32::from(prefix_len))
+        .unwrap_or(0);
+    let masked = Ipv4Addr::from(u32::from(network) & mask);
+    if masked != network {
+        return Err(CoreError::ValidationFailed {
+            message: format!(
+                "route destination '{destination}' has host bits set (did you mean {masked}/{prefix_len}?)"
+            ),
+        });
+    }
+
+    body.insert(
+        "static-route_type".into(),
+        route_type.as_legacy_str().into(),
+    );
+    body.insert("static-route_network".into(), destination.into());
+    body.remove("static-route_nexthop");
+    body.remove("static-route_interface");
+
+    match route_type {
+        StaticRouteType::NextHop => {
+            let hop = next_hop.ok_or_else(|| CoreError::ValidationFailed {
+                message: "next-hop routes require a next hop address".into(),
+            })?;
+            hop.parse::<Ipv4Addr>()
+                .map_err(|_| CoreError::ValidationFailed {
+                    message: format!("invalid next hop address '{hop}'"),
+                })?;
+            body.insert("static-route_nexthop".into(), hop.into());
+        }
+        StaticRouteType::Interface => {
+            let iface = interface.ok_or_else(|| CoreError::ValidationFailed {
+                message: "interface routes require an interface".into(),
+            })?;
+            body.insert("static-route_interface".into(), iface.into());
+        }
+        StaticRouteType::Blackhole => {}
+    }
+    Ok(())
+}
+
 /// Extract a `Uuid` from an `EntityId`, or return an error.
 fn require_uuid(id: &EntityId) -> Result<uuid::Uuid, CoreError> {
     id.as_uuid().copied().ok_or_else(|| CoreError::Unsupported {
@@ -3412,18 +4439,19 @@ fn require_uuid(id: &EntityId) -> Result<uuid::Uuid, CoreError> {
     })
 }
 
-fn require_legacy<'a>(
-    guard: &'a tokio::sync::MutexGuard<'_, Option<LegacyClient>>,
-) -> Result<&'a LegacyClient, CoreError> {
-    guard.as_ref().ok_or(CoreError::ControllerDisconnected)
+// Callers pass either a lock guard or a cloned client; both deref to `&Option`.
+#[allow(clippy::ref_option)]
+fn require_legacy(client: &Option<LegacyClient>) -> Result<&LegacyClient, CoreError> {
+    client.as_ref().ok_or(CoreError::ControllerDisconnected)
 }
 
+#[allow(clippy::ref_option)]
 fn require_integration<'a>(
-    guard: &'a tokio::sync::MutexGuard<'_, Option<IntegrationClient>>,
+    client: &'a Option<IntegrationClient>,
     site_id: Option<uuid::Uuid>,
     operation: &str,
 ) -> Result<(&'a IntegrationClient, uuid::Uuid), CoreError> {
-    let client = guard.as_ref().ok_or_else(|| unsupported(operation))?;
+    let client = client.as_ref().ok_or_else(|| unsupported(operation))?;
     let sid = site_id.ok_or_else(|| unsupported(operation))?;
     Ok((client, sid))
 }
@@ -3455,9 +4483,366 @@ fn client_mac(store: &DataStore, id: &EntityId) -> Result<MacAddress, CoreError>
         })
 }
 
+/// Resolve an [`EntityId`] to a client MAC, falling back to the Legacy
+/// API's known clients (`stat/alluser`) for clients that are offline.
+async fn known_client_mac(
+    store: &DataStore,
+    legacy: &LegacyClient,
+    id: &EntityId,
+) -> Result<MacAddress, CoreError> {
+    if let Ok(mac) = client_mac(store, id) {
+        return Ok(mac);
+    }
+    let id = id.to_string();
+    legacy
+        .list_known_clients(None)
+        .await?
+        .into_iter()
+        .find(|k| k.id == id)
+        .map(|k| MacAddress::new(k.mac))
+        .ok_or(CoreError::ClientNotFound { identifier: id })
+}
+
+/// `rest/` PUTs for port forwards, static routes, user groups and client
+/// records replace the whole object, so updates start from the current
+/// one: find `id` among `objects` and return it along with its JSON form,
+/// ready for the changed fields to be overwritten. (`rest/device` merges
+/// top-level fields instead; see `LegacyClient::update_device`.)
+fn current_rest_object<T: serde::Serialize>(
+    objects: Vec<T>,
+    id: &str,
+    id_of: impl Fn(&T) -> &str,
+    entity_type: &str,
+) -> Result<(T, serde_json::Map<String, serde_json::Value>), CoreError> {
+    let existing = objects
+        .into_iter()
+        .find(|o| id_of(o) == id)
+        .ok_or_else(|| CoreError::NotFound {
+            entity_type: entity_type.into(),
+            identifier: id.into(),
+        })?;
+    let body = rest_body(&existing)?;
+    Ok((existing, body))
+}
+
+/// JSON form of a `rest/` object, as the body of a whole-object PUT.
+fn rest_body<T: serde::Serialize>(
+    object: &T,
+) -> Result<serde_json::Map<String, serde_json::Value>, CoreError> {
+    match serde_json::to_value(object) {
+        Ok(serde_json::Value::Object(obj)) => Ok(obj),
+        Ok(_) => Ok(serde_json::Map::new()),
+        Err(e) => Err(CoreError::Internal(e.to_string())),
+    }
+}
+
+/// Legacy `qos_rate_max_*` value for a Kbps cap: `-1` when unlimited.
+fn legacy_rate_limit(kbps: Option<u32>) -> i64 {
+    kbps.filter(|&k| k > 0).map_or(-1, i64::from)
+}
+
+/// Look up the `rest/user` record for a client MAC: its ID and its JSON
+/// form, for a whole-object PUT (see `current_rest_object`).
+async fn client_user(
+    legacy: &LegacyClient,
+    mac: &MacAddress,
+) -> Result<(String, serde_json::Map<String, serde_json::Value>), CoreError> {
+    let user =
+        legacy
+            .get_client_user(mac.as_str())
+            .await?
+            .ok_or_else(|| CoreError::ClientNotFound {
+                identifier: mac.to_string(),
+            })?;
+    let body = rest_body(&user)?;
+    Ok((user.id, body))
+}
+
+/// Upsert the `port_overrides` entry for `port_idx` with `update`.
+///
+/// Disabling a port is expressed as `forward: "disabled"`; re-enabling
+/// drops that so the port falls back to its profile's forwarding.
+fn apply_port_override(
+    device: &LegacyDevice,
+    overrides: &mut Vec<LegacyPortOverride>,
+    port_idx: u32,
+    update: &UpdatePortRequest,
+    native_conf_id: Option<String>,
+) -> Result<(), CoreError> {
+    let invalid = |message: String| CoreError::ValidationFailed { message };
+    let port = device
+        .port_table
+        .iter()
+        .find(|p| p.port_idx == Some(port_idx));
+    if port.is_none() && !device.port_table.is_empty() {
+        return Err(invalid(format!("device has no port {port_idx}")));
+    }
+    if update.poe_mode.is_some() && port.and_then(|p| p.port_poe) == Some(false) {
+        return Err(invalid(format!("port {port_idx} does not support PoE")));
+    }
+
+    let i = if let Some(i) = overrides.iter().position(|o| o.port_idx == port_idx) {
+        i
+    } else {
+        overrides.push(LegacyPortOverride {
+            port_idx,
+            ..Default::default()
+        });
+        overrides.len() - 1
+    };
+    let entry = &mut overrides[i];
+
+    if let Some(ref name) = update.name {
+        entry.name = Some(name.clone());
+    }
+    if let Some(ref profile_id) = update.profile_id {
+        entry.portconf_id = Some(profile_id.to_string());
+    }
+    if native_conf_id.is_some() {
+        entry.native_networkconf_id = native_conf_id;
+    }
+    if let Some(mode) = update.poe_mode {
+        entry.poe_mode = Some(mode.as_legacy_str().to_owned());
+    }
+    match update.enabled {
+        Some(false) => entry.forward = Some("disabled".into()),
+        Some(true) if entry.forward.as_deref() == Some("disabled") => entry.forward = None,
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Apply a settings change to one band's `radio_table` entry, checking
+/// the resulting channel and width against the site's regulatory plan.
+fn apply_radio_update(
+    radios: &mut [LegacyRadioConfig],
+    band: RadioBand,
+    update: &UpdateDeviceRadioRequest,
+    plan: Option<&LegacyChannelPlan>,
+) -> Result<(), CoreError> {
+    let invalid = |message: String| CoreError::ValidationFailed { message };
+    let entry = radios
+        .iter_mut()
+        .find(|r| r.radio == band.as_legacy_str())
+        .ok_or_else(|| invalid(format!("device has no {band} radio")))?;
+
+    if let Some(width) = update.channel_width_mhz {
+        let widths: &[u32] = match band {
+            RadioBand::Ghz2_4 => &[20, 40],
+            RadioBand::Ghz5 => &[20, 40, 80, 160],
+            RadioBand::Ghz6 => &[20, 40, 80, 160, 320],
+        };
+        if !widths.contains(&width) {
+            return Err(invalid(format!(
+                "{width} MHz is not a valid channel width on {band}"
+            )));
+        }
+    }
+    if let Some(rssi) = update.min_rssi_dbm {
+        if rssi >= 0 {
+            return Err(invalid(format!(
+                "minimum RSSI must be negative dBm, got {rssi}"
+            )));
+        }
+    }
+
+    let channel = match update.channel {
+        Some(RadioChannel::Fixed(channel)) => Some(channel),
+        Some(RadioChannel::Auto) => None,
+        None => entry.channel.as_ref().and_then(legacy_number),
+    };
+    let width = update
+        .channel_width_mhz
+        .or_else(|| entry.ht.as_ref().and_then(legacy_number));
+    if let (Some(plan), Some(channel)) = (plan, channel) {
+        check_regulatory_channel(plan, band, channel, width)?;
+    }
+
+    match update.channel {
+        Some(RadioChannel::Auto) => entry.channel = Some(serde_json::json!("auto")),
+        Some(RadioChannel::Fixed(channel)) => {
+            entry.channel = Some(legacy_number_like(entry.channel.as_ref(), channel.into()));
+        }
+        None => {}
+    }
+    if let Some(width) = update.channel_width_mhz {
+        entry.ht = Some(legacy_number_like(entry.ht.as_ref(), width.into()));
+    }
+    if let Some(power) = update.tx_power {
+        entry.tx_power_mode = Some(power.legacy_mode().to_owned());
+        if let TxPower::Custom(dbm) = power {
+            entry.tx_power = Some(legacy_number_like(entry.tx_power.as_ref(), dbm.into()));
+        }
+    }
+    if let Some(rssi) = update.min_rssi_dbm {
+        entry.min_rssi = Some(rssi);
+        entry.min_rssi_enabled = Some(true);
+    }
+    if let Some(enabled) = update.min_rssi_enabled {
+        entry.min_rssi_enabled = Some(enabled);
+    }
+    Ok(())
+}
+
+/// Reject a channel plan whose country is missing from the controller's
+/// `list_countries` data: its channels would not be a regulatory set the
+/// controller knows. An empty country list is not checked.
+fn check_site_country(plan: &LegacyChannelPlan, countries: &[Country]) -> Result<(), CoreError> {
+    if countries.is_empty() {
+        return Ok(());
+    }
+    let invalid = |message: String| CoreError::ValidationFailed { message };
+    let code = plan.key.as_deref().ok_or_else(|| {
+        invalid("the site has no country set; set country.code before choosing channels".into())
+    })?;
+    if countries.iter().any(|c| c.code.eq_ignore_ascii_case(code)) {
+        Ok(())
+    } else {
+        Err(invalid(format!(
+            "the site's country {code} is not in the controller's country list"
+        )))
+    }
+}
+
+/// Reject channels (or channel/width pairs) the site's country doesn't
+/// allow. Bands the plan has no data for are not checked.
+fn check_regulatory_channel(
+    plan: &LegacyChannelPlan,
+    band: RadioBand,
+    channel: u32,
+    width: Option<u32>,
+) -> Result<(), CoreError> {
+    let country = plan
+        .name
+        .as_deref()
+        .or(plan.key.as_deref())
+        .unwrap_or("the site's country");
+    let allowed: Vec<u32> = match band {
+        RadioBand::Ghz2_4 => plan.channels_ng.clone(),
+        RadioBand::Ghz5 => plan
+            .channels_na
+            .iter()
+            .chain(&plan.channels_na_dfs)
+            .copied()
+            .collect(),
+        RadioBand::Ghz6 => plan.channels_6e.clone(),
+    };
+    if !allowed.is_empty() && !allowed.contains(&channel) {
+        return Err(CoreError::ValidationFailed {
+            message: format!("channel {channel} is not allowed on {band} in {country}"),
+        });
+    }
+
+    let wide: &[u32] = match (band, width) {
+        (RadioBand::Ghz2_4, Some(40)) => &plan.channels_ng_40,
+        (RadioBand::Ghz5, Some(40)) => &plan.channels_na_40,
+        (RadioBand::Ghz5, Some(80)) => &plan.channels_na_80,
+        (RadioBand::Ghz5, Some(160)) => &plan.channels_na_160,
+        _ => &[],
+    };
+    if let Some(width) = width {
+        if !wide.is_empty() && !wide.contains(&channel) {
+            return Err(CoreError::ValidationFailed {
+                message: format!("channel {channel} cannot be used at {width} MHz in {country}"),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Encode `n` the way the controller sent the existing value: as a string
+/// if it was one, otherwise as a number.
+fn legacy_number_like(existing: Option<&serde_json::Value>, n: i64) -> serde_json::Value {
+    match existing {
+        Some(serde_json::Value::String(_)) => serde_json::Value::String(n.to_string()),
+        _ => serde_json::Value::from(n),
+    }
+}
+
+/// Map a `Network` to its `rest/networkconf` ID.
+///
+/// Integration API networks carry UUIDs, which Legacy endpoints don't
+/// accept, so those are matched to their networkconf entry by name.
+async fn legacy_network_id(legacy: &LegacyClient, network: &Network) -> Result<String, CoreError> {
+    if network.id.as_uuid().is_none() {
+        return Ok(network.id.to_string());
+    }
+    legacy
+        .list_network_conf()
+        .await?
+        .into_iter()
+        .find(|n| n.name.as_deref() == Some(network.name.as_str()))
+        .map(|n| n.id)
+        .ok_or_else(|| CoreError::NetworkNotFound {
+            identifier: network.name.clone(),
+        })
+}
+
+/// Check that `ip` can be reserved on `network`: it must be a usable host
+/// address inside the subnet, not the gateway, and outside the DHCP pool
+/// (where the server could lease it to someone else).
+fn validate_fixed_ip(network: &Network, ip: Ipv4Addr) -> Result<(), CoreError> {
+    let invalid = |message: String| CoreError::ValidationFailed { message };
+    let subnet = network
+        .subnet
+        .as_deref()
+        .ok_or_else(|| invalid(format!("network '{}' has no IPv4 subnet", network.name)))?;
+    let (host, prefix_len) = parse_ipv4_cidr(subnet)?;
+    let mask = u32::MAX
+        .checked_shl(32 - u32::from(prefix_len))
+        .unwrap_or(0);
+    let base = u32::from(host) & mask;
+    let addr = u32::from(ip);
+
+    if addr & mask != base {
+        return Err(invalid(format!(
+            "{ip} is outside {}'s subnet {subnet}",
+            network.name
+        )));
+    }
+    if prefix_len < 31 && (addr == base || addr == base | !mask) {
+        return Err(invalid(format!(
+            "{ip} is the network or broadcast address of {subnet}"
+        )));
+    }
+    if network.gateway_ip == Some(ip) || (network.gateway_ip.is_none() && ip == host) {
+        return Err(invalid(format!(
+            "{ip} is {}'s gateway address",
+            network.name
+        )));
+    }
+    let pool = network
+        .dhcp
+        .as_ref()
+        .filter(|d| d.enabled)
+        .and_then(|d| Some((d.range_start?, d.range_stop?)));
+    if let Some((start, stop)) = pool.filter(|(start, stop)| (*start..=*stop).contains(&ip)) {
+        return Err(invalid(format!(
+            "{ip} is inside {}'s DHCP range {start}-{stop}; pick an address outside the pool",
+            network.name
+        )));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::parse_ipv4_cidr;
+    use std::net::Ipv4Addr;
+
+    use super::{
+        Command, Controller, StaticRouteType, UpdateDeviceRadioRequest, UpdatePortRequest,
+        apply_port_override, apply_radio_update, check_site_country, parse_ipv4_cidr,
+        route_command, set_static_route_target, validate_fixed_ip,
+    };
+    use crate::config::ControllerConfig;
+    use crate::model::{
+        Country, DataSource, DhcpConfig, EntityId, MacAddress, Network, PoeMode, RadioBand,
+        RadioChannel, TxPower,
+    };
+    use unifly_api::legacy::models::{LegacyChannelPlan, LegacyDevice};
+    use unifly_api::{ControllerPlatform, LegacyClient};
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[test]
     fn parse_ipv4_cidr_accepts_valid_input() {
@@ -3475,4 +4860,412 @@ mod tests {
     fn parse_ipv4_cidr_rejects_missing_prefix() {
         assert!(parse_ipv4_cidr("192.168.10.1").is_err());
     }
+
+    fn lan(dhcp_enabled: bool) -> Network {
+        Network {
+            id: EntityId::from("n1"),
+            name: "LAN".into(),
+            enabled: true,
+            management: None,
+            purpose: None,
+            is_default: true,
+            vlan_id: None,
+            subnet: Some("192.168.1.1/24".into()),
+            gateway_ip: Some(Ipv4Addr::new(192, 168, 1, 1)),
+            dhcp: Some(DhcpConfig {
+                enabled: dhcp_enabled,
+                range_start: Some(Ipv4Addr::new(192, 168, 1, 100)),
+                range_stop: Some(Ipv4Addr::new(192, 168, 1, 199)),
+                lease_time_secs: None,
+                dns_servers: Vec::new(),
+                gateway: None,
+            }),
+            ipv6_enabled: false,
+            ipv6_mode: None,
+            ipv6_prefix: None,
+            dhcpv6_enabled: false,
+            slaac_enabled: false,
+            ntp_server: None,
+            pxe_enabled: false,
+            tftp_server: None,
+            firewall_zone_id: None,
+            isolation_enabled: false,
+            internet_access_enabled: true,
+            mdns_forwarding_enabled: false,
+            cellular_backup_enabled: false,
+            site_id: None,
+            origin: None,
+            source: DataSource::LegacyApi,
+        }
+    }
+
+    #[test]
+    fn fixed_ip_must_be_in_subnet_and_outside_dhcp_pool() {
+        let net = lan(true);
+        assert!(validate_fixed_ip(&net, Ipv4Addr::new(192, 168, 1, 50)).is_ok());
+        assert!(validate_fixed_ip(&net, Ipv4Addr::new(192, 168, 1, 150)).is_err());
+        assert!(validate_fixed_ip(&net, Ipv4Addr::new(192, 168, 2, 50)).is_err());
+        assert!(validate_fixed_ip(&net, Ipv4Addr::new(192, 168, 1, 1)).is_err());
+        assert!(validate_fixed_ip(&net, Ipv4Addr::new(192, 168, 1, 255)).is_err());
+        // The pool only matters while the DHCP server is on.
+        assert!(validate_fixed_ip(&lan(false), Ipv4Addr::new(192, 168, 1, 150)).is_ok());
+    }
+
+    #[test]
+    fn static_route_target_requires_network_address() {
+        let mut body = serde_json::Map::new();
+        let err = set_static_route_target(
+            &mut body,
+            StaticRouteType::Blackhole,
+            "10.1.2.3/16",
+            None,
+            None,
+        )
+        .expect_err("host bits set");
+        assert!(err.to_string().contains("10.1.0.0/16"));
+        assert!(
+            set_static_route_target(
+                &mut body,
+                StaticRouteType::Blackhole,
+                "0.0.0.0/0",
+                None,
+                None
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn static_route_target_switching_type_drops_stale_fields() {
+        let mut body = serde_json::Map::new();
+        body.insert("static-route_interface".into(), "WAN1".into());
+        assert!(
+            set_static_route_target(
+                &mut body,
+                StaticRouteType::NextHop,
+                "10.50.0.0/16",
+                None,
+                None
+            )
+            .is_err()
+        );
+        set_static_route_target(
+            &mut body,
+            StaticRouteType::NextHop,
+            "10.50.0.0/16",
+            Some("192.168.1.2"),
+            None,
+        )
+        .expect("valid route");
+        assert_eq!(body["static-route_nexthop"], "192.168.1.2");
+        assert_eq!(body["static-route_type"], "nexthop-route");
+        assert!(!body.contains_key("static-route_interface"));
+    }
+
+    fn switch() -> LegacyDevice {
+        serde_json::from_value(serde_json::json!({
+            "_id": "dev1",
+            "mac": "aa:bb:cc:dd:ee:01",
+            "type": "usw",
+            "port_table": [
+                { "port_idx": 1, "port_poe": true },
+                { "port_idx": 2, "port_poe": false }
+            ],
+            "port_overrides": [
+                { "port_idx": 1, "name": "Camera", "forward": "disabled", "stp_port_mode": true }
+            ]
+        }))
+        .expect("valid device")
+    }
+
+    #[test]
+    fn port_override_updates_existing_entry_and_keeps_unknown_fields() {
+        let device = switch();
+        let mut overrides = device.port_overrides.clone();
+        let update = UpdatePortRequest {
+            enabled: Some(true),
+            poe_mode: Some(PoeMode::Off),
+            profile_id: Some(EntityId::from("pc1".to_owned())),
+            ..Default::default()
+        };
+        apply_port_override(&device, &mut overrides, 1, &update, Some("net1".into()))
+            .expect("valid update");
+
+        assert_eq!(overrides.len(), 1);
+        let body = serde_json::to_value(&overrides[0]).expect("serializable");
+        assert_eq!(body["name"], "Camera");
+        assert_eq!(body["poe_mode"], "off");
+        assert_eq!(body["portconf_id"], "pc1");
+        assert_eq!(body["native_networkconf_id"], "net1");
+        assert_eq!(body["stp_port_mode"], true);
+        assert!(body.get("forward").is_none());
+    }
+
+    #[test]
+    fn port_override_is_added_for_new_port_and_validated() {
+        let device = switch();
+        let mut overrides = device.port_overrides.clone();
+        let disable = UpdatePortRequest {
+            enabled: Some(false),
+            ..Default::default()
+        };
+        apply_port_override(&device, &mut overrides, 2, &disable, None).expect("valid update");
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(overrides[1].port_idx, 2);
+        assert_eq!(overrides[1].forward.as_deref(), Some("disabled"));
+
+        assert!(apply_port_override(&device, &mut overrides, 9, &disable, None).is_err());
+        let poe = UpdatePortRequest {
+            poe_mode: Some(PoeMode::Auto),
+            ..Default::default()
+        };
+        assert!(apply_port_override(&device, &mut overrides, 2, &poe, None).is_err());
+    }
+
+    fn access_point() -> LegacyDevice {
+        serde_json::from_value(serde_json::json!({
+            "_id": "ap1",
+            "mac": "aa:bb:cc:dd:ee:02",
+            "type": "uap",
+            "radio_table": [
+                { "radio": "ng", "name": "wifi0", "channel": "auto", "ht": "20" },
+                { "radio": "na", "name": "wifi1", "channel": 44, "ht": 80,
+                  "tx_power_mode": "auto", "hard_noise_floor_enabled": false }
+            ]
+        }))
+        .expect("valid device")
+    }
+
+    fn us_plan() -> LegacyChannelPlan {
+        serde_json::from_value(serde_json::json!({
+            "key": "US",
+            "name": "United States",
+            "channels_ng": [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+            "channels_na": [36, 40, 44, 48, 149, 153, 157, 161, 165],
+            "channels_na_dfs": [52, 56, 60, 64],
+            "channels_na_80": [36, 40, 44, 48, 52, 56, 60, 64, 149, 153, 157, 161]
+        }))
+        .expect("valid plan")
+    }
+
+    #[test]
+    fn radio_update_rewrites_band_and_keeps_encoding() {
+        let device = access_point();
+        let mut radios = device.radio_table.clone();
+        let update = UpdateDeviceRadioRequest {
+            channel: Some(RadioChannel::Fixed(6)),
+            channel_width_mhz: Some(40),
+            tx_power: Some(TxPower::Custom(12)),
+            min_rssi_dbm: Some(-75),
+            ..Default::default()
+        };
+        apply_radio_update(&mut radios, RadioBand::Ghz2_4, &update, Some(&us_plan()))
+            .expect("valid update");
+
+        let body = serde_json::to_value(&radios[0]).expect("serializable");
+        assert_eq!(body["channel"], "6");
+        assert_eq!(body["ht"], "40");
+        assert_eq!(body["tx_power_mode"], "custom");
+        assert_eq!(body["tx_power"], 12);
+        assert_eq!(body["min_rssi"], -75);
+        assert_eq!(body["min_rssi_enabled"], true);
+
+        let five = serde_json::to_value(&radios[1]).expect("serializable");
+        assert_eq!(five["channel"], 44);
+        assert_eq!(five["hard_noise_floor_enabled"], false);
+    }
+
+    #[test]
+    fn site_country_must_be_a_listed_country() {
+        let country = |code: &str| Country {
+            code: code.into(),
+            name: code.into(),
+        };
+        let plan = us_plan();
+        check_site_country(&plan, &[country("CA"), country("us")]).expect("listed");
+        check_site_country(&plan, &[]).expect("no list, no check");
+        assert!(check_site_country(&plan, &[country("CA")]).is_err());
+
+        let no_country = LegacyChannelPlan {
+            key: None,
+            ..us_plan()
+        };
+        assert!(check_site_country(&no_country, &[country("US")]).is_err());
+    }
+
+    #[test]
+    fn radio_update_rejects_channels_outside_regulatory_plan() {
+        let device = access_point();
+        let mut radios = device.radio_table.clone();
+        let plan = us_plan();
+        let channel = |c| UpdateDeviceRadioRequest {
+            channel: Some(RadioChannel::Fixed(c)),
+            ..Default::default()
+        };
+
+        assert!(
+            apply_radio_update(&mut radios, RadioBand::Ghz2_4, &channel(13), Some(&plan)).is_err()
+        );
+        // DFS channels are allowed; 165 is, but not at the AP's 80 MHz width
+        apply_radio_update(&mut radios, RadioBand::Ghz5, &channel(52), Some(&plan)).expect("DFS");
+        assert!(
+            apply_radio_update(&mut radios, RadioBand::Ghz5, &channel(165), Some(&plan)).is_err()
+        );
+        assert!(
+            apply_radio_update(&mut radios, RadioBand::Ghz6, &channel(5), Some(&plan)).is_err()
+        );
+
+        let too_wide = UpdateDeviceRadioRequest {
+            channel_width_mhz: Some(80),
+            ..Default::default()
+        };
+        assert!(
+            apply_radio_update(&mut radios, RadioBand::Ghz2_4, &too_wide, Some(&plan)).is_err()
+        );
+    }
+
+    fn legacy_ok(data: &serde_json::Value) -> ResponseTemplate {
+        ResponseTemplate::new(200)
+            .set_body_json(serde_json::json!({ "meta": { "rc": "ok" }, "data": data }))
+    }
+
+    async fn legacy_controller(server: &MockServer) -> Controller {
+        let controller = Controller::new(ControllerConfig::default());
+        *controller.inner.legacy_client.lock().await = Some(LegacyClient::with_client(
+            reqwest::Client::new(),
+            server.uri().parse().expect("valid URL"),
+            "default".into(),
+            ControllerPlatform::ClassicController,
+        ));
+        controller
+    }
+
+    #[tokio::test]
+    async fn client_edits_put_the_whole_user_record() {
+        let server = MockServer::start().await;
+        let user = serde_json::json!({
+            "_id": "u1",
+            "mac": "aa:bb:cc:00:00:30",
+            "name": "NAS",
+            "use_fixedip": true,
+            "fixed_ip": "192.168.1.50",
+            "network_id": "net1",
+            "usergroup_id": "g1"
+        });
+        Mock::given(method("GET"))
+            .and(path("/api/s/default/stat/user/aa:bb:cc:00:00:30"))
+            .respond_with(legacy_ok(&serde_json::json!([user])))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/api/s/default/rest/user/u1"))
+            .and(body_partial_json(serde_json::json!({
+                "_id": "u1",
+                "name": "NAS",
+                "fixed_ip": "192.168.1.50",
+                "usergroup_id": "g1",
+                "noted": true,
+                "note": "backup box"
+            })))
+            .respond_with(legacy_ok(&serde_json::json!([user])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let controller = legacy_controller(&server).await;
+        let note = Command::SetClientNote {
+            mac: MacAddress::new("aa:bb:cc:00:00:30"),
+            note: Some("backup box".into()),
+        };
+        route_command(&controller, note).await.expect("note saved");
+    }
+
+    #[tokio::test]
+    async fn group_assignment_puts_the_whole_user_record() {
+        let server = MockServer::start().await;
+        let user = serde_json::json!({
+            "_id": "u1",
+            "mac": "aa:bb:cc:00:00:31",
+            "name": "Console",
+            "note": "living room",
+            "usergroup_id": "g1"
+        });
+        Mock::given(method("GET"))
+            .and(path("/api/s/default/stat/user/aa:bb:cc:00:00:31"))
+            .respond_with(legacy_ok(&serde_json::json!([user])))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/api/s/default/rest/user/u1"))
+            .and(body_partial_json(serde_json::json!({
+                "_id": "u1",
+                "name": "Console",
+                "note": "living room",
+                "usergroup_id": ""
+            })))
+            .respond_with(legacy_ok(&serde_json::json!([user])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let controller = legacy_controller(&server).await;
+        let to_default = Command::AssignClientGroup {
+            mac: MacAddress::new("aa:bb:cc:00:00:31"),
+            group_id: None,
+        };
+        route_command(&controller, to_default)
+            .await
+            .expect("client moved to the default group");
+    }
+
+    #[tokio::test]
+    async fn guest_commands_resolve_offline_clients() {
+        let server = MockServer::start().await;
+        let ok = |data: serde_json::Value| {
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "meta": { "rc": "ok" }, "data": data }))
+        };
+        // Not connected, so only the known-client list has it.
+        Mock::given(method("POST"))
+            .and(path("/api/s/default/stat/alluser"))
+            .respond_with(ok(serde_json::json!([
+                { "_id": "u1", "mac": "aa:bb:cc:00:00:20" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/s/default/cmd/stamgr"))
+            .and(body_partial_json(serde_json::json!({
+                "cmd": "authorize-guest",
+                "mac": "aa:bb:cc:00:00:20",
+                "minutes": 60
+            })))
+            .respond_with(ok(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let controller = Controller::new(ControllerConfig::default());
+        *controller.inner.legacy_client.lock().await = Some(LegacyClient::with_client(
+            reqwest::Client::new(),
+            server.uri().parse().expect("valid URL"),
+            "default".into(),
+            ControllerPlatform::ClassicController,
+        ));
+        let authorize = |client_id: &str| Command::AuthorizeGuest {
+            client_id: EntityId::from(client_id),
+            time_limit_minutes: None,
+            data_limit_mb: None,
+            rx_rate_kbps: None,
+            tx_rate_kbps: None,
+        };
+
+        route_command(&controller, authorize("u1"))
+            .await
+            .expect("offline client authorized");
+        assert!(matches!(
+            route_command(&controller, authorize("u2")).await,
+            Err(crate::error::CoreError::ClientNotFound { .. })
+        ));
+    }
 }
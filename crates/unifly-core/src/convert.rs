@@ -12,24 +12,34 @@ use serde_json::Value;
 
 use unifly_api::integration_types;
 use unifly_api::legacy::models::{
-    LegacyAlarm, LegacyClientEntry, LegacyDevice, LegacyEvent, LegacySite,
+    LegacyAlarm, LegacyClientEntry, LegacyDevice, LegacyEvent, LegacyIpsEvent, LegacyPortConf,
+    LegacyPortForward, LegacyPortOverride, LegacyPortTableEntry, LegacyRadioConfig,
+    LegacyRadioStats, LegacyRogueAp, LegacySession, LegacySetting, LegacySite, LegacySpectrumEntry,
+    LegacySpectrumScan, LegacyStaticRoute, LegacyUserEntry, LegacyUserGroup,
 };
 use unifly_api::websocket::UnifiEvent;
 
 use crate::model::{
-    client::{Client, ClientType, GuestAuth, WirelessInfo},
+    client::{Client, ClientSession, ClientType, GuestAuth, KnownClient, WirelessInfo},
     common::{Bandwidth, DataSource, EntityOrigin},
-    device::{Device, DeviceState, DeviceStats, DeviceType},
+    device::{
+        Device, DeviceState, DeviceStats, DeviceType, PoeInfo, PoeMode, Port, PortConnector,
+        PortState, Radio, RadioBand, TxPower,
+    },
     dns::{DnsPolicy, DnsPolicyType},
     entity_id::{EntityId, MacAddress},
-    event::{Alarm, Event, EventCategory, EventSeverity},
+    event::{Alarm, Event, EventCategory, EventSeverity, ThreatEvent},
     firewall::{AclAction, AclRule, AclRuleType, FirewallAction, FirewallPolicy, FirewallZone},
     hotspot::Voucher,
+    legacy_resources::{ChannelSpectrum, NeighborAp, PortProfile, SpectrumScan, UserGroup},
     network::{DhcpConfig, Ipv6Mode, Network, NetworkManagement},
+    port_forward::{PortForward, PortForwardProtocol},
+    route::{StaticRoute, StaticRouteType},
     site::Site,
     supporting::TrafficMatchingList,
     wifi::{WifiBroadcast, WifiBroadcastType, WifiSecurityMode},
 };
+use crate::settings::SiteSettings;
 
 // ── Helpers ────────────────────────────────────────────────────────
 
@@ -146,6 +156,9 @@ impl From<LegacyDevice> for Device {
     fn from(d: LegacyDevice) -> Self {
         let device_type = infer_device_type(&d.device_type, d.model.as_ref());
         let state = map_device_state(d.state);
+        let ports = legacy_ports(&d);
+        let radios = legacy_radios(&d);
+        let firmware_target = legacy_firmware_target(&d);
 
         // Build device_stats from sys_stats + uptime
         let device_stats = {
@@ -182,13 +195,14 @@ impl From<LegacyDevice> for Device {
             state,
             firmware_version: d.version,
             firmware_updatable: d.upgradable.unwrap_or(false),
+            firmware_target,
             adopted_at: None, // Legacy API doesn't provide adoption timestamp
             provisioned_at: None,
             last_seen: epoch_to_datetime(d.last_seen),
             serial: d.serial,
             supported: true, // Legacy API only returns adopted/supported devices
-            ports: Vec::new(),
-            radios: Vec::new(),
+            ports,
+            radios,
             uplink_device_id: None,
             uplink_device_mac: d
                 .extra
@@ -200,6 +214,7 @@ impl From<LegacyDevice> for Device {
             has_access_point: device_type == DeviceType::AccessPoint,
             stats: device_stats,
             client_count: d.num_sta.and_then(|n| n.try_into().ok()),
+            site_id: d.site_id.map(EntityId::from),
             origin: None,
             source: DataSource::LegacyApi,
             updated_at: Utc::now(),
@@ -207,6 +222,248 @@ impl From<LegacyDevice> for Device {
     }
 }
 
+/// Firmware version the controller offers, from `upgrade_to_firmware`.
+///
+/// The field lingers after an upgrade on some controllers, so it only
+/// counts while the device is flagged `upgradable`.
+pub(crate) fn legacy_firmware_target(d: &LegacyDevice) -> Option<String> {
+    if !d.upgradable.unwrap_or(false) {
+        return None;
+    }
+    d.extra
+        .get("upgrade_to_firmware")
+        .and_then(Value::as_str)
+        .filter(|v| !v.is_empty())
+        .map(str::to_owned)
+}
+
+/// Build the port list from `port_table`, filling gaps from `port_overrides`.
+pub(crate) fn legacy_ports(d: &LegacyDevice) -> Vec<Port> {
+    d.port_table
+        .iter()
+        .filter_map(|p| {
+            let index = p.port_idx?;
+            let ov = d.port_overrides.iter().find(|o| o.port_idx == index);
+            Some(legacy_port(p, index, ov))
+        })
+        .collect()
+}
+
+fn legacy_port(p: &LegacyPortTableEntry, index: u32, ov: Option<&LegacyPortOverride>) -> Port {
+    let forward = p
+        .forward
+        .as_deref()
+        .or_else(|| ov.and_then(|o| o.forward.as_deref()));
+    let poe_mode = p
+        .poe_mode
+        .as_deref()
+        .or_else(|| ov.and_then(|o| o.poe_mode.as_deref()))
+        .and_then(PoeMode::from_legacy_str);
+    let poe = p.port_poe.unwrap_or(false).then(|| PoeInfo {
+        standard: None,
+        enabled: p.poe_enable.unwrap_or(false),
+        state: match p.extra.get("poe_good").and_then(Value::as_bool) {
+            Some(true) => PortState::Up,
+            Some(false) => PortState::Down,
+            None => PortState::Unknown,
+        },
+        mode: poe_mode,
+    });
+    let connector = p.media.as_deref().and_then(|m| match m {
+        "FE" | "GE" | "2P5GE" | "5GE" | "10GE" => Some(PortConnector::Rj45),
+        "SFP" => Some(PortConnector::Sfp),
+        "SFP+" => Some(PortConnector::SfpPlus),
+        "SFP28" => Some(PortConnector::Sfp28),
+        "QSFP28" => Some(PortConnector::Qsfp28),
+        _ => None,
+    });
+
+    Port {
+        index,
+        name: p.name.clone().or_else(|| ov.and_then(|o| o.name.clone())),
+        state: match p.up {
+            Some(true) => PortState::Up,
+            Some(false) => PortState::Down,
+            None => PortState::Unknown,
+        },
+        speed_mbps: p.speed.filter(|s| *s > 0),
+        max_speed_mbps: None,
+        connector,
+        poe,
+        enabled: p.enable.unwrap_or(true) && forward != Some("disabled"),
+        profile_id: p
+            .portconf_id
+            .clone()
+            .or_else(|| ov.and_then(|o| o.portconf_id.clone()))
+            .map(EntityId::from),
+        native_network_id: p
+            .native_networkconf_id
+            .clone()
+            .or_else(|| ov.and_then(|o| o.native_networkconf_id.clone()))
+            .map(EntityId::from),
+    }
+}
+
+/// A JSON number or numeric string, as the legacy API sends radio settings.
+pub(crate) fn legacy_number<T: std::str::FromStr + TryFrom<i64>>(v: &Value) -> Option<T> {
+    match v {
+        Value::Number(n) => n.as_i64().and_then(|n| T::try_from(n).ok()),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Build an AP's radios from its configured `radio_table`, filling in the
+/// live channel and power from `radio_table_stats`.
+pub(crate) fn legacy_radios(d: &LegacyDevice) -> Vec<Radio> {
+    d.radio_table
+        .iter()
+        .filter_map(|r| {
+            let band = RadioBand::from_legacy_str(&r.radio)?;
+            let stats = d
+                .radio_table_stats
+                .iter()
+                .find(|s| s.name.is_some() && s.name == r.name);
+            Some(legacy_radio(r, band, stats))
+        })
+        .collect()
+}
+
+fn legacy_radio(r: &LegacyRadioConfig, band: RadioBand, stats: Option<&LegacyRadioStats>) -> Radio {
+    let configured_dbm = r.tx_power.as_ref().and_then(legacy_number);
+    let tx_retries_pct = stats.and_then(|s| match (s.tx_retries, s.tx_packets) {
+        (Some(retries), Some(packets)) if packets > 0.0 => Some(retries / packets * 100.0),
+        _ => None,
+    });
+    Radio {
+        frequency_ghz: band.frequency_ghz(),
+        channel: stats
+            .and_then(|s| s.channel)
+            .or_else(|| r.channel.as_ref().and_then(legacy_number)),
+        channel_width_mhz: r.ht.as_ref().and_then(legacy_number),
+        wlan_standard: None,
+        tx_retries_pct,
+        tx_power: r
+            .tx_power_mode
+            .as_deref()
+            .and_then(|mode| TxPower::from_legacy(mode, configured_dbm)),
+        tx_power_dbm: stats.and_then(|s| s.tx_power),
+        min_rssi_dbm: r.min_rssi.filter(|_| r.min_rssi_enabled == Some(true)),
+    }
+}
+
+// ── Port profile ───────────────────────────────────────────────────
+
+impl From<LegacyPortConf> for PortProfile {
+    fn from(p: LegacyPortConf) -> Self {
+        PortProfile {
+            id: EntityId::from(p.id),
+            name: p.name.unwrap_or_default(),
+            forward: p.forward,
+            native_network_id: p.native_networkconf_id.map(EntityId::from),
+            poe_mode: p.poe_mode.as_deref().and_then(PoeMode::from_legacy_str),
+        }
+    }
+}
+
+// ── Site settings ──────────────────────────────────────────────────
+
+impl From<Vec<LegacySetting>> for SiteSettings {
+    fn from(raw: Vec<LegacySetting>) -> Self {
+        let sections = raw
+            .into_iter()
+            .map(|s| {
+                let mut fields = s.fields;
+                fields.remove("site_id");
+                (s.key, fields)
+            })
+            .collect();
+        SiteSettings::from_sections(sections)
+    }
+}
+
+// ── User group ─────────────────────────────────────────────────────
+
+impl From<LegacyUserGroup> for UserGroup {
+    fn from(g: LegacyUserGroup) -> Self {
+        // Rates of -1 (or anything non-positive) mean "no limit".
+        let limit = |rate: Option<i64>| rate.filter(|&r| r > 0).and_then(|r| u32::try_from(r).ok());
+        let is_default = g
+            .extra
+            .get("attr_no_delete")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        UserGroup {
+            id: EntityId::from(g.id),
+            name: g.name,
+            download_limit_kbps: limit(g.qos_rate_max_down),
+            upload_limit_kbps: limit(g.qos_rate_max_up),
+            is_default,
+        }
+    }
+}
+
+// ── Neighbor AP ────────────────────────────────────────────────────
+
+impl From<LegacyRogueAp> for NeighborAp {
+    fn from(r: LegacyRogueAp) -> Self {
+        let band_ghz = match r.radio.as_deref() {
+            Some("ng") => Some(2.4),
+            Some("na") => Some(5.0),
+            Some("6e") => Some(6.0),
+            _ => None,
+        };
+        #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+        let frequency_ghz = band_ghz.or_else(|| r.freq.map(|mhz| mhz as f32 / 1000.0));
+        NeighborAp {
+            bssid: MacAddress::new(&r.bssid),
+            ssid: r.essid.filter(|s| !s.is_empty()),
+            channel: r.channel,
+            frequency_ghz,
+            rssi: r.rssi,
+            signal_dbm: r.signal,
+            security: r.security,
+            vendor: r.oui.filter(|s| !s.is_empty()),
+            detected_by: r.ap_mac.as_deref().map(MacAddress::new),
+            is_rogue: r.is_rogue.unwrap_or(false),
+            last_seen: epoch_to_datetime(r.last_seen),
+        }
+    }
+}
+
+// ── Spectrum scan ──────────────────────────────────────────────────
+
+impl From<LegacySpectrumEntry> for ChannelSpectrum {
+    fn from(e: LegacySpectrumEntry) -> Self {
+        #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+        let frequency_ghz = e.freq.map(|mhz| mhz as f32 / 1000.0);
+        ChannelSpectrum {
+            channel: e.channel,
+            frequency_ghz,
+            width_mhz: e.width,
+            utilization_pct: e.utilization,
+            interference_pct: e.interference,
+            interference_types: e.interference_type,
+        }
+    }
+}
+
+impl From<LegacySpectrumScan> for SpectrumScan {
+    fn from(s: LegacySpectrumScan) -> Self {
+        let mut channels: Vec<ChannelSpectrum> = s
+            .spectrum_table
+            .into_iter()
+            .map(ChannelSpectrum::from)
+            .collect();
+        channels.sort_by_key(|c| c.channel);
+        SpectrumScan {
+            device_mac: MacAddress::new(s.mac.as_deref().unwrap_or_default()),
+            scanning: s.spectrum_scanning.unwrap_or(false),
+            channels,
+        }
+    }
+}
+
 // ── Client ─────────────────────────────────────────────────────────
 
 impl From<LegacyClientEntry> for Client {
@@ -298,12 +555,112 @@ impl From<LegacyClientEntry> for Client {
             os_name: None,
             device_class: None,
             blocked: c.blocked.unwrap_or(false),
+            fixed_ip: if c.use_fixedip.unwrap_or(false) {
+                c.fixed_ip.as_deref().and_then(|ip| ip.parse().ok())
+            } else {
+                None
+            },
+            note: c.note.filter(|n| !n.is_empty()),
+            user_group_id: c.usergroup_id.filter(|g| !g.is_empty()).map(EntityId::from),
+            user_group: None,
+            site_id: c.site_id.map(EntityId::from),
             source: DataSource::LegacyApi,
             updated_at: Utc::now(),
         }
     }
 }
 
+fn wired_type(is_wired: Option<bool>) -> ClientType {
+    if is_wired.unwrap_or(false) {
+        ClientType::Wired
+    } else {
+        ClientType::Wireless
+    }
+}
+
+impl From<LegacyUserEntry> for KnownClient {
+    fn from(u: LegacyUserEntry) -> Self {
+        KnownClient {
+            id: EntityId::from(u.id),
+            mac: MacAddress::new(&u.mac),
+            name: u.name.filter(|n| !n.is_empty()),
+            hostname: u.hostname,
+            oui: u.oui,
+            client_type: wired_type(u.is_wired),
+            is_guest: u.is_guest.unwrap_or(false),
+            blocked: u.blocked.unwrap_or(false),
+            fixed_ip: if u.use_fixedip.unwrap_or(false) {
+                u.fixed_ip.as_deref().and_then(|ip| ip.parse().ok())
+            } else {
+                None
+            },
+            note: u.note.filter(|n| !n.is_empty()),
+            user_group_id: u.usergroup_id.filter(|g| !g.is_empty()).map(EntityId::from),
+            first_seen: epoch_to_datetime(u.first_seen),
+            last_seen: epoch_to_datetime(u.last_seen),
+        }
+    }
+}
+
+/// An offline client in the shape of a live one, for listings that mix
+/// both. Connection fields (IP, uplink, traffic) are left empty.
+impl From<KnownClient> for Client {
+    fn from(k: KnownClient) -> Self {
+        Client {
+            id: k.id,
+            mac: k.mac,
+            ip: None,
+            name: k.name,
+            hostname: k.hostname,
+            client_type: k.client_type,
+            connected_at: None,
+            uplink_device_id: None,
+            uplink_device_mac: None,
+            network_id: None,
+            vlan: None,
+            wireless: None,
+            guest_auth: None,
+            is_guest: k.is_guest,
+            tx_bytes: None,
+            rx_bytes: None,
+            bandwidth: None,
+            oui: k.oui,
+            network_name: None,
+            sw_port: None,
+            os_name: None,
+            device_class: None,
+            blocked: k.blocked,
+            fixed_ip: k.fixed_ip,
+            note: k.note,
+            user_group_id: k.user_group_id,
+            user_group: None,
+            site_id: None,
+            source: DataSource::LegacyApi,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+impl From<LegacySession> for ClientSession {
+    fn from(s: LegacySession) -> Self {
+        ClientSession {
+            mac: MacAddress::new(&s.mac),
+            client_type: wired_type(s.is_wired),
+            connected_at: epoch_to_datetime(s.assoc_time).unwrap_or_else(Utc::now),
+            disconnected_at: epoch_to_datetime(s.disassoc_time),
+            duration_secs: s.duration.and_then(|d| d.try_into().ok()),
+            ip: parse_ip(s.ip.as_ref()),
+            hostname: s.hostname,
+            ap_mac: s.ap_mac.as_deref().map(MacAddress::new),
+            ssid: s.essid.filter(|e| !e.is_empty()),
+            switch_mac: s.sw_mac.as_deref().map(MacAddress::new),
+            switch_port: s.sw_port.and_then(|p| p.try_into().ok()),
+            tx_bytes: s.tx_bytes.and_then(|b| b.try_into().ok()),
+            rx_bytes: s.rx_bytes.and_then(|b| b.try_into().ok()),
+        }
+    }
+}
+
 /// Rough channel-to-frequency mapping for common Wi-Fi channels.
 fn channel_to_frequency(channel: Option<i32>) -> Option<f32> {
     channel.map(|ch| match ch {
@@ -390,6 +747,11 @@ impl From<LegacyAlarm> for Event {
 
 impl From<LegacyAlarm> for Alarm {
     fn from(a: LegacyAlarm) -> Self {
+        // The affected device is keyed by its role: ap / gw / sw.
+        let device_mac = ["ap", "gw", "sw"]
+            .iter()
+            .find_map(|k| a.extra.get(*k).and_then(serde_json::Value::as_str))
+            .map(MacAddress::new);
         Alarm {
             id: EntityId::from(a.id),
             timestamp: parse_datetime(a.datetime.as_ref()).unwrap_or_else(Utc::now),
@@ -397,8 +759,38 @@ impl From<LegacyAlarm> for Alarm {
             severity: EventSeverity::Warning,
             message: a.msg.unwrap_or_default(),
             archived: a.archived.unwrap_or(false),
-            device_mac: None,
+            device_mac,
             site_id: None,
+            raw_key: a.key,
+        }
+    }
+}
+
+// ── IPS event ──────────────────────────────────────────────────────
+
+impl From<LegacyIpsEvent> for ThreatEvent {
+    fn from(e: LegacyIpsEvent) -> Self {
+        ThreatEvent {
+            id: EntityId::from(e.id),
+            timestamp: e
+                .timestamp
+                .and_then(DateTime::from_timestamp_millis)
+                .unwrap_or_else(Utc::now),
+            signature: e.inner_alert_signature,
+            signature_id: e.inner_alert_signature_id,
+            category: e.inner_alert_category,
+            blocked: e
+                .inner_alert_action
+                .is_some_and(|a| a.eq_ignore_ascii_case("blocked")),
+            severity: e.inner_alert_severity,
+            protocol: e.proto,
+            src_ip: parse_ip(e.src_ip.as_ref()),
+            src_port: e.src_port,
+            src_country: e.src_country.filter(|c| !c.is_empty()),
+            src_mac: e.src_mac.as_deref().map(MacAddress::new),
+            dst_ip: parse_ip(e.dest_ip.as_ref()),
+            dst_port: e.dest_port,
+            dst_country: e.dest_country.filter(|c| !c.is_empty()),
         }
     }
 }
@@ -557,6 +949,7 @@ impl From<integration_types::DeviceResponse> for Device {
             state,
             firmware_version: d.firmware_version,
             firmware_updatable: d.firmware_updatable,
+            firmware_target: None,
             adopted_at: None,
             provisioned_at: None,
             last_seen: None,
@@ -570,6 +963,7 @@ impl From<integration_types::DeviceResponse> for Device {
             has_access_point: d.features.iter().any(|f| f == "accessPoint"),
             stats: DeviceStats::default(),
             client_count: None,
+            site_id: None,
             origin: None,
             source: DataSource::IntegrationApi,
             updated_at: Utc::now(),
@@ -666,6 +1060,11 @@ impl From<integration_types::ClientResponse> for Client {
             os_name: None,
             device_class: None,
             blocked: false,
+            fixed_ip: None,
+            note: None,
+            user_group_id: None,
+            user_group: None,
+            site_id: None,
             source: DataSource::IntegrationApi,
             updated_at: Utc::now(),
         }
@@ -931,6 +1330,7 @@ fn parse_network_fields(
         internet_access_enabled,
         mdns_forwarding_enabled,
         cellular_backup_enabled,
+        site_id: None,
         origin: map_origin(management_str),
         source: DataSource::IntegrationApi,
     }
@@ -1009,6 +1409,7 @@ impl From<integration_types::WifiBroadcastResponse> for WifiBroadcast {
             mlo_enabled: false,
             fast_roaming: false,
             hotspot_enabled: false,
+            site_id: None,
             origin: origin_from_metadata(&w.metadata),
             source: DataSource::IntegrationApi,
         }
@@ -1130,12 +1531,22 @@ impl From<integration_types::AclRuleResponse> for AclRule {
             _ => AclAction::Block,
         };
 
+        let zone_id = |filter: Option<&serde_json::Value>| {
+            filter
+                .and_then(|f| f.get("zoneId"))
+                .and_then(serde_json::Value::as_str)
+                .and_then(|s| uuid::Uuid::parse_str(s).ok())
+                .map(EntityId::Uuid)
+        };
+
         AclRule {
             id: EntityId::Uuid(r.id),
             name: r.name,
             enabled: r.enabled,
             rule_type,
             action,
+            source_zone_id: zone_id(r.source_filter.as_ref()),
+            destination_zone_id: zone_id(r.destination_filter.as_ref()),
             source_summary: None,
             destination_summary: None,
             origin: origin_from_metadata(&r.metadata),
@@ -1205,6 +1616,59 @@ impl From<integration_types::TrafficMatchingListResponse> for TrafficMatchingLis
     }
 }
 
+// ── Port Forward ─────────────────────────────────────────────────
+
+impl From<LegacyPortForward> for PortForward {
+    fn from(p: LegacyPortForward) -> Self {
+        let protocol = match p.proto.as_deref() {
+            Some("tcp") => PortForwardProtocol::Tcp,
+            Some("udp") => PortForwardProtocol::Udp,
+            _ => PortForwardProtocol::TcpUdp,
+        };
+        let wan_port = p.dst_port.unwrap_or_default();
+
+        PortForward {
+            id: EntityId::from(p.id),
+            name: p.name.unwrap_or_default(),
+            enabled: p.enabled.unwrap_or(true),
+            protocol,
+            wan_interface: p.pfwd_interface.unwrap_or_else(|| "wan".into()),
+            source_address: p
+                .src
+                .filter(|s| !s.is_empty() && !s.eq_ignore_ascii_case("any")),
+            forward_port: p.fwd_port.unwrap_or_else(|| wan_port.clone()),
+            wan_port,
+            forward_ip: p.fwd.unwrap_or_default(),
+            logging: p.log.unwrap_or(false),
+            source: DataSource::LegacyApi,
+        }
+    }
+}
+
+// ── Static Route ─────────────────────────────────────────────────
+
+impl From<LegacyStaticRoute> for StaticRoute {
+    fn from(r: LegacyStaticRoute) -> Self {
+        let route_type = match r.static_route_type.as_deref() {
+            Some("interface-route") => StaticRouteType::Interface,
+            Some("blackhole") => StaticRouteType::Blackhole,
+            _ => StaticRouteType::NextHop,
+        };
+
+        StaticRoute {
+            id: EntityId::from(r.id),
+            name: r.name.unwrap_or_default(),
+            enabled: r.enabled.unwrap_or(true),
+            route_type,
+            destination: r.static_route_network.unwrap_or_default(),
+            next_hop: r.static_route_nexthop.filter(|s| !s.is_empty()),
+            interface: r.static_route_interface.filter(|s| !s.is_empty()),
+            distance: r.static_route_distance,
+            source: DataSource::LegacyApi,
+        }
+    }
+}
+
 // ── Voucher ──────────────────────────────────────────────────────
 
 impl From<integration_types::VoucherResponse> for Voucher {
@@ -1344,4 +1808,94 @@ mod tests {
         assert_eq!(channel_to_frequency(Some(149)), Some(5.0));
         assert_eq!(channel_to_frequency(None), None);
     }
+
+    #[test]
+    fn legacy_radios_merge_config_and_live_stats() {
+        let device: LegacyDevice = serde_json::from_value(serde_json::json!({
+            "_id": "ap1",
+            "mac": "aa:bb:cc:dd:ee:02",
+            "type": "uap",
+            "radio_table": [
+                { "radio": "ng", "name": "wifi0", "channel": "auto", "ht": "20",
+                  "tx_power_mode": "custom", "tx_power": "10",
+                  "min_rssi_enabled": false, "min_rssi": -80 },
+                { "radio": "na", "name": "wifi1", "channel": 44, "ht": 80,
+                  "tx_power_mode": "high", "min_rssi_enabled": true, "min_rssi": -75 }
+            ],
+            "radio_table_stats": [
+                { "name": "wifi0", "channel": 11, "tx_power": 10,
+                  "tx_retries": 50.0, "tx_packets": 1000.0 }
+            ]
+        }))
+        .expect("valid device");
+
+        let radios = legacy_radios(&device);
+        assert_eq!(radios.len(), 2);
+        assert_eq!(radios[0].channel, Some(11));
+        assert_eq!(radios[0].channel_width_mhz, Some(20));
+        assert_eq!(radios[0].tx_power, Some(TxPower::Custom(10)));
+        assert_eq!(radios[0].tx_retries_pct, Some(5.0));
+        assert_eq!(radios[0].min_rssi_dbm, None);
+        assert_eq!(radios[1].frequency_ghz, 5.0);
+        assert_eq!(radios[1].channel, Some(44));
+        assert_eq!(radios[1].tx_power, Some(TxPower::High));
+        assert_eq!(radios[1].min_rssi_dbm, Some(-75));
+    }
+
+    #[test]
+    fn legacy_ips_event_to_threat() {
+        let raw: LegacyIpsEvent = serde_json::from_value(serde_json::json!({
+            "_id": "ips1",
+            "timestamp": 1_700_000_000_000_i64,
+            "src_ip": "203.0.113.7",
+            "src_port": 51234,
+            "srcipCountry": "NL",
+            "dest_ip": "192.168.1.10",
+            "dest_port": 22,
+            "dstipCountry": "",
+            "proto": "TCP",
+            "inner_alert_signature": "ET SCAN Potential SSH Scan",
+            "inner_alert_category": "Attempted Information Leak",
+            "inner_alert_action": "blocked",
+            "inner_alert_severity": 2
+        }))
+        .expect("valid ips event");
+
+        let threat = ThreatEvent::from(raw);
+        assert_eq!(threat.timestamp.timestamp(), 1_700_000_000);
+        assert!(threat.blocked);
+        assert_eq!(threat.src_ip, "203.0.113.7".parse().ok());
+        assert_eq!(threat.src_country.as_deref(), Some("NL"));
+        assert_eq!(threat.dst_country, None);
+        assert_eq!(threat.dst_port, Some(22));
+        assert_eq!(threat.severity, Some(2));
+    }
+
+    #[test]
+    fn legacy_known_client_to_offline_client() {
+        let raw: LegacyUserEntry = serde_json::from_value(serde_json::json!({
+            "_id": "u1",
+            "mac": "AA:BB:CC:00:00:10",
+            "name": "",
+            "hostname": "printer",
+            "is_wired": true,
+            "last_seen": 1_700_000_000,
+            "use_fixedip": true,
+            "fixed_ip": "192.168.1.20",
+            "usergroup_id": ""
+        }))
+        .expect("valid user entry");
+
+        let known = KnownClient::from(raw);
+        assert_eq!(known.name, None);
+        assert_eq!(known.client_type, ClientType::Wired);
+        assert_eq!(known.last_seen.map(|t| t.timestamp()), Some(1_700_000_000));
+        assert_eq!(known.user_group_id, None);
+
+        let client = Client::from(known);
+        assert_eq!(client.mac, MacAddress::new("aa:bb:cc:00:00:10"));
+        assert_eq!(client.fixed_ip, "192.168.1.20".parse().ok());
+        assert!(client.connected_at.is_none());
+        assert!(client.ip.is_none());
+    }
 }
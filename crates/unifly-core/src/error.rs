@@ -17,6 +17,10 @@ pub enum CoreError {
     #[error("Authentication failed: {message}")]
     AuthenticationFailed { message: String },
 
+    /// The account requires a two-factor code and none was supplied.
+    #[error("Two-factor authentication code required")]
+    TwoFactorRequired,
+
     #[error("Controller disconnected")]
     ControllerDisconnected,
 
@@ -82,9 +86,7 @@ impl From<unifly_api::Error> for CoreError {
             unifly_api::Error::Authentication { message } => {
                 CoreError::AuthenticationFailed { message }
             }
-            unifly_api::Error::TwoFactorRequired => CoreError::AuthenticationFailed {
-                message: "Two-factor authentication token required".into(),
-            },
+            unifly_api::Error::TwoFactorRequired => CoreError::TwoFactorRequired,
             unifly_api::Error::SessionExpired => CoreError::AuthenticationFailed {
                 message: "Session expired -- re-authentication required".into(),
             },
@@ -132,10 +134,20 @@ impl From<unifly_api::Error> for CoreError {
                 code: Some("rate_limited".into()),
                 status: Some(429),
             },
+            unifly_api::Error::SiteManager { message, status } => CoreError::Api {
+                message,
+                code: None,
+                status: Some(status),
+            },
             unifly_api::Error::Integration {
                 message,
                 code,
                 status,
+            }
+            | unifly_api::Error::V2Api {
+                message,
+                code,
+                status,
             } => CoreError::Api {
                 message,
                 code,
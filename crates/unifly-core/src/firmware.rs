@@ -0,0 +1,285 @@
+// ── Firmware rollouts ──
+//
+// Per-device firmware status and staged upgrades. `plan_rollout` splits
+// the upgradable devices into batches, upstream devices last, and a
+// `BatchTracker` watches `DataStore` snapshots to decide when a batch has
+// come back online on new firmware or has failed.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::model::{Device, DeviceState, DeviceType, EntityId, MacAddress};
+
+/// Firmware state of a single device.
+#[derive(Debug, Clone, Serialize)]
+pub struct FirmwareStatus {
+    pub device_id: EntityId,
+    pub mac: MacAddress,
+    pub name: Option<String>,
+    pub model: Option<String>,
+    pub device_type: DeviceType,
+    pub state: DeviceState,
+    pub current_version: Option<String>,
+    pub upgradable: bool,
+    pub target_version: Option<String>,
+}
+
+impl From<&Device> for FirmwareStatus {
+    fn from(d: &Device) -> Self {
+        Self {
+            device_id: d.id.clone(),
+            mac: d.mac.clone(),
+            name: d.name.clone(),
+            model: d.model.clone(),
+            device_type: d.device_type,
+            state: d.state,
+            current_version: d.firmware_version.clone(),
+            upgradable: d.firmware_updatable,
+            target_version: d.firmware_target.clone(),
+        }
+    }
+}
+
+/// Upgrade order: APs first, gateways last, so an upgrade never takes
+/// down the path to devices still waiting in the same rollout.
+fn rollout_rank(device_type: DeviceType) -> u8 {
+    match device_type {
+        DeviceType::AccessPoint => 0,
+        DeviceType::Other => 1,
+        DeviceType::Switch => 2,
+        DeviceType::Gateway => 3,
+    }
+}
+
+/// Split the online, upgradable devices into batches of `batch_size`.
+///
+/// Offline devices are left out; the controller cannot upgrade them.
+pub fn plan_rollout(devices: &[Arc<Device>], batch_size: usize) -> Vec<Vec<Arc<Device>>> {
+    let mut candidates: Vec<Arc<Device>> = devices
+        .iter()
+        .filter(|d| d.firmware_updatable && d.state.is_online())
+        .cloned()
+        .collect();
+    candidates.sort_by(|a, b| {
+        rollout_rank(a.device_type)
+            .cmp(&rollout_rank(b.device_type))
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.mac.as_str().cmp(b.mac.as_str()))
+    });
+    candidates
+        .chunks(batch_size.max(1))
+        .map(<[Arc<Device>]>::to_vec)
+        .collect()
+}
+
+/// Where a batch of upgrades stands after the latest snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchState {
+    /// At least one device has not come back on new firmware yet.
+    InProgress,
+    /// Every device is online on a different firmware version.
+    Done,
+    /// A device came back on its old firmware.
+    Failed { mac: MacAddress, reason: String },
+}
+
+#[derive(Debug)]
+struct Member {
+    mac: MacAddress,
+    name: String,
+    version: Option<String>,
+}
+
+/// Follows one batch of upgrades through successive device snapshots.
+#[derive(Debug)]
+pub struct BatchTracker {
+    members: Vec<Member>,
+    /// Devices seen in a non-online state since the upgrade was sent.
+    went_down: HashSet<MacAddress>,
+}
+
+impl BatchTracker {
+    /// Start tracking `batch`, remembering each device's current firmware.
+    pub fn new(batch: &[Arc<Device>]) -> Self {
+        let members = batch
+            .iter()
+            .map(|d| Member {
+                mac: d.mac.clone(),
+                name: d.name.clone().unwrap_or_else(|| d.mac.to_string()),
+                version: d.firmware_version.clone(),
+            })
+            .collect();
+        Self {
+            members,
+            went_down: HashSet::new(),
+        }
+    }
+
+    /// Update from a device snapshot and report the batch's state.
+    ///
+    /// A device is done once it is online on a version other than the one
+    /// it started on. Coming back online on the old version after going
+    /// down means the upgrade failed. Devices missing from the snapshot
+    /// are still in progress.
+    pub fn observe(&mut self, devices: &[Arc<Device>]) -> BatchState {
+        let mut pending = false;
+        for member in &self.members {
+            let Some(device) = devices.iter().find(|d| d.mac == member.mac) else {
+                pending = true;
+                continue;
+            };
+            if !device.state.is_online() {
+                self.went_down.insert(member.mac.clone());
+                pending = true;
+                continue;
+            }
+            if device.firmware_version != member.version {
+                continue;
+            }
+            if self.went_down.contains(&member.mac) {
+                return BatchState::Failed {
+                    mac: member.mac.clone(),
+                    reason: format!(
+                        "{} came back online still on {}",
+                        member.name,
+                        member.version.as_deref().unwrap_or("its old firmware")
+                    ),
+                };
+            }
+            pending = true;
+        }
+        if pending {
+            BatchState::InProgress
+        } else {
+            BatchState::Done
+        }
+    }
+
+    /// Devices in this batch that have not finished yet.
+    pub fn waiting_on(&self, devices: &[Arc<Device>]) -> Vec<String> {
+        self.members
+            .iter()
+            .filter(|m| {
+                devices
+                    .iter()
+                    .find(|d| d.mac == m.mac)
+                    .is_none_or(|d| !d.state.is_online() || d.firmware_version == m.version)
+            })
+            .map(|m| m.name.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::model::DeviceState::{Offline, Online, Updating};
+    use crate::model::DeviceType::{AccessPoint, Gateway, Switch};
+    use crate::model::{DataSource, DeviceStats};
+
+    fn device(
+        mac: &str,
+        kind: DeviceType,
+        state: DeviceState,
+        version: &str,
+        updatable: bool,
+    ) -> Arc<Device> {
+        Arc::new(Device {
+            id: EntityId::from(mac),
+            mac: MacAddress::new(mac),
+            ip: None,
+            wan_ipv6: None,
+            name: Some(format!("dev-{mac}")),
+            model: None,
+            device_type: kind,
+            state,
+            firmware_version: Some(version.into()),
+            firmware_updatable: updatable,
+            firmware_target: None,
+            adopted_at: None,
+            provisioned_at: None,
+            last_seen: None,
+            serial: None,
+            supported: true,
+            ports: Vec::new(),
+            radios: Vec::new(),
+            uplink_device_id: None,
+            uplink_device_mac: None,
+            has_switching: false,
+            has_access_point: false,
+            stats: DeviceStats::default(),
+            client_count: None,
+            site_id: None,
+            origin: None,
+            source: DataSource::IntegrationApi,
+            updated_at: chrono::Utc::now(),
+        })
+    }
+
+    #[test]
+    fn rollout_skips_offline_and_current_devices_and_upgrades_gateways_last() {
+        let devices = vec![
+            device("00:00:00:00:00:01", Gateway, Online, "4.0", true),
+            device("00:00:00:00:00:02", Switch, Online, "7.0", true),
+            device("00:00:00:00:00:03", AccessPoint, Online, "6.0", true),
+            device("00:00:00:00:00:04", AccessPoint, Offline, "6.0", true),
+            device("00:00:00:00:00:05", AccessPoint, Online, "6.5", false),
+            device("00:00:00:00:00:06", AccessPoint, Online, "6.0", true),
+        ];
+
+        let batches = plan_rollout(&devices, 2);
+        let macs: Vec<Vec<&str>> = batches
+            .iter()
+            .map(|b| b.iter().map(|d| d.mac.as_str()).collect())
+            .collect();
+        assert_eq!(
+            macs,
+            vec![
+                vec!["00:00:00:00:00:03", "00:00:00:00:00:06"],
+                vec!["00:00:00:00:00:02", "00:00:00:00:00:01"],
+            ]
+        );
+        assert_eq!(plan_rollout(&devices, 0).len(), 4);
+    }
+
+    #[test]
+    fn tracker_waits_for_new_firmware_and_flags_rollbacks() {
+        let a = "00:00:00:00:00:0a";
+        let b = "00:00:00:00:00:0b";
+        let batch = vec![
+            device(a, AccessPoint, Online, "6.0", true),
+            device(b, AccessPoint, Online, "6.0", true),
+        ];
+        let mut tracker = BatchTracker::new(&batch);
+
+        // Upgrade not picked up yet.
+        assert_eq!(tracker.observe(&batch), BatchState::InProgress);
+
+        let upgrading = vec![
+            device(a, AccessPoint, Updating, "6.0", true),
+            device(b, AccessPoint, Online, "6.2", false),
+        ];
+        assert_eq!(tracker.observe(&upgrading), BatchState::InProgress);
+        assert_eq!(tracker.waiting_on(&upgrading), vec![format!("dev-{a}")]);
+
+        let done = vec![
+            device(a, AccessPoint, Online, "6.2", false),
+            device(b, AccessPoint, Online, "6.2", false),
+        ];
+        assert_eq!(tracker.observe(&done), BatchState::Done);
+
+        let mut tracker = BatchTracker::new(&batch);
+        tracker.observe(&upgrading);
+        let rolled_back = vec![
+            device(a, AccessPoint, Online, "6.0", true),
+            device(b, AccessPoint, Online, "6.2", false),
+        ];
+        assert!(matches!(
+            tracker.observe(&rolled_back),
+            BatchState::Failed { mac, .. } if mac.as_str() == a
+        ));
+    }
+}
@@ -0,0 +1,178 @@
+// ── Fleet status ──
+//
+// Probes several controllers at once and summarizes each one. Every
+// controller gets its own short-lived `Controller::oneshot` session; a
+// failure on one never affects the others, it is reported in its entry.
+
+use futures_util::StreamExt;
+use serde::Serialize;
+
+use crate::config::ControllerConfig;
+use crate::controller::Controller;
+use crate::model::{DeviceState, HealthSummary};
+
+/// Default number of controllers probed at the same time.
+pub const DEFAULT_FLEET_CONCURRENCY: usize = 8;
+
+/// One controller to probe, identified by its profile name.
+#[derive(Debug, Clone)]
+pub struct FleetTarget {
+    pub name: String,
+    pub config: ControllerConfig,
+}
+
+/// Summary of a single controller in the fleet.
+#[derive(Debug, Clone, Serialize)]
+pub struct ControllerStatus {
+    /// Profile name.
+    pub name: String,
+    /// Controller URL (as configured).
+    pub url: String,
+    pub site: String,
+    /// Why the controller could not be reached; `None` when it was.
+    pub error: Option<String>,
+    pub devices_online: usize,
+    pub devices_offline: usize,
+    pub devices_total: usize,
+    pub health: Vec<HealthSummary>,
+}
+
+impl ControllerStatus {
+    /// An entry for a profile that could not be probed at all.
+    pub fn unreachable(
+        name: impl Into<String>,
+        url: impl Into<String>,
+        site: impl Into<String>,
+        error: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            url: url.into(),
+            site: site.into(),
+            error: Some(error.into()),
+            devices_online: 0,
+            devices_offline: 0,
+            devices_total: 0,
+            health: Vec::new(),
+        }
+    }
+
+    pub fn is_reachable(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Worst subsystem status: "error" over "warn" over "ok".
+    ///
+    /// Returns `None` for unreachable controllers or when the controller
+    /// reported no health data.
+    pub fn overall_health(&self) -> Option<&str> {
+        let rank = |status: &str| match status {
+            "ok" => 0,
+            "warn" | "warning" => 1,
+            _ => 2,
+        };
+        self.health
+            .iter()
+            .map(|h| h.status.as_str())
+            .max_by_key(|s| rank(s))
+    }
+}
+
+/// Probe every target, at most `concurrency` at a time.
+///
+/// Results come back in the order of `targets`.
+pub async fn fleet_status(targets: Vec<FleetTarget>, concurrency: usize) -> Vec<ControllerStatus> {
+    futures_util::stream::iter(targets)
+        .map(probe)
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+/// Connect to one controller and summarize it.
+pub async fn probe(target: FleetTarget) -> ControllerStatus {
+    let url = target.config.url.to_string();
+    let site = target.config.site.clone();
+
+    let result = Controller::oneshot(target.config, |controller| async move {
+        let health = controller.get_site_health().await?;
+        Ok((controller.devices_snapshot(), health))
+    })
+    .await;
+
+    match result {
+        Ok((devices, health)) => {
+            let online = devices.iter().filter(|d| d.state.is_online()).count();
+            let offline = devices
+                .iter()
+                .filter(|d| d.state == DeviceState::Offline)
+                .count();
+            ControllerStatus {
+                name: target.name,
+                url,
+                site,
+                error: None,
+                devices_online: online,
+                devices_offline: offline,
+                devices_total: devices.len(),
+                health,
+            }
+        }
+        Err(e) => ControllerStatus::unreachable(target.name, url, site, e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn health(subsystem: &str, status: &str) -> HealthSummary {
+        HealthSummary {
+            subsystem: subsystem.into(),
+            status: status.into(),
+            num_adopted: None,
+            num_sta: None,
+            tx_bytes_r: None,
+            rx_bytes_r: None,
+            latency: None,
+            wan_ip: None,
+            gateways: None,
+            extra: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn overall_health_picks_worst_subsystem() {
+        let mut status = ControllerStatus::unreachable("a", "https://a", "default", "x");
+        status.error = None;
+        assert_eq!(status.overall_health(), None);
+
+        status.health = vec![health("wan", "ok"), health("wlan", "warn")];
+        assert_eq!(status.overall_health(), Some("warn"));
+
+        status.health.push(health("lan", "error"));
+        assert_eq!(status.overall_health(), Some("error"));
+    }
+
+    #[tokio::test]
+    async fn fleet_status_reports_unreachable_controllers() {
+        let targets = ["first", "second"]
+            .into_iter()
+            .map(|name| FleetTarget {
+                name: name.into(),
+                config: ControllerConfig {
+                    url: "http://127.0.0.1:9".parse().expect("valid URL"),
+                    timeout: std::time::Duration::from_secs(2),
+                    ..ControllerConfig::default()
+                },
+            })
+            .collect();
+
+        let statuses = fleet_status(targets, 1).await;
+
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(statuses[0].name, "first");
+        assert_eq!(statuses[1].name, "second");
+        assert!(statuses.iter().all(|s| !s.is_reachable()));
+    }
+}
@@ -0,0 +1,464 @@
+// ── Live stats history ──
+//
+// Opt-in recorder that samples WAN health, per-device stats and per-client
+// bandwidth from the `DataStore` at a fixed interval and appends them to
+// JSON-lines files on disk -- one file per series per UTC day, so retention
+// is a matter of deleting old files. `HistoryStore::query` reads them back
+// for the TUI's Stats screen and `unifly history`.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+use crate::controller::Controller;
+use crate::error::CoreError;
+use crate::model::{Client, Device, HealthSummary, MacAddress};
+
+/// Where and how often to record.
+#[derive(Debug, Clone)]
+pub struct HistoryConfig {
+    /// Directory holding the day files (one per profile).
+    pub dir: PathBuf,
+    /// Time between samples.
+    pub interval: Duration,
+    /// Days of history to keep. 0 keeps everything.
+    pub retention_days: u32,
+}
+
+impl HistoryConfig {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            interval: Duration::from_secs(10),
+            retention_days: 7,
+        }
+    }
+}
+
+/// Which series a sample belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleKind {
+    Wan,
+    Device,
+    Client,
+}
+
+impl SampleKind {
+    fn file_prefix(self) -> &'static str {
+        match self {
+            Self::Wan => "wan",
+            Self::Device => "device",
+            Self::Client => "client",
+        }
+    }
+
+    fn file_name(self, day: NaiveDate) -> String {
+        format!("{}-{day}.jsonl", self.file_prefix())
+    }
+}
+
+/// One recorded data point. Fields a series does not carry are absent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Sample {
+    pub at: DateTime<Utc>,
+    /// Device or client MAC; absent for WAN samples.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mac: Option<MacAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_bytes_per_sec: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rx_bytes_per_sec: Option<u64>,
+    /// WAN latency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<f64>,
+    /// Connected clients (site-wide for WAN, per device otherwise).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clients: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_pct: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_pct: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_dbm: Option<i32>,
+}
+
+impl Sample {
+    /// WAN rates from `stat/health`; `None` when no WAN subsystem is reported.
+    pub fn wan(at: DateTime<Utc>, health: &[HealthSummary]) -> Option<Self> {
+        let wan = health.iter().find(|h| h.subsystem == "wan")?;
+        let latency_ms = health
+            .iter()
+            .find(|h| h.subsystem == "www")
+            .and_then(|h| h.latency);
+        let clients = health
+            .iter()
+            .filter(|h| h.subsystem == "wlan" || h.subsystem == "lan")
+            .filter_map(|h| h.num_sta)
+            .reduce(|a, b| a + b);
+        Some(Self {
+            at,
+            tx_bytes_per_sec: wan.tx_bytes_r,
+            rx_bytes_per_sec: wan.rx_bytes_r,
+            latency_ms,
+            clients,
+            ..Self::default()
+        })
+    }
+
+    pub fn device(at: DateTime<Utc>, device: &Device) -> Self {
+        let stats = &device.stats;
+        Self {
+            at,
+            mac: Some(device.mac.clone()),
+            tx_bytes_per_sec: stats.uplink_bandwidth.map(|b| b.tx_bytes_per_sec),
+            rx_bytes_per_sec: stats.uplink_bandwidth.map(|b| b.rx_bytes_per_sec),
+            clients: device.client_count,
+            cpu_pct: stats.cpu_utilization_pct,
+            memory_pct: stats.memory_utilization_pct,
+            ..Self::default()
+        }
+    }
+
+    /// `None` for clients without live bandwidth.
+    pub fn client(at: DateTime<Utc>, client: &Client) -> Option<Self> {
+        let bandwidth = client.bandwidth?;
+        Some(Self {
+            at,
+            mac: Some(client.mac.clone()),
+            tx_bytes_per_sec: Some(bandwidth.tx_bytes_per_sec),
+            rx_bytes_per_sec: Some(bandwidth.rx_bytes_per_sec),
+            signal_dbm: client.wireless.as_ref().and_then(|w| w.signal_dbm),
+            ..Self::default()
+        })
+    }
+}
+
+/// Filter for [`HistoryStore::query`].
+#[derive(Debug, Clone)]
+pub struct HistoryQuery {
+    pub kind: SampleKind,
+    /// Only this device or client.
+    pub mac: Option<MacAddress>,
+    pub since: DateTime<Utc>,
+    /// Defaults to now.
+    pub until: Option<DateTime<Utc>>,
+}
+
+/// Append-only sample files in one directory.
+#[derive(Debug, Clone)]
+pub struct HistoryStore {
+    dir: PathBuf,
+}
+
+impl HistoryStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Append samples to their day file, creating the directory on demand.
+    pub fn append(&self, kind: SampleKind, samples: &[Sample]) -> Result<(), CoreError> {
+        let Some(first) = samples.first() else {
+            return Ok(());
+        };
+        fs::create_dir_all(&self.dir).map_err(|e| self.io_error(&e))?;
+
+        let mut day = first.at.date_naive();
+        let mut file = self.open_day(kind, day)?;
+        for sample in samples {
+            if sample.at.date_naive() != day {
+                day = sample.at.date_naive();
+                file = self.open_day(kind, day)?;
+            }
+            let mut line =
+                serde_json::to_string(sample).map_err(|e| CoreError::Internal(e.to_string()))?;
+            line.push('\n');
+            file.write_all(line.as_bytes())
+                .map_err(|e| self.io_error(&e))?;
+        }
+        Ok(())
+    }
+
+    /// Samples matching `query`, oldest first.
+    ///
+    /// Missing day files are skipped, as are lines that do not parse (e.g.
+    /// a write cut short by a crash).
+    pub fn query(&self, query: &HistoryQuery) -> Result<Vec<Sample>, CoreError> {
+        let until = query.until.unwrap_or_else(Utc::now);
+        let mut samples = Vec::new();
+
+        let mut day = query.since.date_naive();
+        while day <= until.date_naive() {
+            let path = self.dir.join(query.kind.file_name(day));
+            match File::open(&path) {
+                Ok(file) => {
+                    for line in BufReader::new(file).lines() {
+                        let line = line.map_err(|e| self.io_error(&e))?;
+                        let Ok(sample) = serde_json::from_str::<Sample>(&line) else {
+                            continue;
+                        };
+                        if sample.at < query.since || sample.at > until {
+                            continue;
+                        }
+                        if query.mac.is_some() && sample.mac != query.mac {
+                            continue;
+                        }
+                        samples.push(sample);
+                    }
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(self.io_error(&e)),
+            }
+            let Some(next) = day.succ_opt() else { break };
+            day = next;
+        }
+
+        samples.sort_by_key(|s| s.at);
+        Ok(samples)
+    }
+
+    /// Delete day files older than `retention_days` before `now`.
+    /// Returns how many files were removed.
+    pub fn prune(&self, retention_days: u32, now: DateTime<Utc>) -> Result<usize, CoreError> {
+        if retention_days == 0 {
+            return Ok(0);
+        }
+        let cutoff = now.date_naive() - chrono::Days::new(u64::from(retention_days));
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(self.io_error(&e)),
+        };
+
+        let mut removed = 0;
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let Some(day) = name.to_str().and_then(file_day) else {
+                continue;
+            };
+            if day < cutoff {
+                fs::remove_file(entry.path()).map_err(|e| self.io_error(&e))?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    fn open_day(&self, kind: SampleKind, day: NaiveDate) -> Result<File, CoreError> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.dir.join(kind.file_name(day)))
+            .map_err(|e| self.io_error(&e))
+    }
+
+    fn io_error(&self, err: &std::io::Error) -> CoreError {
+        CoreError::OperationFailed {
+            message: format!("history store {}: {err}", self.dir.display()),
+        }
+    }
+}
+
+/// Day of a `<series>-YYYY-MM-DD.jsonl` file name.
+fn file_day(name: &str) -> Option<NaiveDate> {
+    let stem = name.strip_suffix(".jsonl")?;
+    let (_, date) = stem.split_once('-')?;
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+// ── Recorder task ───────────────────────────────────────────────────
+
+/// Sample the store every `config.interval` until cancelled.
+///
+/// Write failures are logged and the next tick tries again; a full disk
+/// never takes the connection down.
+pub(crate) async fn record_task(
+    controller: Controller,
+    config: HistoryConfig,
+    cancel: CancellationToken,
+) {
+    let store = HistoryStore::new(&config.dir);
+    let mut interval = tokio::time::interval(config.interval);
+    let mut pruned_on: Option<NaiveDate> = None;
+
+    loop {
+        tokio::select! {
+            biased;
+            () = cancel.cancelled() => break,
+            _ = interval.tick() => {
+                let now = Utc::now();
+                let health = controller.site_health().borrow().clone();
+                let devices = controller.devices_snapshot();
+                let clients = controller.clients_snapshot();
+
+                let store = store.clone();
+                let prune = (pruned_on != Some(now.date_naive())).then_some(config.retention_days);
+                let result = tokio::task::spawn_blocking(move || {
+                    if let Some(days) = prune {
+                        store.prune(days, now)?;
+                    }
+                    let wan: Vec<_> = Sample::wan(now, &health).into_iter().collect();
+                    store.append(SampleKind::Wan, &wan)?;
+                    let devices: Vec<_> = devices.iter().map(|d| Sample::device(now, d)).collect();
+                    store.append(SampleKind::Device, &devices)?;
+                    let clients: Vec<_> = clients.iter().filter_map(|c| Sample::client(now, c)).collect();
+                    store.append(SampleKind::Client, &clients)
+                })
+                .await;
+
+                match result {
+                    Ok(Ok(())) => {
+                        if prune.is_some() {
+                            pruned_on = Some(now.date_naive());
+                        }
+                        debug!("history sample recorded");
+                    }
+                    Ok(Err(e)) => warn!(error = %e, "history: write failed"),
+                    Err(e) => warn!(error = %e, "history: recorder panicked"),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        s.parse().expect("valid timestamp")
+    }
+
+    fn wan(ts: &str, tx: u64) -> Sample {
+        Sample {
+            at: at(ts),
+            tx_bytes_per_sec: Some(tx),
+            ..Sample::default()
+        }
+    }
+
+    #[test]
+    fn query_spans_day_files_and_filters_by_time() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let store = HistoryStore::new(dir.path());
+        store
+            .append(
+                SampleKind::Wan,
+                &[
+                    wan("2026-03-01T23:59:50Z", 1),
+                    wan("2026-03-02T00:00:00Z", 2),
+                    wan("2026-03-02T00:00:10Z", 3),
+                ],
+            )
+            .expect("append");
+
+        assert!(dir.path().join("wan-2026-03-01.jsonl").exists());
+        assert!(dir.path().join("wan-2026-03-02.jsonl").exists());
+
+        let samples = store
+            .query(&HistoryQuery {
+                kind: SampleKind::Wan,
+                mac: None,
+                since: at("2026-03-01T23:59:55Z"),
+                until: Some(at("2026-03-02T00:00:05Z")),
+            })
+            .expect("query");
+        assert_eq!(samples, vec![wan("2026-03-02T00:00:00Z", 2)]);
+    }
+
+    #[test]
+    fn query_filters_by_mac_and_skips_torn_lines() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let store = HistoryStore::new(dir.path());
+        let sample = |mac: &str| Sample {
+            at: at("2026-03-01T12:00:00Z"),
+            mac: Some(MacAddress::new(mac)),
+            cpu_pct: Some(5.0),
+            ..Sample::default()
+        };
+        store
+            .append(
+                SampleKind::Device,
+                &[sample("aa:aa:aa:aa:aa:01"), sample("aa:aa:aa:aa:aa:02")],
+            )
+            .expect("append");
+        let path = dir.path().join("device-2026-03-01.jsonl");
+        let mut file = OpenOptions::new().append(true).open(path).expect("open");
+        file.write_all(b"{\"at\":\"2026-03-01T12:").expect("write");
+
+        let samples = store
+            .query(&HistoryQuery {
+                kind: SampleKind::Device,
+                mac: Some(MacAddress::new("AA:AA:AA:AA:AA:02")),
+                since: at("2026-03-01T00:00:00Z"),
+                until: Some(at("2026-03-02T00:00:00Z")),
+            })
+            .expect("query");
+        assert_eq!(samples, vec![sample("aa:aa:aa:aa:aa:02")]);
+    }
+
+    #[test]
+    fn prune_removes_days_past_retention() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let store = HistoryStore::new(dir.path());
+        store
+            .append(
+                SampleKind::Wan,
+                &[
+                    wan("2026-03-01T12:00:00Z", 1),
+                    wan("2026-03-05T12:00:00Z", 1),
+                ],
+            )
+            .expect("append");
+        fs::write(dir.path().join("notes.txt"), "keep").expect("write");
+
+        let removed = store.prune(3, at("2026-03-06T00:00:00Z")).expect("prune");
+
+        assert_eq!(removed, 1);
+        assert!(!dir.path().join("wan-2026-03-01.jsonl").exists());
+        assert!(dir.path().join("wan-2026-03-05.jsonl").exists());
+        assert!(dir.path().join("notes.txt").exists());
+    }
+
+    #[test]
+    fn wan_sample_reads_health_subsystems() {
+        let summary = |subsystem: &str| HealthSummary {
+            subsystem: subsystem.into(),
+            status: "ok".into(),
+            num_adopted: None,
+            num_sta: Some(4),
+            tx_bytes_r: Some(100),
+            rx_bytes_r: Some(200),
+            latency: Some(12.0),
+            wan_ip: None,
+            gateways: None,
+            extra: serde_json::Value::Null,
+        };
+        let now = at("2026-03-01T00:00:00Z");
+
+        let sample = Sample::wan(
+            now,
+            &[
+                summary("wan"),
+                summary("www"),
+                summary("wlan"),
+                summary("lan"),
+            ],
+        )
+        .expect("wan present");
+
+        assert_eq!(sample.rx_bytes_per_sec, Some(200));
+        assert_eq!(sample.latency_ms, Some(12.0));
+        assert_eq!(sample.clients, Some(8));
+        assert!(Sample::wan(now, &[summary("lan")]).is_none());
+    }
+}
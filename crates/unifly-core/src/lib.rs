@@ -23,20 +23,57 @@
 //! - **Domain model** ([`model`]) — Canonical types (`Device`, `Client`,
 //!   `Network`, `FirewallPolicy`, `Event`, etc.) with [`EntityId`] supporting
 //!   both UUID (Integration API) and string-based (Legacy API) identifiers.
+//!
+//! - **[`alerts`]** — Rule matching over events, alarms and device state
+//!   transitions, with cooldowns, hourly caps and webhook / Slack / shell
+//!   command notifiers.
+//!
+//! - **[`cloud`]** — Site Manager (api.ui.com) listings of hosts, sites and
+//!   devices, and configs that reach a host through the cloud connector.
+//!
+//! - **[`firmware`]** — Per-device firmware status and staged rollouts that
+//!   upgrade in batches and wait for each batch to come back online.
+//!
+//! - **[`fleet`]** — Concurrent health and device summaries across many
+//!   controllers, one `oneshot` session each.
+//!
+//! - **[`history`]** — Opt-in recorder that appends live WAN, device and
+//!   client samples to day files on disk, with retention and a query API.
+//!
+//! - **[`metrics`]** — Prometheus / OpenMetrics text rendered from a live
+//!   controller's devices, ports, clients, WAN health and connection state.
+//!
+//! - **[`settings`]** — Typed views of the common site settings sections
+//!   (NTP, SNMP, syslog, management, guest access, ...) and validation
+//!   for `section.key=value` changes.
+//!
+//! - **[`reconcile`]** — Declarative desired-state documents, plan/diff
+//!   against the `DataStore`, and dependency-ordered apply.
 
+pub mod alerts;
+pub mod cloud;
 pub mod command;
 pub mod config;
 pub mod controller;
 pub mod convert;
 pub mod error;
+pub mod firmware;
+pub mod fleet;
+pub mod history;
+pub mod metrics;
 pub mod model;
+pub mod reconcile;
+pub mod settings;
 pub mod store;
 pub mod stream;
 
 // ── Primary re-exports ──────────────────────────────────────────────
 pub use command::requests::*;
 pub use command::{Command, CommandResult};
-pub use config::{AuthCredentials, ControllerConfig, TlsVerification};
+pub use config::{
+    ALL_SITES, AuthCredentials, CachedSession, ControllerConfig, RetryConfig, SessionCache,
+    TlsVerification, TwoFactor,
+};
 pub use controller::{ConnectionState, Controller};
 pub use error::CoreError;
 pub use store::DataStore;
@@ -52,6 +89,7 @@ pub use model::{
     Alarm,
     // Core entities
     Client,
+    ClientSession,
     ClientType,
     Country,
     Device,
@@ -66,14 +104,23 @@ pub use model::{
     FirewallPolicy,
     FirewallZone,
     HealthSummary,
+    KnownClient,
     MacAddress,
+    NeighborAp,
     Network,
+    PortForward,
+    PortProfile,
     RadiusProfile,
     Site,
+    SpectrumScan,
+    StaticRoute,
     SysInfo,
     SystemInfo,
+    ThreatEvent,
+    ThreatSource,
     // Supporting types
     TrafficMatchingList,
+    UserGroup,
     VpnServer,
     VpnTunnel,
     WanInterface,
@@ -0,0 +1,629 @@
+// ── Metrics exposition ──
+//
+// Maps the live `DataStore` onto Prometheus / OpenMetrics text. A
+// `MetricsSnapshot` is captured from a connected `Controller` on every
+// scrape and rendered in one pass; nothing is cached between scrapes.
+
+use std::fmt::{Display, Write as _};
+use std::sync::Arc;
+
+use crate::controller::{ConnectionState, Controller};
+use crate::model::{Client, ClientType, Device, DeviceType, HealthSummary, PortState};
+
+/// Text format of the exposition, negotiated from the `Accept` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpositionFormat {
+    /// Prometheus text format 0.0.4.
+    Prometheus,
+    /// OpenMetrics 1.0.0 text format.
+    OpenMetrics,
+}
+
+impl ExpositionFormat {
+    /// Pick OpenMetrics when the scraper asks for it, plain text otherwise.
+    pub fn from_accept(accept: &str) -> Self {
+        if accept.contains("application/openmetrics-text") {
+            Self::OpenMetrics
+        } else {
+            Self::Prometheus
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Prometheus => "text/plain; version=0.0.4; charset=utf-8",
+            Self::OpenMetrics => "application/openmetrics-text; version=1.0.0; charset=utf-8",
+        }
+    }
+}
+
+/// Reads one optional gauge value off a device.
+type DeviceGauge = fn(&Device) -> Option<f64>;
+
+/// Everything one scrape reports, taken from the controller at once.
+#[derive(Debug, Clone)]
+pub struct MetricsSnapshot {
+    pub state: ConnectionState,
+    pub devices: Arc<Vec<Arc<Device>>>,
+    pub clients: Arc<Vec<Arc<Client>>>,
+    pub health: Arc<Vec<HealthSummary>>,
+    /// Month-to-date WAN (tx, rx) bytes.
+    pub monthly_wan_bytes: (u64, u64),
+}
+
+impl MetricsSnapshot {
+    pub fn capture(controller: &Controller) -> Self {
+        Self {
+            state: controller.connection_state().borrow().clone(),
+            devices: controller.devices_snapshot(),
+            clients: controller.clients_snapshot(),
+            health: controller.site_health().borrow().clone(),
+            monthly_wan_bytes: *controller.monthly_wan_bytes().borrow(),
+        }
+    }
+
+    /// Render the snapshot as exposition text.
+    pub fn render(&self, format: ExpositionFormat) -> String {
+        let mut out = Exposition::new(format);
+        self.render_controller(&mut out);
+        self.render_devices(&mut out);
+        self.render_ports(&mut out);
+        self.render_clients(&mut out);
+        self.render_wan(&mut out);
+        out.finish()
+    }
+
+    fn render_controller(&self, out: &mut Exposition) {
+        out.family(
+            "unifly_up",
+            Kind::Gauge,
+            "Whether the controller session is connected.",
+        );
+        out.sample(&[], u8::from(self.state == ConnectionState::Connected));
+
+        out.family(
+            "unifly_connection_state",
+            Kind::Gauge,
+            "Controller connection state (1 for the current state).",
+        );
+        let current = connection_state_label(&self.state);
+        for state in [
+            "disconnected",
+            "connecting",
+            "connected",
+            "reconnecting",
+            "failed",
+        ] {
+            out.sample(&[("state", state)], u8::from(state == current));
+        }
+    }
+
+    fn render_devices(&self, out: &mut Exposition) {
+        out.family(
+            "unifly_device_up",
+            Kind::Gauge,
+            "Whether the device is online.",
+        );
+        for d in self.devices.iter() {
+            out.sample(&device_labels(d), u8::from(d.state.is_online()));
+        }
+
+        let gauges: [(&str, &str, DeviceGauge); 3] = [
+            (
+                "unifly_device_cpu_percent",
+                "Device CPU utilization in percent.",
+                |d| d.stats.cpu_utilization_pct,
+            ),
+            (
+                "unifly_device_memory_percent",
+                "Device memory utilization in percent.",
+                |d| d.stats.memory_utilization_pct,
+            ),
+            (
+                "unifly_device_load1",
+                "Device 1-minute load average.",
+                |d| d.stats.load_average_1m,
+            ),
+        ];
+        for (name, help, value) in gauges {
+            out.family(name, Kind::Gauge, help);
+            for d in self.devices.iter() {
+                if let Some(v) = value(d) {
+                    out.sample(&device_labels(d), v);
+                }
+            }
+        }
+
+        out.family(
+            "unifly_device_uptime_seconds",
+            Kind::Gauge,
+            "Device uptime in seconds.",
+        );
+        for d in self.devices.iter() {
+            if let Some(uptime) = d.stats.uptime_secs {
+                out.sample(&device_labels(d), uptime);
+            }
+        }
+
+        out.family(
+            "unifly_device_clients",
+            Kind::Gauge,
+            "Clients connected to the device.",
+        );
+        for d in self.devices.iter() {
+            if let Some(count) = d.client_count {
+                out.sample(&device_labels(d), count);
+            }
+        }
+
+        out.family(
+            "unifly_device_uplink_tx_bytes_per_second",
+            Kind::Gauge,
+            "Device uplink transmit rate.",
+        );
+        for d in self.devices.iter() {
+            if let Some(bw) = &d.stats.uplink_bandwidth {
+                out.sample(&device_labels(d), bw.tx_bytes_per_sec);
+            }
+        }
+
+        out.family(
+            "unifly_device_uplink_rx_bytes_per_second",
+            Kind::Gauge,
+            "Device uplink receive rate.",
+        );
+        for d in self.devices.iter() {
+            if let Some(bw) = &d.stats.uplink_bandwidth {
+                out.sample(&device_labels(d), bw.rx_bytes_per_sec);
+            }
+        }
+    }
+
+    fn render_ports(&self, out: &mut Exposition) {
+        let ports = || {
+            self.devices.iter().flat_map(|d| {
+                d.ports.iter().map(move |p| {
+                    let mac = d.mac.to_string();
+                    let device = d.name.clone().unwrap_or_else(|| mac.clone());
+                    let port = p.index.to_string();
+                    let name = p.name.clone().unwrap_or_default();
+                    ([mac, device, port, name], p)
+                })
+            })
+        };
+
+        out.family("unifly_port_up", Kind::Gauge, "Whether the port has link.");
+        for (l, p) in ports() {
+            out.sample(&port_labels(&l), u8::from(p.state == PortState::Up));
+        }
+
+        out.family(
+            "unifly_port_speed_mbps",
+            Kind::Gauge,
+            "Negotiated port link speed in Mbit/s.",
+        );
+        for (l, p) in ports() {
+            if let Some(speed) = p.speed_mbps {
+                out.sample(&port_labels(&l), speed);
+            }
+        }
+
+        out.family(
+            "unifly_port_poe_enabled",
+            Kind::Gauge,
+            "Whether PoE output is enabled on the port.",
+        );
+        for (l, p) in ports() {
+            if let Some(poe) = &p.poe {
+                out.sample(&port_labels(&l), u8::from(poe.enabled));
+            }
+        }
+
+        out.family(
+            "unifly_port_poe_active",
+            Kind::Gauge,
+            "Whether the port is delivering PoE power.",
+        );
+        for (l, p) in ports() {
+            if let Some(poe) = &p.poe {
+                out.sample(&port_labels(&l), u8::from(poe.state == PortState::Up));
+            }
+        }
+    }
+
+    fn render_clients(&self, out: &mut Exposition) {
+        out.family(
+            "unifly_client_tx_bytes_per_second",
+            Kind::Gauge,
+            "Client transmit rate.",
+        );
+        for c in self.clients.iter() {
+            if let Some(bw) = &c.bandwidth {
+                out.sample(&client_labels(c), bw.tx_bytes_per_sec);
+            }
+        }
+
+        out.family(
+            "unifly_client_rx_bytes_per_second",
+            Kind::Gauge,
+            "Client receive rate.",
+        );
+        for c in self.clients.iter() {
+            if let Some(bw) = &c.bandwidth {
+                out.sample(&client_labels(c), bw.rx_bytes_per_sec);
+            }
+        }
+
+        out.family(
+            "unifly_client_tx_bytes",
+            Kind::Counter,
+            "Bytes sent by the client this session.",
+        );
+        for c in self.clients.iter() {
+            if let Some(bytes) = c.tx_bytes {
+                out.sample(&client_labels(c), bytes);
+            }
+        }
+
+        out.family(
+            "unifly_client_rx_bytes",
+            Kind::Counter,
+            "Bytes received by the client this session.",
+        );
+        for c in self.clients.iter() {
+            if let Some(bytes) = c.rx_bytes {
+                out.sample(&client_labels(c), bytes);
+            }
+        }
+
+        out.family(
+            "unifly_client_signal_dbm",
+            Kind::Gauge,
+            "Wireless client signal strength in dBm.",
+        );
+        for c in self.clients.iter() {
+            if let Some(signal) = c.wireless.as_ref().and_then(|w| w.signal_dbm) {
+                out.sample(&client_labels(c), signal);
+            }
+        }
+
+        out.family(
+            "unifly_client_satisfaction_percent",
+            Kind::Gauge,
+            "Wireless client experience score.",
+        );
+        for c in self.clients.iter() {
+            if let Some(score) = c.wireless.as_ref().and_then(|w| w.satisfaction) {
+                out.sample(&client_labels(c), score);
+            }
+        }
+    }
+
+    fn render_wan(&self, out: &mut Exposition) {
+        let wan = self.health.iter().find(|h| h.subsystem == "wan");
+        let www = self.health.iter().find(|h| h.subsystem == "www");
+
+        out.family(
+            "unifly_subsystem_up",
+            Kind::Gauge,
+            "Whether the site subsystem reports status ok.",
+        );
+        for h in self.health.iter() {
+            out.sample(
+                &[("subsystem", h.subsystem.as_str())],
+                u8::from(h.status == "ok"),
+            );
+        }
+
+        out.family(
+            "unifly_wan_tx_bytes_per_second",
+            Kind::Gauge,
+            "WAN transmit rate.",
+        );
+        if let Some(rate) = wan.and_then(|h| h.tx_bytes_r) {
+            out.sample(&[], rate);
+        }
+
+        out.family(
+            "unifly_wan_rx_bytes_per_second",
+            Kind::Gauge,
+            "WAN receive rate.",
+        );
+        if let Some(rate) = wan.and_then(|h| h.rx_bytes_r) {
+            out.sample(&[], rate);
+        }
+
+        out.family(
+            "unifly_wan_latency_milliseconds",
+            Kind::Gauge,
+            "Internet latency measured by the gateway.",
+        );
+        if let Some(latency) = www.and_then(|h| h.latency) {
+            out.sample(&[], latency);
+        }
+
+        let (tx, rx) = self.monthly_wan_bytes;
+        out.family(
+            "unifly_wan_month_tx_bytes",
+            Kind::Gauge,
+            "WAN bytes sent this month.",
+        );
+        out.sample(&[], tx);
+        out.family(
+            "unifly_wan_month_rx_bytes",
+            Kind::Gauge,
+            "WAN bytes received this month.",
+        );
+        out.sample(&[], rx);
+    }
+}
+
+fn connection_state_label(state: &ConnectionState) -> &'static str {
+    match state {
+        ConnectionState::Disconnected => "disconnected",
+        ConnectionState::Connecting => "connecting",
+        ConnectionState::Connected => "connected",
+        ConnectionState::Reconnecting { .. } => "reconnecting",
+        ConnectionState::Failed => "failed",
+    }
+}
+
+fn device_type_label(device_type: DeviceType) -> &'static str {
+    match device_type {
+        DeviceType::Gateway => "gateway",
+        DeviceType::Switch => "switch",
+        DeviceType::AccessPoint => "access_point",
+        DeviceType::Other => "other",
+    }
+}
+
+fn client_type_label(client_type: ClientType) -> &'static str {
+    match client_type {
+        ClientType::Wired => "wired",
+        ClientType::Wireless => "wireless",
+        ClientType::Vpn => "vpn",
+        ClientType::Teleport => "teleport",
+        ClientType::Unknown => "unknown",
+    }
+}
+
+fn device_labels(d: &Device) -> [(&'static str, &str); 4] {
+    [
+        ("mac", d.mac.as_str()),
+        ("name", d.name.as_deref().unwrap_or_else(|| d.mac.as_str())),
+        ("model", d.model.as_deref().unwrap_or_default()),
+        ("type", device_type_label(d.device_type)),
+    ]
+}
+
+fn port_labels(l: &[String; 4]) -> [(&'static str, &str); 4] {
+    [
+        ("device_mac", l[0].as_str()),
+        ("device", l[1].as_str()),
+        ("port", l[2].as_str()),
+        ("name", l[3].as_str()),
+    ]
+}
+
+fn client_labels(c: &Client) -> [(&'static str, &str); 3] {
+    let name = c
+        .name
+        .as_deref()
+        .or(c.hostname.as_deref())
+        .unwrap_or_else(|| c.mac.as_str());
+    [
+        ("mac", c.mac.as_str()),
+        ("name", name),
+        ("type", client_type_label(c.client_type)),
+    ]
+}
+
+// ── Text writer ─────────────────────────────────────────────────────
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Gauge,
+    Counter,
+}
+
+struct Exposition {
+    format: ExpositionFormat,
+    out: String,
+    /// Sample name of the current family (`_total` added for counters).
+    sample_name: String,
+}
+
+impl Exposition {
+    fn new(format: ExpositionFormat) -> Self {
+        Self {
+            format,
+            out: String::new(),
+            sample_name: String::new(),
+        }
+    }
+
+    /// Start a metric family with its `HELP` and `TYPE` lines.
+    ///
+    /// Prometheus 0.0.4 names counter families with their `_total`
+    /// suffix; OpenMetrics names the family without it.
+    fn family(&mut self, name: &str, kind: Kind, help: &str) {
+        let (family, kind_name) = match (kind, self.format) {
+            (Kind::Gauge, _) => (name.to_owned(), "gauge"),
+            (Kind::Counter, ExpositionFormat::Prometheus) => (format!("{name}_total"), "counter"),
+            (Kind::Counter, ExpositionFormat::OpenMetrics) => (name.to_owned(), "counter"),
+        };
+        let _ = writeln!(self.out, "# HELP {family} {help}");
+        let _ = writeln!(self.out, "# TYPE {family} {kind_name}");
+        self.sample_name = match kind {
+            Kind::Gauge => name.to_owned(),
+            Kind::Counter => format!("{name}_total"),
+        };
+    }
+
+    fn sample(&mut self, labels: &[(&str, &str)], value: impl Display) {
+        self.out.push_str(&self.sample_name);
+        if !labels.is_empty() {
+            self.out.push('{');
+            for (i, (key, val)) in labels.iter().enumerate() {
+                if i > 0 {
+                    self.out.push(',');
+                }
+                let _ = write!(self.out, "{key}=\"{}\"", escape_label(val));
+            }
+            self.out.push('}');
+        }
+        let _ = writeln!(self.out, " {value}");
+    }
+
+    fn finish(mut self) -> String {
+        if self.format == ExpositionFormat::OpenMetrics {
+            self.out.push_str("# EOF\n");
+        }
+        self.out
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device() -> Device {
+        serde_json::from_value(serde_json::json!({
+            "id": "dev1",
+            "mac": "aa:bb:cc:00:00:01",
+            "ip": null,
+            "wan_ipv6": null,
+            "name": "Core \"Switch\"",
+            "model": "USW-24-PoE",
+            "device_type": "Switch",
+            "state": "Online",
+            "firmware_version": null,
+            "firmware_updatable": false,
+            "adopted_at": null,
+            "provisioned_at": null,
+            "last_seen": null,
+            "serial": null,
+            "supported": true,
+            "ports": [{
+                "index": 1,
+                "name": "Uplink",
+                "state": "Up",
+                "speed_mbps": 1000,
+                "max_speed_mbps": null,
+                "connector": null,
+                "poe": { "standard": null, "enabled": true, "state": "Down", "mode": null },
+                "enabled": true,
+                "profile_id": null,
+                "native_network_id": null
+            }],
+            "radios": [],
+            "uplink_device_id": null,
+            "uplink_device_mac": null,
+            "has_switching": true,
+            "has_access_point": false,
+            "stats": {
+                "uptime_secs": 3600,
+                "cpu_utilization_pct": 12.5,
+                "memory_utilization_pct": 40.0,
+                "load_average_1m": null,
+                "load_average_5m": null,
+                "load_average_15m": null,
+                "uplink_bandwidth": null,
+                "last_heartbeat": null,
+                "next_heartbeat": null
+            },
+            "client_count": 7,
+            "site_id": null,
+            "origin": null
+        }))
+        .expect("valid device fixture")
+    }
+
+    fn snapshot() -> MetricsSnapshot {
+        MetricsSnapshot {
+            state: ConnectionState::Connected,
+            devices: Arc::new(vec![Arc::new(device())]),
+            clients: Arc::new(Vec::new()),
+            health: Arc::new(vec![HealthSummary {
+                subsystem: "wan".into(),
+                status: "ok".into(),
+                num_adopted: None,
+                num_sta: None,
+                tx_bytes_r: Some(1200),
+                rx_bytes_r: Some(3400),
+                latency: None,
+                wan_ip: None,
+                gateways: None,
+                extra: serde_json::Value::Null,
+            }]),
+            monthly_wan_bytes: (10, 20),
+        }
+    }
+
+    #[test]
+    fn renders_device_port_and_wan_samples() {
+        let text = snapshot().render(ExpositionFormat::Prometheus);
+
+        assert!(text.contains("unifly_up 1\n"));
+        assert!(text.contains("unifly_connection_state{state=\"connected\"} 1\n"));
+        assert!(text.contains("unifly_connection_state{state=\"failed\"} 0\n"));
+        assert!(text.contains(
+            "unifly_device_cpu_percent{mac=\"aa:bb:cc:00:00:01\",name=\"Core \\\"Switch\\\"\",model=\"USW-24-PoE\",type=\"switch\"} 12.5\n"
+        ));
+        assert!(text.contains("unifly_device_uptime_seconds{"));
+        assert!(text.contains("port=\"1\",name=\"Uplink\"} 1000\n"));
+        assert!(text.contains("unifly_port_poe_active{"));
+        assert!(text.contains("unifly_wan_rx_bytes_per_second 3400\n"));
+        assert!(text.contains("unifly_wan_month_tx_bytes 10\n"));
+        assert!(!text.contains("unifly_device_load1{"));
+        assert!(!text.contains("# EOF"));
+    }
+
+    #[test]
+    fn counters_follow_the_format() {
+        let mut prom = Exposition::new(ExpositionFormat::Prometheus);
+        prom.family("x_bytes", Kind::Counter, "help");
+        prom.sample(&[], 1);
+        let prom = prom.finish();
+        assert!(prom.contains("# TYPE x_bytes_total counter\n"));
+        assert!(prom.contains("x_bytes_total 1\n"));
+
+        let mut om = Exposition::new(ExpositionFormat::OpenMetrics);
+        om.family("x_bytes", Kind::Counter, "help");
+        om.sample(&[], 1);
+        let om = om.finish();
+        assert!(om.contains("# TYPE x_bytes counter\n"));
+        assert!(om.contains("x_bytes_total 1\n"));
+        assert!(om.ends_with("# EOF\n"));
+    }
+
+    #[test]
+    fn accept_header_selects_format() {
+        assert_eq!(
+            ExpositionFormat::from_accept("application/openmetrics-text; version=1.0.0"),
+            ExpositionFormat::OpenMetrics
+        );
+        assert_eq!(
+            ExpositionFormat::from_accept("text/plain"),
+            ExpositionFormat::Prometheus
+        );
+    }
+
+    #[test]
+    fn label_values_are_escaped() {
+        assert_eq!(escape_label("a\\b\"c\nd"), "a\\\\b\\\"c\\nd");
+    }
+}
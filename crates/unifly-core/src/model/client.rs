@@ -2,7 +2,7 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::net::IpAddr;
+use std::net::{IpAddr, Ipv4Addr};
 
 use super::common::{Bandwidth, DataSource};
 use super::entity_id::{EntityId, MacAddress};
@@ -86,6 +86,20 @@ pub struct Client {
     // Blocking state (legacy API)
     pub blocked: bool,
 
+    // User-set fields (legacy API)
+    /// Active DHCP reservation, if any.
+    pub fixed_ip: Option<Ipv4Addr>,
+    pub note: Option<String>,
+    /// User group (bandwidth profile) the client is assigned to; `None`
+    /// is the default group.
+    pub user_group_id: Option<EntityId>,
+    /// Name of `user_group_id`. Not part of refreshed data; filled in by
+    /// callers that have looked the group up.
+    pub user_group: Option<String>,
+
+    // Owning site (always set when the controller loads all sites)
+    pub site_id: Option<EntityId>,
+
     #[serde(skip)]
     #[allow(dead_code)]
     pub(crate) source: DataSource,
@@ -93,3 +107,44 @@ pub struct Client {
     #[allow(dead_code)]
     pub(crate) updated_at: DateTime<Utc>,
 }
+
+/// A client the controller has seen, connected now or not
+/// (from Legacy `stat/alluser`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnownClient {
+    pub id: EntityId,
+    pub mac: MacAddress,
+    pub name: Option<String>,
+    pub hostname: Option<String>,
+    pub oui: Option<String>,
+    pub client_type: ClientType,
+    pub is_guest: bool,
+    pub blocked: bool,
+    /// Active DHCP reservation, if any.
+    pub fixed_ip: Option<Ipv4Addr>,
+    pub note: Option<String>,
+    pub user_group_id: Option<EntityId>,
+    pub first_seen: Option<DateTime<Utc>>,
+    pub last_seen: Option<DateTime<Utc>>,
+}
+
+/// One connection of a client to the network (from Legacy `stat/session`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientSession {
+    pub mac: MacAddress,
+    pub client_type: ClientType,
+    pub connected_at: DateTime<Utc>,
+    /// `None` while the session is still open.
+    pub disconnected_at: Option<DateTime<Utc>>,
+    pub duration_secs: Option<u64>,
+    pub ip: Option<IpAddr>,
+    pub hostname: Option<String>,
+    /// AP the client associated with (wireless).
+    pub ap_mac: Option<MacAddress>,
+    pub ssid: Option<String>,
+    /// Switch and port the client was plugged into (wired).
+    pub switch_mac: Option<MacAddress>,
+    pub switch_port: Option<u16>,
+    pub tx_bytes: Option<u64>,
+    pub rx_bytes: Option<u64>,
+}
@@ -56,6 +56,12 @@ pub struct Port {
     pub max_speed_mbps: Option<u32>,
     pub connector: Option<PortConnector>,
     pub poe: Option<PoeInfo>,
+    /// `false` when the port is administratively disabled.
+    pub enabled: bool,
+    /// Assigned switch port profile (`rest/portconf` id).
+    pub profile_id: Option<EntityId>,
+    /// Native (untagged) network override (`rest/networkconf` id).
+    pub native_network_id: Option<EntityId>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -79,6 +85,45 @@ pub struct PoeInfo {
     pub standard: Option<String>,
     pub enabled: bool,
     pub state: PortState,
+    pub mode: Option<PoeMode>,
+}
+
+/// PoE output mode of a switch port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PoeMode {
+    Auto,
+    Off,
+    /// Passive 24V.
+    Passive24V,
+    Passthrough,
+}
+
+impl PoeMode {
+    /// Value used by the legacy API's `poe_mode` field.
+    pub fn as_legacy_str(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Off => "off",
+            Self::Passive24V => "pasv24",
+            Self::Passthrough => "passthrough",
+        }
+    }
+
+    pub fn from_legacy_str(raw: &str) -> Option<Self> {
+        match raw {
+            "auto" => Some(Self::Auto),
+            "off" => Some(Self::Off),
+            "pasv24" => Some(Self::Passive24V),
+            "passthrough" => Some(Self::Passthrough),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for PoeMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_legacy_str())
+    }
 }
 
 /// Radio on an access point.
@@ -89,6 +134,121 @@ pub struct Radio {
     pub channel_width_mhz: Option<u32>,
     pub wlan_standard: Option<String>,
     pub tx_retries_pct: Option<f64>,
+    /// Configured transmit power.
+    pub tx_power: Option<TxPower>,
+    /// Transmit power currently in use, in dBm.
+    pub tx_power_dbm: Option<i32>,
+    /// Clients below this signal (dBm) are disconnected; `None` when off.
+    pub min_rssi_dbm: Option<i32>,
+}
+
+/// WiFi band of an AP radio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RadioBand {
+    Ghz2_4,
+    Ghz5,
+    Ghz6,
+}
+
+impl RadioBand {
+    /// Value used by the legacy API's `radio` field.
+    pub fn as_legacy_str(self) -> &'static str {
+        match self {
+            Self::Ghz2_4 => "ng",
+            Self::Ghz5 => "na",
+            Self::Ghz6 => "6e",
+        }
+    }
+
+    pub fn from_legacy_str(raw: &str) -> Option<Self> {
+        match raw {
+            "ng" => Some(Self::Ghz2_4),
+            "na" => Some(Self::Ghz5),
+            "6e" => Some(Self::Ghz6),
+            _ => None,
+        }
+    }
+
+    pub fn frequency_ghz(self) -> f32 {
+        match self {
+            Self::Ghz2_4 => 2.4,
+            Self::Ghz5 => 5.0,
+            Self::Ghz6 => 6.0,
+        }
+    }
+
+    /// Band a radio operates in, from its nominal frequency.
+    pub fn from_frequency_ghz(ghz: f32) -> Self {
+        if ghz < 3.0 {
+            Self::Ghz2_4
+        } else if ghz < 5.9 {
+            Self::Ghz5
+        } else {
+            Self::Ghz6
+        }
+    }
+}
+
+impl std::fmt::Display for RadioBand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ghz2_4 => f.write_str("2.4 GHz"),
+            Self::Ghz5 => f.write_str("5 GHz"),
+            Self::Ghz6 => f.write_str("6 GHz"),
+        }
+    }
+}
+
+/// Channel setting for an AP radio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RadioChannel {
+    Auto,
+    Fixed(u32),
+}
+
+/// Transmit power setting for an AP radio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TxPower {
+    Auto,
+    High,
+    Medium,
+    Low,
+    /// Fixed power in dBm.
+    Custom(i32),
+}
+
+impl TxPower {
+    /// Value used by the legacy API's `tx_power_mode` field.
+    pub fn legacy_mode(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::High => "high",
+            Self::Medium => "medium",
+            Self::Low => "low",
+            Self::Custom(_) => "custom",
+        }
+    }
+
+    /// Parse the legacy `tx_power_mode` / `tx_power` pair.
+    pub fn from_legacy(mode: &str, dbm: Option<i32>) -> Option<Self> {
+        match mode {
+            "auto" => Some(Self::Auto),
+            "high" => Some(Self::High),
+            "medium" => Some(Self::Medium),
+            "low" => Some(Self::Low),
+            "custom" => dbm.map(Self::Custom),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TxPower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Custom(dbm) => write!(f, "{dbm} dBm"),
+            other => f.write_str(other.legacy_mode()),
+        }
+    }
 }
 
 /// Real-time device statistics.
@@ -169,6 +329,8 @@ pub struct Device {
     // Firmware
     pub firmware_version: Option<String>,
     pub firmware_updatable: bool,
+    /// Version the controller would upgrade to, when one is available.
+    pub firmware_target: Option<String>,
 
     // Lifecycle
     pub adopted_at: Option<DateTime<Utc>>,
@@ -197,6 +359,9 @@ pub struct Device {
     // Client count (if known)
     pub client_count: Option<u32>,
 
+    // Owning site (always set when the controller loads all sites)
+    pub site_id: Option<EntityId>,
+
     // Metadata
     pub origin: Option<EntityOrigin>,
 
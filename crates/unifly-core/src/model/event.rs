@@ -1,4 +1,7 @@
-// ── Event and alarm domain types ──
+// ── Event, alarm and threat domain types ──
+
+use std::collections::HashMap;
+use std::net::IpAddr;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -63,4 +66,128 @@ pub struct Alarm {
 
     pub device_mac: Option<MacAddress>,
     pub site_id: Option<EntityId>,
+
+    /// Alarm key, e.g. `EVT_AP_Lost_Contact`.
+    pub raw_key: Option<String>,
+}
+
+/// Threat management (IDS/IPS) detection on the gateway
+/// (from Legacy `stat/ips/event`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreatEvent {
+    pub id: EntityId,
+    pub timestamp: DateTime<Utc>,
+    pub signature: Option<String>,
+    pub signature_id: Option<u64>,
+    /// Signature category, e.g. `Attempted Information Leak`.
+    pub category: Option<String>,
+    /// The gateway dropped the traffic (IPS) rather than only alerting (IDS).
+    pub blocked: bool,
+    /// 1 (high) to 3 (low).
+    pub severity: Option<u8>,
+    pub protocol: Option<String>,
+    pub src_ip: Option<IpAddr>,
+    pub src_port: Option<u16>,
+    pub src_country: Option<String>,
+    pub src_mac: Option<MacAddress>,
+    pub dst_ip: Option<IpAddr>,
+    pub dst_port: Option<u16>,
+    pub dst_country: Option<String>,
+}
+
+/// Threat detections grouped by source address.
+#[derive(Debug, Clone, Serialize)]
+pub struct ThreatSource {
+    pub src_ip: IpAddr,
+    pub country: Option<String>,
+    pub events: usize,
+    pub blocked: usize,
+    /// Most frequent category from this source.
+    pub top_category: Option<String>,
+    pub last_seen: DateTime<Utc>,
+}
+
+impl ThreatSource {
+    /// Group `events` by source IP, most active source first. Events
+    /// without a source IP are skipped.
+    pub fn tally(events: &[ThreatEvent]) -> Vec<Self> {
+        let mut by_ip: HashMap<IpAddr, Vec<&ThreatEvent>> = HashMap::new();
+        for event in events {
+            if let Some(ip) = event.src_ip {
+                by_ip.entry(ip).or_default().push(event);
+            }
+        }
+        let mut sources: Vec<Self> = by_ip
+            .into_iter()
+            .map(|(src_ip, events)| {
+                let mut categories: HashMap<&str, usize> = HashMap::new();
+                for c in events.iter().filter_map(|e| e.category.as_deref()) {
+                    *categories.entry(c).or_default() += 1;
+                }
+                let top_category = categories
+                    .into_iter()
+                    .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+                    .map(|(c, _)| c.to_owned());
+                Self {
+                    src_ip,
+                    country: events.iter().find_map(|e| e.src_country.clone()),
+                    events: events.len(),
+                    blocked: events.iter().filter(|e| e.blocked).count(),
+                    top_category,
+                    last_seen: events.iter().map(|e| e.timestamp).max().unwrap_or_default(),
+                }
+            })
+            .collect();
+        sources.sort_by(|a, b| {
+            b.events
+                .cmp(&a.events)
+                .then_with(|| b.last_seen.cmp(&a.last_seen))
+                .then_with(|| a.src_ip.cmp(&b.src_ip))
+        });
+        sources
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn threat(src: &str, category: &str, blocked: bool, secs: i64) -> ThreatEvent {
+        ThreatEvent {
+            id: EntityId::from(format!("{src}-{secs}")),
+            timestamp: DateTime::from_timestamp(secs, 0).unwrap(),
+            signature: None,
+            signature_id: None,
+            category: Some(category.into()),
+            blocked,
+            severity: Some(2),
+            protocol: None,
+            src_ip: Some(src.parse().unwrap()),
+            src_port: None,
+            src_country: None,
+            src_mac: None,
+            dst_ip: None,
+            dst_port: None,
+            dst_country: None,
+        }
+    }
+
+    #[test]
+    fn tally_groups_by_source_busiest_first() {
+        let events = vec![
+            threat("198.51.100.1", "Scan", true, 10),
+            threat("203.0.113.7", "Scan", false, 20),
+            threat("203.0.113.7", "Exploit", true, 30),
+            threat("203.0.113.7", "Exploit", true, 40),
+        ];
+        let sources = ThreatSource::tally(&events);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].src_ip.to_string(), "203.0.113.7");
+        assert_eq!(sources[0].events, 3);
+        assert_eq!(sources[0].blocked, 2);
+        assert_eq!(sources[0].top_category.as_deref(), Some("Exploit"));
+        assert_eq!(sources[0].last_seen.timestamp(), 40);
+        assert_eq!(sources[1].events, 1);
+    }
 }
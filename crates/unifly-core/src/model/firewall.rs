@@ -88,6 +88,8 @@ pub struct AclRule {
     pub enabled: bool,
     pub rule_type: AclRuleType,
     pub action: AclAction,
+    pub source_zone_id: Option<EntityId>,
+    pub destination_zone_id: Option<EntityId>,
     pub source_summary: Option<String>,
     pub destination_summary: Option<String>,
     pub origin: Option<EntityOrigin>,
@@ -7,6 +7,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::net::IpAddr;
 
+use super::device::PoeMode;
 use super::entity_id::{EntityId, MacAddress};
 
 /// Statistical report (from Legacy API `stat/report/*`).
@@ -99,6 +100,70 @@ pub struct Country {
     pub name: String,
 }
 
+/// Switch port profile (from Legacy `rest/portconf`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortProfile {
+    pub id: EntityId,
+    pub name: String,
+    /// VLAN forwarding mode: "all", "native", "customize", "disabled"
+    pub forward: Option<String>,
+    pub native_network_id: Option<EntityId>,
+    pub poe_mode: Option<PoeMode>,
+}
+
+/// Bandwidth profile clients can be assigned to (from Legacy `rest/usergroup`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserGroup {
+    pub id: EntityId,
+    pub name: String,
+    /// Per-client download cap in Kbps; `None` is unlimited.
+    pub download_limit_kbps: Option<u32>,
+    /// Per-client upload cap in Kbps; `None` is unlimited.
+    pub upload_limit_kbps: Option<u32>,
+    /// The built-in group clients fall back to; it cannot be deleted.
+    pub is_default: bool,
+}
+
+/// Foreign access point heard by one of our APs (from Legacy `stat/rogueap`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeighborAp {
+    pub bssid: MacAddress,
+    pub ssid: Option<String>,
+    pub channel: Option<u32>,
+    pub frequency_ghz: Option<f32>,
+    /// Signal above the noise floor, in dB.
+    pub rssi: Option<i32>,
+    pub signal_dbm: Option<i32>,
+    pub security: Option<String>,
+    pub vendor: Option<String>,
+    /// Our AP that heard it.
+    pub detected_by: Option<MacAddress>,
+    /// The controller also saw this BSSID on the wired network.
+    pub is_rogue: bool,
+    pub last_seen: Option<DateTime<Utc>>,
+}
+
+/// Results of an AP's RF spectrum scan (from Legacy `stat/spectrum-scan`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpectrumScan {
+    pub device_mac: MacAddress,
+    /// A scan is still running; `channels` holds the previous results, if any.
+    pub scanning: bool,
+    pub channels: Vec<ChannelSpectrum>,
+}
+
+/// Per-channel utilization and interference measured by a spectrum scan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelSpectrum {
+    pub channel: u32,
+    pub frequency_ghz: Option<f32>,
+    pub width_mhz: Option<u32>,
+    pub utilization_pct: Option<f64>,
+    /// Airtime lost to non-WiFi interference.
+    pub interference_pct: Option<f64>,
+    pub interference_types: Vec<String>,
+}
+
 /// DPI application entry.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DpiApplication {
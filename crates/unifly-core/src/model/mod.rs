@@ -15,6 +15,8 @@ pub mod firewall;
 pub mod hotspot;
 pub mod legacy_resources;
 pub mod network;
+pub mod port_forward;
+pub mod route;
 pub mod site;
 pub mod supporting;
 pub mod wifi;
@@ -33,11 +35,12 @@ pub use site::Site;
 
 // Device
 pub use device::{
-    Device, DeviceState, DeviceStats, DeviceType, PoeInfo, Port, PortConnector, PortState, Radio,
+    Device, DeviceState, DeviceStats, DeviceType, PoeInfo, PoeMode, Port, PortConnector, PortState,
+    Radio, RadioBand, RadioChannel, TxPower,
 };
 
 // Client
-pub use client::{Client, ClientType, GuestAuth, WirelessInfo};
+pub use client::{Client, ClientSession, ClientType, GuestAuth, KnownClient, WirelessInfo};
 
 // Network
 pub use network::{DhcpConfig, Ipv6Mode, Network, NetworkManagement, NetworkPurpose};
@@ -53,11 +56,17 @@ pub use firewall::{
 // DNS
 pub use dns::{DnsPolicy, DnsPolicyType};
 
+// Port forwarding
+pub use port_forward::{PortForward, PortForwardProtocol};
+
+// Routing
+pub use route::{StaticRoute, StaticRouteType};
+
 // Hotspot
 pub use hotspot::Voucher;
 
 // Events
-pub use event::{Alarm, Event, EventCategory, EventSeverity};
+pub use event::{Alarm, Event, EventCategory, EventSeverity, ThreatEvent, ThreatSource};
 
 // Supporting types
 pub use supporting::{
@@ -66,6 +75,7 @@ pub use supporting::{
 
 // Legacy-only resources
 pub use legacy_resources::{
-    Admin, Backup, Country, DeviceStatsSample, DpiApplication, DpiCategory, HealthSummary,
-    SiteStatsSample, StatEntry, StatReport, StatsInterval, SysInfo, SystemInfo,
+    Admin, Backup, ChannelSpectrum, Country, DeviceStatsSample, DpiApplication, DpiCategory,
+    HealthSummary, NeighborAp, PortProfile, SiteStatsSample, SpectrumScan, StatEntry, StatReport,
+    StatsInterval, SysInfo, SystemInfo, UserGroup,
 };
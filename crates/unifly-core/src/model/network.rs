@@ -95,6 +95,7 @@ pub struct Network {
     pub mdns_forwarding_enabled: bool,
     pub cellular_backup_enabled: bool,
 
+    pub site_id: Option<EntityId>,
     pub origin: Option<EntityOrigin>,
 
     #[serde(skip)]
@@ -0,0 +1,59 @@
+// ── Port-forward domain types ──
+
+use serde::{Deserialize, Serialize};
+
+use super::common::DataSource;
+use super::entity_id::EntityId;
+
+/// Transport protocol matched by a port-forward rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PortForwardProtocol {
+    Tcp,
+    Udp,
+    TcpUdp,
+}
+
+impl PortForwardProtocol {
+    /// Wire value used by the Legacy API (`proto` field).
+    pub fn as_legacy_str(self) -> &'static str {
+        match self {
+            Self::Tcp => "tcp",
+            Self::Udp => "udp",
+            Self::TcpUdp => "tcp_udp",
+        }
+    }
+}
+
+impl std::fmt::Display for PortForwardProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tcp => f.write_str("TCP"),
+            Self::Udp => f.write_str("UDP"),
+            Self::TcpUdp => f.write_str("TCP/UDP"),
+        }
+    }
+}
+
+/// Port-forward (destination NAT) rule. Legacy API only (`rest/portforward`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortForward {
+    pub id: EntityId,
+    pub name: String,
+    pub enabled: bool,
+    pub protocol: PortForwardProtocol,
+    /// WAN interface the rule listens on: `wan`, `wan2`, or `both`.
+    pub wan_interface: String,
+    /// Allowed source address or CIDR; `None` means any.
+    pub source_address: Option<String>,
+    /// External port or range (e.g. `443`, `5000-5010`).
+    pub wan_port: String,
+    /// Internal host the traffic is forwarded to.
+    pub forward_ip: String,
+    /// Internal port or range.
+    pub forward_port: String,
+    pub logging: bool,
+
+    #[serde(skip)]
+    #[allow(dead_code)]
+    pub(crate) source: DataSource,
+}
@@ -0,0 +1,59 @@
+// ── Static route domain types ──
+
+use serde::{Deserialize, Serialize};
+
+use super::common::DataSource;
+use super::entity_id::EntityId;
+
+/// How a static route forwards matching traffic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StaticRouteType {
+    /// Forward to a gateway IP.
+    NextHop,
+    /// Send out a specific interface.
+    Interface,
+    /// Silently drop.
+    Blackhole,
+}
+
+impl StaticRouteType {
+    /// Wire value used by the Legacy API (`static-route_type` field).
+    pub fn as_legacy_str(self) -> &'static str {
+        match self {
+            Self::NextHop => "nexthop-route",
+            Self::Interface => "interface-route",
+            Self::Blackhole => "blackhole",
+        }
+    }
+}
+
+impl std::fmt::Display for StaticRouteType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NextHop => f.write_str("Next Hop"),
+            Self::Interface => f.write_str("Interface"),
+            Self::Blackhole => f.write_str("Blackhole"),
+        }
+    }
+}
+
+/// User-defined static route on the gateway. Legacy API only (`rest/routing`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaticRoute {
+    pub id: EntityId,
+    pub name: String,
+    pub enabled: bool,
+    pub route_type: StaticRouteType,
+    /// Destination network in CIDR notation.
+    pub destination: String,
+    /// Gateway IP; set for `NextHop` routes.
+    pub next_hop: Option<String>,
+    /// Egress interface (e.g. `WAN1`); set for `Interface` routes.
+    pub interface: Option<String>,
+    /// Administrative distance.
+    pub distance: Option<u32>,
+
+    #[serde(skip)]
+    #[allow(dead_code)]
+    pub(crate) source: DataSource,
+}
@@ -49,6 +49,7 @@ pub struct WifiBroadcast {
     // Hotspot
     pub hotspot_enabled: bool,
 
+    pub site_id: Option<EntityId>,
     pub origin: Option<EntityOrigin>,
 
     #[serde(skip)]
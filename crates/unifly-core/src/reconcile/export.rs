@@ -0,0 +1,324 @@
+// ── Site export ──
+//
+// Builds a portable `DesiredState` from the current `DataStore`
+// contents. Cross-resource references are rewritten from entity IDs to
+// names, so the document can be applied to a different site (or
+// controller), where `plan()` maps the names back to that site's IDs.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::plan::voucher_key;
+use super::spec::{
+    AclRuleSpec, DesiredState, DhcpSpec, DnsPolicySpec, FirewallPolicySpec, FirewallZoneSpec,
+    NetworkSpec, TrafficMatchingListSpec, VoucherSpec, WifiBroadcastSpec,
+};
+use crate::model::{EntityId, EntityOrigin, WifiSecurityMode};
+use crate::store::DataStore;
+
+/// A site snapshot plus notes about anything that could not be captured.
+#[derive(Debug, Clone, Default)]
+pub struct Export {
+    pub state: DesiredState,
+    pub warnings: Vec<String>,
+}
+
+/// Export the logical configuration of the store's site.
+///
+/// Only user-defined resources are exported; system-defined ones exist
+/// on every site already. System firewall zones are included when they
+/// contain exported networks, so zone membership survives the round trip.
+/// Secrets (WiFi passphrases) are never available to read back and are
+/// reported as warnings.
+pub fn export(store: &DataStore) -> Export {
+    let mut exporter = Exporter::new(store);
+    let state = DesiredState {
+        prune: false,
+        traffic_matching_lists: Some(traffic_matching_lists(store)),
+        networks: Some(networks(store)),
+        firewall_zones: Some(exporter.firewall_zones(store)),
+        wifi_broadcasts: Some(exporter.wifi_broadcasts(store)),
+        firewall_policies: Some(exporter.firewall_policies(store)),
+        acl_rules: Some(exporter.acl_rules(store)),
+        dns_policies: Some(dns_policies(store)),
+        vouchers: Some(vouchers(store)),
+    };
+    Export {
+        state,
+        warnings: exporter.warnings,
+    }
+}
+
+fn user_defined(origin: Option<EntityOrigin>) -> bool {
+    matches!(origin, None | Some(EntityOrigin::UserDefined))
+}
+
+struct Exporter {
+    network_names: HashMap<EntityId, String>,
+    zone_names: HashMap<EntityId, String>,
+    warnings: Vec<String>,
+}
+
+impl Exporter {
+    fn new(store: &DataStore) -> Self {
+        let network_names = store
+            .networks_snapshot()
+            .iter()
+            .map(|n| (n.id.clone(), n.name.clone()))
+            .collect();
+        let zone_names = store
+            .firewall_zones_snapshot()
+            .iter()
+            .map(|z| (z.id.clone(), z.name.clone()))
+            .collect();
+        Self {
+            network_names,
+            zone_names,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Resolve a zone reference, warning and returning `None` when unknown.
+    fn zone_name(&mut self, id: Option<&EntityId>, owner: &str) -> Option<String> {
+        let name = id.and_then(|id| self.zone_names.get(id)).cloned();
+        if name.is_none() {
+            self.warnings.push(format!(
+                "{owner}: zone reference could not be resolved; skipped"
+            ));
+        }
+        name
+    }
+
+    // ── Firewall zones ───────────────────────────────────────────
+
+    fn firewall_zones(&mut self, store: &DataStore) -> Vec<FirewallZoneSpec> {
+        let exported: Vec<EntityId> = store
+            .networks_snapshot()
+            .iter()
+            .filter(|n| user_defined(n.origin) && !n.is_default)
+            .map(|n| n.id.clone())
+            .collect();
+
+        let mut specs = Vec::new();
+        for zone in store.firewall_zones_snapshot().iter() {
+            let has_exported = zone.network_ids.iter().any(|id| exported.contains(id));
+            if !user_defined(zone.origin) && !has_exported {
+                continue;
+            }
+            let mut networks = Vec::with_capacity(zone.network_ids.len());
+            for id in &zone.network_ids {
+                match self.network_names.get(id) {
+                    Some(name) => networks.push(name.clone()),
+                    None => self.warnings.push(format!(
+                        "firewall_zone '{}': unknown member network {id}; skipped",
+                        zone.name
+                    )),
+                }
+            }
+            networks.sort();
+            specs.push(FirewallZoneSpec {
+                name: zone.name.clone(),
+                networks,
+            });
+        }
+        specs.sort_by(|a, b| a.name.cmp(&b.name));
+        specs
+    }
+
+    // ── WiFi broadcasts ──────────────────────────────────────────
+
+    fn wifi_broadcasts(&mut self, store: &DataStore) -> Vec<WifiBroadcastSpec> {
+        let mut specs = Vec::new();
+        for wifi in store.wifi_broadcasts_snapshot().iter() {
+            if !user_defined(wifi.origin) {
+                continue;
+            }
+            if wifi.security != WifiSecurityMode::Open {
+                self.warnings.push(format!(
+                    "wifi_broadcast '{}': passphrase is not exported; add it before importing",
+                    wifi.name
+                ));
+            }
+            specs.push(WifiBroadcastSpec {
+                name: wifi.name.clone(),
+                ssid: None,
+                network: wifi
+                    .network_id
+                    .as_ref()
+                    .and_then(|id| self.network_names.get(id))
+                    .cloned(),
+                security: wifi.security,
+                passphrase: None,
+                enabled: wifi.enabled,
+                hidden: wifi.hidden,
+                broadcast_type: Some(wifi.broadcast_type),
+                frequencies_ghz: (!wifi.frequencies_ghz.is_empty())
+                    .then(|| wifi.frequencies_ghz.clone()),
+                band_steering: wifi.band_steering,
+                fast_roaming: wifi.fast_roaming,
+            });
+        }
+        specs.sort_by(|a, b| a.name.cmp(&b.name));
+        specs
+    }
+
+    // ── Firewall policies ────────────────────────────────────────
+
+    fn firewall_policies(&mut self, store: &DataStore) -> Vec<FirewallPolicySpec> {
+        let mut specs = Vec::new();
+        for policy in store.firewall_policies_snapshot().iter() {
+            if !user_defined(policy.origin) {
+                continue;
+            }
+            let owner = format!("firewall_policy '{}'", policy.name);
+            let (Some(source_zone), Some(destination_zone)) = (
+                self.zone_name(policy.source_zone_id.as_ref(), &owner),
+                self.zone_name(policy.destination_zone_id.as_ref(), &owner),
+            ) else {
+                continue;
+            };
+            if policy.source_summary.is_some() || policy.destination_summary.is_some() {
+                self.warnings.push(format!(
+                    "{owner}: address and port filters are not exported"
+                ));
+            }
+            specs.push(FirewallPolicySpec {
+                name: policy.name.clone(),
+                action: policy.action,
+                source_zone,
+                destination_zone,
+                enabled: policy.enabled,
+                description: policy.description.clone(),
+                logging_enabled: policy.logging_enabled,
+                protocol: None,
+                source_address: None,
+                destination_address: None,
+                destination_port: None,
+            });
+        }
+        specs.sort_by(|a, b| a.name.cmp(&b.name));
+        specs
+    }
+
+    // ── ACL rules ────────────────────────────────────────────────
+
+    fn acl_rules(&mut self, store: &DataStore) -> Vec<AclRuleSpec> {
+        let mut specs = Vec::new();
+        for rule in store.acl_rules_snapshot().iter() {
+            if !user_defined(rule.origin) {
+                continue;
+            }
+            let owner = format!("acl_rule '{}'", rule.name);
+            let (Some(source_zone), Some(destination_zone)) = (
+                self.zone_name(rule.source_zone_id.as_ref(), &owner),
+                self.zone_name(rule.destination_zone_id.as_ref(), &owner),
+            ) else {
+                continue;
+            };
+            specs.push(AclRuleSpec {
+                name: rule.name.clone(),
+                rule_type: rule.rule_type,
+                action: rule.action,
+                source_zone,
+                destination_zone,
+                enabled: rule.enabled,
+                protocol: None,
+                source_port: None,
+                destination_port: None,
+            });
+        }
+        specs.sort_by(|a, b| a.name.cmp(&b.name));
+        specs
+    }
+}
+
+// ── Traffic matching lists ─────────────────────────────────────────
+
+fn traffic_matching_lists(store: &DataStore) -> Vec<TrafficMatchingListSpec> {
+    let mut specs: Vec<_> = store
+        .traffic_matching_lists_snapshot()
+        .iter()
+        .filter(|l| user_defined(l.origin))
+        .map(|l| TrafficMatchingListSpec {
+            name: l.name.clone(),
+            list_type: l.list_type.clone(),
+            items: l.items.clone(),
+            description: None,
+        })
+        .collect();
+    specs.sort_by(|a, b| a.name.cmp(&b.name));
+    specs
+}
+
+// ── Networks ───────────────────────────────────────────────────────
+
+fn networks(store: &DataStore) -> Vec<NetworkSpec> {
+    let mut specs: Vec<_> = store
+        .networks_snapshot()
+        .iter()
+        .filter(|n| user_defined(n.origin) && !n.is_default)
+        .map(|n| NetworkSpec {
+            name: n.name.clone(),
+            enabled: n.enabled,
+            vlan_id: n.vlan_id,
+            management: n.management,
+            purpose: n.purpose,
+            subnet: n.subnet.clone(),
+            dhcp: n.dhcp.as_ref().map(|d| DhcpSpec {
+                enabled: d.enabled,
+                range_start: d.range_start.map(|ip| ip.to_string()),
+                range_stop: d.range_stop.map(|ip| ip.to_string()),
+                lease_time_secs: d.lease_time_secs.and_then(|t| u32::try_from(t).ok()),
+            }),
+            isolation_enabled: Some(n.isolation_enabled),
+            internet_access_enabled: Some(n.internet_access_enabled),
+            mdns_forwarding_enabled: Some(n.mdns_forwarding_enabled),
+            ipv6_enabled: Some(n.ipv6_enabled),
+        })
+        .collect();
+    specs.sort_by(|a, b| a.name.cmp(&b.name));
+    specs
+}
+
+// ── DNS policies ───────────────────────────────────────────────────
+
+fn dns_policies(store: &DataStore) -> Vec<DnsPolicySpec> {
+    let mut specs: Vec<_> = store
+        .dns_policies_snapshot()
+        .iter()
+        .filter(|d| user_defined(d.origin))
+        .map(|d| DnsPolicySpec {
+            policy_type: d.policy_type,
+            domain: d.domain.clone(),
+            value: d.value.clone(),
+            ttl_seconds: d.ttl_seconds,
+            priority: None,
+            enabled: true,
+        })
+        .collect();
+    specs.sort_by(|a, b| a.domain.cmp(&b.domain));
+    specs
+}
+
+// ── Vouchers ───────────────────────────────────────────────────────
+
+/// Group unexpired vouchers into batches by name. An unnamed voucher
+/// is a batch of one, keyed by its code.
+fn vouchers(store: &DataStore) -> Vec<VoucherSpec> {
+    let mut batches: BTreeMap<String, VoucherSpec> = BTreeMap::new();
+    for voucher in store.vouchers_snapshot().iter().filter(|v| !v.expired) {
+        let name = voucher_key(voucher);
+        batches
+            .entry(name.clone())
+            .and_modify(|b| b.count += 1)
+            .or_insert_with(|| VoucherSpec {
+                name,
+                count: 1,
+                time_limit_minutes: voucher.time_limit_minutes,
+                data_usage_limit_mb: voucher.data_usage_limit_mb,
+                authorized_guest_limit: voucher.authorized_guest_limit,
+                rx_rate_limit_kbps: voucher.rx_rate_limit_kbps,
+                tx_rate_limit_kbps: voucher.tx_rate_limit_kbps,
+            });
+    }
+    batches.into_values().collect()
+}
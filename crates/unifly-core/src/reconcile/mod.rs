@@ -0,0 +1,296 @@
+// ── Declarative reconciliation ──
+//
+// Converges a site on a desired-state document: `plan()` diffs the
+// document against `DataStore` snapshots, `apply()` executes the
+// resulting `Command`s stage by stage in dependency order, refreshing
+// the store between stages so later stages can resolve references to
+// freshly created resources. `export()` produces the same document
+// format from a site, which makes site-to-site cloning an apply.
+
+mod export;
+mod plan;
+mod spec;
+
+pub use export::{Export, export};
+pub use plan::{ChangeAction, FieldChange, Plan, PlannedChange, ResourceKind, plan};
+pub use spec::{
+    AclRuleSpec, DesiredState, DhcpSpec, DnsPolicySpec, FirewallPolicySpec, FirewallZoneSpec,
+    NetworkSpec, TrafficMatchingListSpec, VoucherSpec, WifiBroadcastSpec,
+};
+
+use crate::controller::Controller;
+use crate::error::CoreError;
+
+/// Apply `desired` to the controller's site.
+///
+/// Creates and updates run first, in [`ResourceKind::APPLY_ORDER`];
+/// deletes (only when `desired.prune` is set) run afterwards in reverse
+/// order. `on_change` is invoked right before each change is executed.
+/// Execution stops at the first failed command. Returns the number of
+/// changes applied.
+pub async fn apply(
+    controller: &Controller,
+    desired: &DesiredState,
+    mut on_change: impl FnMut(&PlannedChange),
+) -> Result<usize, CoreError> {
+    let mut applied = 0;
+
+    for kind in ResourceKind::APPLY_ORDER {
+        let stage = plan::plan_kinds(desired, controller.store(), &[kind])?;
+        let changes = stage
+            .changes
+            .into_iter()
+            .filter(|c| c.action != ChangeAction::Delete);
+        applied += execute_stage(controller, changes, &mut on_change).await?;
+    }
+
+    for kind in ResourceKind::APPLY_ORDER.into_iter().rev() {
+        let stage = plan::plan_kinds(desired, controller.store(), &[kind])?;
+        let changes = stage
+            .changes
+            .into_iter()
+            .filter(|c| c.action == ChangeAction::Delete);
+        applied += execute_stage(controller, changes, &mut on_change).await?;
+    }
+
+    Ok(applied)
+}
+
+/// Execute one stage's changes, then refresh the store if anything ran.
+async fn execute_stage(
+    controller: &Controller,
+    changes: impl Iterator<Item = PlannedChange>,
+    on_change: &mut impl FnMut(&PlannedChange),
+) -> Result<usize, CoreError> {
+    let mut count = 0;
+    for change in changes {
+        on_change(&change);
+        let Some(cmd) = change.command else {
+            return Err(CoreError::ValidationFailed {
+                message: format!(
+                    "{} '{}' still references resources that do not exist",
+                    change.kind, change.name
+                ),
+            });
+        };
+        controller.execute(cmd).await?;
+        count += 1;
+    }
+    if count > 0 {
+        controller.full_refresh().await?;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::command::Command;
+    use crate::model::{
+        DataSource, EntityId, EntityOrigin, FirewallZone, Network, NetworkManagement, Voucher,
+    };
+    use crate::store::DataStore;
+
+    fn network(id: &str, name: &str, vlan: Option<u16>, origin: EntityOrigin) -> Network {
+        Network {
+            id: EntityId::from(id),
+            name: name.into(),
+            enabled: true,
+            management: Some(NetworkManagement::Gateway),
+            purpose: None,
+            is_default: false,
+            vlan_id: vlan,
+            subnet: None,
+            gateway_ip: None,
+            dhcp: None,
+            ipv6_enabled: false,
+            ipv6_mode: None,
+            ipv6_prefix: None,
+            dhcpv6_enabled: false,
+            slaac_enabled: false,
+            ntp_server: None,
+            pxe_enabled: false,
+            tftp_server: None,
+            firewall_zone_id: None,
+            isolation_enabled: false,
+            internet_access_enabled: true,
+            mdns_forwarding_enabled: false,
+            cellular_backup_enabled: false,
+            site_id: None,
+            origin: Some(origin),
+            source: DataSource::IntegrationApi,
+        }
+    }
+
+    fn store_with(networks: Vec<Network>) -> DataStore {
+        let store = DataStore::new();
+        for n in networks {
+            store
+                .networks
+                .upsert(format!("net:{}", n.id), n.id.clone(), n);
+        }
+        store
+    }
+
+    fn zone(id: &str, name: &str, networks: &[&str], origin: EntityOrigin) -> FirewallZone {
+        FirewallZone {
+            id: EntityId::from(id),
+            name: name.into(),
+            network_ids: networks.iter().map(|n| EntityId::from(*n)).collect(),
+            origin: Some(origin),
+            source: DataSource::IntegrationApi,
+        }
+    }
+
+    fn voucher(id: &str, code: &str, name: Option<&str>) -> Voucher {
+        Voucher {
+            id: EntityId::from(id),
+            code: code.into(),
+            name: name.map(Into::into),
+            created_at: None,
+            activated_at: None,
+            expires_at: None,
+            expired: false,
+            time_limit_minutes: Some(60),
+            data_usage_limit_mb: None,
+            authorized_guest_limit: Some(1),
+            authorized_guest_count: None,
+            rx_rate_limit_kbps: None,
+            tx_rate_limit_kbps: None,
+            source: DataSource::IntegrationApi,
+        }
+    }
+
+    fn parse(json: &str) -> DesiredState {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn unchanged_state_produces_empty_plan() {
+        let store = store_with(vec![network(
+            "n1",
+            "IoT",
+            Some(30),
+            EntityOrigin::UserDefined,
+        )]);
+        let desired = parse(r#"{"networks": [{"name": "IoT", "vlan_id": 30}]}"#);
+        let plan = plan(&desired, &store).unwrap();
+        assert!(plan.is_empty(), "unexpected changes: {:?}", plan.changes);
+    }
+
+    #[test]
+    fn changed_field_produces_update_with_old_and_new() {
+        let store = store_with(vec![network(
+            "n1",
+            "IoT",
+            Some(30),
+            EntityOrigin::UserDefined,
+        )]);
+        let desired = parse(r#"{"networks": [{"name": "IoT", "vlan_id": 40}]}"#);
+        let plan = plan(&desired, &store).unwrap();
+        assert_eq!(plan.count(ChangeAction::Update), 1);
+        let change = &plan.changes[0];
+        assert_eq!(change.fields[0].field, "vlan_id");
+        assert_eq!(change.fields[0].old, Some(serde_json::json!(30)));
+        assert_eq!(change.fields[0].new, serde_json::json!(40));
+        match &change.command {
+            Some(Command::UpdateNetwork { update, .. }) => assert_eq!(update.vlan_id, Some(40)),
+            other => panic!("unexpected command: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn references_to_pending_resources_defer_the_command() {
+        let store = store_with(Vec::new());
+        let desired = parse(
+            r#"{
+                "networks": [{"name": "IoT", "vlan_id": 30}],
+                "firewall_zones": [{"name": "Things", "networks": ["IoT"]}]
+            }"#,
+        );
+        let plan = plan(&desired, &store).unwrap();
+        let kinds: Vec<ResourceKind> = plan.changes.iter().map(|c| c.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![ResourceKind::Network, ResourceKind::FirewallZone]
+        );
+        assert!(plan.changes[0].command.is_some());
+        assert!(plan.changes[1].command.is_none());
+    }
+
+    #[test]
+    fn unknown_reference_is_rejected() {
+        let store = store_with(Vec::new());
+        let desired = parse(r#"{"firewall_zones": [{"name": "Things", "networks": ["Nope"]}]}"#);
+        assert!(plan(&desired, &store).is_err());
+    }
+
+    #[test]
+    fn prune_only_deletes_user_defined_resources() {
+        let store = store_with(vec![
+            network("n1", "Default", None, EntityOrigin::SystemDefined),
+            network("n2", "Old", Some(99), EntityOrigin::UserDefined),
+        ]);
+        let desired = parse(r#"{"prune": true, "networks": []}"#);
+        let plan = plan(&desired, &store).unwrap();
+        assert_eq!(plan.count(ChangeAction::Delete), 1);
+        assert_eq!(plan.changes[0].name, "Old");
+
+        let without_prune = parse(r#"{"networks": []}"#);
+        assert!(super::plan(&without_prune, &store).unwrap().is_empty());
+    }
+
+    #[test]
+    fn export_uses_names_and_plans_no_changes_against_its_source() {
+        let store = store_with(vec![
+            network("n0", "Default", None, EntityOrigin::SystemDefined),
+            network("n1", "IoT", Some(30), EntityOrigin::UserDefined),
+        ]);
+        store.firewall_zones.upsert(
+            "fwz:z1".into(),
+            EntityId::from("z1"),
+            zone("z1", "Internal", &["n0", "n1"], EntityOrigin::SystemDefined),
+        );
+        store.firewall_zones.upsert(
+            "fwz:z2".into(),
+            EntityId::from("z2"),
+            zone("z2", "External", &[], EntityOrigin::SystemDefined),
+        );
+
+        let export = export(&store);
+        let networks = export.state.networks.as_ref().unwrap();
+        assert_eq!(networks.len(), 1);
+        assert_eq!(networks[0].name, "IoT");
+        let zones = export.state.firewall_zones.as_ref().unwrap();
+        assert_eq!(zones.len(), 1);
+        assert_eq!(zones[0].networks, vec!["Default", "IoT"]);
+
+        let plan = plan(&export.state, &store).unwrap();
+        assert!(plan.is_empty(), "unexpected changes: {:?}", plan.changes);
+    }
+
+    #[test]
+    fn exported_vouchers_round_trip_with_prune() {
+        let store = store_with(Vec::new());
+        for v in [
+            voucher("v1", "11111-11111", Some("Lobby")),
+            voucher("v2", "22222-22222", Some("Lobby")),
+            voucher("v3", "33333-33333", None),
+            voucher("v4", "44444-44444", None),
+        ] {
+            store
+                .vouchers
+                .upsert(format!("vch:{}", v.id), v.id.clone(), v);
+        }
+
+        let mut state = export(&store).state;
+        let batches = state.vouchers.as_ref().unwrap();
+        let names: Vec<&str> = batches.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(names, vec!["33333-33333", "44444-44444", "Lobby"]);
+
+        state.prune = true;
+        let plan = plan(&state, &store).unwrap();
+        assert!(plan.is_empty(), "unexpected changes: {:?}", plan.changes);
+    }
+}
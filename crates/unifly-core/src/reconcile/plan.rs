@@ -0,0 +1,1168 @@
+// ── Plan computation ──
+//
+// Diffs a `DesiredState` against the current `DataStore` contents and
+// produces an ordered list of changes, each carrying the `Command`
+// that realises it. Only fields that the `Update*Request` structs can
+// express are compared; drift in create-only fields is reported as a
+// warning instead of a perpetual diff.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use super::spec::{
+    AclRuleSpec, DesiredState, DnsPolicySpec, FirewallPolicySpec, FirewallZoneSpec, NetworkSpec,
+    TrafficMatchingListSpec, VoucherSpec, WifiBroadcastSpec,
+};
+use crate::command::{
+    Command, CreateAclRuleRequest, CreateDnsPolicyRequest, CreateFirewallPolicyRequest,
+    CreateFirewallZoneRequest, CreateNetworkRequest, CreateTrafficMatchingListRequest,
+    CreateVouchersRequest, CreateWifiBroadcastRequest, UpdateAclRuleRequest,
+    UpdateDnsPolicyRequest, UpdateFirewallPolicyRequest, UpdateFirewallZoneRequest,
+    UpdateNetworkRequest, UpdateTrafficMatchingListRequest, UpdateWifiBroadcastRequest,
+};
+use crate::error::CoreError;
+use crate::model::{
+    AclAction, AclRule, AclRuleType, DnsPolicy, EntityId, EntityOrigin, FirewallAction,
+    FirewallPolicy, FirewallZone, Network, TrafficMatchingList, Voucher, WifiBroadcast,
+    WifiBroadcastType,
+};
+use crate::store::DataStore;
+
+// ── Plan types ─────────────────────────────────────────────────────
+
+/// Resource kinds managed by `unifly apply`, in dependency order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceKind {
+    TrafficMatchingList,
+    Network,
+    FirewallZone,
+    WifiBroadcast,
+    FirewallPolicy,
+    AclRule,
+    DnsPolicy,
+    Voucher,
+}
+
+impl ResourceKind {
+    /// Creates and updates run in this order; deletes run in reverse.
+    pub const APPLY_ORDER: [Self; 8] = [
+        Self::TrafficMatchingList,
+        Self::Network,
+        Self::FirewallZone,
+        Self::WifiBroadcast,
+        Self::FirewallPolicy,
+        Self::AclRule,
+        Self::DnsPolicy,
+        Self::Voucher,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::TrafficMatchingList => "traffic_matching_list",
+            Self::Network => "network",
+            Self::FirewallZone => "firewall_zone",
+            Self::WifiBroadcast => "wifi_broadcast",
+            Self::FirewallPolicy => "firewall_policy",
+            Self::AclRule => "acl_rule",
+            Self::DnsPolicy => "dns_policy",
+            Self::Voucher => "voucher",
+        }
+    }
+}
+
+impl fmt::Display for ResourceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeAction {
+    Create,
+    Update,
+    Delete,
+}
+
+/// A single attribute change. `old` is `None` for creates.
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<serde_json::Value>,
+    pub new: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedChange {
+    pub kind: ResourceKind,
+    pub action: ChangeAction,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<EntityId>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldChange>,
+    /// The command realising this change, or `None` while it still
+    /// references resources that are created earlier in the same apply.
+    #[serde(skip)]
+    pub command: Option<Command>,
+}
+
+/// Ordered set of changes needed to converge a site on a `DesiredState`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Plan {
+    pub changes: Vec<PlannedChange>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl Plan {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Number of changes with the given action.
+    pub fn count(&self, action: ChangeAction) -> usize {
+        self.changes.iter().filter(|c| c.action == action).count()
+    }
+}
+
+// ── Entry points ───────────────────────────────────────────────────
+
+/// Compute the full plan for `desired` against the store's current contents.
+pub fn plan(desired: &DesiredState, store: &DataStore) -> Result<Plan, CoreError> {
+    plan_kinds(desired, store, &ResourceKind::APPLY_ORDER)
+}
+
+/// Compute the plan restricted to `kinds`, in the order given.
+pub(crate) fn plan_kinds(
+    desired: &DesiredState,
+    store: &DataStore,
+    kinds: &[ResourceKind],
+) -> Result<Plan, CoreError> {
+    let mut planner = Planner {
+        refs: Refs::new(desired, store),
+        prune: desired.prune,
+        plan: Plan::default(),
+    };
+
+    for kind in kinds {
+        match kind {
+            ResourceKind::TrafficMatchingList => {
+                if let Some(specs) = &desired.traffic_matching_lists {
+                    let current = store.traffic_matching_lists_snapshot();
+                    planner.traffic_matching_lists(specs, &current)?;
+                }
+            }
+            ResourceKind::Network => {
+                if let Some(specs) = &desired.networks {
+                    let current = store.networks_snapshot();
+                    planner.networks(specs, &current)?;
+                }
+            }
+            ResourceKind::FirewallZone => {
+                if let Some(specs) = &desired.firewall_zones {
+                    let current = store.firewall_zones_snapshot();
+                    planner.firewall_zones(specs, &current)?;
+                }
+            }
+            ResourceKind::WifiBroadcast => {
+                if let Some(specs) = &desired.wifi_broadcasts {
+                    let current = store.wifi_broadcasts_snapshot();
+                    planner.wifi_broadcasts(specs, &current)?;
+                }
+            }
+            ResourceKind::FirewallPolicy => {
+                if let Some(specs) = &desired.firewall_policies {
+                    let current = store.firewall_policies_snapshot();
+                    planner.firewall_policies(specs, &current)?;
+                }
+            }
+            ResourceKind::AclRule => {
+                if let Some(specs) = &desired.acl_rules {
+                    let current = store.acl_rules_snapshot();
+                    planner.acl_rules(specs, &current)?;
+                }
+            }
+            ResourceKind::DnsPolicy => {
+                if let Some(specs) = &desired.dns_policies {
+                    let current = store.dns_policies_snapshot();
+                    planner.dns_policies(specs, &current)?;
+                }
+            }
+            ResourceKind::Voucher => {
+                if let Some(specs) = &desired.vouchers {
+                    let current = store.vouchers_snapshot();
+                    planner.vouchers(specs, &current)?;
+                }
+            }
+        }
+    }
+
+    Ok(planner.plan)
+}
+
+// ── Reference resolution ───────────────────────────────────────────
+
+enum Resolved {
+    Id(EntityId),
+    /// Created by an earlier stage of the same apply.
+    Pending,
+}
+
+/// Name → ID lookup for cross-resource references.
+struct Refs {
+    networks: HashMap<String, EntityId>,
+    network_names: HashMap<EntityId, String>,
+    zones: HashMap<String, EntityId>,
+    pending_networks: HashSet<String>,
+    pending_zones: HashSet<String>,
+}
+
+impl Refs {
+    fn new(desired: &DesiredState, store: &DataStore) -> Self {
+        let mut networks = HashMap::new();
+        let mut network_names = HashMap::new();
+        for n in store.networks_snapshot().iter() {
+            networks
+                .entry(n.name.clone())
+                .or_insert_with(|| n.id.clone());
+            network_names.insert(n.id.clone(), n.name.clone());
+        }
+        let mut zones = HashMap::new();
+        for z in store.firewall_zones_snapshot().iter() {
+            zones.entry(z.name.clone()).or_insert_with(|| z.id.clone());
+        }
+
+        let pending_networks = desired
+            .networks
+            .iter()
+            .flatten()
+            .filter(|s| !networks.contains_key(&s.name))
+            .map(|s| s.name.clone())
+            .collect();
+        let pending_zones = desired
+            .firewall_zones
+            .iter()
+            .flatten()
+            .filter(|s| !zones.contains_key(&s.name))
+            .map(|s| s.name.clone())
+            .collect();
+
+        Self {
+            networks,
+            network_names,
+            zones,
+            pending_networks,
+            pending_zones,
+        }
+    }
+
+    fn network(&self, name: &str, owner: &str) -> Result<Resolved, CoreError> {
+        resolve(
+            &self.networks,
+            &self.pending_networks,
+            name,
+            "network",
+            owner,
+        )
+    }
+
+    fn zone(&self, name: &str, owner: &str) -> Result<Resolved, CoreError> {
+        resolve(
+            &self.zones,
+            &self.pending_zones,
+            name,
+            "firewall zone",
+            owner,
+        )
+    }
+
+    fn network_name(&self, id: &EntityId) -> String {
+        self.network_names
+            .get(id)
+            .cloned()
+            .unwrap_or_else(|| id.to_string())
+    }
+}
+
+fn resolve(
+    existing: &HashMap<String, EntityId>,
+    pending: &HashSet<String>,
+    name: &str,
+    what: &str,
+    owner: &str,
+) -> Result<Resolved, CoreError> {
+    if let Some(id) = existing.get(name) {
+        Ok(Resolved::Id(id.clone()))
+    } else if pending.contains(name) {
+        Ok(Resolved::Pending)
+    } else {
+        Err(CoreError::ValidationFailed {
+            message: format!("{owner} references unknown {what} '{name}'"),
+        })
+    }
+}
+
+// ── Entity plumbing ────────────────────────────────────────────────
+
+/// Common accessors the planner needs from every managed entity.
+trait Managed {
+    const KIND: ResourceKind;
+    fn key(&self) -> String;
+    fn entity_id(&self) -> &EntityId;
+    fn origin(&self) -> Option<EntityOrigin>;
+    fn delete_command(id: EntityId) -> Command;
+}
+
+impl Managed for TrafficMatchingList {
+    const KIND: ResourceKind = ResourceKind::TrafficMatchingList;
+    fn key(&self) -> String {
+        self.name.clone()
+    }
+    fn entity_id(&self) -> &EntityId {
+        &self.id
+    }
+    fn origin(&self) -> Option<EntityOrigin> {
+        self.origin
+    }
+    fn delete_command(id: EntityId) -> Command {
+        Command::DeleteTrafficMatchingList { id }
+    }
+}
+
+impl Managed for Network {
+    const KIND: ResourceKind = ResourceKind::Network;
+    fn key(&self) -> String {
+        self.name.clone()
+    }
+    fn entity_id(&self) -> &EntityId {
+        &self.id
+    }
+    fn origin(&self) -> Option<EntityOrigin> {
+        self.origin
+    }
+    fn delete_command(id: EntityId) -> Command {
+        Command::DeleteNetwork { id, force: false }
+    }
+}
+
+impl Managed for FirewallZone {
+    const KIND: ResourceKind = ResourceKind::FirewallZone;
+    fn key(&self) -> String {
+        self.name.clone()
+    }
+    fn entity_id(&self) -> &EntityId {
+        &self.id
+    }
+    fn origin(&self) -> Option<EntityOrigin> {
+        self.origin
+    }
+    fn delete_command(id: EntityId) -> Command {
+        Command::DeleteFirewallZone { id }
+    }
+}
+
+impl Managed for WifiBroadcast {
+    const KIND: ResourceKind = ResourceKind::WifiBroadcast;
+    fn key(&self) -> String {
+        self.name.clone()
+    }
+    fn entity_id(&self) -> &EntityId {
+        &self.id
+    }
+    fn origin(&self) -> Option<EntityOrigin> {
+        self.origin
+    }
+    fn delete_command(id: EntityId) -> Command {
+        Command::DeleteWifiBroadcast { id, force: false }
+    }
+}
+
+impl Managed for FirewallPolicy {
+    const KIND: ResourceKind = ResourceKind::FirewallPolicy;
+    fn key(&self) -> String {
+        self.name.clone()
+    }
+    fn entity_id(&self) -> &EntityId {
+        &self.id
+    }
+    fn origin(&self) -> Option<EntityOrigin> {
+        self.origin
+    }
+    fn delete_command(id: EntityId) -> Command {
+        Command::DeleteFirewallPolicy { id }
+    }
+}
+
+impl Managed for AclRule {
+    const KIND: ResourceKind = ResourceKind::AclRule;
+    fn key(&self) -> String {
+        self.name.clone()
+    }
+    fn entity_id(&self) -> &EntityId {
+        &self.id
+    }
+    fn origin(&self) -> Option<EntityOrigin> {
+        self.origin
+    }
+    fn delete_command(id: EntityId) -> Command {
+        Command::DeleteAclRule { id }
+    }
+}
+
+impl Managed for DnsPolicy {
+    const KIND: ResourceKind = ResourceKind::DnsPolicy;
+    fn key(&self) -> String {
+        dns_key(self.policy_type, &self.domain)
+    }
+    fn entity_id(&self) -> &EntityId {
+        &self.id
+    }
+    fn origin(&self) -> Option<EntityOrigin> {
+        self.origin
+    }
+    fn delete_command(id: EntityId) -> Command {
+        Command::DeleteDnsPolicy { id }
+    }
+}
+
+impl Managed for Voucher {
+    const KIND: ResourceKind = ResourceKind::Voucher;
+    fn key(&self) -> String {
+        voucher_key(self)
+    }
+    fn entity_id(&self) -> &EntityId {
+        &self.id
+    }
+    fn origin(&self) -> Option<EntityOrigin> {
+        None
+    }
+    fn delete_command(id: EntityId) -> Command {
+        Command::DeleteVoucher { id }
+    }
+}
+
+/// Batch name of a voucher: its name, or its code when unnamed.
+/// Export uses the same key, so an exported site plans no changes.
+pub(super) fn voucher_key(voucher: &Voucher) -> String {
+    voucher.name.clone().unwrap_or_else(|| voucher.code.clone())
+}
+
+fn dns_key(policy_type: crate::model::DnsPolicyType, domain: &str) -> String {
+    format!("{} {policy_type:?}", domain.to_ascii_lowercase())
+}
+
+/// Only user-created resources are ever deleted by pruning. Entities
+/// without origin metadata (DNS policies, traffic lists) are user-created.
+fn prunable(origin: Option<EntityOrigin>) -> bool {
+    matches!(origin, None | Some(EntityOrigin::UserDefined))
+}
+
+/// Record a field change if `current` differs from `desired`.
+fn diff_field<T: PartialEq + Serialize>(
+    fields: &mut Vec<FieldChange>,
+    field: &str,
+    current: &T,
+    desired: &T,
+) -> bool {
+    if current == desired {
+        return false;
+    }
+    fields.push(FieldChange {
+        field: field.into(),
+        old: Some(to_value(current)),
+        new: to_value(desired),
+    });
+    true
+}
+
+fn to_value<T: Serialize + ?Sized>(v: &T) -> serde_json::Value {
+    serde_json::to_value(v).unwrap_or(serde_json::Value::Null)
+}
+
+/// List every populated attribute of a spec as a create-time field change.
+fn spec_fields<T: Serialize>(spec: &T) -> Vec<FieldChange> {
+    let serde_json::Value::Object(map) = to_value(spec) else {
+        return Vec::new();
+    };
+    map.into_iter()
+        .map(|(field, value)| {
+            let new = if field == "passphrase" {
+                serde_json::Value::String("(sensitive)".into())
+            } else {
+                value
+            };
+            FieldChange {
+                field,
+                old: None,
+                new,
+            }
+        })
+        .collect()
+}
+
+fn sorted(items: &[String]) -> Vec<String> {
+    let mut v = items.to_vec();
+    v.sort();
+    v
+}
+
+fn ensure_unique<'a>(
+    kind: ResourceKind,
+    keys: impl Iterator<Item = &'a str>,
+) -> Result<(), CoreError> {
+    let mut seen = HashSet::new();
+    for key in keys {
+        if !seen.insert(key) {
+            return Err(CoreError::ValidationFailed {
+                message: format!("duplicate {kind} '{key}' in desired state"),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ── Planner ────────────────────────────────────────────────────────
+
+struct Planner {
+    refs: Refs,
+    prune: bool,
+    plan: Plan,
+}
+
+impl Planner {
+    /// Find the current entity matching `key`, warning when the name is ambiguous.
+    fn find<'a, T: Managed>(&mut self, current: &'a [Arc<T>], key: &str) -> Option<&'a Arc<T>> {
+        let mut matches = current.iter().filter(|e| e.key() == key);
+        let first = matches.next()?;
+        if matches.next().is_some() {
+            self.plan.warnings.push(format!(
+                "multiple {} resources named '{key}'; using {}",
+                T::KIND,
+                first.entity_id()
+            ));
+        }
+        Some(first)
+    }
+
+    fn create<T: Serialize>(
+        &mut self,
+        kind: ResourceKind,
+        name: &str,
+        spec: &T,
+        cmd: Option<Command>,
+    ) {
+        self.plan.changes.push(PlannedChange {
+            kind,
+            action: ChangeAction::Create,
+            name: name.into(),
+            id: None,
+            fields: spec_fields(spec),
+            command: cmd,
+        });
+    }
+
+    fn update(
+        &mut self,
+        kind: ResourceKind,
+        name: &str,
+        id: &EntityId,
+        fields: Vec<FieldChange>,
+        cmd: Option<Command>,
+    ) {
+        if fields.is_empty() {
+            return;
+        }
+        self.plan.changes.push(PlannedChange {
+            kind,
+            action: ChangeAction::Update,
+            name: name.into(),
+            id: Some(id.clone()),
+            fields,
+            command: cmd,
+        });
+    }
+
+    /// Queue deletes for prunable entities whose key is not in `listed`.
+    fn prune_unlisted<T: Managed>(&mut self, current: &[Arc<T>], listed: &HashSet<String>) {
+        if !self.prune {
+            return;
+        }
+        for entity in current {
+            let key = entity.key();
+            if listed.contains(&key) || !prunable(entity.origin()) {
+                continue;
+            }
+            let id = entity.entity_id().clone();
+            self.plan.changes.push(PlannedChange {
+                kind: T::KIND,
+                action: ChangeAction::Delete,
+                name: key,
+                id: Some(id.clone()),
+                fields: Vec::new(),
+                command: Some(T::delete_command(id)),
+            });
+        }
+    }
+
+    fn warn(&mut self, kind: ResourceKind, name: &str, field: &str) {
+        self.plan.warnings.push(format!(
+            "{kind} '{name}': {field} differs but cannot be changed in place; \
+             delete and re-create the resource to change it"
+        ));
+    }
+
+    // ── Traffic matching lists ───────────────────────────────────
+
+    fn traffic_matching_lists(
+        &mut self,
+        specs: &[TrafficMatchingListSpec],
+        current: &[Arc<TrafficMatchingList>],
+    ) -> Result<(), CoreError> {
+        let kind = ResourceKind::TrafficMatchingList;
+        ensure_unique(kind, specs.iter().map(|s| s.name.as_str()))?;
+
+        for spec in specs {
+            let Some(existing) = self.find(current, &spec.name) else {
+                let cmd = Command::CreateTrafficMatchingList(CreateTrafficMatchingListRequest {
+                    name: spec.name.clone(),
+                    list_type: spec.list_type.clone(),
+                    entries: spec.items.clone(),
+                    description: spec.description.clone(),
+                });
+                self.create(kind, &spec.name, spec, Some(cmd));
+                continue;
+            };
+
+            if !existing.list_type.eq_ignore_ascii_case(&spec.list_type) {
+                self.warn(kind, &spec.name, "list_type");
+            }
+            let mut fields = Vec::new();
+            let mut update = UpdateTrafficMatchingListRequest::default();
+            if diff_field(
+                &mut fields,
+                "items",
+                &sorted(&existing.items),
+                &sorted(&spec.items),
+            ) {
+                update.entries = Some(spec.items.clone());
+            }
+            let id = existing.id.clone();
+            let cmd = Command::UpdateTrafficMatchingList {
+                id: id.clone(),
+                update,
+            };
+            self.update(kind, &spec.name, &id, fields, Some(cmd));
+        }
+
+        let listed = specs.iter().map(|s| s.name.clone()).collect();
+        self.prune_unlisted(current, &listed);
+        Ok(())
+    }
+
+    // ── Networks ─────────────────────────────────────────────────
+
+    fn networks(
+        &mut self,
+        specs: &[NetworkSpec],
+        current: &[Arc<Network>],
+    ) -> Result<(), CoreError> {
+        let kind = ResourceKind::Network;
+        ensure_unique(kind, specs.iter().map(|s| s.name.as_str()))?;
+
+        for spec in specs {
+            let Some(existing) = self.find(current, &spec.name) else {
+                let dhcp = spec.dhcp.as_ref();
+                let cmd = Command::CreateNetwork(CreateNetworkRequest {
+                    name: spec.name.clone(),
+                    vlan_id: spec.vlan_id,
+                    subnet: spec.subnet.clone(),
+                    management: spec.management,
+                    purpose: spec.purpose,
+                    dhcp_enabled: dhcp.is_some_and(|d| d.enabled),
+                    enabled: spec.enabled,
+                    dhcp_range_start: dhcp.and_then(|d| d.range_start.clone()),
+                    dhcp_range_stop: dhcp.and_then(|d| d.range_stop.clone()),
+                    dhcp_lease_time: dhcp.and_then(|d| d.lease_time_secs),
+                    firewall_zone_id: None,
+                    isolation_enabled: spec.isolation_enabled.unwrap_or(false),
+                    internet_access_enabled: spec.internet_access_enabled.unwrap_or(true),
+                });
+                self.create(kind, &spec.name, spec, Some(cmd));
+                continue;
+            };
+
+            if spec.management.is_some() && existing.management != spec.management {
+                self.warn(kind, &spec.name, "management");
+            }
+
+            let mut fields = Vec::new();
+            let mut update = UpdateNetworkRequest::default();
+            if diff_field(&mut fields, "enabled", &existing.enabled, &spec.enabled) {
+                update.enabled = Some(spec.enabled);
+            }
+            if let Some(vlan) = spec.vlan_id {
+                if diff_field(&mut fields, "vlan_id", &existing.vlan_id, &Some(vlan)) {
+                    update.vlan_id = Some(vlan);
+                }
+            }
+            if let Some(v) = spec.isolation_enabled {
+                if diff_field(
+                    &mut fields,
+                    "isolation_enabled",
+                    &existing.isolation_enabled,
+                    &v,
+                ) {
+                    update.isolation_enabled = Some(v);
+                }
+            }
+            if let Some(v) = spec.internet_access_enabled {
+                if diff_field(
+                    &mut fields,
+                    "internet_access_enabled",
+                    &existing.internet_access_enabled,
+                    &v,
+                ) {
+                    update.internet_access_enabled = Some(v);
+                }
+            }
+            if let Some(v) = spec.mdns_forwarding_enabled {
+                if diff_field(
+                    &mut fields,
+                    "mdns_forwarding_enabled",
+                    &existing.mdns_forwarding_enabled,
+                    &v,
+                ) {
+                    update.mdns_forwarding_enabled = Some(v);
+                }
+            }
+            if let Some(v) = spec.ipv6_enabled {
+                if diff_field(&mut fields, "ipv6_enabled", &existing.ipv6_enabled, &v) {
+                    update.ipv6_enabled = Some(v);
+                }
+            }
+            let id = existing.id.clone();
+            let cmd = Command::UpdateNetwork {
+                id: id.clone(),
+                update,
+            };
+            self.update(kind, &spec.name, &id, fields, Some(cmd));
+        }
+
+        let listed = specs.iter().map(|s| s.name.clone()).collect();
+        self.prune_unlisted(current, &listed);
+        Ok(())
+    }
+
+    // ── Firewall zones ───────────────────────────────────────────
+
+    fn firewall_zones(
+        &mut self,
+        specs: &[FirewallZoneSpec],
+        current: &[Arc<FirewallZone>],
+    ) -> Result<(), CoreError> {
+        let kind = ResourceKind::FirewallZone;
+        ensure_unique(kind, specs.iter().map(|s| s.name.as_str()))?;
+
+        for spec in specs {
+            let owner = format!("{kind} '{}'", spec.name);
+            let mut network_ids = Some(Vec::with_capacity(spec.networks.len()));
+            for name in &spec.networks {
+                match self.refs.network(name, &owner)? {
+                    Resolved::Id(id) => {
+                        if let Some(ids) = network_ids.as_mut() {
+                            ids.push(id);
+                        }
+                    }
+                    Resolved::Pending => network_ids = None,
+                }
+            }
+
+            let Some(existing) = self.find(current, &spec.name) else {
+                let cmd = network_ids.map(|network_ids| {
+                    Command::CreateFirewallZone(CreateFirewallZoneRequest {
+                        name: spec.name.clone(),
+                        description: None,
+                        network_ids,
+                    })
+                });
+                self.create(kind, &spec.name, spec, cmd);
+                continue;
+            };
+
+            let current_names: Vec<String> = existing
+                .network_ids
+                .iter()
+                .map(|id| self.refs.network_name(id))
+                .collect();
+            let mut fields = Vec::new();
+            let cmd = if diff_field(
+                &mut fields,
+                "networks",
+                &sorted(&current_names),
+                &sorted(&spec.networks),
+            ) {
+                network_ids.map(|ids| Command::UpdateFirewallZone {
+                    id: existing.id.clone(),
+                    update: UpdateFirewallZoneRequest {
+                        network_ids: Some(ids),
+                        ..Default::default()
+                    },
+                })
+            } else {
+                None
+            };
+            let id = existing.id.clone();
+            self.update(kind, &spec.name, &id, fields, cmd);
+        }
+
+        let listed = specs.iter().map(|s| s.name.clone()).collect();
+        self.prune_unlisted(current, &listed);
+        Ok(())
+    }
+
+    // ── WiFi broadcasts ──────────────────────────────────────────
+
+    fn wifi_broadcasts(
+        &mut self,
+        specs: &[WifiBroadcastSpec],
+        current: &[Arc<WifiBroadcast>],
+    ) -> Result<(), CoreError> {
+        let kind = ResourceKind::WifiBroadcast;
+        ensure_unique(kind, specs.iter().map(|s| s.name.as_str()))?;
+
+        for spec in specs {
+            let owner = format!("{kind} '{}'", spec.name);
+            let network = match spec.network.as_deref() {
+                Some(name) => Some(self.refs.network(name, &owner)?),
+                None => None,
+            };
+
+            let Some(existing) = self.find(current, &spec.name) else {
+                let network_id = match network {
+                    Some(Resolved::Pending) => {
+                        self.create(kind, &spec.name, spec, None);
+                        continue;
+                    }
+                    Some(Resolved::Id(id)) => Some(id),
+                    None => None,
+                };
+                let cmd = Command::CreateWifiBroadcast(CreateWifiBroadcastRequest {
+                    name: spec.name.clone(),
+                    ssid: spec.ssid.clone().unwrap_or_else(|| spec.name.clone()),
+                    security_mode: spec.security,
+                    passphrase: spec.passphrase.clone(),
+                    enabled: spec.enabled,
+                    network_id,
+                    hide_ssid: spec.hidden,
+                    broadcast_type: spec.broadcast_type.map(|t| {
+                        match t {
+                            WifiBroadcastType::Standard => "STANDARD",
+                            WifiBroadcastType::IotOptimized => "IOT_OPTIMIZED",
+                        }
+                        .into()
+                    }),
+                    frequencies_ghz: spec.frequencies_ghz.clone(),
+                    band_steering: spec.band_steering,
+                    fast_roaming: spec.fast_roaming,
+                });
+                self.create(kind, &spec.name, spec, Some(cmd));
+                continue;
+            };
+
+            match network {
+                Some(Resolved::Id(id)) if existing.network_id.as_ref() != Some(&id) => {
+                    self.warn(kind, &spec.name, "network");
+                }
+                Some(Resolved::Pending) => self.warn(kind, &spec.name, "network"),
+                _ => {}
+            }
+
+            let mut fields = Vec::new();
+            let mut update = UpdateWifiBroadcastRequest::default();
+            if diff_field(&mut fields, "enabled", &existing.enabled, &spec.enabled) {
+                update.enabled = Some(spec.enabled);
+            }
+            if diff_field(&mut fields, "security", &existing.security, &spec.security) {
+                update.security_mode = Some(spec.security);
+                update.passphrase.clone_from(&spec.passphrase);
+            }
+            if diff_field(&mut fields, "hidden", &existing.hidden, &spec.hidden) {
+                update.hide_ssid = Some(spec.hidden);
+            }
+            let id = existing.id.clone();
+            let cmd = Command::UpdateWifiBroadcast {
+                id: id.clone(),
+                update,
+            };
+            self.update(kind, &spec.name, &id, fields, Some(cmd));
+        }
+
+        let listed = specs.iter().map(|s| s.name.clone()).collect();
+        self.prune_unlisted(current, &listed);
+        Ok(())
+    }
+
+    // ── Firewall policies ────────────────────────────────────────
+
+    fn firewall_policies(
+        &mut self,
+        specs: &[FirewallPolicySpec],
+        current: &[Arc<FirewallPolicy>],
+    ) -> Result<(), CoreError> {
+        let kind = ResourceKind::FirewallPolicy;
+        ensure_unique(kind, specs.iter().map(|s| s.name.as_str()))?;
+
+        for spec in specs {
+            let owner = format!("{kind} '{}'", spec.name);
+            let source = self.refs.zone(&spec.source_zone, &owner)?;
+            let destination = self.refs.zone(&spec.destination_zone, &owner)?;
+
+            let Some(existing) = self.find(current, &spec.name) else {
+                let cmd = match (source, destination) {
+                    (Resolved::Id(source_zone_id), Resolved::Id(destination_zone_id)) => {
+                        Some(Command::CreateFirewallPolicy(CreateFirewallPolicyRequest {
+                            name: spec.name.clone(),
+                            action: spec.action,
+                            source_zone_id,
+                            destination_zone_id,
+                            enabled: spec.enabled,
+                            logging_enabled: spec.logging_enabled,
+                            description: spec.description.clone(),
+                            protocol: spec.protocol.clone(),
+                            source_address: spec.source_address.clone(),
+                            destination_address: spec.destination_address.clone(),
+                            destination_port: spec.destination_port.clone(),
+                        }))
+                    }
+                    _ => None,
+                };
+                self.create(kind, &spec.name, spec, cmd);
+                continue;
+            };
+
+            let zones_match = matches!(
+                (&source, &destination),
+                (Resolved::Id(s), Resolved::Id(d))
+                    if existing.source_zone_id.as_ref() == Some(s)
+                        && existing.destination_zone_id.as_ref() == Some(d)
+            );
+            if !zones_match {
+                self.warn(kind, &spec.name, "source/destination zone");
+            }
+
+            let mut fields = Vec::new();
+            let mut update = UpdateFirewallPolicyRequest::default();
+            if diff_field(&mut fields, "enabled", &existing.enabled, &spec.enabled) {
+                update.enabled = Some(spec.enabled);
+            }
+            if diff_field(&mut fields, "action", &existing.action, &spec.action) {
+                update.action = Some(spec.action);
+            }
+            if spec.description.is_some()
+                && diff_field(
+                    &mut fields,
+                    "description",
+                    &existing.description,
+                    &spec.description,
+                )
+            {
+                update.description.clone_from(&spec.description);
+            }
+            let id = existing.id.clone();
+            let cmd = Command::UpdateFirewallPolicy {
+                id: id.clone(),
+                update,
+            };
+            self.update(kind, &spec.name, &id, fields, Some(cmd));
+        }
+
+        let listed = specs.iter().map(|s| s.name.clone()).collect();
+        self.prune_unlisted(current, &listed);
+        Ok(())
+    }
+
+    // ── ACL rules ────────────────────────────────────────────────
+
+    fn acl_rules(
+        &mut self,
+        specs: &[AclRuleSpec],
+        current: &[Arc<AclRule>],
+    ) -> Result<(), CoreError> {
+        let kind = ResourceKind::AclRule;
+        ensure_unique(kind, specs.iter().map(|s| s.name.as_str()))?;
+
+        for spec in specs {
+            let owner = format!("{kind} '{}'", spec.name);
+            let source = self.refs.zone(&spec.source_zone, &owner)?;
+            let destination = self.refs.zone(&spec.destination_zone, &owner)?;
+
+            let Some(existing) = self.find(current, &spec.name) else {
+                let cmd = match (source, destination) {
+                    (Resolved::Id(source_zone_id), Resolved::Id(destination_zone_id)) => {
+                        Some(Command::CreateAclRule(CreateAclRuleRequest {
+                            name: spec.name.clone(),
+                            rule_type: match spec.rule_type {
+                                AclRuleType::Ipv4 => "IPV4",
+                                AclRuleType::Mac => "MAC",
+                            }
+                            .into(),
+                            action: acl_to_firewall_action(spec.action),
+                            source_zone_id,
+                            destination_zone_id,
+                            protocol: spec.protocol.clone(),
+                            source_port: spec.source_port.clone(),
+                            destination_port: spec.destination_port.clone(),
+                            enabled: spec.enabled,
+                        }))
+                    }
+                    _ => None,
+                };
+                self.create(kind, &spec.name, spec, cmd);
+                continue;
+            };
+
+            if existing.rule_type != spec.rule_type {
+                self.warn(kind, &spec.name, "rule_type");
+            }
+            let zones_match = matches!(
+                (&source, &destination),
+                (Resolved::Id(s), Resolved::Id(d))
+                    if existing.source_zone_id.as_ref() == Some(s)
+                        && existing.destination_zone_id.as_ref() == Some(d)
+            );
+            if !zones_match {
+                self.warn(kind, &spec.name, "source/destination zone");
+            }
+
+            let mut fields = Vec::new();
+            let mut update = UpdateAclRuleRequest::default();
+            if diff_field(&mut fields, "enabled", &existing.enabled, &spec.enabled) {
+                update.enabled = Some(spec.enabled);
+            }
+            if diff_field(&mut fields, "action", &existing.action, &spec.action) {
+                update.action = Some(acl_to_firewall_action(spec.action));
+            }
+            let id = existing.id.clone();
+            let cmd = Command::UpdateAclRule {
+                id: id.clone(),
+                update,
+            };
+            self.update(kind, &spec.name, &id, fields, Some(cmd));
+        }
+
+        let listed = specs.iter().map(|s| s.name.clone()).collect();
+        self.prune_unlisted(current, &listed);
+        Ok(())
+    }
+
+    // ── DNS policies ─────────────────────────────────────────────
+
+    fn dns_policies(
+        &mut self,
+        specs: &[DnsPolicySpec],
+        current: &[Arc<DnsPolicy>],
+    ) -> Result<(), CoreError> {
+        let kind = ResourceKind::DnsPolicy;
+        let keys: Vec<String> = specs
+            .iter()
+            .map(|s| dns_key(s.policy_type, &s.domain))
+            .collect();
+        ensure_unique(kind, keys.iter().map(String::as_str))?;
+
+        for (spec, key) in specs.iter().zip(&keys) {
+            let Some(existing) = self.find(current, key) else {
+                let cmd = Command::CreateDnsPolicy(CreateDnsPolicyRequest {
+                    name: spec.domain.clone(),
+                    policy_type: spec.policy_type,
+                    enabled: spec.enabled,
+                    domains: Some(vec![spec.domain.clone()]),
+                    upstream: None,
+                    value: Some(spec.value.clone()),
+                    ttl_seconds: spec.ttl_seconds,
+                    priority: spec.priority,
+                });
+                self.create(kind, key, spec, Some(cmd));
+                continue;
+            };
+
+            let mut fields = Vec::new();
+            let mut update = UpdateDnsPolicyRequest::default();
+            if diff_field(&mut fields, "value", &existing.value, &spec.value) {
+                update.value = Some(spec.value.clone());
+            }
+            if spec.ttl_seconds.is_some()
+                && diff_field(
+                    &mut fields,
+                    "ttl_seconds",
+                    &existing.ttl_seconds,
+                    &spec.ttl_seconds,
+                )
+            {
+                update.ttl_seconds = spec.ttl_seconds;
+            }
+            let id = existing.id.clone();
+            let cmd = Command::UpdateDnsPolicy {
+                id: id.clone(),
+                update,
+            };
+            self.update(kind, key, &id, fields, Some(cmd));
+        }
+
+        let listed = keys.into_iter().collect();
+        self.prune_unlisted(current, &listed);
+        Ok(())
+    }
+
+    // ── Vouchers ─────────────────────────────────────────────────
+
+    /// Vouchers are immutable, so a batch is only ever topped up to
+    /// `count` unexpired vouchers; surplus vouchers are left alone.
+    fn vouchers(
+        &mut self,
+        specs: &[VoucherSpec],
+        current: &[Arc<Voucher>],
+    ) -> Result<(), CoreError> {
+        let kind = ResourceKind::Voucher;
+        ensure_unique(kind, specs.iter().map(|s| s.name.as_str()))?;
+
+        let active: Vec<Arc<Voucher>> = current.iter().filter(|v| !v.expired).cloned().collect();
+        for spec in specs {
+            let existing = active.iter().filter(|v| v.key() == spec.name).count();
+            let existing = u32::try_from(existing).unwrap_or(u32::MAX);
+            if existing >= spec.count {
+                continue;
+            }
+            let batch = VoucherSpec {
+                count: spec.count - existing,
+                ..spec.clone()
+            };
+            let cmd = Command::CreateVouchers(CreateVouchersRequest {
+                count: batch.count,
+                name: Some(spec.name.clone()),
+                time_limit_minutes: spec.time_limit_minutes,
+                data_usage_limit_mb: spec.data_usage_limit_mb,
+                rx_rate_limit_kbps: spec.rx_rate_limit_kbps,
+                tx_rate_limit_kbps: spec.tx_rate_limit_kbps,
+                authorized_guest_limit: spec.authorized_guest_limit,
+            });
+            self.create(kind, &spec.name, &batch, Some(cmd));
+        }
+
+        let listed = specs.iter().map(|s| s.name.clone()).collect();
+        self.prune_unlisted(&active, &listed);
+        Ok(())
+    }
+}
+
+fn acl_to_firewall_action(action: AclAction) -> FirewallAction {
+    match action {
+        AclAction::Allow => FirewallAction::Allow,
+        AclAction::Block => FirewallAction::Block,
+    }
+}
@@ -0,0 +1,251 @@
+// ── Desired-state documents ──
+//
+// Serde schema for the files consumed by `unifly plan` / `unifly apply`.
+// Resources refer to each other by *name*, never by ID, so the same
+// document can be applied to any site.
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::{
+    AclAction, AclRuleType, DnsPolicyType, FirewallAction, NetworkManagement, NetworkPurpose,
+    WifiBroadcastType, WifiSecurityMode,
+};
+
+/// Desired configuration for a single site.
+///
+/// Each section is optional: a section that is absent leaves that
+/// resource kind untouched, while a present (even empty) section puts
+/// the kind under management. With `prune` enabled, user-defined
+/// resources of a managed kind that are missing from the document are
+/// deleted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DesiredState {
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub prune: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub traffic_matching_lists: Option<Vec<TrafficMatchingListSpec>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub networks: Option<Vec<NetworkSpec>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firewall_zones: Option<Vec<FirewallZoneSpec>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wifi_broadcasts: Option<Vec<WifiBroadcastSpec>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firewall_policies: Option<Vec<FirewallPolicySpec>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acl_rules: Option<Vec<AclRuleSpec>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dns_policies: Option<Vec<DnsPolicySpec>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vouchers: Option<Vec<VoucherSpec>>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn is_true(v: &bool) -> bool {
+    *v
+}
+
+// ── Network ────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkSpec {
+    pub name: String,
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vlan_id: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub management: Option<NetworkManagement>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<NetworkPurpose>,
+    /// Gateway address with prefix, e.g. `10.0.30.1/24`. Only used on create.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subnet: Option<String>,
+    /// DHCP server settings. Only used on create.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dhcp: Option<DhcpSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub isolation_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub internet_access_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mdns_forwarding_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ipv6_enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DhcpSpec {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range_start: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range_stop: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lease_time_secs: Option<u32>,
+}
+
+// ── Firewall Zone ──────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FirewallZoneSpec {
+    pub name: String,
+    /// Member networks, by name.
+    #[serde(default)]
+    pub networks: Vec<String>,
+}
+
+// ── WiFi ───────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct WifiBroadcastSpec {
+    pub name: String,
+    /// Broadcast SSID; defaults to `name`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssid: Option<String>,
+    /// Associated network, by name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    pub security: WifiSecurityMode,
+    /// Write-only: sent on create, never compared against the controller.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passphrase: Option<String>,
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub enabled: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hidden: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub broadcast_type: Option<WifiBroadcastType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequencies_ghz: Option<Vec<f32>>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub band_steering: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub fast_roaming: bool,
+}
+
+// ── Firewall Policy ────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FirewallPolicySpec {
+    pub name: String,
+    pub action: FirewallAction,
+    /// Source zone, by name.
+    pub source_zone: String,
+    /// Destination zone, by name.
+    pub destination_zone: String,
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub logging_enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination_port: Option<String>,
+}
+
+// ── ACL Rule ───────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AclRuleSpec {
+    pub name: String,
+    #[serde(default = "default_acl_rule_type")]
+    pub rule_type: AclRuleType,
+    pub action: AclAction,
+    /// Source zone, by name.
+    pub source_zone: String,
+    /// Destination zone, by name.
+    pub destination_zone: String,
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_port: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination_port: Option<String>,
+}
+
+fn default_acl_rule_type() -> AclRuleType {
+    AclRuleType::Ipv4
+}
+
+// ── DNS Policy ─────────────────────────────────────────────────────
+
+/// DNS policies have no name of their own; they are keyed by
+/// `(policy_type, domain)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DnsPolicySpec {
+    pub policy_type: DnsPolicyType,
+    pub domain: String,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_seconds: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u16>,
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub enabled: bool,
+}
+
+// ── Traffic Matching List ──────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TrafficMatchingListSpec {
+    pub name: String,
+    /// List type: `PORTS`, `IPV4_ADDRESSES` or `IPV6_ADDRESSES`.
+    pub list_type: String,
+    #[serde(default)]
+    pub items: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+// ── Vouchers ───────────────────────────────────────────────────────
+
+/// A batch of hotspot vouchers sharing a name and limits.
+///
+/// Codes are generated by the controller, so applying a batch tops it
+/// up to `count` unexpired vouchers with fresh codes rather than
+/// copying codes between sites.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VoucherSpec {
+    pub name: String,
+    #[serde(default = "default_voucher_count")]
+    pub count: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_limit_minutes: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_usage_limit_mb: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorized_guest_limit: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rx_rate_limit_kbps: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_rate_limit_kbps: Option<u64>,
+}
+
+fn default_voucher_count() -> u32 {
+    1
+}
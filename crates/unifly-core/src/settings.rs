@@ -0,0 +1,510 @@
+// ── Site settings ──
+//
+// Site-wide configuration from Legacy `get/setting`: one section per key
+// (`ntp`, `mgmt`, `guest_access`, ...). The common sections get typed
+// views; every other section passes through untouched. `parse_value`
+// checks a `section.key=value` assignment against a small schema before
+// it is written back through `Command::UpdateSiteSettings`.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::error::CoreError;
+
+/// `ntp` — time servers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NtpSettings {
+    /// `auto` (controller defaults) or `manual`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub setting_preference: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ntp_server_1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ntp_server_2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ntp_server_3: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ntp_server_4: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// `country` — regulatory domain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CountrySettings {
+    /// ISO 3166-1 numeric code, stored as a string (`"840"`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// `snmp` — SNMP agent on the site's devices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnmpSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub community: Option<String>,
+    #[serde(rename = "enabledV3", default, skip_serializing_if = "Option::is_none")]
+    pub enabled_v3: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// `rsyslogd` — remote syslog target.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyslogSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// `auto_upgrade` — scheduled device firmware upgrades.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoUpgradeSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Hour of day (0-23, controller time) upgrades start.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hour: Option<u8>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// `ips` — intrusion detection / prevention.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpsSettings {
+    /// `disabled`, `ids` (detect only), `ips`, or `ipsInline`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ips_mode: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// `mgmt` — device management (LEDs, SSH).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MgmtSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub led_enabled: Option<bool>,
+    #[serde(
+        rename = "x_ssh_enabled",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ssh_enabled: Option<bool>,
+    #[serde(
+        rename = "x_ssh_auth_password_enabled",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ssh_password_auth: Option<bool>,
+    #[serde(
+        rename = "x_ssh_username",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ssh_username: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// `guest_access` — guest portal and authorization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuestAccessSettings {
+    /// Authentication method (`none`, `hotspot`, `custom`, ...).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub portal_enabled: Option<bool>,
+    /// Authorization lifetime in minutes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expire: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirect_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirect_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// All settings sections for a site, keyed by their Legacy section key
+/// when serialized.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SiteSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ntp: Option<NtpSettings>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<CountrySettings>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snmp: Option<SnmpSettings>,
+    #[serde(rename = "rsyslogd", default, skip_serializing_if = "Option::is_none")]
+    pub syslog: Option<SyslogSettings>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_upgrade: Option<AutoUpgradeSettings>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ips: Option<IpsSettings>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mgmt: Option<MgmtSettings>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guest_access: Option<GuestAccessSettings>,
+    /// Sections without a typed view, or whose fields did not match it.
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Map<String, Value>>,
+}
+
+impl SiteSettings {
+    /// Build from raw sections keyed by section key.
+    ///
+    /// A section whose fields don't fit its typed view (e.g. a string
+    /// where a number was expected) is kept in `other` instead.
+    pub fn from_sections(mut sections: BTreeMap<String, Map<String, Value>>) -> Self {
+        fn take<T: serde::de::DeserializeOwned>(
+            sections: &mut BTreeMap<String, Map<String, Value>>,
+            key: &str,
+        ) -> Option<T> {
+            let fields = sections.remove(key)?;
+            match serde_json::from_value(Value::Object(fields.clone())) {
+                Ok(typed) => Some(typed),
+                Err(e) => {
+                    tracing::debug!(key, error = %e, "settings section kept untyped");
+                    sections.insert(key.to_owned(), fields);
+                    None
+                }
+            }
+        }
+
+        Self {
+            ntp: take(&mut sections, "ntp"),
+            country: take(&mut sections, "country"),
+            snmp: take(&mut sections, "snmp"),
+            syslog: take(&mut sections, "rsyslogd"),
+            auto_upgrade: take(&mut sections, "auto_upgrade"),
+            ips: take(&mut sections, "ips"),
+            mgmt: take(&mut sections, "mgmt"),
+            guest_access: take(&mut sections, "guest_access"),
+            other: sections,
+        }
+    }
+
+    /// Every section's fields, keyed by section key.
+    pub fn sections(&self) -> BTreeMap<String, Map<String, Value>> {
+        match serde_json::to_value(self) {
+            Ok(Value::Object(map)) => map
+                .into_iter()
+                .filter_map(|(key, v)| match v {
+                    Value::Object(fields) => Some((key, fields)),
+                    _ => None,
+                })
+                .collect(),
+            _ => BTreeMap::new(),
+        }
+    }
+
+    /// One section's fields. Accepts the aliases from [`section_key`].
+    pub fn section(&self, name: &str) -> Option<Map<String, Value>> {
+        self.sections().remove(section_key(name))
+    }
+}
+
+/// Legacy section key for a user-facing section name (`syslog` is
+/// stored as `rsyslogd`).
+pub fn section_key(name: &str) -> &str {
+    match name {
+        "syslog" => "rsyslogd",
+        other => other,
+    }
+}
+
+// ── Validation ──────────────────────────────────────────────────────
+
+/// Value type of a known setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingKind {
+    Bool,
+    Integer {
+        min: i64,
+        max: i64,
+    },
+    /// A whole number the controller stores as a string.
+    NumericText {
+        min: i64,
+        max: i64,
+    },
+    Text,
+    /// IP address or hostname; empty clears it.
+    Host,
+    OneOf(&'static [&'static str]),
+}
+
+/// A setting with a known type.
+#[derive(Debug, Clone, Copy)]
+pub struct SettingField {
+    pub section: &'static str,
+    pub key: &'static str,
+    pub kind: SettingKind,
+}
+
+const fn field(section: &'static str, key: &'static str, kind: SettingKind) -> SettingField {
+    SettingField { section, key, kind }
+}
+
+/// Settings whose values are checked against a type. Other keys that
+/// already exist in a section take the type of their current value.
+pub const KNOWN_SETTINGS: &[SettingField] = &[
+    field(
+        "ntp",
+        "setting_preference",
+        SettingKind::OneOf(&["auto", "manual"]),
+    ),
+    field("ntp", "ntp_server_1", SettingKind::Host),
+    field("ntp", "ntp_server_2", SettingKind::Host),
+    field("ntp", "ntp_server_3", SettingKind::Host),
+    field("ntp", "ntp_server_4", SettingKind::Host),
+    field(
+        "country",
+        "code",
+        SettingKind::NumericText { min: 1, max: 999 },
+    ),
+    field("snmp", "enabled", SettingKind::Bool),
+    field("snmp", "community", SettingKind::Text),
+    field("snmp", "enabledV3", SettingKind::Bool),
+    field("snmp", "username", SettingKind::Text),
+    field("rsyslogd", "enabled", SettingKind::Bool),
+    field("rsyslogd", "ip", SettingKind::Host),
+    field(
+        "rsyslogd",
+        "port",
+        SettingKind::Integer { min: 1, max: 65535 },
+    ),
+    field("auto_upgrade", "enabled", SettingKind::Bool),
+    field(
+        "auto_upgrade",
+        "hour",
+        SettingKind::Integer { min: 0, max: 23 },
+    ),
+    field(
+        "ips",
+        "ips_mode",
+        SettingKind::OneOf(&["disabled", "ids", "ips", "ipsInline"]),
+    ),
+    field("mgmt", "led_enabled", SettingKind::Bool),
+    field("mgmt", "x_ssh_enabled", SettingKind::Bool),
+    field("mgmt", "x_ssh_auth_password_enabled", SettingKind::Bool),
+    field("mgmt", "x_ssh_username", SettingKind::Text),
+    field("guest_access", "auth", SettingKind::Text),
+    field("guest_access", "portal_enabled", SettingKind::Bool),
+    field(
+        "guest_access",
+        "expire",
+        SettingKind::Integer {
+            min: 1,
+            max: 525_600,
+        },
+    ),
+    field("guest_access", "redirect_enabled", SettingKind::Bool),
+    field("guest_access", "redirect_url", SettingKind::Text),
+];
+
+fn invalid(message: impl Into<String>) -> CoreError {
+    CoreError::ValidationFailed {
+        message: message.into(),
+    }
+}
+
+/// A `section.key=value` assignment, before validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingAssignment {
+    /// Legacy section key (aliases already resolved).
+    pub section: String,
+    pub key: String,
+    pub value: String,
+}
+
+impl FromStr for SettingAssignment {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (path, value) = s
+            .split_once('=')
+            .ok_or_else(|| invalid(format!("'{s}' is not section.key=value")))?;
+        let (section, key) = path
+            .trim()
+            .split_once('.')
+            .ok_or_else(|| invalid(format!("'{path}' is not section.key")))?;
+        if section.is_empty() || key.is_empty() {
+            return Err(invalid(format!("'{path}' is not section.key")));
+        }
+        Ok(Self {
+            section: section_key(section).to_owned(),
+            key: key.to_owned(),
+            value: value.trim().to_owned(),
+        })
+    }
+}
+
+fn parse_bool(raw: &str) -> Option<bool> {
+    match raw.to_ascii_lowercase().as_str() {
+        "true" | "yes" | "on" => Some(true),
+        "false" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_kind(path: &str, kind: SettingKind, raw: &str) -> Result<Value, CoreError> {
+    match kind {
+        SettingKind::Bool => parse_bool(raw)
+            .map(Value::Bool)
+            .ok_or_else(|| invalid(format!("{path} expects true or false, got '{raw}'"))),
+        SettingKind::Integer { min, max } => match raw.parse::<i64>() {
+            Ok(n) if (min..=max).contains(&n) => Ok(n.into()),
+            _ => Err(invalid(format!(
+                "{path} expects a whole number from {min} to {max}, got '{raw}'"
+            ))),
+        },
+        SettingKind::NumericText { min, max } => {
+            parse_kind(path, SettingKind::Integer { min, max }, raw).map(|n| n.to_string().into())
+        }
+        SettingKind::Text => Ok(raw.into()),
+        SettingKind::Host => {
+            let valid = raw
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':'));
+            if valid {
+                Ok(raw.into())
+            } else {
+                Err(invalid(format!(
+                    "{path} expects an IP address or hostname, got '{raw}'"
+                )))
+            }
+        }
+        SettingKind::OneOf(options) => options
+            .iter()
+            .find(|o| o.eq_ignore_ascii_case(raw))
+            .map(|o| Value::from(*o))
+            .ok_or_else(|| {
+                invalid(format!(
+                    "{path} expects one of {}, got '{raw}'",
+                    options.join(", ")
+                ))
+            }),
+    }
+}
+
+/// Validate and convert an assignment's value.
+///
+/// Known settings are checked against [`KNOWN_SETTINGS`]. Any other key
+/// must already exist in the section (`current` is its value) and is
+/// parsed to the same JSON type.
+pub fn parse_value(
+    assignment: &SettingAssignment,
+    current: Option<&Value>,
+) -> Result<Value, CoreError> {
+    let SettingAssignment {
+        section,
+        key,
+        value: raw,
+    } = assignment;
+    let path = format!("{section}.{key}");
+    if let Some(known) = KNOWN_SETTINGS
+        .iter()
+        .find(|f| f.section == section && f.key == key)
+    {
+        return parse_kind(&path, known.kind, raw);
+    }
+    match current {
+        None => Err(invalid(format!("unknown setting '{path}'"))),
+        Some(Value::Bool(_)) => parse_kind(&path, SettingKind::Bool, raw),
+        Some(Value::Number(_)) => raw
+            .parse::<i64>()
+            .map(Value::from)
+            .or_else(|_| raw.parse::<f64>().map(Value::from))
+            .map_err(|_| invalid(format!("{path} expects a number, got '{raw}'"))),
+        Some(Value::String(_)) => Ok(raw.as_str().into()),
+        Some(_) => serde_json::from_str(raw)
+            .map_err(|e| invalid(format!("{path} expects a JSON value: {e}"))),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sections(value: Value) -> BTreeMap<String, Map<String, Value>> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn typed_sections_and_passthrough() {
+        let settings = SiteSettings::from_sections(sections(json!({
+            "ntp": { "setting_preference": "manual", "ntp_server_1": "pool.ntp.org" },
+            "mgmt": { "x_ssh_enabled": true, "led_enabled": false, "x_mgmt_key": "k" },
+            "auto_upgrade": { "enabled": true, "hour": "three" },
+            "country": { "code": "840" },
+            "radius": { "enabled": false }
+        })));
+
+        let ntp = settings.ntp.as_ref().unwrap();
+        assert_eq!(ntp.ntp_server_1.as_deref(), Some("pool.ntp.org"));
+        let mgmt = settings.mgmt.as_ref().unwrap();
+        assert_eq!(mgmt.ssh_enabled, Some(true));
+        assert!(mgmt.extra.contains_key("x_mgmt_key"));
+        assert_eq!(
+            settings.country.as_ref().unwrap().code.as_deref(),
+            Some("840")
+        );
+        // Doesn't fit the typed view, so it passes through as-is.
+        assert!(settings.auto_upgrade.is_none());
+        assert_eq!(settings.other["auto_upgrade"]["hour"], "three");
+        assert!(settings.other.contains_key("radius"));
+
+        let mgmt = settings.section("mgmt").unwrap();
+        assert_eq!(mgmt["x_ssh_enabled"], true);
+        assert_eq!(mgmt["x_mgmt_key"], "k");
+        assert!(settings.section("syslog").is_none());
+    }
+
+    #[test]
+    fn assignments_are_validated() {
+        let parse =
+            |s: &str, current: Option<Value>| parse_value(&s.parse().unwrap(), current.as_ref());
+
+        let a: SettingAssignment = "syslog.port=514".parse().unwrap();
+        assert_eq!(a.section, "rsyslogd");
+        assert_eq!(parse_value(&a, None).unwrap(), json!(514));
+        assert!("ntp".parse::<SettingAssignment>().is_err());
+        assert!("ntp=1".parse::<SettingAssignment>().is_err());
+
+        assert_eq!(parse("mgmt.x_ssh_enabled=yes", None).unwrap(), json!(true));
+        assert_eq!(parse("ips.ips_mode=IPS", None).unwrap(), json!("ips"));
+        assert!(parse("ips.ips_mode=block", None).is_err());
+        assert!(parse("auto_upgrade.hour=24", None).is_err());
+        assert_eq!(parse("country.code=840", None).unwrap(), json!("840"));
+        assert!(parse("country.code=US", None).is_err());
+        assert!(parse("ntp.ntp_server_1=bad host", None).is_err());
+
+        // Keys outside the schema must exist and keep their type.
+        assert!(parse("mgmt.x_unknown=1", None).is_err());
+        assert_eq!(
+            parse("mgmt.alert_enabled=false", Some(json!(true))).unwrap(),
+            json!(false)
+        );
+        assert!(parse("mgmt.alert_enabled=maybe", Some(json!(true))).is_err());
+        assert_eq!(
+            parse("guest_access.payment_gateway=paypal", Some(json!(""))).unwrap(),
+            json!("paypal")
+        );
+    }
+}
@@ -12,7 +12,8 @@ use tokio::sync::watch;
 use super::collection::EntityCollection;
 use crate::model::{
     AclRule, Client, Device, DnsPolicy, EntityId, Event, FirewallPolicy, FirewallZone,
-    HealthSummary, MacAddress, Network, Site, TrafficMatchingList, Voucher, WifiBroadcast,
+    HealthSummary, MacAddress, Network, PortForward, Site, StaticRoute, TrafficMatchingList,
+    Voucher, WifiBroadcast,
 };
 use crate::stream::EntityStream;
 
@@ -34,6 +35,8 @@ pub struct DataStore {
     pub(crate) sites: EntityCollection<Site>,
     pub(crate) events: EntityCollection<Event>,
     pub(crate) traffic_matching_lists: EntityCollection<TrafficMatchingList>,
+    pub(crate) port_forwards: EntityCollection<PortForward>,
+    pub(crate) static_routes: EntityCollection<StaticRoute>,
     pub(crate) site_health: watch::Sender<Arc<Vec<HealthSummary>>>,
     /// Monthly WAN usage: (tx_bytes, rx_bytes) for the current month.
     pub(crate) monthly_wan_bytes: watch::Sender<(u64, u64)>,
@@ -64,6 +67,8 @@ impl DataStore {
             sites: EntityCollection::new(),
             events: EntityCollection::new(),
             traffic_matching_lists: EntityCollection::new(),
+            port_forwards: EntityCollection::new(),
+            static_routes: EntityCollection::new(),
             site_health,
             monthly_wan_bytes,
             client_daily_usage,
@@ -122,6 +127,14 @@ impl DataStore {
         self.traffic_matching_lists.snapshot()
     }
 
+    pub fn port_forwards_snapshot(&self) -> Arc<Vec<Arc<PortForward>>> {
+        self.port_forwards.snapshot()
+    }
+
+    pub fn static_routes_snapshot(&self) -> Arc<Vec<Arc<StaticRoute>>> {
+        self.static_routes.snapshot()
+    }
+
     // ── Single-entity lookups ────────────────────────────────────────
 
     pub fn device_by_mac(&self, mac: &MacAddress) -> Option<Arc<Device>> {
@@ -132,8 +145,16 @@ impl DataStore {
         self.devices.get_by_id(id)
     }
 
+    /// With every site loaded, clients are keyed by site as well; a MAC
+    /// seen on several sites returns one of its records.
     pub fn client_by_mac(&self, mac: &MacAddress) -> Option<Arc<Client>> {
-        self.clients.get_by_key(mac.as_str())
+        self.clients.get_by_key(mac.as_str()).or_else(|| {
+            self.clients
+                .snapshot()
+                .iter()
+                .find(|c| c.mac == *mac)
+                .cloned()
+        })
     }
 
     pub fn client_by_id(&self, id: &EntityId) -> Option<Arc<Client>> {
@@ -208,6 +229,14 @@ impl DataStore {
         EntityStream::new(self.traffic_matching_lists.subscribe())
     }
 
+    pub fn subscribe_port_forwards(&self) -> EntityStream<PortForward> {
+        EntityStream::new(self.port_forwards.subscribe())
+    }
+
+    pub fn subscribe_static_routes(&self) -> EntityStream<StaticRoute> {
+        EntityStream::new(self.static_routes.subscribe())
+    }
+
     // ── Site health ──────────────────────────────────────────────────
 
     pub fn site_health_snapshot(&self) -> Arc<Vec<HealthSummary>> {
@@ -10,8 +10,8 @@ use chrono::Utc;
 use super::DataStore;
 use super::collection::EntityCollection;
 use crate::model::{
-    AclRule, Device, DnsPolicy, EntityId, Event, FirewallPolicy, FirewallZone, Network, Site,
-    TrafficMatchingList, Voucher, WifiBroadcast,
+    AclRule, Client, Device, DnsPolicy, EntityId, Event, FirewallPolicy, FirewallZone, Network,
+    PortForward, Site, StaticRoute, TrafficMatchingList, Voucher, WifiBroadcast,
 };
 
 /// Upsert all incoming entities, then prune any existing keys not in the
@@ -33,8 +33,8 @@ fn upsert_and_prune<T: Clone + Send + Sync + 'static>(
 
 /// All collections fetched during a single refresh cycle.
 ///
-/// Client data is NOT included — it is managed exclusively by
-/// `client_poll_task` (Legacy API, 2s interval).
+/// Client data is NOT included — it is applied separately through
+/// [`DataStore::apply_clients`] by the client poll (or the all-sites refresh).
 pub(crate) struct RefreshSnapshot {
     pub devices: Vec<Device>,
     pub networks: Vec<Network>,
@@ -47,6 +47,8 @@ pub(crate) struct RefreshSnapshot {
     pub sites: Vec<Site>,
     pub events: Vec<Event>,
     pub traffic_matching_lists: Vec<TrafficMatchingList>,
+    pub port_forwards: Vec<PortForward>,
+    pub static_routes: Vec<StaticRoute>,
 }
 
 impl DataStore {
@@ -206,7 +208,118 @@ impl DataStore {
                 .collect(),
         );
 
+        upsert_and_prune(
+            &self.port_forwards,
+            snap.port_forwards
+                .into_iter()
+                .map(|p| {
+                    let key = format!("pfw:{}", p.id);
+                    let id = p.id.clone();
+                    (key, id, p)
+                })
+                .collect(),
+        );
+
+        upsert_and_prune(
+            &self.static_routes,
+            snap.static_routes
+                .into_iter()
+                .map(|r| {
+                    let key = format!("srt:{}", r.id);
+                    let id = r.id.clone();
+                    (key, id, r)
+                })
+                .collect(),
+        );
+
         let _ = self.last_full_refresh.send(Some(Utc::now()));
     }
 
+    /// Replace the client collection with a freshly polled list.
+    ///
+    /// Upserts silently and notifies subscribers once, after stale clients
+    /// (no longer connected) have been pruned.
+    pub(crate) fn apply_clients(&self, clients: Vec<Client>) {
+        self.replace_clients(clients, |c| c.mac.as_str().to_owned());
+    }
+
+    /// All-sites variant of [`apply_clients`](Self::apply_clients).
+    ///
+    /// Clients are keyed by site and MAC, so a MAC seen on two sites keeps
+    /// one record per site instead of the last site loaded overwriting
+    /// the others.
+    pub(crate) fn apply_site_clients(&self, clients: Vec<Client>) {
+        self.replace_clients(clients, |c| match &c.site_id {
+            Some(site) => format!("{site}/{}", c.mac),
+            None => c.mac.as_str().to_owned(),
+        });
+    }
+
+    fn replace_clients(&self, clients: Vec<Client>, key_of: impl Fn(&Client) -> String) {
+        let col = &self.clients;
+        let incoming_keys: HashSet<String> = clients.iter().map(&key_of).collect();
+        for client in clients {
+            let key = key_of(&client);
+            let id = client.id.clone();
+            col.upsert_silent(key, id, client);
+        }
+        let stale: Vec<String> = col
+            .keys()
+            .into_iter()
+            .filter(|k| !incoming_keys.contains(k))
+            .collect();
+        for key in &stale {
+            col.remove(key);
+        }
+        if stale.is_empty() {
+            col.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::MacAddress;
+
+    fn client(id: &str, mac: &str, site: &str) -> Client {
+        let raw: unifly_api::legacy::models::LegacyClientEntry =
+            serde_json::from_value(serde_json::json!({ "_id": id, "mac": mac }))
+                .expect("valid client entry");
+        Client {
+            site_id: Some(EntityId::from(site)),
+            ..Client::from(raw)
+        }
+    }
+
+    #[test]
+    fn all_sites_clients_keep_one_record_per_site() {
+        let store = DataStore::new();
+        store.apply_site_clients(vec![
+            client("c1", "aa:bb:cc:00:00:01", "s1"),
+            client("c2", "aa:bb:cc:00:00:01", "s2"),
+            client("c3", "aa:bb:cc:00:00:02", "s2"),
+        ]);
+        assert_eq!(store.client_count(), 3);
+
+        let mut sites: Vec<String> = store
+            .clients_snapshot()
+            .iter()
+            .filter(|c| c.mac == MacAddress::new("aa:bb:cc:00:00:01"))
+            .filter_map(|c| c.site_id.as_ref().map(ToString::to_string))
+            .collect();
+        sites.sort();
+        assert_eq!(sites, vec!["s1".to_owned(), "s2".to_owned()]);
+
+        // The roaming client left s1: only its s2 record remains.
+        store.apply_site_clients(vec![
+            client("c2", "aa:bb:cc:00:00:01", "s2"),
+            client("c3", "aa:bb:cc:00:00:02", "s2"),
+        ]);
+        assert_eq!(store.client_count(), 2);
+        let found = store
+            .client_by_mac(&MacAddress::new("aa:bb:cc:00:00:01"))
+            .expect("client found by MAC");
+        assert_eq!(found.site_id, Some(EntityId::from("s2")));
+    }
 }
@@ -1,6 +1,9 @@
 // ── Filter predicates for entity streams ──
 //
-// Used by the TUI to filter snapshots without re-querying the API.
+// Used by the TUI to filter snapshots without re-querying the API, and
+// by CLI bulk operations through `--selector` expressions.
+
+use std::collections::HashSet;
 
 use crate::model::{Client, ClientType, Device, DeviceState, DeviceType, EntityId, MacAddress};
 
@@ -10,8 +13,14 @@ pub enum DeviceFilter {
     ByType(DeviceType),
     ByState(DeviceState),
     BySite(EntityId),
+    /// Model code, compared case-insensitively (e.g. `U6-Lite`).
+    ByModel(String),
+    /// Member IDs of a device tag, resolved up front.
+    ByTag(HashSet<EntityId>),
     Online,
     Offline,
+    /// Every filter must match.
+    AllOf(Vec<DeviceFilter>),
     Custom(Box<dyn Fn(&Device) -> bool + Send + Sync>),
 }
 
@@ -21,12 +30,15 @@ impl DeviceFilter {
             Self::All => true,
             Self::ByType(dt) => device.device_type == *dt,
             Self::ByState(ds) => device.state == *ds,
-            // All devices in the DataStore belong to the configured site,
-            // so BySite is effectively a no-op in single-site mode.
-            // Multi-site support would require adding site_id to Device.
-            Self::BySite(_sid) => true,
+            Self::BySite(sid) => device.site_id.as_ref() == Some(sid),
+            Self::ByModel(model) => device
+                .model
+                .as_deref()
+                .is_some_and(|m| m.eq_ignore_ascii_case(model)),
+            Self::ByTag(members) => members.contains(&device.id),
             Self::Online => device.state.is_online(),
             Self::Offline => matches!(device.state, DeviceState::Offline),
+            Self::AllOf(filters) => filters.iter().all(|f| f.matches(device)),
             Self::Custom(f) => f(device),
         }
     }
@@ -38,8 +50,17 @@ pub enum ClientFilter {
     ByType(ClientType),
     ByNetwork(EntityId),
     ByDevice(MacAddress),
+    BySite(EntityId),
+    /// Wireless clients on this SSID, compared case-insensitively.
+    BySsid(String),
+    /// Wireless clients with a signal weaker than this (dBm).
+    SignalBelow(i32),
+    /// Wireless clients with a signal stronger than this (dBm).
+    SignalAbove(i32),
     Guests,
     Blocked,
+    /// Every filter must match.
+    AllOf(Vec<ClientFilter>),
     Custom(Box<dyn Fn(&Client) -> bool + Send + Sync>),
 }
 
@@ -50,9 +71,22 @@ impl ClientFilter {
             Self::ByType(ct) => client.client_type == *ct,
             Self::ByNetwork(nid) => client.network_id.as_ref() == Some(nid),
             Self::ByDevice(mac) => client.uplink_device_mac.as_ref() == Some(mac),
+            Self::BySite(sid) => client.site_id.as_ref() == Some(sid),
+            Self::BySsid(ssid) => client
+                .wireless
+                .as_ref()
+                .and_then(|w| w.ssid.as_deref())
+                .is_some_and(|s| s.eq_ignore_ascii_case(ssid)),
+            Self::SignalBelow(dbm) => signal(client).is_some_and(|s| s < *dbm),
+            Self::SignalAbove(dbm) => signal(client).is_some_and(|s| s > *dbm),
             Self::Guests => client.is_guest,
             Self::Blocked => client.blocked,
+            Self::AllOf(filters) => filters.iter().all(|f| f.matches(client)),
             Self::Custom(f) => f(client),
         }
     }
 }
+
+fn signal(client: &Client) -> Option<i32> {
+    client.wireless.as_ref().and_then(|w| w.signal_dbm)
+}
@@ -3,6 +3,7 @@
 // Subscription types for consuming entity changes from the DataStore.
 
 mod filter;
+mod selector;
 
 use std::pin::Pin;
 use std::sync::Arc;
@@ -13,6 +14,7 @@ use tokio::sync::watch;
 use tokio_stream::wrappers::WatchStream;
 
 pub use filter::{ClientFilter, DeviceFilter};
+pub use selector::{Selector, SelectorOp, SelectorTerm};
 
 /// A subscription to a collection of entities.
 ///
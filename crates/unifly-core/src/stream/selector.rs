@@ -0,0 +1,406 @@
+// ── Selector expressions ──
+//
+// Comma-separated `key=value` terms (`type=ap,tag=floor2`,
+// `ssid=Guest,signal<-75`) parsed into `DeviceFilter` / `ClientFilter`
+// predicates. All terms must match.
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use super::filter::{ClientFilter, DeviceFilter};
+use crate::error::CoreError;
+use crate::model::{ClientType, DeviceState, DeviceType, EntityId, MacAddress};
+
+/// Comparison in a selector term.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectorOp {
+    Eq,
+    Lt,
+    Gt,
+}
+
+/// One `key<op>value` term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorTerm {
+    pub key: String,
+    pub op: SelectorOp,
+    pub value: String,
+}
+
+/// A parsed selector expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    pub terms: Vec<SelectorTerm>,
+}
+
+fn invalid(message: impl Into<String>) -> CoreError {
+    CoreError::ValidationFailed {
+        message: message.into(),
+    }
+}
+
+impl FromStr for Selector {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let terms = s
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(|term| {
+                let pos = term
+                    .find(['=', '<', '>'])
+                    .ok_or_else(|| invalid(format!("selector term '{term}' has no =, < or >")))?;
+                let op = match &term[pos..=pos] {
+                    "<" => SelectorOp::Lt,
+                    ">" => SelectorOp::Gt,
+                    _ => SelectorOp::Eq,
+                };
+                let key = term[..pos].trim().to_ascii_lowercase();
+                let value = term[pos + 1..].trim().to_owned();
+                if key.is_empty() || value.is_empty() {
+                    return Err(invalid(format!("selector term '{term}' is incomplete")));
+                }
+                Ok(SelectorTerm { key, op, value })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if terms.is_empty() {
+            return Err(invalid("selector is empty"));
+        }
+        Ok(Self { terms })
+    }
+}
+
+impl SelectorTerm {
+    fn require_eq(&self) -> Result<&str, CoreError> {
+        if self.op == SelectorOp::Eq {
+            Ok(&self.value)
+        } else {
+            Err(invalid(format!(
+                "selector key '{}' only supports '='",
+                self.key
+            )))
+        }
+    }
+
+    fn bool_value(&self) -> Result<bool, CoreError> {
+        match self.require_eq()?.to_ascii_lowercase().as_str() {
+            "true" | "yes" => Ok(true),
+            "false" | "no" => Ok(false),
+            other => Err(invalid(format!(
+                "selector key '{}' expects true or false, got '{other}'",
+                self.key
+            ))),
+        }
+    }
+}
+
+impl Selector {
+    /// Tag names or IDs referenced by `tag=` terms, to resolve before
+    /// calling [`device_filter`](Self::device_filter).
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.terms
+            .iter()
+            .filter(|t| t.key == "tag")
+            .map(|t| t.value.as_str())
+    }
+
+    /// Build a device filter.
+    ///
+    /// Keys: `type`, `state`, `model`, `tag`, `name`, `mac`. `tag_members`
+    /// maps a tag name or ID to its member device IDs.
+    pub fn device_filter(
+        &self,
+        tag_members: impl Fn(&str) -> Option<HashSet<EntityId>>,
+    ) -> Result<DeviceFilter, CoreError> {
+        let filters = self
+            .terms
+            .iter()
+            .map(|term| {
+                let value = term.require_eq()?;
+                Ok(match term.key.as_str() {
+                    "type" => DeviceFilter::ByType(parse_device_type(value)?),
+                    "state" => DeviceFilter::ByState(parse_device_state(value)?),
+                    "model" => DeviceFilter::ByModel(value.to_owned()),
+                    "tag" => DeviceFilter::ByTag(
+                        tag_members(value)
+                            .ok_or_else(|| invalid(format!("device tag '{value}' not found")))?,
+                    ),
+                    "name" => {
+                        let name = value.to_owned();
+                        DeviceFilter::Custom(Box::new(move |d| {
+                            d.name
+                                .as_deref()
+                                .is_some_and(|n| n.eq_ignore_ascii_case(&name))
+                        }))
+                    }
+                    "mac" => {
+                        let mac = MacAddress::new(value);
+                        DeviceFilter::Custom(Box::new(move |d| d.mac == mac))
+                    }
+                    other => {
+                        return Err(invalid(format!(
+                            "unknown device selector key '{other}' \
+                             (expected type, state, model, tag, name or mac)"
+                        )));
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, CoreError>>()?;
+        Ok(DeviceFilter::AllOf(filters))
+    }
+
+    /// Build a client filter.
+    ///
+    /// Keys: `ssid`, `signal` (with `=`, `<` or `>`, in dBm), `type`,
+    /// `network`, `ap` (uplink device MAC), `guest`, `blocked`.
+    pub fn client_filter(&self) -> Result<ClientFilter, CoreError> {
+        let filters = self
+            .terms
+            .iter()
+            .map(|term| {
+                if term.key == "signal" {
+                    let dbm: i32 = term.value.parse().map_err(|_| {
+                        invalid(format!("signal expects dBm, got '{}'", term.value))
+                    })?;
+                    return Ok(match term.op {
+                        SelectorOp::Lt => ClientFilter::SignalBelow(dbm),
+                        SelectorOp::Gt => ClientFilter::SignalAbove(dbm),
+                        SelectorOp::Eq => ClientFilter::Custom(Box::new(move |c| {
+                            c.wireless.as_ref().and_then(|w| w.signal_dbm) == Some(dbm)
+                        })),
+                    });
+                }
+                Ok(match term.key.as_str() {
+                    "ssid" => ClientFilter::BySsid(term.require_eq()?.to_owned()),
+                    "type" => ClientFilter::ByType(parse_client_type(term.require_eq()?)?),
+                    "network" => {
+                        let network = term.require_eq()?.to_owned();
+                        ClientFilter::Custom(Box::new(move |c| {
+                            c.network_name
+                                .as_deref()
+                                .is_some_and(|n| n.eq_ignore_ascii_case(&network))
+                        }))
+                    }
+                    "ap" => ClientFilter::ByDevice(MacAddress::new(term.require_eq()?)),
+                    "guest" if term.bool_value()? => ClientFilter::Guests,
+                    "guest" => ClientFilter::Custom(Box::new(|c| !c.is_guest)),
+                    "blocked" if term.bool_value()? => ClientFilter::Blocked,
+                    "blocked" => ClientFilter::Custom(Box::new(|c| !c.blocked)),
+                    other => {
+                        return Err(invalid(format!(
+                            "unknown client selector key '{other}' \
+                             (expected ssid, signal, type, network, ap, guest or blocked)"
+                        )));
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, CoreError>>()?;
+        Ok(ClientFilter::AllOf(filters))
+    }
+}
+
+fn parse_device_type(value: &str) -> Result<DeviceType, CoreError> {
+    match value.to_ascii_lowercase().as_str() {
+        "ap" | "uap" | "accesspoint" => Ok(DeviceType::AccessPoint),
+        "switch" | "usw" => Ok(DeviceType::Switch),
+        "gateway" | "ugw" | "udm" => Ok(DeviceType::Gateway),
+        "other" => Ok(DeviceType::Other),
+        _ => Err(invalid(format!(
+            "unknown device type '{value}' (expected ap, switch, gateway or other)"
+        ))),
+    }
+}
+
+fn parse_device_state(value: &str) -> Result<DeviceState, CoreError> {
+    match value.to_ascii_lowercase().as_str() {
+        "online" => Ok(DeviceState::Online),
+        "offline" => Ok(DeviceState::Offline),
+        "pending" | "pendingadoption" => Ok(DeviceState::PendingAdoption),
+        "updating" => Ok(DeviceState::Updating),
+        "isolated" => Ok(DeviceState::Isolated),
+        _ => Err(invalid(format!(
+            "unknown device state '{value}' \
+             (expected online, offline, pending, updating or isolated)"
+        ))),
+    }
+}
+
+fn parse_client_type(value: &str) -> Result<ClientType, CoreError> {
+    match value.to_ascii_lowercase().as_str() {
+        "wired" => Ok(ClientType::Wired),
+        "wireless" | "wifi" => Ok(ClientType::Wireless),
+        "vpn" => Ok(ClientType::Vpn),
+        "teleport" => Ok(ClientType::Teleport),
+        _ => Err(invalid(format!(
+            "unknown client type '{value}' (expected wired, wireless, vpn or teleport)"
+        ))),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::model::{Client, Device};
+
+    fn client(ssid: Option<&str>, signal: Option<i32>) -> Client {
+        serde_json::from_value(serde_json::json!({
+            "id": "c1",
+            "mac": "aa:bb:cc:00:00:10",
+            "ip": null,
+            "name": null,
+            "hostname": null,
+            "client_type": if ssid.is_some() { "Wireless" } else { "Wired" },
+            "connected_at": null,
+            "uplink_device_id": null,
+            "uplink_device_mac": null,
+            "network_id": null,
+            "vlan": null,
+            "wireless": ssid.map(|s| serde_json::json!({
+                "ssid": s, "bssid": null, "channel": null, "frequency_ghz": null,
+                "signal_dbm": signal, "noise_dbm": null, "satisfaction": null,
+                "tx_rate_kbps": null, "rx_rate_kbps": null
+            })),
+            "guest_auth": null,
+            "is_guest": false,
+            "tx_bytes": null,
+            "rx_bytes": null,
+            "bandwidth": null,
+            "oui": null,
+            "network_name": null,
+            "sw_port": null,
+            "os_name": null,
+            "device_class": null,
+            "blocked": false,
+            "fixed_ip": null,
+            "note": null,
+            "site_id": null
+        }))
+        .unwrap()
+    }
+
+    fn device(mac: &str, kind: &str, model: &str) -> Device {
+        serde_json::from_value(serde_json::json!({
+            "id": mac,
+            "mac": mac,
+            "ip": null,
+            "wan_ipv6": null,
+            "name": null,
+            "model": model,
+            "device_type": kind,
+            "state": "Online",
+            "firmware_version": null,
+            "firmware_updatable": false,
+            "firmware_target": null,
+            "adopted_at": null,
+            "provisioned_at": null,
+            "last_seen": null,
+            "serial": null,
+            "supported": true,
+            "ports": [],
+            "radios": [],
+            "uplink_device_id": null,
+            "uplink_device_mac": null,
+            "has_switching": false,
+            "has_access_point": false,
+            "stats": {
+                "uptime_secs": null,
+                "cpu_utilization_pct": null,
+                "memory_utilization_pct": null,
+                "load_average_1m": null,
+                "load_average_5m": null,
+                "load_average_15m": null,
+                "uplink_bandwidth": null,
+                "last_heartbeat": null,
+                "next_heartbeat": null
+            },
+            "client_count": null,
+            "site_id": null,
+            "origin": null
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_terms_and_operators() {
+        let sel: Selector = "type=ap, tag = floor 2,signal<-75".parse().unwrap();
+        assert_eq!(sel.terms.len(), 3);
+        assert_eq!(sel.terms[1].value, "floor 2");
+        assert_eq!(sel.terms[2].op, SelectorOp::Lt);
+        assert_eq!(sel.terms[2].value, "-75");
+        assert_eq!(sel.tags().collect::<Vec<_>>(), vec!["floor 2"]);
+
+        assert!("".parse::<Selector>().is_err());
+        assert!("type".parse::<Selector>().is_err());
+        assert!("type=".parse::<Selector>().is_err());
+    }
+
+    #[test]
+    fn client_selector_matches_ssid_and_signal() {
+        let filter = "ssid=guest,signal<-75"
+            .parse::<Selector>()
+            .unwrap()
+            .client_filter()
+            .unwrap();
+        assert!(filter.matches(&client(Some("Guest"), Some(-80))));
+        assert!(!filter.matches(&client(Some("Guest"), Some(-60))));
+        assert!(!filter.matches(&client(Some("Office"), Some(-80))));
+        assert!(!filter.matches(&client(None, None)));
+    }
+
+    #[test]
+    fn device_selector_matches_type_tag_and_model() {
+        let lobby = device("aa:bb:cc:00:00:01", "AccessPoint", "U6-Lite");
+        let office = device("aa:bb:cc:00:00:02", "AccessPoint", "U6-Lite");
+        let pro = device("aa:bb:cc:00:00:03", "AccessPoint", "U6-Pro");
+        let switch = device("aa:bb:cc:00:00:04", "Switch", "U6-Lite");
+        let floor2: HashSet<EntityId> = [&lobby, &pro, &switch]
+            .iter()
+            .map(|d| d.id.clone())
+            .collect();
+
+        let filter = "type=ap,tag=floor 2,model=u6-lite"
+            .parse::<Selector>()
+            .unwrap()
+            .device_filter(|tag| (tag == "floor 2").then(|| floor2.clone()))
+            .unwrap();
+        assert!(filter.matches(&lobby));
+        assert!(!filter.matches(&office));
+        assert!(!filter.matches(&pro));
+        assert!(!filter.matches(&switch));
+    }
+
+    #[test]
+    fn device_selector_rejects_unknown_keys_and_tags() {
+        let no_tags = |_: &str| None;
+        assert!(
+            "colour=red"
+                .parse::<Selector>()
+                .unwrap()
+                .device_filter(no_tags)
+                .is_err()
+        );
+        assert!(
+            "tag=floor2"
+                .parse::<Selector>()
+                .unwrap()
+                .device_filter(no_tags)
+                .is_err()
+        );
+        assert!(
+            "type>ap"
+                .parse::<Selector>()
+                .unwrap()
+                .device_filter(no_tags)
+                .is_err()
+        );
+        assert!(
+            "type=ap,model=U6-Lite"
+                .parse::<Selector>()
+                .unwrap()
+                .device_filter(no_tags)
+                .is_ok()
+        );
+    }
+}
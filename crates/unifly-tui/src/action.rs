@@ -1,13 +1,28 @@
 //! All possible UI actions. Actions are the sole mechanism for state mutation.
 
+use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
 
-use unifly_core::model::{AclRule, EventCategory, FirewallPolicy, FirewallZone, WifiBroadcast};
-use unifly_core::{Client, Device, EntityId, Event, Network, Site, UpdateNetworkRequest};
+use unifly_core::fleet::ControllerStatus;
+use unifly_core::model::{
+    AclRule, EventCategory, FirewallPolicy, FirewallZone, NeighborAp, PortProfile, WifiBroadcast,
+};
+use unifly_core::{
+    Client, Device, EntityId, Event, MacAddress, Network, Site, SpectrumScan, ThreatEvent,
+    UpdateNetworkRequest, UpdatePortRequest,
+};
 
 use crate::screen::ScreenId;
 
+/// Choices for the port editor that are not in the store: port profiles,
+/// and networks keyed by the legacy id switch ports reference them by.
+#[derive(Debug, Default)]
+pub struct PortOptions {
+    pub profiles: Vec<PortProfile>,
+    pub networks_by_legacy_id: HashMap<EntityId, Arc<Network>>,
+}
+
 /// Direction for reorder operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
@@ -158,6 +173,7 @@ pub enum ConfirmAction {
     UnadoptDevice { id: EntityId, name: String },
     AdoptDevice { mac: String },
     PowerCyclePort { device_id: EntityId, port_idx: u32 },
+    SpectrumScan { id: EntityId, name: String },
     BlockClient { id: EntityId, name: String },
     UnblockClient { id: EntityId, name: String },
     ForgetClient { id: EntityId, name: String },
@@ -173,6 +189,12 @@ impl fmt::Display for ConfirmAction {
             }
             Self::AdoptDevice { mac } => write!(f, "Adopt device {mac}?"),
             Self::PowerCyclePort { port_idx, .. } => write!(f, "Power cycle port {port_idx}?"),
+            Self::SpectrumScan { name, .. } => {
+                write!(
+                    f,
+                    "Scan spectrum on {name}? Its radios go offline for a few minutes."
+                )
+            }
             Self::BlockClient { name, .. } => write!(f, "Block {name}?"),
             Self::UnblockClient { name, .. } => write!(f, "Unblock {name}?"),
             Self::ForgetClient { name, .. } => {
@@ -212,6 +234,31 @@ pub enum Action {
     MonthlyWanUsage(u64, u64),
     ClientDailyUsageUpdated(Arc<std::collections::HashMap<String, (u64, u64)>>),
     SiteUpdated(Arc<Site>),
+    SitesUpdated(Arc<Vec<Arc<Site>>>),
+    /// The controller now has this site loaded (internal name or `all`).
+    SiteSwitched(String),
+
+    // ── Sites ─────────────────────────────────────────────────────
+    OpenSitePicker,
+    /// Load another site (internal name or `all`) without reconnecting.
+    SwitchSite(String),
+
+    // ── Fleet ─────────────────────────────────────────────────────
+    /// Open the fleet dashboard and probe every configured profile.
+    OpenFleet,
+    /// Probe again, cycling the tag filter when `true`.
+    RefreshFleet {
+        next_tag: bool,
+    },
+    FleetUpdated(Arc<Vec<ControllerStatus>>),
+
+    // ── Neighbor APs ──────────────────────────────────────────────
+    /// Open the neighbor AP list and scan results from the controller.
+    OpenNeighbors,
+    RefreshNeighbors,
+    NeighborsUpdated(Result<Arc<Vec<NeighborAp>>, String>),
+    /// Flip the known-rogue flag on a BSSID and save it to the profile.
+    ToggleKnownRogue(MacAddress),
 
     // ── Connection Status ─────────────────────────────────────────
     Connected,
@@ -240,11 +287,22 @@ pub enum Action {
     NetworkEditResult(Result<(), String>),
 
     // ── Device Commands ───────────────────────────────────────────
+    /// Apply a port override: device, port index, changes.
+    PortSave(EntityId, u32, Box<UpdatePortRequest>),
+    /// Fetch port profiles and legacy network ids; results arrive as
+    /// `PortOptionsUpdated`.
+    FetchPortOptions,
+    PortOptionsUpdated(Result<Arc<PortOptions>, String>),
     RequestRestart(EntityId),
     RequestLocate(EntityId),
     RequestAdopt(String),
     RequestUnadopt(EntityId),
     RequestPortPowerCycle(EntityId, u32),
+    /// Start an RF spectrum scan on an AP (asks for confirmation first).
+    RequestSpectrumScan(EntityId),
+    /// Fetch an AP's latest spectrum scan; results arrive as `SpectrumUpdated`.
+    FetchSpectrum(EntityId),
+    SpectrumUpdated(EntityId, Result<Option<Arc<SpectrumScan>>, String>),
 
     // ── Client Commands ───────────────────────────────────────────
     RequestBlockClient(EntityId),
@@ -285,6 +343,10 @@ pub enum Action {
     ToggleEventPause,
     FilterEventType(Option<String>),
     FilterEventCategory(Option<EventCategory>),
+    /// Load IDS/IPS detections from the last 24 hours; results arrive as
+    /// `ThreatsUpdated`.
+    FetchThreats,
+    ThreatsUpdated(Result<Arc<Vec<ThreatEvent>>, String>),
 
     // ── Table Operations ──────────────────────────────────────────
     SortColumn(SortField),
@@ -301,6 +363,8 @@ pub enum Action {
         config: Box<unifly_core::ControllerConfig>,
     },
     OnboardingTestResult(Result<(), String>),
+    /// The test login was refused until a two-factor code is supplied.
+    OnboardingTwoFactorRequired,
 
     // ── Settings ────────────────────────────────────────────────────
     OpenSettings,
@@ -1,6 +1,7 @@
 //! Application core — event loop, screen management, action dispatch.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use color_eyre::eyre::Result;
@@ -10,15 +11,18 @@ use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Clear, Paragraph, Tabs},
+    widgets::{Block, BorderType, Borders, Cell, Clear, Paragraph, Row, Table, Tabs},
 };
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 
-use unifly_core::{Command, Controller, EntityId, MacAddress};
+use unifly_core::fleet::{self, ControllerStatus, FleetTarget};
+use unifly_core::history::{HistoryQuery, HistoryStore, Sample, SampleKind};
+use unifly_core::model::NeighborAp;
+use unifly_core::{ALL_SITES, Command, Controller, EntityId, MacAddress, Site};
 
-use crate::action::{Action, ConfirmAction, Notification};
+use crate::action::{Action, ConfirmAction, Notification, PortOptions};
 use crate::component::Component;
 use crate::event::{Event, EventReader};
 use crate::screen::ScreenId;
@@ -78,6 +82,52 @@ pub struct App {
     last_stats_fetch: Option<std::time::Instant>,
     /// Currently selected stats period — preserved for auto-refresh.
     stats_period: crate::action::StatsPeriod,
+    /// Sites on the controller, offered by the site picker.
+    sites: Arc<Vec<Arc<Site>>>,
+    /// Site the controller has loaded (internal name or `all`).
+    active_site: String,
+    /// Site picker cursor — `Some` while the picker is open. Entry 0 is
+    /// "All sites", followed by `sites`.
+    site_picker: Option<usize>,
+    /// Fleet dashboard — `Some` while the overlay is open.
+    fleet: Option<FleetView>,
+    /// Neighbor AP list — `Some` while the overlay is open.
+    neighbors: Option<NeighborsView>,
+}
+
+/// State of the fleet dashboard overlay.
+#[derive(Default)]
+struct FleetView {
+    /// Only profiles carrying this tag are probed.
+    tag: Option<String>,
+    /// `None` while a probe is in flight.
+    statuses: Option<Arc<Vec<ControllerStatus>>>,
+}
+
+/// State of the neighbor AP overlay.
+#[derive(Default)]
+struct NeighborsView {
+    /// `None` while a scan listing is in flight; sorted strongest first.
+    aps: Option<Result<Arc<Vec<NeighborAp>>, String>>,
+    cursor: usize,
+    /// BSSIDs flagged as known rogues in the active profile.
+    known: HashSet<MacAddress>,
+}
+
+impl NeighborsView {
+    fn selected(&self) -> Option<&NeighborAp> {
+        match self.aps {
+            Some(Ok(ref aps)) => aps.get(self.cursor),
+            _ => None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self.aps {
+            Some(Ok(ref aps)) => aps.len(),
+            _ => 0,
+        }
+    }
 }
 
 impl App {
@@ -120,6 +170,11 @@ impl App {
             stats_generation: std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0)),
             last_stats_fetch: None,
             stats_period: crate::action::StatsPeriod::default(),
+            sites: Arc::new(Vec::new()),
+            active_site: String::new(),
+            site_picker: None,
+            fleet: None,
+            neighbors: None,
         }
     }
 
@@ -205,6 +260,7 @@ impl App {
 
     /// Map a key event to an action. Global keys are handled here;
     /// screen-specific keys are delegated to the active screen component.
+    #[allow(clippy::too_many_lines)]
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         // Onboarding wizard captures all keys except Ctrl+C
         if self.active_screen == ScreenId::Setup {
@@ -237,6 +293,67 @@ impl App {
             };
         }
 
+        // Site picker captures all input
+        if let Some(cursor) = self.site_picker {
+            let count = self.sites.len() + 1;
+            return match key.code {
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.site_picker = Some((cursor + 1) % count);
+                    Ok(None)
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.site_picker = Some((cursor + count - 1) % count);
+                    Ok(None)
+                }
+                KeyCode::Enter => {
+                    self.site_picker = None;
+                    Ok(Some(Action::SwitchSite(self.site_picker_entry(cursor))))
+                }
+                KeyCode::Esc => {
+                    self.site_picker = None;
+                    Ok(None)
+                }
+                _ => Ok(None),
+            };
+        }
+
+        // Fleet dashboard captures all input
+        if self.fleet.is_some() {
+            return Ok(match key.code {
+                KeyCode::Char('r') => Some(Action::RefreshFleet { next_tag: false }),
+                KeyCode::Char('t') => Some(Action::RefreshFleet { next_tag: true }),
+                KeyCode::Esc | KeyCode::Char('F') => {
+                    self.fleet = None;
+                    None
+                }
+                _ => None,
+            });
+        }
+
+        // Neighbor AP list captures all input
+        if let Some(view) = self.neighbors.as_mut() {
+            let count = view.len();
+            return Ok(match key.code {
+                KeyCode::Char('j') | KeyCode::Down if count > 0 => {
+                    view.cursor = (view.cursor + 1) % count;
+                    None
+                }
+                KeyCode::Char('k') | KeyCode::Up if count > 0 => {
+                    view.cursor = (view.cursor + count - 1) % count;
+                    None
+                }
+                KeyCode::Char('f' | ' ') => view
+                    .selected()
+                    .map(|ap| Action::ToggleKnownRogue(ap.bssid.clone())),
+                KeyCode::Char('r') => Some(Action::RefreshNeighbors),
+                KeyCode::Esc | KeyCode::Char('N') => {
+                    self.neighbors = None;
+                    None
+                }
+                _ => None,
+            });
+        }
+
         // Global keys always take priority (except when search is active)
         if self.search_active {
             return match key.code {
@@ -280,6 +397,21 @@ impl App {
             // Settings
             (KeyModifiers::NONE, KeyCode::Char(',')) => return Ok(Some(Action::OpenSettings)),
 
+            // Site picker
+            (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char('S')) => {
+                return Ok(Some(Action::OpenSitePicker));
+            }
+
+            // Fleet dashboard
+            (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char('F')) => {
+                return Ok(Some(Action::OpenFleet));
+            }
+
+            // Neighbor APs
+            (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char('N')) => {
+                return Ok(Some(Action::OpenNeighbors));
+            }
+
             // Pause/resume data updates
             (KeyModifiers::NONE, KeyCode::Char('s')) => {
                 self.paused = !self.paused;
@@ -479,6 +611,31 @@ impl App {
                 }
             }
 
+            Action::RequestSpectrumScan(id) => {
+                let name = self.resolve_device_name(id);
+                self.action_tx
+                    .send(Action::ShowConfirm(ConfirmAction::SpectrumScan {
+                        id: id.clone(),
+                        name,
+                    }))?;
+            }
+
+            Action::FetchSpectrum(id) => self.fetch_spectrum(id.clone()),
+
+            Action::FetchPortOptions => self.fetch_port_options(),
+
+            Action::FetchThreats => self.fetch_threats(),
+
+            // Threat results belong to the Events screen even if the user
+            // has moved on before they arrive.
+            Action::ThreatsUpdated(_) => {
+                if let Some(screen) = self.screens.get_mut(&ScreenId::Events) {
+                    if let Some(follow_up) = screen.update(action)? {
+                        self.action_tx.send(follow_up)?;
+                    }
+                }
+            }
+
             // Destructive client commands → confirmation dialog
             Action::RequestBlockClient(id) => {
                 let name = self.resolve_client_name(id);
@@ -541,6 +698,18 @@ impl App {
                 );
             }
 
+            // Port editing → execute override command
+            Action::PortSave(device_id, port_idx, update) => {
+                self.execute_command(
+                    Command::UpdatePort {
+                        device_id: device_id.clone(),
+                        port_idx: *port_idx,
+                        update: *update.clone(),
+                    },
+                    format!("Updated port {port_idx}"),
+                );
+            }
+
             // Stats fetch
             Action::RequestStats(period) => {
                 self.stats_period = *period;
@@ -574,7 +743,7 @@ impl App {
                     .send(Action::Notify(Notification::success("Connected!")))?;
             }
 
-            Action::OnboardingTestResult(_) => {
+            Action::OnboardingTestResult(_) | Action::OnboardingTwoFactorRequired => {
                 // Forward to the setup screen
                 if let Some(screen) = self.screens.get_mut(&ScreenId::Setup) {
                     if let Some(follow_up) = screen.update(action)? {
@@ -646,6 +815,96 @@ impl App {
                 )))?;
             }
 
+            // ── Sites ─────────────────────────────────────────────────
+            Action::SitesUpdated(sites) => {
+                self.sites = Arc::clone(sites);
+            }
+
+            Action::SiteSwitched(site) => {
+                self.active_site.clone_from(site);
+            }
+
+            Action::OpenSitePicker => {
+                if self.controller.is_some() {
+                    let current = self
+                        .sites
+                        .iter()
+                        .position(|s| s.internal_name == self.active_site)
+                        .map_or(0, |i| i + 1);
+                    self.site_picker = Some(current);
+                }
+            }
+
+            Action::SwitchSite(site) => {
+                self.switch_site(site.clone());
+            }
+
+            // ── Fleet ─────────────────────────────────────────────────
+            Action::OpenFleet => {
+                self.fleet = Some(FleetView::default());
+                self.load_fleet();
+            }
+
+            Action::RefreshFleet { next_tag } => {
+                if let Some(view) = self.fleet.as_mut() {
+                    if *next_tag {
+                        view.tag = next_fleet_tag(view.tag.as_deref());
+                    }
+                    view.statuses = None;
+                    self.load_fleet();
+                }
+            }
+
+            Action::FleetUpdated(statuses) => {
+                if let Some(view) = self.fleet.as_mut() {
+                    view.statuses = Some(Arc::clone(statuses));
+                }
+            }
+
+            // ── Neighbor APs ──────────────────────────────────────────
+            Action::OpenNeighbors => {
+                self.neighbors = Some(NeighborsView {
+                    known: known_rogue_aps(),
+                    ..NeighborsView::default()
+                });
+                self.load_neighbors();
+            }
+
+            Action::RefreshNeighbors => {
+                if let Some(view) = self.neighbors.as_mut() {
+                    view.aps = None;
+                    self.load_neighbors();
+                }
+            }
+
+            Action::NeighborsUpdated(result) => {
+                if let Some(view) = self.neighbors.as_mut() {
+                    view.cursor = view
+                        .cursor
+                        .min(result.as_ref().map_or(0, |aps| aps.len().saturating_sub(1)));
+                    view.aps = Some(result.clone());
+                }
+            }
+
+            Action::ToggleKnownRogue(bssid) => {
+                if let Some(view) = self.neighbors.as_mut() {
+                    let known = !view.known.contains(bssid);
+                    match save_known_rogue(bssid, known) {
+                        Ok(()) => {
+                            if known {
+                                view.known.insert(bssid.clone());
+                            } else {
+                                view.known.remove(bssid);
+                            }
+                        }
+                        Err(e) => {
+                            self.action_tx
+                                .send(Action::Notify(Notification::error(e)))?;
+                        }
+                    }
+                }
+            }
+
             // Notifications
             Action::Notify(n) => {
                 self.notification = Some((n.clone(), Instant::now()));
@@ -724,6 +983,195 @@ impl App {
         });
     }
 
+    /// Start a spectrum scan, then fetch the scan state so the Radios tab
+    /// shows it as running.
+    fn start_spectrum_scan(&self, id: EntityId, mac: MacAddress, name: String) {
+        let Some(controller) = self.controller.clone() else {
+            let _ = self
+                .action_tx
+                .send(Action::Notify(Notification::error("Not connected")));
+            return;
+        };
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            match controller.execute(Command::SpectrumScan { mac }).await {
+                Ok(_) => {
+                    let _ = tx.send(Action::Notify(Notification::success(format!(
+                        "Spectrum scan started on {name}"
+                    ))));
+                    let _ = tx.send(Action::FetchSpectrum(id));
+                }
+                Err(e) => {
+                    warn!(error = %e, "spectrum scan failed to start");
+                    let _ = tx.send(Action::Notify(Notification::error(format!("{e}"))));
+                }
+            }
+        });
+    }
+
+    /// Fetch an AP's latest spectrum scan in the background. Results
+    /// arrive as `SpectrumUpdated`.
+    fn fetch_spectrum(&self, id: EntityId) {
+        let (Some(controller), Some(mac)) = (self.controller.clone(), self.resolve_device_mac(&id))
+        else {
+            return;
+        };
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            let result = controller
+                .get_spectrum_scan(&mac)
+                .await
+                .map(|scan| scan.map(Arc::new))
+                .map_err(|e| e.to_string());
+            let _ = tx.send(Action::SpectrumUpdated(id, result));
+        });
+    }
+
+    /// Fetch what the port editor offers beyond the store in the
+    /// background. Results arrive as `PortOptionsUpdated`.
+    fn fetch_port_options(&self) {
+        let Some(controller) = self.controller.clone() else {
+            return;
+        };
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            let result = async {
+                Ok::<_, unifly_core::CoreError>(PortOptions {
+                    profiles: controller.list_port_profiles().await?,
+                    networks_by_legacy_id: controller.networks_by_legacy_id().await?,
+                })
+            }
+            .await
+            .map(Arc::new)
+            .map_err(|e| e.to_string());
+            let _ = tx.send(Action::PortOptionsUpdated(result));
+        });
+    }
+
+    /// Fetch the last 24 hours of IDS/IPS detections in the background.
+    /// Results arrive as `ThreatsUpdated`.
+    fn fetch_threats(&self) {
+        let Some(controller) = self.controller.clone() else {
+            let _ = self
+                .action_tx
+                .send(Action::ThreatsUpdated(Err("Not connected".into())));
+            return;
+        };
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            let end = chrono::Utc::now();
+            let result = controller
+                .list_threat_events(end - chrono::TimeDelta::hours(24), end)
+                .await
+                .map(Arc::new)
+                .map_err(|e| e.to_string());
+            let _ = tx.send(Action::ThreatsUpdated(result));
+        });
+    }
+
+    /// Spawn a site switch. The data bridge streams in the new site's data.
+    fn switch_site(&self, site: String) {
+        let Some(controller) = self.controller.clone() else {
+            let _ = self
+                .action_tx
+                .send(Action::Notify(Notification::error("Not connected")));
+            return;
+        };
+        let label = self.site_label(&site);
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            match controller.switch_site(&site).await {
+                Ok(()) => {
+                    let _ = tx.send(Action::Notify(Notification::success(format!(
+                        "Switched to {label}"
+                    ))));
+                }
+                Err(e) => {
+                    warn!(error = %e, "site switch failed");
+                    let _ = tx.send(Action::Notify(Notification::error(format!("{e}"))));
+                }
+            }
+        });
+    }
+
+    /// Probe every configured profile (or the tagged subset) in the
+    /// background. Results arrive as `FleetUpdated`.
+    fn load_fleet(&self) {
+        let tags: Vec<String> = self
+            .fleet
+            .as_ref()
+            .and_then(|v| v.tag.clone())
+            .into_iter()
+            .collect();
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            let cfg = unifly_config::load_config_or_default();
+            let mut targets = Vec::new();
+            let mut failed = Vec::new();
+            for (name, profile) in cfg.profiles_tagged(&tags) {
+                match unifly_config::profile_to_controller_config(profile, name) {
+                    Ok(config) => targets.push(FleetTarget {
+                        name: name.to_owned(),
+                        config,
+                    }),
+                    Err(e) => failed.push(ControllerStatus::unreachable(
+                        name,
+                        &profile.controller,
+                        &profile.site,
+                        e.to_string(),
+                    )),
+                }
+            }
+
+            let mut statuses = fleet::fleet_status(targets, fleet::DEFAULT_FLEET_CONCURRENCY).await;
+            statuses.extend(failed);
+            statuses.sort_by(|a, b| a.name.cmp(&b.name));
+            let _ = tx.send(Action::FleetUpdated(Arc::new(statuses)));
+        });
+    }
+
+    /// List neighboring APs in the background, strongest first. Results
+    /// arrive as `NeighborsUpdated`.
+    fn load_neighbors(&self) {
+        let Some(controller) = self.controller.clone() else {
+            let _ = self
+                .action_tx
+                .send(Action::NeighborsUpdated(Err("Not connected".into())));
+            return;
+        };
+        let tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            let result = controller
+                .list_neighbor_aps(None)
+                .await
+                .map(|mut aps| {
+                    aps.sort_by_key(|ap| std::cmp::Reverse(ap.signal_dbm.unwrap_or(i32::MIN)));
+                    Arc::new(aps)
+                })
+                .map_err(|e| e.to_string());
+            let _ = tx.send(Action::NeighborsUpdated(result));
+        });
+    }
+
+    /// Site picker entry at `index` (0 = all sites).
+    fn site_picker_entry(&self, index: usize) -> String {
+        index
+            .checked_sub(1)
+            .and_then(|i| self.sites.get(i))
+            .map_or_else(|| ALL_SITES.to_owned(), |s| s.internal_name.clone())
+    }
+
+    /// Display name for a site's internal name.
+    fn site_label(&self, site: &str) -> String {
+        if site == ALL_SITES {
+            return "all sites".into();
+        }
+        self.sites
+            .iter()
+            .find(|s| s.internal_name == site)
+            .map_or_else(|| site.to_owned(), |s| s.name.clone())
+    }
+
     /// Map a confirmed action to its Command and execute it.
     fn execute_confirm(&self, action: ConfirmAction) {
         match action {
@@ -754,6 +1202,11 @@ impl App {
                     format!("Power cycling port {port_idx}"),
                 );
             }
+            ConfirmAction::SpectrumScan { id, name } => {
+                if let Some(mac) = self.resolve_device_mac(&id) {
+                    self.start_spectrum_scan(id, mac, name);
+                }
+            }
             ConfirmAction::BlockClient { id, name } => {
                 if let Some(mac) = self.resolve_client_mac(&id) {
                     self.execute_command(Command::BlockClient { mac }, format!("Blocked {name}"));
@@ -872,6 +1325,20 @@ impl App {
                 }
             }
 
+            // Recorded history is finer than the 5-minute report buckets
+            if let Some(samples) = recorded_wan_samples(&controller, period) {
+                #[allow(clippy::cast_precision_loss, clippy::as_conversions)]
+                let series = |value: fn(&Sample) -> Option<u64>| -> Vec<(f64, f64)> {
+                    samples
+                        .iter()
+                        .filter_map(|s| Some((s.at.timestamp() as f64, value(s)? as f64)))
+                        .collect()
+                };
+                data.bandwidth_tx = series(|s| s.tx_bytes_per_sec);
+                data.bandwidth_rx = series(|s| s.rx_bytes_per_sec);
+                data.client_counts = series(|s| s.clients.map(u64::from));
+            }
+
             // DPI applications — prefer Integration API (has names), fall back to Legacy
             if let Ok(apps) = dpi_apps_res {
                 if !apps.is_empty() {
@@ -965,6 +1432,18 @@ impl App {
             self.render_confirm_dialog(frame, area, confirm);
         }
 
+        if let Some(cursor) = self.site_picker {
+            self.render_site_picker(frame, area, cursor);
+        }
+
+        if let Some(ref view) = self.fleet {
+            Self::render_fleet(frame, area, view);
+        }
+
+        if let Some(ref view) = self.neighbors {
+            self.render_neighbors(frame, area, view);
+        }
+
         if self.help_visible {
             self.render_help_overlay(frame, area);
         }
@@ -1035,8 +1514,17 @@ impl App {
             }
         };
 
+        let site = if self.active_site.is_empty() {
+            Span::raw("")
+        } else {
+            Span::styled(
+                format!(" │ ⌂ {}", self.site_label(&self.active_site)),
+                Style::default().fg(theme::NEON_CYAN),
+            )
+        };
+
         let hints = Span::styled(
-            " │ ? help  / search  s pause  , settings  q quit",
+            " │ ? help  / search  s pause  S site  F fleet  N neighbors  , settings  q quit",
             theme::key_hint(),
         );
 
@@ -1046,7 +1534,13 @@ impl App {
             Style::default().fg(theme::BORDER_GRAY),
         );
 
-        let line = Line::from(vec![Span::raw(" "), connection_indicator, hints, timestamp]);
+        let line = Line::from(vec![
+            Span::raw(" "),
+            connection_indicator,
+            site,
+            hints,
+            timestamp,
+        ]);
 
         frame.render_widget(Paragraph::new(line), area);
     }
@@ -1062,7 +1556,7 @@ impl App {
         );
 
         let help_width = 60u16.min(area.width.saturating_sub(4));
-        let help_height = 22u16.min(area.height.saturating_sub(4));
+        let help_height = 23u16.min(area.height.saturating_sub(4));
 
         let x = (area.width.saturating_sub(help_width)) / 2;
         let y = (area.height.saturating_sub(help_height)) / 2;
@@ -1142,6 +1636,16 @@ impl App {
                 Span::styled("q  ", theme::key_hint_key()),
                 Span::styled("Quit", theme::key_hint()),
             ]),
+            Line::from(vec![
+                Span::styled("  S           ", theme::key_hint_key()),
+                Span::styled("Switch site        ", theme::key_hint()),
+                Span::styled("F  ", theme::key_hint_key()),
+                Span::styled("Fleet", theme::key_hint()),
+            ]),
+            Line::from(vec![
+                Span::styled("  N           ", theme::key_hint_key()),
+                Span::styled("Neighbor APs", theme::key_hint()),
+            ]),
             Line::from(""),
             Line::from(Span::styled(
                 "                         Esc or ? to close",
@@ -1196,6 +1700,301 @@ impl App {
         frame.render_widget(Paragraph::new(text), inner);
     }
 
+    /// Render the site picker: "All sites" followed by every site.
+    fn render_site_picker(&self, frame: &mut Frame, area: Rect, cursor: usize) {
+        let entries: Vec<(String, bool)> = std::iter::once(("All sites".to_owned(), ALL_SITES))
+            .chain(
+                self.sites
+                    .iter()
+                    .map(|s| (s.name.clone(), s.internal_name.as_str())),
+            )
+            .map(|(label, site)| (label, site == self.active_site))
+            .collect();
+
+        let width = 44u16.min(area.width.saturating_sub(4));
+        let max_rows = usize::from(area.height.saturating_sub(6)).max(1);
+        let visible = entries.len().min(max_rows);
+        let height = u16::try_from(visible).unwrap_or(u16::MAX).saturating_add(3);
+
+        let x = (area.width.saturating_sub(width)) / 2;
+        let y = (area.height.saturating_sub(height)) / 2;
+        let picker_area = Rect::new(area.x + x, area.y + y, width, height);
+
+        frame.render_widget(Clear, picker_area);
+        let block = Block::default()
+            .title(" Switch Site ")
+            .title_style(theme::title_style())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme::border_focused());
+        let inner = block.inner(picker_area);
+        frame.render_widget(block, picker_area);
+
+        // Keep the cursor in view.
+        let offset = cursor.saturating_sub(visible - 1);
+        let mut lines: Vec<Line> = entries
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(visible)
+            .map(|(i, (label, active))| {
+                let marker = if *active { "●" } else { " " };
+                let style = if i == cursor {
+                    theme::table_selected()
+                } else {
+                    theme::table_row()
+                };
+                Line::from(Span::styled(format!(" {marker} {label}"), style))
+            })
+            .collect();
+        lines.push(Line::from(vec![
+            Span::styled(" Enter ", theme::key_hint_key()),
+            Span::styled("switch  ", theme::key_hint()),
+            Span::styled("Esc ", theme::key_hint_key()),
+            Span::styled("cancel", theme::key_hint()),
+        ]));
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// Render the fleet dashboard: one row per configured controller.
+    fn render_fleet(frame: &mut Frame, area: Rect, view: &FleetView) {
+        let rows_wanted = view.statuses.as_ref().map_or(1, |s| s.len().max(1));
+        let width = 96u16.min(area.width.saturating_sub(4));
+        let height = u16::try_from(rows_wanted)
+            .unwrap_or(u16::MAX)
+            .saturating_add(5)
+            .min(area.height.saturating_sub(4));
+
+        let x = (area.width.saturating_sub(width)) / 2;
+        let y = (area.height.saturating_sub(height)) / 2;
+        let fleet_area = Rect::new(area.x + x, area.y + y, width, height);
+
+        frame.render_widget(Clear, fleet_area);
+        let title = match view.tag {
+            Some(ref tag) => format!(" Fleet · tag {tag} "),
+            None => " Fleet ".to_owned(),
+        };
+        let block = Block::default()
+            .title(title)
+            .title_style(theme::title_style())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme::border_focused());
+        let inner = block.inner(fleet_area);
+        frame.render_widget(block, fleet_area);
+
+        let [table_area, hint_area] =
+            Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+
+        let hints = Line::from(vec![
+            Span::styled(" r ", theme::key_hint_key()),
+            Span::styled("refresh  ", theme::key_hint()),
+            Span::styled("t ", theme::key_hint_key()),
+            Span::styled("next tag  ", theme::key_hint()),
+            Span::styled("Esc ", theme::key_hint_key()),
+            Span::styled("close", theme::key_hint()),
+        ]);
+        frame.render_widget(Paragraph::new(hints), hint_area);
+
+        let Some(ref statuses) = view.statuses else {
+            frame.render_widget(
+                Paragraph::new(Span::styled(
+                    " Contacting controllers\u{2026}",
+                    theme::key_hint(),
+                )),
+                table_area,
+            );
+            return;
+        };
+        if statuses.is_empty() {
+            frame.render_widget(
+                Paragraph::new(Span::styled(" No matching profiles", theme::key_hint())),
+                table_area,
+            );
+            return;
+        }
+
+        let header = Row::new(vec![
+            Cell::from("Profile").style(theme::table_header()),
+            Cell::from("Health").style(theme::table_header()),
+            Cell::from("Online").style(theme::table_header()),
+            Cell::from("Offline").style(theme::table_header()),
+            Cell::from("Subsystems / Error").style(theme::table_header()),
+        ]);
+        let rows = statuses.iter().map(|s| {
+            let (health, color) = match (s.is_reachable(), s.overall_health()) {
+                (false, _) => ("unreachable", theme::ERROR_RED),
+                (true, Some("ok")) => ("ok", theme::SUCCESS_GREEN),
+                (true, Some("warn" | "warning")) => ("warn", theme::ELECTRIC_YELLOW),
+                (true, Some(other)) => (other, theme::ERROR_RED),
+                (true, None) => ("-", theme::DIM_WHITE),
+            };
+            let detail = s.error.clone().unwrap_or_else(|| {
+                s.health
+                    .iter()
+                    .map(|h| format!("{}:{}", h.subsystem, h.status))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            });
+            Row::new(vec![
+                Cell::from(s.name.clone()),
+                Cell::from(health.to_owned()).style(Style::default().fg(color)),
+                Cell::from(s.devices_online.to_string()),
+                Cell::from(s.devices_offline.to_string()),
+                Cell::from(detail),
+            ])
+            .style(theme::table_row())
+        });
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(18),
+                Constraint::Length(12),
+                Constraint::Length(7),
+                Constraint::Length(8),
+                Constraint::Min(10),
+            ],
+        )
+        .header(header);
+        frame.render_widget(table, table_area);
+    }
+
+    /// Render the neighbor AP list: one row per foreign BSSID.
+    #[allow(clippy::too_many_lines)]
+    fn render_neighbors(&self, frame: &mut Frame, area: Rect, view: &NeighborsView) {
+        let rows_wanted = view.len().max(1);
+        let width = 104u16.min(area.width.saturating_sub(4));
+        let height = u16::try_from(rows_wanted)
+            .unwrap_or(u16::MAX)
+            .saturating_add(5)
+            .min(area.height.saturating_sub(4));
+
+        let x = (area.width.saturating_sub(width)) / 2;
+        let y = (area.height.saturating_sub(height)) / 2;
+        let overlay = Rect::new(area.x + x, area.y + y, width, height);
+
+        frame.render_widget(Clear, overlay);
+        let block = Block::default()
+            .title(format!(" Neighbor APs ({}) ", view.len()))
+            .title_style(theme::title_style())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme::border_focused());
+        let inner = block.inner(overlay);
+        frame.render_widget(block, overlay);
+
+        let [table_area, hint_area] =
+            Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+
+        let hints = Line::from(vec![
+            Span::styled(" j/k ", theme::key_hint_key()),
+            Span::styled("move  ", theme::key_hint()),
+            Span::styled("f ", theme::key_hint_key()),
+            Span::styled("flag known rogue  ", theme::key_hint()),
+            Span::styled("r ", theme::key_hint_key()),
+            Span::styled("refresh  ", theme::key_hint()),
+            Span::styled("Esc ", theme::key_hint_key()),
+            Span::styled("close", theme::key_hint()),
+        ]);
+        frame.render_widget(Paragraph::new(hints), hint_area);
+
+        let aps = match view.aps {
+            None => {
+                frame.render_widget(
+                    Paragraph::new(Span::styled(
+                        " Listing neighbors\u{2026}",
+                        theme::key_hint(),
+                    )),
+                    table_area,
+                );
+                return;
+            }
+            Some(Err(ref e)) => {
+                frame.render_widget(
+                    Paragraph::new(Span::styled(
+                        format!(" {e}"),
+                        Style::default().fg(theme::ERROR_RED),
+                    )),
+                    table_area,
+                );
+                return;
+            }
+            Some(Ok(ref aps)) if aps.is_empty() => {
+                frame.render_widget(
+                    Paragraph::new(Span::styled(" No neighboring APs heard", theme::key_hint())),
+                    table_area,
+                );
+                return;
+            }
+            Some(Ok(ref aps)) => aps,
+        };
+
+        let store = self.controller.as_ref().map(Controller::store);
+        let header = Row::new(vec![
+            Cell::from("").style(theme::table_header()),
+            Cell::from("BSSID").style(theme::table_header()),
+            Cell::from("SSID").style(theme::table_header()),
+            Cell::from("Ch").style(theme::table_header()),
+            Cell::from("Band").style(theme::table_header()),
+            Cell::from("Signal").style(theme::table_header()),
+            Cell::from("Heard by").style(theme::table_header()),
+        ]);
+        let rows = aps.iter().enumerate().map(|(i, ap)| {
+            let known = view.known.contains(&ap.bssid);
+            let (flag, color) = match (known, ap.is_rogue) {
+                (true, _) => ("\u{2691}", theme::ERROR_RED),
+                (false, true) => ("!", theme::ELECTRIC_YELLOW),
+                (false, false) => (" ", theme::DIM_WHITE),
+            };
+            let heard_by = ap
+                .detected_by
+                .as_ref()
+                .map(|mac| {
+                    store
+                        .and_then(|s| s.device_by_mac(mac))
+                        .and_then(|d| d.name.clone())
+                        .unwrap_or_else(|| mac.to_string())
+                })
+                .unwrap_or_default();
+            let row = Row::new(vec![
+                Cell::from(flag).style(Style::default().fg(color)),
+                Cell::from(ap.bssid.to_string()),
+                Cell::from(ap.ssid.clone().unwrap_or_else(|| "(hidden)".into())),
+                Cell::from(ap.channel.map(|c| c.to_string()).unwrap_or_default()),
+                Cell::from(
+                    ap.frequency_ghz
+                        .map(|f| format!("{f} GHz"))
+                        .unwrap_or_default(),
+                ),
+                Cell::from(
+                    ap.signal_dbm
+                        .map(|s| format!("{s} dBm"))
+                        .unwrap_or_default(),
+                ),
+                Cell::from(heard_by),
+            ]);
+            if i == view.cursor {
+                row.style(theme::table_selected())
+            } else {
+                row.style(theme::table_row())
+            }
+        });
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(2),
+                Constraint::Length(18),
+                Constraint::Min(12),
+                Constraint::Length(4),
+                Constraint::Length(8),
+                Constraint::Length(8),
+                Constraint::Length(20),
+            ],
+        )
+        .header(header);
+        frame.render_widget(table, table_area);
+    }
+
     /// Render a notification toast in the bottom-right corner.
     #[allow(clippy::unused_self)]
     fn render_notification(&self, frame: &mut Frame, area: Rect, notif: &Notification) {
@@ -1265,6 +2064,34 @@ fn dpi_category_name(id: u64) -> &'static str {
     }
 }
 
+/// WAN samples recorded on disk for `period`, when the history reaches
+/// back to the start of the window. Only the short periods are served from
+/// disk; a month at the recorder's interval is far more points than a chart
+/// can use.
+fn recorded_wan_samples(
+    controller: &Controller,
+    period: crate::action::StatsPeriod,
+) -> Option<Vec<Sample>> {
+    use crate::action::StatsPeriod;
+
+    if !matches!(period, StatsPeriod::OneHour | StatsPeriod::TwentyFourHours) {
+        return None;
+    }
+    let history = controller.config().history.as_ref()?;
+    let since = chrono::Utc::now() - chrono::Duration::seconds(period.duration_secs());
+    let samples = HistoryStore::new(&history.dir)
+        .query(&HistoryQuery {
+            kind: SampleKind::Wan,
+            mac: None,
+            since,
+            until: None,
+        })
+        .ok()?;
+    // A few missed ticks at the window start still count as covered
+    let slack = chrono::Duration::from_std(history.interval * 3).ok()?;
+    (samples.first()?.at <= since + slack).then_some(samples)
+}
+
 /// Parse Legacy `stat/sitedpi` `by_app` response into `(name, total_bytes)` tuples.
 ///
 /// The response is `[{"by_app": [{"app": 65541, "cat": 13, "rx_bytes": N, "tx_bytes": N}]}]`.
@@ -1335,3 +2162,45 @@ fn parse_legacy_dpi_categories(raw: &[serde_json::Value]) -> Vec<(String, u64)>
     cats.sort_by(|a, b| b.1.cmp(&a.1));
     cats
 }
+
+/// BSSIDs flagged as known rogues in the default profile.
+fn known_rogue_aps() -> HashSet<MacAddress> {
+    let cfg = unifly_config::load_config_or_default();
+    let name = cfg.default_profile.as_deref().unwrap_or("default");
+    cfg.profiles
+        .get(name)
+        .map(|p| p.known_rogue_aps.iter().map(MacAddress::new).collect())
+        .unwrap_or_default()
+}
+
+/// Flag or unflag `bssid` in the default profile and save the config.
+fn save_known_rogue(bssid: &MacAddress, known: bool) -> Result<(), String> {
+    let mut cfg = unifly_config::load_config().map_err(|e| e.to_string())?;
+    let name = cfg
+        .default_profile
+        .clone()
+        .unwrap_or_else(|| "default".into());
+    let profile = cfg
+        .profiles
+        .get_mut(&name)
+        .ok_or_else(|| format!("profile '{name}' not found in config"))?;
+    profile.set_known_rogue(bssid, known);
+    unifly_config::save_config(&cfg).map_err(|e| e.to_string())
+}
+
+/// The tag after `current` among all profile tags, wrapping back to
+/// "no filter" after the last one.
+fn next_fleet_tag(current: Option<&str>) -> Option<String> {
+    let cfg = unifly_config::load_config_or_default();
+    let mut tags: Vec<String> = cfg
+        .profiles
+        .values()
+        .flat_map(|p| p.tags.iter().cloned())
+        .collect();
+    tags.sort();
+    tags.dedup();
+    match current {
+        None => tags.into_iter().next(),
+        Some(tag) => tags.into_iter().skip_while(|t| t != tag).nth(1),
+    }
+}
@@ -17,6 +17,7 @@ use crate::action::Action;
 /// Connects to the controller, sends initial data snapshots, then loops
 /// forwarding every entity change and connection-state transition as an
 /// [`Action`]. Shuts down cleanly on cancellation.
+#[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
 pub async fn spawn_data_bridge(
     controller: Controller,
     action_tx: mpsc::UnboundedSender<Action>,
@@ -49,6 +50,8 @@ pub async fn spawn_data_bridge(
     let mut fw_zones = controller.firewall_zones();
     let mut acl_rules = controller.acl_rules();
     let mut wifi = controller.wifi_broadcasts();
+    let mut sites = controller.sites();
+    let mut active_site = controller.active_site_changes();
     let mut events = controller.events();
     let mut conn_state = controller.connection_state();
     let mut site_health = controller.site_health();
@@ -70,6 +73,10 @@ pub async fn spawn_data_bridge(
     let _ = action_tx.send(Action::FirewallZonesUpdated(fw_zones.current().clone()));
     let _ = action_tx.send(Action::AclRulesUpdated(acl_rules.current().clone()));
     let _ = action_tx.send(Action::WifiBroadcastsUpdated(wifi.current().clone()));
+    let _ = action_tx.send(Action::SitesUpdated(sites.current().clone()));
+    let _ = action_tx.send(Action::SiteSwitched(
+        active_site.borrow_and_update().clone(),
+    ));
 
     // Push initial health snapshot
     let health_snap = site_health.borrow_and_update().clone();
@@ -113,6 +120,13 @@ pub async fn spawn_data_bridge(
             Some(w) = wifi.changed() => {
                 let _ = action_tx.send(Action::WifiBroadcastsUpdated(w));
             }
+            Some(s) = sites.changed() => {
+                let _ = action_tx.send(Action::SitesUpdated(s));
+            }
+            Ok(()) = active_site.changed() => {
+                let site = active_site.borrow_and_update().clone();
+                let _ = action_tx.send(Action::SiteSwitched(site));
+            }
             Ok(event) = events.recv() => {
                 let _ = action_tx.send(Action::EventReceived(event));
             }
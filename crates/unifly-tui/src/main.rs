@@ -31,7 +31,7 @@ use tracing::info;
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt};
 
-use unifly_core::{AuthCredentials, Controller, ControllerConfig, TlsVerification};
+use unifly_core::{AuthCredentials, Controller, ControllerConfig, RetryConfig, TlsVerification};
 
 use crate::app::App;
 
@@ -134,12 +134,16 @@ fn build_controller(cli: &Cli) -> Option<Controller> {
         site: cli.site.clone(),
         tls,
         timeout: std::time::Duration::from_secs(30),
+        retry: RetryConfig::default(),
         refresh_interval_secs: 10,
         websocket_enabled: true,
         polling_interval_secs: 10,
         bandwidth_poll_interval: std::time::Duration::from_millis(500),
         client_poll_interval: std::time::Duration::from_secs(2),
         device_stats_poll_interval: std::time::Duration::from_secs(2),
+        two_factor: None,
+        session_cache: None,
+        history: None,
     };
 
     Some(Controller::new(config))
@@ -1,4 +1,5 @@
-//! Devices screen — sortable table with detail expansion (spec §2.2).
+//! Devices screen — sortable table with detail expansion (spec §2.2) and
+//! a port override editor on the Ports tab.
 
 use std::sync::Arc;
 
@@ -7,24 +8,292 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::style::{Modifier, Style};
+use ratatui::symbols::Marker;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table, TableState};
+use ratatui::widgets::{
+    Axis, Block, BorderType, Borders, Cell, Chart, Clear, Dataset, GraphType, Paragraph, Row,
+    Table, TableState,
+};
 use tokio::sync::mpsc::UnboundedSender;
 
-use unifly_core::{Device, DeviceState};
+use unifly_core::model::{ChannelSpectrum, PoeMode, Port, PortProfile};
+use unifly_core::{Device, DeviceState, EntityId, Network, SpectrumScan, UpdatePortRequest};
 
-use crate::action::{Action, DeviceDetailTab};
+use crate::action::{Action, DeviceDetailTab, PortOptions};
 use crate::component::Component;
 use crate::theme;
 use crate::widgets::{bytes_fmt, status_indicator, sub_tabs};
 
+// ── Port edit form state ─────────────────────────────────────────────
+
+const POE_MODES: [PoeMode; 4] = [
+    PoeMode::Auto,
+    PoeMode::Off,
+    PoeMode::Passive24V,
+    PoeMode::Passthrough,
+];
+
+/// Editable override fields for one switch port. Only fields that differ
+/// from the port's current state end up in the request.
+struct PortEditState {
+    port_idx: u32,
+    name: String,
+    enabled: bool,
+    /// `None` for ports without PoE.
+    poe_mode: Option<PoeMode>,
+    /// Index into the port profile list; `None` leaves it unchanged.
+    profile: Option<usize>,
+    /// Index into the screen's network list; `None` leaves it unchanged.
+    native_network: Option<usize>,
+    /// The port's native network as a store id, resolved from the legacy
+    /// id the port carries.
+    original_native_network: Option<EntityId>,
+    original: Port,
+    /// Which field is currently focused (0-indexed).
+    field_idx: usize,
+}
+
+impl PortEditState {
+    fn from_port(port: &Port, networks: &[Arc<Network>], options: &PortOptions) -> Self {
+        let original_native_network = port
+            .native_network_id
+            .as_ref()
+            .and_then(|id| native_network(id, networks, options))
+            .map(|n| n.id.clone());
+        Self {
+            port_idx: port.index,
+            name: port.name.clone().unwrap_or_default(),
+            enabled: port.enabled,
+            poe_mode: port.poe.as_ref().map(|p| p.mode.unwrap_or(PoeMode::Auto)),
+            profile: port
+                .profile_id
+                .as_ref()
+                .and_then(|id| options.profiles.iter().position(|p| &p.id == id)),
+            native_network: original_native_network
+                .as_ref()
+                .and_then(|id| networks.iter().position(|n| &n.id == id)),
+            original_native_network,
+            original: port.clone(),
+            field_idx: 0,
+        }
+    }
+
+    const FIELD_COUNT: usize = 5;
+
+    fn field_label(idx: usize) -> &'static str {
+        match idx {
+            0 => "Name",
+            1 => "Enabled",
+            2 => "PoE Mode",
+            3 => "Profile",
+            4 => "Native VLAN",
+            _ => "",
+        }
+    }
+
+    fn field_value(
+        &self,
+        idx: usize,
+        networks: &[Arc<Network>],
+        profiles: &[PortProfile],
+    ) -> String {
+        match idx {
+            0 => self.name.clone(),
+            1 => if self.enabled { "Enabled" } else { "Disabled" }.into(),
+            2 => self.poe_mode.map_or_else(|| "─".into(), |m| m.to_string()),
+            3 => self
+                .profile
+                .and_then(|i| profiles.get(i))
+                .map_or_else(|| "(unchanged)".into(), |p| p.name.clone()),
+            4 => self
+                .native_network
+                .and_then(|i| networks.get(i))
+                .map_or_else(|| "(unchanged)".into(), |n| network_label(n)),
+            _ => String::new(),
+        }
+    }
+
+    fn is_text_field(idx: usize) -> bool {
+        idx == 0
+    }
+
+    /// Toggle or cycle the focused non-text field.
+    fn cycle(&mut self, network_count: usize, profile_count: usize) {
+        match self.field_idx {
+            1 => self.enabled = !self.enabled,
+            2 => {
+                if let Some(mode) = self.poe_mode {
+                    let pos = POE_MODES.iter().position(|m| *m == mode).unwrap_or(0);
+                    self.poe_mode = Some(POE_MODES[(pos + 1) % POE_MODES.len()]);
+                }
+            }
+            3 if profile_count > 0 => {
+                self.profile = Some(self.profile.map_or(0, |i| (i + 1) % profile_count));
+            }
+            4 if network_count > 0 => {
+                self.native_network =
+                    Some(self.native_network.map_or(0, |i| (i + 1) % network_count));
+            }
+            _ => {}
+        }
+    }
+
+    fn build_request(
+        &self,
+        networks: &[Arc<Network>],
+        profiles: &[PortProfile],
+    ) -> UpdatePortRequest {
+        let original_name = self.original.name.as_deref().unwrap_or_default();
+        let original_poe = self.original.poe.as_ref().and_then(|p| p.mode);
+        let native_network_id = self
+            .native_network
+            .and_then(|i| networks.get(i))
+            .map(|n| n.id.clone())
+            .filter(|id| self.original_native_network.as_ref() != Some(id));
+        let profile_id = self
+            .profile
+            .and_then(|i| profiles.get(i))
+            .map(|p| p.id.clone())
+            .filter(|id| self.original.profile_id.as_ref() != Some(id));
+        UpdatePortRequest {
+            name: (self.name != original_name).then(|| self.name.clone()),
+            profile_id,
+            native_network_id,
+            enabled: (self.enabled != self.original.enabled).then_some(self.enabled),
+            poe_mode: self.poe_mode.filter(|m| original_poe != Some(*m)),
+        }
+    }
+}
+
+/// Look up the network a port's native network id points at. Ports carry
+/// legacy `networkconf` ids, which match store networks only when the
+/// Integration API is unavailable.
+fn native_network<'a>(
+    id: &EntityId,
+    networks: &'a [Arc<Network>],
+    options: &'a PortOptions,
+) -> Option<&'a Arc<Network>> {
+    options
+        .networks_by_legacy_id
+        .get(id)
+        .or_else(|| networks.iter().find(|n| &n.id == id))
+}
+
+fn network_label(network: &Network) -> String {
+    match network.vlan_id {
+        Some(vlan) => format!("{} (VLAN {vlan})", network.name),
+        None => network.name.clone(),
+    }
+}
+
+// ── Spectrum chart ───────────────────────────────────────────────────
+
+/// Outcome of fetching an AP's spectrum scan; `Ok(None)` = never scanned.
+type SpectrumResult = Result<Option<Arc<SpectrumScan>>, String>;
+
+/// Band label for a scanned channel; channel numbers alone overlap
+/// between 5 and 6 GHz, so prefer the measured frequency.
+fn spectrum_band(channel: &ChannelSpectrum) -> &'static str {
+    match channel.frequency_ghz {
+        Some(f) if f < 3.0 => "2.4 GHz",
+        Some(f) if f < 5.9 => "5 GHz",
+        Some(_) => "6 GHz",
+        None if channel.channel <= 14 => "2.4 GHz",
+        None => "5 GHz",
+    }
+}
+
+/// Utilization and interference bars per channel for one band.
+fn render_spectrum_chart(
+    frame: &mut Frame,
+    area: Rect,
+    band: &str,
+    channels: &[&ChannelSpectrum],
+    scanning: bool,
+) {
+    let points = |pct: fn(&ChannelSpectrum) -> Option<f64>| -> Vec<(f64, f64)> {
+        channels
+            .iter()
+            .map(|c| (f64::from(c.channel), pct(c).unwrap_or(0.0)))
+            .collect()
+    };
+    let utilization = points(|c| c.utilization_pct);
+    let interference = points(|c| c.interference_pct);
+
+    let first = channels.first().map_or(0, |c| c.channel);
+    let last = channels.last().map_or(0, |c| c.channel);
+    let title = if scanning {
+        format!(" {band} \u{00b7} scanning\u{2026} ")
+    } else {
+        format!(" {band} ")
+    };
+    let block = Block::default()
+        .title(title)
+        .title_style(theme::title_style())
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(theme::border_default());
+
+    // Interference renders after utilization so it shows on top
+    let datasets = vec![
+        Dataset::default()
+            .name("Utilization")
+            .marker(Marker::HalfBlock)
+            .graph_type(GraphType::Bar)
+            .style(Style::default().fg(theme::NEON_CYAN))
+            .data(&utilization),
+        Dataset::default()
+            .name("Interference")
+            .marker(Marker::HalfBlock)
+            .graph_type(GraphType::Bar)
+            .style(Style::default().fg(theme::ERROR_RED))
+            .data(&interference),
+    ];
+
+    let label = |s: String| Span::styled(s, Style::default().fg(theme::BORDER_GRAY));
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .bounds([f64::from(first) - 1.0, f64::from(last) + 1.0])
+                .labels(vec![
+                    label(format!("ch {first}")),
+                    label(format!("ch {last}")),
+                ])
+                .style(Style::default().fg(theme::BORDER_GRAY)),
+        )
+        .y_axis(
+            Axis::default()
+                .bounds([0.0, 100.0])
+                .labels(vec![
+                    label("0".into()),
+                    label("50%".into()),
+                    label("100%".into()),
+                ])
+                .style(Style::default().fg(theme::BORDER_GRAY)),
+        );
+    frame.render_widget(chart, area);
+}
+
+// ── Main screen ──────────────────────────────────────────────────────
+
 pub struct DevicesScreen {
     focused: bool,
     action_tx: Option<UnboundedSender<Action>>,
     devices: Arc<Vec<Arc<Device>>>,
+    networks: Arc<Vec<Arc<Network>>>,
     table_state: TableState,
     detail_open: bool,
     detail_tab: DeviceDetailTab,
+    /// Selected row on the Ports tab.
+    port_cursor: usize,
+    port_edit: Option<PortEditState>,
+    /// Port profiles and legacy network ids, fetched when the Ports tab
+    /// opens.
+    port_options: Arc<PortOptions>,
+    /// Spectrum scan shown on the Radios tab, keyed by the device it was
+    /// fetched for. `Ok(None)` means the AP has never been scanned.
+    spectrum: Option<(EntityId, SpectrumResult)>,
     search_query: String,
 }
 
@@ -34,9 +303,14 @@ impl DevicesScreen {
             focused: false,
             action_tx: None,
             devices: Arc::new(Vec::new()),
+            networks: Arc::new(Vec::new()),
             table_state: TableState::default(),
             detail_open: false,
             detail_tab: DeviceDetailTab::default(),
+            port_cursor: 0,
+            port_edit: None,
+            port_options: Arc::default(),
+            spectrum: None,
             search_query: String::new(),
         }
     }
@@ -70,6 +344,28 @@ impl DevicesScreen {
         filtered.get(self.selected_index()).copied()
     }
 
+    /// Spectrum scan fetched for `device`, if any.
+    fn spectrum_for(&self, device: &Device) -> Option<&SpectrumResult> {
+        self.spectrum
+            .as_ref()
+            .filter(|(id, _)| *id == device.id)
+            .map(|(_, result)| result)
+    }
+
+    /// Switch detail tabs, fetching the spectrum scan when landing on an
+    /// AP's Radios tab and the port editor's options on the Ports tab.
+    fn set_detail_tab(&mut self, tab: DeviceDetailTab) -> Option<Action> {
+        self.detail_tab = tab;
+        let device = self.selected_device()?;
+        match tab {
+            DeviceDetailTab::Radios if !device.radios.is_empty() => {
+                Some(Action::FetchSpectrum(device.id.clone()))
+            }
+            DeviceDetailTab::Ports if !device.ports.is_empty() => Some(Action::FetchPortOptions),
+            _ => None,
+        }
+    }
+
     fn select(&mut self, idx: usize) {
         let len = self.filtered_devices().len();
         let clamped = if len == 0 { 0 } else { idx.min(len - 1) };
@@ -147,9 +443,27 @@ impl DevicesScreen {
         }
 
         // Key hints
-        let hints = Line::from(vec![
+        let mut hints = vec![
             Span::styled("  h/l ", theme::key_hint_key()),
             Span::styled("switch tabs  ", theme::key_hint()),
+        ];
+        if self.detail_tab == DeviceDetailTab::Ports && !device.ports.is_empty() {
+            hints.extend([
+                Span::styled("j/k ", theme::key_hint_key()),
+                Span::styled("select port  ", theme::key_hint()),
+                Span::styled("e ", theme::key_hint_key()),
+                Span::styled("edit port  ", theme::key_hint()),
+            ]);
+        }
+        if self.detail_tab == DeviceDetailTab::Radios && !device.radios.is_empty() {
+            hints.extend([
+                Span::styled("s ", theme::key_hint_key()),
+                Span::styled("spectrum scan  ", theme::key_hint()),
+                Span::styled("r ", theme::key_hint_key()),
+                Span::styled("refresh  ", theme::key_hint()),
+            ]);
+        }
+        hints.extend([
             Span::styled("R ", theme::key_hint_key()),
             Span::styled("restart  ", theme::key_hint()),
             Span::styled("L ", theme::key_hint_key()),
@@ -157,7 +471,7 @@ impl DevicesScreen {
             Span::styled("Esc ", theme::key_hint_key()),
             Span::styled("back", theme::key_hint()),
         ]);
-        frame.render_widget(Paragraph::new(hints), tabs_layout[2]);
+        frame.render_widget(Paragraph::new(Line::from(hints)), tabs_layout[2]);
     }
 
     #[allow(clippy::unused_self)]
@@ -165,10 +479,10 @@ impl DevicesScreen {
         let state_span = status_indicator::status_span(device.state);
         let state_label = format!("{:?}", device.state);
         let firmware = device.firmware_version.as_deref().unwrap_or("─");
-        let fw_status = if device.firmware_updatable {
-            "update available"
-        } else {
-            "up to date"
+        let fw_status = match (device.firmware_updatable, &device.firmware_target) {
+            (true, Some(target)) => format!("{target} available"),
+            (true, None) => "update available".into(),
+            (false, _) => "up to date".into(),
         };
         let uptime = device
             .stats
@@ -282,7 +596,6 @@ impl DevicesScreen {
         frame.render_widget(Paragraph::new(lines), area);
     }
 
-    #[allow(clippy::unused_self)]
     fn render_radios_tab(&self, frame: &mut Frame, area: Rect, device: &Device) {
         let mut lines = vec![Line::from("")];
 
@@ -291,35 +604,100 @@ impl DevicesScreen {
                 "  No radio data available",
                 Style::default().fg(theme::BORDER_GRAY),
             )));
-        } else {
-            for radio in &device.radios {
-                let freq = format!("{:.1} GHz", radio.frequency_ghz);
-                let ch = radio
-                    .channel
-                    .map_or_else(|| "─".into(), |c| format!("ch {c}"));
-                let width = radio
-                    .channel_width_mhz
-                    .map_or_else(|| "─".into(), |w| format!("{w} MHz"));
+            frame.render_widget(Paragraph::new(lines), area);
+            return;
+        }
 
-                lines.push(Line::from(vec![
-                    Span::styled(
-                        format!("  {freq:<10}"),
-                        Style::default()
-                            .fg(theme::NEON_CYAN)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled(
-                        format!("{ch:<8} {width}"),
-                        Style::default().fg(theme::DIM_WHITE),
-                    ),
-                ]));
+        for radio in &device.radios {
+            let freq = format!("{:.1} GHz", radio.frequency_ghz);
+            let ch = radio
+                .channel
+                .map_or_else(|| "─".into(), |c| format!("ch {c}"));
+            let width = radio
+                .channel_width_mhz
+                .map_or_else(|| "─".into(), |w| format!("{w} MHz"));
+            let power = radio
+                .tx_power_dbm
+                .map_or_else(|| "─".into(), |p| format!("{p} dBm"));
+            let min_rssi = radio
+                .min_rssi_dbm
+                .map(|v| format!("min RSSI {v} dBm"))
+                .unwrap_or_default();
+
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {freq:<10}"),
+                    Style::default()
+                        .fg(theme::NEON_CYAN)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{ch:<8} {width:<9} {power:<8} {min_rssi}"),
+                    Style::default().fg(theme::DIM_WHITE),
+                ),
+            ]));
+        }
+
+        let radio_rows = u16::try_from(lines.len() + 1).unwrap_or(u16::MAX);
+        let [radios_area, spectrum_area] =
+            Layout::vertical([Constraint::Length(radio_rows), Constraint::Min(0)]).areas(area);
+        frame.render_widget(Paragraph::new(lines), radios_area);
+        self.render_spectrum(frame, spectrum_area, device);
+    }
+
+    /// Spectrum scan panel: one utilization chart per band.
+    fn render_spectrum(&self, frame: &mut Frame, area: Rect, device: &Device) {
+        let scan = match self.spectrum_for(device) {
+            None => {
+                frame.render_widget(
+                    Paragraph::new("  Loading spectrum scan\u{2026}")
+                        .style(Style::default().fg(theme::BORDER_GRAY)),
+                    area,
+                );
+                return;
+            }
+            Some(Err(e)) => {
+                frame.render_widget(
+                    Paragraph::new(format!("  Spectrum scan unavailable: {e}"))
+                        .style(Style::default().fg(theme::ERROR_RED)),
+                    area,
+                );
+                return;
             }
+            Some(Ok(scan)) => scan.as_deref(),
+        };
+
+        let scanning = scan.is_some_and(|s| s.scanning);
+        let channels = scan.map_or(&[][..], |s| s.channels.as_slice());
+        if channels.is_empty() {
+            let text = if scanning {
+                "  Spectrum scan running\u{2026}"
+            } else {
+                "  No spectrum scan yet \u{2014} press s to run one"
+            };
+            frame.render_widget(
+                Paragraph::new(text).style(Style::default().fg(theme::BORDER_GRAY)),
+                area,
+            );
+            return;
         }
 
-        frame.render_widget(Paragraph::new(lines), area);
+        let mut bands: Vec<(&str, Vec<&ChannelSpectrum>)> = Vec::new();
+        for channel in channels {
+            let band = spectrum_band(channel);
+            match bands.iter_mut().find(|(b, _)| *b == band) {
+                Some((_, list)) => list.push(channel),
+                None => bands.push((band, vec![channel])),
+            }
+        }
+
+        let count = u32::try_from(bands.len()).unwrap_or(1);
+        let areas = Layout::horizontal(vec![Constraint::Ratio(1, count); bands.len()]).split(area);
+        for ((band, list), band_area) in bands.iter().zip(areas.iter()) {
+            render_spectrum_chart(frame, *band_area, band, list, scanning);
+        }
     }
 
-    #[allow(clippy::unused_self)]
     fn render_ports_tab(&self, frame: &mut Frame, area: Rect, device: &Device) {
         let mut lines = vec![Line::from("")];
 
@@ -331,11 +709,12 @@ impl DevicesScreen {
         } else {
             // Header
             lines.push(Line::from(Span::styled(
-                "  Port  State   Speed      PoE",
+                "  Port    State   Speed      PoE  Native VLAN",
                 theme::table_header(),
             )));
 
-            for port in &device.ports {
+            for (i, port) in device.ports.iter().enumerate() {
+                let marker = if i == self.port_cursor { "▸ " } else { "  " };
                 let idx_str = port.index.to_string();
                 let name = port.name.as_deref().unwrap_or(&idx_str);
                 let state_color = match port.state {
@@ -354,22 +733,33 @@ impl DevicesScreen {
                         }
                     },
                 );
+                let (state_str, state_color) = if port.enabled {
+                    (state_str, state_color)
+                } else {
+                    ("Disabled".into(), theme::BORDER_GRAY)
+                };
                 let poe = port
                     .poe
                     .as_ref()
                     .map_or("─", |p| if p.enabled { "✓" } else { "✗" });
+                let native = port.native_network_id.as_ref().map_or_else(
+                    || "─".into(),
+                    |id| {
+                        native_network(id, &self.networks, &self.port_options)
+                            .map_or_else(|| id.to_string(), |n| network_label(n))
+                    },
+                );
 
                 lines.push(Line::from(vec![
-                    Span::styled(
-                        format!("  {name:<6}"),
-                        Style::default().fg(theme::NEON_CYAN),
-                    ),
+                    Span::styled(marker, Style::default().fg(theme::ELECTRIC_YELLOW)),
+                    Span::styled(format!("{name:<8}"), Style::default().fg(theme::NEON_CYAN)),
                     Span::styled(format!("{state_str:<8}"), Style::default().fg(state_color)),
                     Span::styled(
                         format!("{speed:<11}"),
                         Style::default().fg(theme::DIM_WHITE),
                     ),
-                    Span::styled(poe, Style::default().fg(theme::DIM_WHITE)),
+                    Span::styled(format!("{poe:<5}"), Style::default().fg(theme::DIM_WHITE)),
+                    Span::styled(native, Style::default().fg(theme::DIM_WHITE)),
                 ]));
             }
         }
@@ -378,13 +768,132 @@ impl DevicesScreen {
     }
 }
 
+impl DevicesScreen {
+    // ── Port edit overlay ──────────────────────────────────────
+
+    fn render_port_edit_overlay(&self, frame: &mut Frame, area: Rect, edit: &PortEditState) {
+        let overlay_w = 48u16.min(area.width.saturating_sub(4));
+        #[allow(clippy::cast_possible_truncation, clippy::as_conversions)]
+        let overlay_h = (PortEditState::FIELD_COUNT as u16 + 6).min(area.height.saturating_sub(2));
+        let x = area.x + (area.width.saturating_sub(overlay_w)) / 2;
+        let y = area.y + (area.height.saturating_sub(overlay_h)) / 2;
+        let overlay_area = Rect::new(x, y, overlay_w, overlay_h);
+
+        frame.render_widget(Clear, overlay_area);
+
+        let block = Block::default()
+            .title(format!(" Edit Port {} ", edit.port_idx))
+            .title_style(
+                Style::default()
+                    .fg(theme::ELECTRIC_YELLOW)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .border_style(Style::default().fg(theme::ELECTRIC_PURPLE));
+
+        let inner = block.inner(overlay_area);
+        frame.render_widget(block, overlay_area);
+
+        let label = Style::default().fg(theme::DIM_WHITE);
+        let value_style = Style::default().fg(theme::NEON_CYAN);
+        let focused_label = Style::default()
+            .fg(theme::ELECTRIC_YELLOW)
+            .add_modifier(Modifier::BOLD);
+
+        let mut lines = Vec::new();
+        for idx in 0..PortEditState::FIELD_COUNT {
+            let is_focused = idx == edit.field_idx;
+            let lbl_style = if is_focused { focused_label } else { label };
+            let marker = if is_focused { "▸ " } else { "  " };
+            let cursor = if is_focused && PortEditState::is_text_field(idx) {
+                "▎"
+            } else {
+                ""
+            };
+            lines.push(Line::from(vec![
+                Span::styled(marker, lbl_style),
+                Span::styled(
+                    format!("{:<14}", PortEditState::field_label(idx)),
+                    lbl_style,
+                ),
+                Span::styled(
+                    edit.field_value(idx, &self.networks, &self.port_options.profiles),
+                    value_style,
+                ),
+                Span::styled(cursor, Style::default().fg(theme::ELECTRIC_YELLOW)),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(" Tab", theme::key_hint_key()),
+            Span::styled(" next  ", theme::key_hint()),
+            Span::styled("Space", theme::key_hint_key()),
+            Span::styled(" change  ", theme::key_hint()),
+            Span::styled("Enter", theme::key_hint_key()),
+            Span::styled(" save  ", theme::key_hint()),
+            Span::styled("Esc", theme::key_hint_key()),
+            Span::styled(" cancel", theme::key_hint()),
+        ]));
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// Key handling while the port edit overlay is open.
+    fn handle_port_edit_key(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Esc => {
+                self.port_edit = None;
+                return None;
+            }
+            KeyCode::Enter => {
+                let edit = self.port_edit.take()?;
+                let device_id = self.selected_device()?.id.clone();
+                let req = edit.build_request(&self.networks, &self.port_options.profiles);
+                return Some(Action::PortSave(device_id, edit.port_idx, Box::new(req)));
+            }
+            _ => {}
+        }
+        let network_count = self.networks.len();
+        let profile_count = self.port_options.profiles.len();
+        let edit = self.port_edit.as_mut()?;
+        match key.code {
+            KeyCode::Tab | KeyCode::Down => {
+                edit.field_idx = (edit.field_idx + 1) % PortEditState::FIELD_COUNT;
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                edit.field_idx = if edit.field_idx == 0 {
+                    PortEditState::FIELD_COUNT - 1
+                } else {
+                    edit.field_idx - 1
+                };
+            }
+            KeyCode::Char(ch) if PortEditState::is_text_field(edit.field_idx) => {
+                edit.name.push(ch);
+            }
+            KeyCode::Backspace if PortEditState::is_text_field(edit.field_idx) => {
+                edit.name.pop();
+            }
+            KeyCode::Char(' ') => edit.cycle(network_count, profile_count),
+            _ => {}
+        }
+        None
+    }
+}
+
 impl Component for DevicesScreen {
     fn init(&mut self, action_tx: UnboundedSender<Action>) -> Result<()> {
         self.action_tx = Some(action_tx);
         Ok(())
     }
 
+    #[allow(clippy::too_many_lines)]
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if self.port_edit.is_some() {
+            return Ok(self.handle_port_edit_key(key));
+        }
+
         if self.detail_open {
             // Detail panel key handling
             return match key.code {
@@ -393,23 +902,56 @@ impl Component for DevicesScreen {
                     Ok(Some(Action::CloseDetail))
                 }
                 KeyCode::Char('h') | KeyCode::Left => {
-                    self.detail_tab = match self.detail_tab {
+                    let tab = match self.detail_tab {
                         DeviceDetailTab::Overview => DeviceDetailTab::Ports,
                         DeviceDetailTab::Performance => DeviceDetailTab::Overview,
                         DeviceDetailTab::Radios => DeviceDetailTab::Performance,
                         DeviceDetailTab::Clients => DeviceDetailTab::Radios,
                         DeviceDetailTab::Ports => DeviceDetailTab::Clients,
                     };
-                    Ok(None)
+                    Ok(self.set_detail_tab(tab))
                 }
                 KeyCode::Char('l') | KeyCode::Right => {
-                    self.detail_tab = match self.detail_tab {
+                    let tab = match self.detail_tab {
                         DeviceDetailTab::Overview => DeviceDetailTab::Performance,
                         DeviceDetailTab::Performance => DeviceDetailTab::Radios,
                         DeviceDetailTab::Radios => DeviceDetailTab::Clients,
                         DeviceDetailTab::Clients => DeviceDetailTab::Ports,
                         DeviceDetailTab::Ports => DeviceDetailTab::Overview,
                     };
+                    Ok(self.set_detail_tab(tab))
+                }
+                KeyCode::Char('s') if self.detail_tab == DeviceDetailTab::Radios => Ok(self
+                    .selected_device()
+                    .filter(|d| !d.radios.is_empty())
+                    .map(|d| Action::RequestSpectrumScan(d.id.clone()))),
+                KeyCode::Char('r') if self.detail_tab == DeviceDetailTab::Radios => Ok(self
+                    .selected_device()
+                    .filter(|d| !d.radios.is_empty())
+                    .map(|d| Action::FetchSpectrum(d.id.clone()))),
+                KeyCode::Char('j') | KeyCode::Down if self.detail_tab == DeviceDetailTab::Ports => {
+                    let count = self.selected_device().map_or(0, |d| d.ports.len());
+                    if self.port_cursor + 1 < count {
+                        self.port_cursor += 1;
+                    }
+                    Ok(None)
+                }
+                KeyCode::Char('k') | KeyCode::Up if self.detail_tab == DeviceDetailTab::Ports => {
+                    self.port_cursor = self.port_cursor.saturating_sub(1);
+                    Ok(None)
+                }
+                KeyCode::Char('e') if self.detail_tab == DeviceDetailTab::Ports => {
+                    let port = self
+                        .selected_device()
+                        .and_then(|d| d.ports.get(self.port_cursor))
+                        .cloned();
+                    if let Some(port) = port {
+                        self.port_edit = Some(PortEditState::from_port(
+                            &port,
+                            &self.networks,
+                            &self.port_options,
+                        ));
+                    }
                     Ok(None)
                 }
                 KeyCode::Char('R') => {
@@ -465,6 +1007,7 @@ impl Component for DevicesScreen {
                 if let Some(id) = id {
                     self.detail_open = true;
                     self.detail_tab = DeviceDetailTab::Overview;
+                    self.port_cursor = 0;
                     Ok(Some(Action::OpenDeviceDetail(id)))
                 } else {
                     Ok(None)
@@ -498,12 +1041,34 @@ impl Component for DevicesScreen {
                 if len > 0 && self.selected_index() >= len {
                     self.select(len - 1);
                 }
+                let ports = self.selected_device().map_or(0, |d| d.ports.len());
+                self.port_cursor = self.port_cursor.min(ports.saturating_sub(1));
+
+                // Keep polling a running spectrum scan while it's on screen
+                if self.detail_open && self.detail_tab == DeviceDetailTab::Radios {
+                    if let Some(device) = self.selected_device() {
+                        if let Some(Ok(Some(scan))) = self.spectrum_for(device) {
+                            if scan.scanning {
+                                return Ok(Some(Action::FetchSpectrum(device.id.clone())));
+                            }
+                        }
+                    }
+                }
+            }
+            Action::NetworksUpdated(networks) => {
+                self.networks = Arc::clone(networks);
             }
             Action::CloseDetail => {
                 self.detail_open = false;
             }
             Action::DeviceDetailTab(tab) => {
-                self.detail_tab = *tab;
+                return Ok(self.set_detail_tab(*tab));
+            }
+            Action::SpectrumUpdated(id, result) => {
+                self.spectrum = Some((id.clone(), result.clone()));
+            }
+            Action::PortOptionsUpdated(Ok(options)) => {
+                self.port_options = Arc::clone(options);
             }
             Action::SearchInput(query) => {
                 self.search_query.clone_from(query);
@@ -694,6 +1259,11 @@ impl Component for DevicesScreen {
                 self.render_detail(frame, detail_area, device);
             }
         }
+
+        // Port edit overlay (rendered on top)
+        if let Some(ref edit) = self.port_edit {
+            self.render_port_edit_overlay(frame, area, edit);
+        }
     }
 
     fn focused(&self) -> bool {
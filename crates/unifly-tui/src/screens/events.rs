@@ -1,4 +1,5 @@
-//! Events screen — live event stream with pause/filter (spec §2.7).
+//! Events screen — live event stream with pause/filter (spec §2.7), plus
+//! a Threats panel of IDS/IPS detections filterable by category.
 
 use std::sync::Arc;
 
@@ -11,13 +12,20 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
 use tokio::sync::mpsc::UnboundedSender;
 
-use unifly_core::Event;
 use unifly_core::model::EventSeverity;
+use unifly_core::{Event, ThreatEvent};
 
 use crate::action::Action;
 use crate::component::Component;
 use crate::theme;
 
+/// Which panel the Events screen shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EventsView {
+    Live,
+    Threats,
+}
+
 pub struct EventsScreen {
     focused: bool,
     events: Vec<Arc<Event>>,
@@ -25,6 +33,12 @@ pub struct EventsScreen {
     scroll_offset: usize,
     /// Max events to keep in memory.
     capacity: usize,
+    view: EventsView,
+    /// `None` until the first fetch completes.
+    threats: Option<Result<Arc<Vec<ThreatEvent>>, String>>,
+    /// Signature category shown in the Threats panel; `None` shows all.
+    threat_category: Option<String>,
+    threat_scroll: usize,
 }
 
 impl EventsScreen {
@@ -35,7 +49,169 @@ impl EventsScreen {
             paused: false,
             scroll_offset: 0,
             capacity: 10_000,
+            view: EventsView::Live,
+            threats: None,
+            threat_category: None,
+            threat_scroll: 0,
+        }
+    }
+
+    /// Distinct signature categories in the loaded threats, sorted.
+    fn threat_categories(&self) -> Vec<&str> {
+        let Some(Ok(threats)) = &self.threats else {
+            return Vec::new();
+        };
+        let mut categories: Vec<&str> = threats
+            .iter()
+            .filter_map(|t| t.category.as_deref())
+            .collect();
+        categories.sort_unstable();
+        categories.dedup();
+        categories
+    }
+
+    /// Step the category filter: all → first category → ... → all.
+    fn cycle_threat_category(&mut self) {
+        let categories = self.threat_categories();
+        let next = match &self.threat_category {
+            None => categories.first(),
+            Some(current) => categories
+                .iter()
+                .position(|c| c == current)
+                .and_then(|i| categories.get(i + 1)),
+        }
+        .map(|c| (*c).to_owned());
+        self.threat_category = next;
+        self.threat_scroll = 0;
+    }
+
+    fn visible_threats(&self) -> Vec<&ThreatEvent> {
+        let Some(Ok(threats)) = &self.threats else {
+            return Vec::new();
+        };
+        threats
+            .iter()
+            .filter(|t| {
+                self.threat_category
+                    .as_ref()
+                    .is_none_or(|c| t.category.as_ref() == Some(c))
+            })
+            .collect()
+    }
+
+    fn handle_threats_key(&mut self, key: KeyEvent) -> Option<Action> {
+        let count = self.visible_threats().len();
+        match key.code {
+            KeyCode::Char('t') => {
+                self.view = EventsView::Live;
+                None
+            }
+            KeyCode::Char('r') => {
+                self.threats = None;
+                Some(Action::FetchThreats)
+            }
+            KeyCode::Char('c') => {
+                self.cycle_threat_category();
+                None
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.threat_scroll = (self.threat_scroll + 1).min(count.saturating_sub(1));
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.threat_scroll = self.threat_scroll.saturating_sub(1);
+                None
+            }
+            KeyCode::Char('g') => {
+                self.threat_scroll = 0;
+                None
+            }
+            KeyCode::Char('G') => {
+                self.threat_scroll = count.saturating_sub(1);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn title(&self) -> String {
+        match (self.view, &self.threats) {
+            (EventsView::Threats, Some(Ok(threats))) => format!(" Threats ({}) ", threats.len()),
+            (EventsView::Threats, _) => " Threats ".to_owned(),
+            (EventsView::Live, _) => format!(" Events ({}) ", self.events.len()),
+        }
+    }
+
+    fn render_threats(&self, frame: &mut Frame, area: Rect) {
+        let layout = Layout::vertical([
+            Constraint::Length(1), // status line
+            Constraint::Min(1),    // threats
+            Constraint::Length(1), // hints
+        ])
+        .split(area);
+
+        let visible = self.visible_threats();
+        let blocked = visible.iter().filter(|t| t.blocked).count();
+        let status = Line::from(vec![
+            Span::styled("  Category: ", Style::default().fg(theme::DIM_WHITE)),
+            Span::styled(
+                format!("[{}]", self.threat_category.as_deref().unwrap_or("all")),
+                Style::default().fg(theme::NEON_CYAN),
+            ),
+            Span::styled("  Last 24h: ", Style::default().fg(theme::DIM_WHITE)),
+            Span::styled(
+                format!("{} detections, {blocked} blocked", visible.len()),
+                Style::default().fg(theme::NEON_CYAN),
+            ),
+        ]);
+        frame.render_widget(Paragraph::new(status), layout[0]);
+
+        let mut lines: Vec<Line> = vec![Line::from(vec![
+            Span::styled("  Time      ", theme::table_header()),
+            Span::styled("Action   ", theme::table_header()),
+            Span::styled("Source                 ", theme::table_header()),
+            Span::styled("Category                      ", theme::table_header()),
+            Span::styled("Signature", theme::table_header()),
+        ])];
+
+        match &self.threats {
+            None => lines.push(Line::from(Span::styled(
+                "  Loading threats...",
+                Style::default().fg(theme::BORDER_GRAY),
+            ))),
+            Some(Err(e)) => lines.push(Line::from(Span::styled(
+                format!("  {e}"),
+                Style::default().fg(theme::ERROR_RED),
+            ))),
+            Some(Ok(_)) if visible.is_empty() => lines.push(Line::from(Span::styled(
+                "  No threats detected",
+                Style::default().fg(theme::BORDER_GRAY),
+            ))),
+            Some(Ok(_)) => {
+                let height = usize::from(layout[1].height.saturating_sub(1));
+                let sig_width = usize::from(layout[1].width.saturating_sub(73).max(10));
+                lines.extend(
+                    visible
+                        .iter()
+                        .skip(self.threat_scroll)
+                        .take(height)
+                        .map(|t| threat_line(t, sig_width)),
+                );
+            }
         }
+        frame.render_widget(Paragraph::new(lines), layout[1]);
+
+        let hints = Line::from(vec![
+            Span::styled("  t ", theme::key_hint_key()),
+            Span::styled("live events  ", theme::key_hint()),
+            Span::styled("c ", theme::key_hint_key()),
+            Span::styled("category  ", theme::key_hint()),
+            Span::styled("r ", theme::key_hint_key()),
+            Span::styled("refresh  ", theme::key_hint()),
+            Span::styled("j/k ", theme::key_hint_key()),
+            Span::styled("scroll", theme::key_hint()),
+        ]);
+        frame.render_widget(Paragraph::new(hints), layout[2]);
     }
 
     #[allow(dead_code, clippy::unused_self)]
@@ -44,13 +220,66 @@ impl EventsScreen {
     }
 }
 
+/// One row of the Threats panel.
+fn threat_line(threat: &ThreatEvent, sig_width: usize) -> Line<'static> {
+    let (action, color) = if threat.blocked {
+        ("blocked", theme::SUCCESS_GREEN)
+    } else {
+        ("alert", theme::ELECTRIC_YELLOW)
+    };
+    let source = threat.src_ip.map_or_else(
+        || "-".to_owned(),
+        |ip| match &threat.src_country {
+            Some(cc) => format!("{ip} ({cc})"),
+            None => ip.to_string(),
+        },
+    );
+    let category: String = threat
+        .category
+        .as_deref()
+        .unwrap_or("-")
+        .chars()
+        .take(29)
+        .collect();
+    let signature: String = threat
+        .signature
+        .as_deref()
+        .unwrap_or("-")
+        .chars()
+        .take(sig_width)
+        .collect();
+    Line::from(vec![
+        Span::styled(
+            format!("  {:<10}", threat.timestamp.format("%H:%M:%S")),
+            Style::default().fg(theme::ELECTRIC_YELLOW),
+        ),
+        Span::styled(format!("{action:<9}"), Style::default().fg(color)),
+        Span::styled(
+            format!("{source:<23}"),
+            Style::default().fg(theme::NEON_CYAN),
+        ),
+        Span::styled(
+            format!("{category:<30}"),
+            Style::default().fg(theme::DIM_WHITE),
+        ),
+        Span::styled(signature, Style::default().fg(theme::DIM_WHITE)),
+    ])
+}
+
 impl Component for EventsScreen {
     fn init(&mut self, _action_tx: UnboundedSender<Action>) -> Result<()> {
         Ok(())
     }
 
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if self.view == EventsView::Threats {
+            return Ok(self.handle_threats_key(key));
+        }
         match key.code {
+            KeyCode::Char('t') => {
+                self.view = EventsView::Threats;
+                Ok(self.threats.is_none().then_some(Action::FetchThreats))
+            }
             KeyCode::Char(' ') => {
                 self.paused = !self.paused;
                 if !self.paused {
@@ -92,26 +321,37 @@ impl Component for EventsScreen {
     }
 
     fn update(&mut self, action: &Action) -> Result<Option<Action>> {
-        if let Action::EventReceived(event) = action {
-            self.events.push(Arc::clone(event));
-            if self.events.len() > self.capacity {
-                self.events.remove(0);
+        match action {
+            Action::EventReceived(event) => {
+                self.events.push(Arc::clone(event));
+                if self.events.len() > self.capacity {
+                    self.events.remove(0);
+                }
             }
+            Action::ThreatsUpdated(result) => {
+                self.threats = Some(result.clone());
+                // Drop a category filter that no longer matches anything
+                if let Some(category) = &self.threat_category {
+                    if !self.threat_categories().contains(&category.as_str()) {
+                        self.threat_category = None;
+                    }
+                }
+                self.threat_scroll = 0;
+            }
+            _ => {}
         }
         Ok(None)
     }
 
     fn render(&self, frame: &mut Frame, area: Rect) {
-        let count = self.events.len();
         let live_indicator = if self.paused {
             Span::styled("PAUSED", Style::default().fg(theme::ELECTRIC_YELLOW))
         } else {
             Span::styled("● LIVE", Style::default().fg(theme::SUCCESS_GREEN))
         };
 
-        let title = format!(" Events ({count}) ");
         let block = Block::default()
-            .title(title)
+            .title(self.title())
             .title_style(theme::title_style())
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
@@ -124,6 +364,11 @@ impl Component for EventsScreen {
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
+        if self.view == EventsView::Threats {
+            self.render_threats(frame, inner);
+            return;
+        }
+
         let layout = Layout::vertical([
             Constraint::Length(1), // status line
             Constraint::Min(1),    // events
@@ -212,6 +457,8 @@ impl Component for EventsScreen {
             Span::styled("pause/resume  ", theme::key_hint()),
             Span::styled("j/k ", theme::key_hint_key()),
             Span::styled("scroll (paused)  ", theme::key_hint()),
+            Span::styled("t ", theme::key_hint_key()),
+            Span::styled("threats  ", theme::key_hint()),
             Span::styled("/ ", theme::key_hint_key()),
             Span::styled("search", theme::key_hint()),
         ]);
@@ -2,6 +2,10 @@
 //!
 //! Flow: Welcome → URL → AuthMode → Credentials → Site → Testing → Done
 //!
+//! When the controller demands a second factor, Testing detours through
+//! a TwoFactor step and retries with the code (or a TOTP secret, which is
+//! then stored in the keyring for future logins).
+//!
 //! On completion, saves the config to disk and emits `OnboardingComplete`
 //! with the built `ControllerConfig` so the app can connect immediately.
 
@@ -16,6 +20,8 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders, Paragraph, Wrap};
 use tokio::sync::mpsc::UnboundedSender;
 
+use unifly_core::{CoreError, TwoFactor};
+
 use crate::action::Action;
 use crate::component::Component;
 use crate::theme;
@@ -29,6 +35,7 @@ enum WizardStep {
     AuthMode,
     Credentials,
     Site,
+    TwoFactor,
     Testing,
     Done,
 }
@@ -42,7 +49,7 @@ impl WizardStep {
             Self::AuthMode => 2,
             Self::Credentials => 3,
             Self::Site => 4,
-            Self::Testing => 5,
+            Self::TwoFactor | Self::Testing => 5,
             Self::Done => 6,
         }
     }
@@ -104,6 +111,8 @@ pub struct OnboardingScreen {
     username_input: String,
     password_input: String,
     site_input: String,
+    /// One-time code or base32 TOTP secret, when the account requires 2FA.
+    two_factor_input: String,
     cred_field: CredentialField,
     show_password: bool,
     // Test state
@@ -128,6 +137,7 @@ impl OnboardingScreen {
             username_input: String::new(),
             password_input: String::new(),
             site_input: "default".into(),
+            two_factor_input: String::new(),
             cred_field: CredentialField::ApiKey,
             show_password: false,
             testing: false,
@@ -178,6 +188,14 @@ impl OnboardingScreen {
                 self.step = WizardStep::Testing;
                 self.start_connection_test();
             }
+            WizardStep::TwoFactor => {
+                if self.two_factor_input.trim().is_empty() {
+                    self.error = Some("Code cannot be empty".into());
+                    return;
+                }
+                self.step = WizardStep::Testing;
+                self.start_connection_test();
+            }
             WizardStep::Testing => {
                 // Can't advance manually — test result moves us
             }
@@ -197,6 +215,10 @@ impl OnboardingScreen {
             WizardStep::AuthMode => self.step = WizardStep::Url,
             WizardStep::Credentials => self.step = WizardStep::AuthMode,
             WizardStep::Site => self.step = WizardStep::Credentials,
+            WizardStep::TwoFactor => {
+                self.two_factor_input.clear();
+                self.step = WizardStep::Site;
+            }
             WizardStep::Testing => {
                 self.testing = false;
                 self.step = WizardStep::Site;
@@ -254,9 +276,29 @@ impl OnboardingScreen {
                 AuthMode::Legacy | AuthMode::Hybrid => Some(self.password_input.clone()),
                 AuthMode::ApiKey => None,
             },
+            host_id: None,
             ca_cert: None,
             insecure: Some(true), // Local controllers are typically self-signed
             timeout: None,
+            retries: None,
+            retry_writes: None,
+            history: None,
+            history_retention_days: None,
+            tags: Vec::new(),
+            known_rogue_aps: Vec::new(),
+        }
+    }
+
+    /// The second factor typed in, if any. All digits is a one-time code;
+    /// anything else is taken as a TOTP secret.
+    fn two_factor(&self) -> Option<TwoFactor> {
+        let input = self.two_factor_input.trim();
+        if input.is_empty() {
+            None
+        } else if input.chars().all(|c| c.is_ascii_digit()) {
+            Some(TwoFactor::Code(input.to_owned().into()))
+        } else {
+            Some(TwoFactor::TotpSecret(input.to_owned().into()))
         }
     }
 
@@ -267,6 +309,7 @@ impl OnboardingScreen {
 
         let profile = self.build_profile();
         let profile_name = "default".to_string();
+        let two_factor = self.two_factor();
 
         let Some(tx) = self.action_tx.clone() else {
             return;
@@ -275,11 +318,19 @@ impl OnboardingScreen {
         tokio::spawn(async move {
             let result = match unifly_config::profile_to_controller_config(&profile, &profile_name)
             {
-                Ok(config) => {
+                Ok(mut config) => {
+                    config.two_factor.clone_from(&two_factor);
                     let controller = unifly_core::Controller::new(config);
                     match controller.connect().await {
                         Ok(()) => {
                             controller.disconnect().await;
+                            if let Some(TwoFactor::TotpSecret(ref secret)) = two_factor {
+                                if let Err(e) =
+                                    unifly_config::store_totp_secret(&profile_name, secret)
+                                {
+                                    tracing::warn!("failed to store TOTP secret: {e}");
+                                }
+                            }
                             // Save config on success
                             let cfg = unifly_config::Config {
                                 default_profile: Some(profile_name),
@@ -296,6 +347,10 @@ impl OnboardingScreen {
                                 Ok(())
                             }
                         }
+                        Err(CoreError::TwoFactorRequired) => {
+                            let _ = tx.send(Action::OnboardingTwoFactorRequired);
+                            return;
+                        }
                         Err(e) => Err(format!("{e}")),
                     }
                 }
@@ -316,7 +371,10 @@ impl OnboardingScreen {
         };
 
         match unifly_config::profile_to_controller_config(&profile, profile_name) {
-            Ok(config) => {
+            Ok(mut config) => {
+                if let Some(two_factor) = self.two_factor() {
+                    config.two_factor = Some(two_factor);
+                }
                 let _ = tx.send(Action::OnboardingComplete {
                     profile_name: profile_name.to_string(),
                     config: Box::new(config),
@@ -337,6 +395,7 @@ impl OnboardingScreen {
         match self.step {
             WizardStep::Url => Some(&mut self.url_input),
             WizardStep::Site => Some(&mut self.site_input),
+            WizardStep::TwoFactor => Some(&mut self.two_factor_input),
             WizardStep::Credentials => match self.cred_field {
                 CredentialField::ApiKey => Some(&mut self.api_key_input),
                 CredentialField::Username => Some(&mut self.username_input),
@@ -489,6 +548,7 @@ impl OnboardingScreen {
             WizardStep::Url | WizardStep::Site => "Enter next  Esc back  Ctrl+C quit",
             WizardStep::AuthMode => "Up/Down select  Enter confirm  Esc back",
             WizardStep::Credentials => "Tab next field  Enter next  Esc back",
+            WizardStep::TwoFactor => "Enter verify  Esc back",
             WizardStep::Testing => "Esc cancel",
             WizardStep::Done => "Enter connect!",
         };
@@ -519,7 +579,7 @@ impl Component for OnboardingScreen {
                 }
             }
 
-            WizardStep::Url | WizardStep::Site => match key.code {
+            WizardStep::Url | WizardStep::Site | WizardStep::TwoFactor => match key.code {
                 KeyCode::Enter => self.advance(),
                 KeyCode::Esc => self.go_back(),
                 KeyCode::Backspace => {
@@ -601,6 +661,14 @@ impl Component for OnboardingScreen {
                     }
                 }
             }
+            Action::OnboardingTwoFactorRequired => {
+                self.testing = false;
+                self.error = self
+                    .two_factor()
+                    .map(|_| "Code rejected, try again".to_owned());
+                self.two_factor_input.clear();
+                self.step = WizardStep::TwoFactor;
+            }
             Action::Tick => {
                 if self.testing {
                     self.throbber_state.calc_next();
@@ -655,6 +723,7 @@ impl Component for OnboardingScreen {
             WizardStep::AuthMode => self.render_auth_mode(frame, content),
             WizardStep::Credentials => self.render_credentials(frame, content),
             WizardStep::Site => self.render_site(frame, content),
+            WizardStep::TwoFactor => self.render_two_factor(frame, content),
             WizardStep::Testing => self.render_testing(frame, content),
             WizardStep::Done => self.render_done(frame, content),
         }
@@ -897,6 +966,50 @@ impl OnboardingScreen {
         self.render_input_field(frame, layout[1], "  Site", &self.site_input, true, false);
     }
 
+    fn render_two_factor(&self, frame: &mut Frame, area: Rect) {
+        let layout = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(4),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                "Two-factor authentication",
+                Style::default()
+                    .fg(theme::NEON_CYAN)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Center),
+            layout[0],
+        );
+
+        self.render_input_field(
+            frame,
+            layout[1],
+            "  Code or TOTP secret",
+            &self.two_factor_input,
+            true,
+            false,
+        );
+
+        frame.render_widget(
+            Paragraph::new(vec![
+                Line::from(Span::styled(
+                    "  Enter the code from your authenticator app, or the",
+                    Style::default().fg(theme::BORDER_GRAY),
+                )),
+                Line::from(Span::styled(
+                    "  base32 secret to store it in the keyring.",
+                    Style::default().fg(theme::BORDER_GRAY),
+                )),
+            ]),
+            layout[2],
+        );
+    }
+
     fn render_testing(&self, frame: &mut Frame, area: Rect) {
         let layout = Layout::vertical([
             Constraint::Length(3),
@@ -112,6 +112,14 @@ pub struct SettingsScreen {
     site_input: String,
     insecure: bool,
     show_password: bool,
+    // Not editable here; carried through so saving keeps them
+    tags: Vec<String>,
+    host_id: Option<String>,
+    retries: Option<u32>,
+    retry_writes: Option<bool>,
+    history: Option<bool>,
+    history_retention_days: Option<u32>,
+    known_rogue_aps: Vec<String>,
     // Profile name we're editing
     profile_name: String,
     // Test state
@@ -137,6 +145,13 @@ impl SettingsScreen {
             password_input: String::new(),
             site_input: "default".into(),
             insecure: true,
+            tags: Vec::new(),
+            host_id: None,
+            retries: None,
+            retry_writes: None,
+            history: None,
+            history_retention_days: None,
+            known_rogue_aps: Vec::new(),
             show_password: false,
             profile_name: "default".into(),
             test_error: None,
@@ -162,6 +177,13 @@ impl SettingsScreen {
         self.url_input.clone_from(&profile.controller);
         self.site_input.clone_from(&profile.site);
         self.insecure = profile.insecure.unwrap_or(false);
+        self.tags.clone_from(&profile.tags);
+        self.host_id.clone_from(&profile.host_id);
+        self.retries = profile.retries;
+        self.retry_writes = profile.retry_writes;
+        self.history = profile.history;
+        self.history_retention_days = profile.history_retention_days;
+        self.known_rogue_aps.clone_from(&profile.known_rogue_aps);
 
         self.auth_mode = AuthMode::from_config(&profile.auth_mode);
         self.auth_mode_index = AuthMode::ALL
@@ -289,9 +311,16 @@ impl SettingsScreen {
                 AuthMode::Legacy | AuthMode::Hybrid => Some(self.password_input.clone()),
                 AuthMode::ApiKey => None,
             },
+            host_id: self.host_id.clone(),
             ca_cert: None,
             insecure: Some(self.insecure),
             timeout: None,
+            retries: self.retries,
+            retry_writes: self.retry_writes,
+            history: self.history,
+            history_retention_days: self.history_retention_days,
+            tags: self.tags.clone(),
+            known_rogue_aps: self.known_rogue_aps.clone(),
         }
     }
 
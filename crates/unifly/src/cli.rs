@@ -2,6 +2,7 @@
 //!
 //! Defines the complete command tree, global flags, and shared types.
 
+use std::net::Ipv4Addr;
 use std::path::PathBuf;
 
 use clap::{Args, Parser, Subcommand, ValueEnum};
@@ -31,7 +32,7 @@ pub struct Cli {
 
 // ── Global Options ───────────────────────────────────────────────────
 
-#[derive(Debug, Args)]
+#[derive(Debug, Clone, Args)]
 pub struct GlobalOpts {
     /// Controller profile to use
     #[arg(long, short = 'p', env = "UNIFI_PROFILE", global = true)]
@@ -41,7 +42,7 @@ pub struct GlobalOpts {
     #[arg(long, short = 'c', env = "UNIFI_CONTROLLER", global = true)]
     pub controller: Option<String>,
 
-    /// Site name or UUID
+    /// Site name or UUID (`all` loads every site; lists gain a Site column)
     #[arg(long, short = 's', env = "UNIFI_SITE", global = true)]
     pub site: Option<String>,
 
@@ -49,6 +50,10 @@ pub struct GlobalOpts {
     #[arg(long, env = "UNIFI_API_KEY", global = true, hide_env = true)]
     pub api_key: Option<String>,
 
+    /// One-time two-factor code for legacy login
+    #[arg(long, value_name = "CODE", global = true)]
+    pub otp: Option<String>,
+
     /// Output format
     #[arg(
         long,
@@ -123,10 +128,16 @@ pub enum Command {
     /// Manage alarms
     Alarms(AlarmsArgs),
 
+    /// Apply a desired-state file (networks, WiFi, firewall, ACL, DNS)
+    Apply(ApplyArgs),
+
     /// Manage connected clients
     #[command(alias = "cl")]
     Clients(ClientsArgs),
 
+    /// List hosts, sites and devices via the Site Manager cloud API
+    Cloud(CloudArgs),
+
     /// Generate shell completions
     Completions(CompletionsArgs),
 
@@ -149,20 +160,55 @@ pub enum Command {
     /// View and stream events
     Events(EventsArgs),
 
+    /// Serve Prometheus / OpenMetrics metrics from a live connection
+    Exporter(ExporterArgs),
+
+    /// Export the site's configuration as a portable snapshot
+    Export(ExportArgs),
+
     /// Manage firewall policies and zones
     #[command(alias = "fw")]
     Firewall(FirewallArgs),
 
+    /// Check firmware versions and roll out upgrades in batches
+    #[command(alias = "fwup")]
+    Firmware(FirmwareArgs),
+
+    /// Summarize every configured controller at once
+    Fleet(FleetArgs),
+
+    /// Manage user groups (per-client bandwidth limits)
+    Groups(GroupsArgs),
+
+    /// Show stats recorded to disk by the history recorder
+    History(HistoryArgs),
+
     /// Manage hotspot vouchers
     Hotspot(HotspotArgs),
 
+    /// Import a snapshot produced by `export` into the site
+    Import(ImportArgs),
+
     /// Manage networks and VLANs
     #[command(alias = "net", alias = "n")]
     Networks(NetworksArgs),
 
+    /// Show the changes `apply` would make for a desired-state file
+    Plan(PlanArgs),
+
+    /// Manage port-forward rules
+    #[command(alias = "pf")]
+    PortForwards(PortForwardsArgs),
+
     /// View RADIUS profiles
     Radius(RadiusArgs),
 
+    /// Manage static routes
+    Routes(RoutesArgs),
+
+    /// View and change site settings (NTP, SNMP, syslog, SSH, guest access, ...)
+    Settings(SettingsArgs),
+
     /// Manage sites
     Sites(SitesArgs),
 
@@ -173,12 +219,18 @@ pub enum Command {
     #[command(alias = "sys")]
     System(SystemArgs),
 
+    /// Report IDS/IPS threat detections (legacy API)
+    Threats(ThreatsArgs),
+
     /// Manage traffic matching lists
     TrafficLists(TrafficListsArgs),
 
     /// View VPN servers and tunnels
     Vpn(VpnArgs),
 
+    /// Watch events, alarms and device state and send rule-based alerts
+    WatchAlerts(WatchAlertsArgs),
+
     /// View WAN interfaces
     Wans(WansArgs),
 
@@ -210,6 +262,27 @@ pub struct ListArgs {
     pub filter: Option<String>,
 }
 
+/// Shared arguments for commands that can act on many targets at once.
+#[derive(Debug, Args)]
+pub struct BulkArgs {
+    /// Act on every match of a selector instead of a single target,
+    /// e.g. 'type=ap,tag=floor2' or 'ssid=Guest,signal<-75'
+    #[arg(long)]
+    pub selector: Option<String>,
+
+    /// List the selector's matches without acting on them
+    #[arg(long, requires = "selector")]
+    pub dry_run: bool,
+
+    /// Targets acted on at the same time
+    #[arg(
+        long,
+        default_value = "4",
+        value_parser = clap::value_parser!(u16).range(1..=32)
+    )]
+    pub concurrency: u16,
+}
+
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 //  DEVICES
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -249,10 +322,16 @@ pub enum DevicesCommand {
         device: String,
     },
 
-    /// Restart a device
+    /// Restart a device, or every device matching --selector
+    ///
+    /// Selector keys: type, state, model, tag, name, mac.
     Restart {
         /// Device ID (UUID) or MAC address
-        device: String,
+        #[arg(required_unless_present = "selector", conflicts_with = "selector")]
+        device: Option<String>,
+
+        #[command(flatten)]
+        bulk: BulkArgs,
     },
 
     /// Toggle locate LED (blink to identify device)
@@ -303,8 +382,192 @@ pub enum DevicesCommand {
     /// Run WAN speed test (legacy API, gateway only)
     Speedtest,
 
+    /// Run an RF spectrum scan on an AP (legacy API)
+    ///
+    /// The AP stops serving clients on its radios while the scan runs
+    /// (usually a few minutes).
+    RfScan {
+        /// Device ID (UUID) or MAC address
+        device: String,
+
+        /// Wait for the scan to finish and show per-channel results
+        #[arg(long)]
+        wait: bool,
+
+        /// Show the previous scan's results without starting a new one
+        #[arg(long, conflicts_with = "wait")]
+        last: bool,
+    },
+
     /// List device tags
     Tags(ListArgs),
+
+    /// Show or configure switch ports (legacy API)
+    Ports(DevicePortsArgs),
+
+    /// List switch port profiles (legacy API)
+    PortProfiles(ListArgs),
+
+    /// Show or change an AP's radio settings (legacy API)
+    ///
+    /// Without --band, lists the AP's radios. Channels and widths are
+    /// checked against the site country's regulatory channel list.
+    Radio {
+        /// Device ID (UUID) or MAC address
+        device: String,
+
+        /// Band to change
+        #[arg(long, value_enum)]
+        band: Option<RadioBand>,
+
+        /// Channel number, or "auto"
+        #[arg(long, requires = "band")]
+        channel: Option<String>,
+
+        /// Channel width in MHz
+        #[arg(long, value_name = "MHZ", requires = "band")]
+        width: Option<u32>,
+
+        /// Transmit power: auto, high, medium, low, or a level in dBm
+        #[arg(long, requires = "band")]
+        tx_power: Option<String>,
+
+        /// Disconnect clients with a weaker signal than this (dBm, e.g. -75)
+        #[arg(
+            long,
+            value_name = "DBM",
+            allow_negative_numbers = true,
+            requires = "band"
+        )]
+        min_rssi: Option<i32>,
+
+        /// Turn off the minimum RSSI check
+        #[arg(long, conflicts_with = "min_rssi", requires = "band")]
+        no_min_rssi: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum RadioBand {
+    #[value(name = "2.4")]
+    Ghz2_4,
+    #[value(name = "5")]
+    Ghz5,
+    #[value(name = "6")]
+    Ghz6,
+}
+
+#[derive(Debug, Args)]
+pub struct DevicePortsArgs {
+    /// Device ID (UUID) or MAC address
+    pub device: String,
+
+    #[command(subcommand)]
+    pub command: DevicePortsCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum DevicePortsCommand {
+    /// List the device's ports
+    #[command(alias = "ls")]
+    List(ListArgs),
+
+    /// Override a port's configuration
+    Set {
+        /// Port index
+        #[arg(value_name = "PORT_IDX")]
+        port: u32,
+
+        /// Port name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Port profile to assign (name or ID)
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Native (untagged) network (name or ID)
+        #[arg(long)]
+        native_network: Option<String>,
+
+        /// Enable the port
+        #[arg(long, conflicts_with = "disable")]
+        enable: bool,
+
+        /// Disable the port
+        #[arg(long)]
+        disable: bool,
+
+        /// PoE mode
+        #[arg(long, value_enum)]
+        poe: Option<PoeMode>,
+    },
+}
+
+#[derive(Debug, Clone, ValueEnum)]
+pub enum PoeMode {
+    Auto,
+    Off,
+    /// Passive 24V
+    Pasv24,
+    Passthrough,
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+//  FIRMWARE
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+#[derive(Debug, Args)]
+pub struct FirmwareArgs {
+    #[command(subcommand)]
+    pub command: FirmwareCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum FirmwareCommand {
+    /// Show current and available firmware for each device
+    Status {
+        /// Only show devices with an upgrade available
+        #[arg(long)]
+        upgradable: bool,
+    },
+
+    /// Upgrade devices in batches, waiting for each batch to come back online
+    ///
+    /// Access points go first and gateways last. The rollout stops at the
+    /// first batch that fails or does not come back in time.
+    Rollout {
+        /// Only upgrade devices of this type
+        #[arg(long = "type", value_enum)]
+        device_type: Option<DeviceKind>,
+
+        /// Only upgrade devices carrying this tag (name or ID)
+        #[arg(long)]
+        tag: Option<String>,
+
+        /// Devices upgraded at the same time
+        #[arg(long, default_value = "1", value_parser = clap::value_parser!(u16).range(1..))]
+        batch_size: u16,
+
+        /// Seconds to wait for a batch to come back online
+        #[arg(long, value_name = "SECS", default_value = "1200")]
+        batch_timeout: u64,
+
+        /// Show the batches without upgrading anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DeviceKind {
+    #[value(alias = "uap")]
+    Ap,
+    #[value(alias = "usw")]
+    Switch,
+    #[value(alias = "ugw")]
+    Gateway,
+    Other,
 }
 
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -321,17 +584,34 @@ pub struct ClientsArgs {
 pub enum ClientsCommand {
     /// List connected clients
     #[command(alias = "ls")]
-    List(ListArgs),
+    List {
+        #[command(flatten)]
+        list: ListArgs,
 
-    /// Get connected client details
+        /// Also list known clients that are not connected now (legacy API)
+        #[arg(long)]
+        include_offline: bool,
+    },
+
+    /// Get client details; offline clients are looked up via the legacy API
     Get {
         /// Client ID (UUID) or MAC address
         client: String,
     },
 
+    /// Show when a client was last seen and its recent sessions (legacy API)
+    History {
+        /// Client ID or MAC address
+        client: String,
+
+        /// How far back to list sessions: 30m, 6h, 2d, or an RFC3339 timestamp
+        #[arg(long, default_value = "7d")]
+        within: String,
+    },
+
     /// Authorize guest access
     Authorize {
-        /// Client ID (UUID)
+        /// Client ID (UUID) or MAC address
         client: String,
 
         /// Authorization duration in minutes
@@ -353,7 +633,7 @@ pub enum ClientsCommand {
 
     /// Revoke guest access
     Unauthorize {
-        /// Client ID (UUID)
+        /// Client ID (UUID) or MAC address
         client: String,
     },
 
@@ -369,10 +649,18 @@ pub enum ClientsCommand {
         mac: String,
     },
 
-    /// Disconnect/reconnect a wireless client (legacy API)
+    /// Disconnect/reconnect a wireless client, or every client matching
+    /// --selector (legacy API)
+    ///
+    /// Selector keys: ssid, signal (=, < or > in dBm), type, network,
+    /// ap, guest, blocked.
     Kick {
         /// Client MAC address
-        mac: String,
+        #[arg(required_unless_present = "selector", conflicts_with = "selector")]
+        mac: Option<String>,
+
+        #[command(flatten)]
+        bulk: BulkArgs,
     },
 
     /// Forget a client from controller history (legacy API)
@@ -380,6 +668,113 @@ pub enum ClientsCommand {
         /// Client MAC address
         mac: String,
     },
+
+    /// Reserve a fixed DHCP address for a client (legacy API)
+    Reserve {
+        /// Client MAC address
+        mac: String,
+
+        /// IPv4 address to reserve; must be outside the network's DHCP range
+        #[arg(long)]
+        ip: Ipv4Addr,
+
+        /// Network the address belongs to (name or ID)
+        #[arg(long)]
+        network: String,
+    },
+
+    /// Release a client's DHCP reservation (legacy API)
+    Unreserve {
+        /// Client MAC address
+        mac: String,
+    },
+
+    /// Set a client's alias; omit the name to clear it (legacy API)
+    Alias {
+        /// Client MAC address
+        mac: String,
+
+        /// New alias
+        name: Option<String>,
+    },
+
+    /// Set a client's note; omit the text to clear it (legacy API)
+    Note {
+        /// Client MAC address
+        mac: String,
+
+        /// Note text
+        note: Option<String>,
+    },
+
+    /// Move a client into a user group (legacy API)
+    SetGroup {
+        /// Client MAC address
+        mac: String,
+
+        /// User group name or ID
+        #[arg(required_unless_present = "default", conflicts_with = "default")]
+        group: Option<String>,
+
+        /// Return the client to the default group
+        #[arg(long)]
+        default: bool,
+    },
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+//  USER GROUPS
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+#[derive(Debug, Args)]
+pub struct GroupsArgs {
+    #[command(subcommand)]
+    pub command: GroupsCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum GroupsCommand {
+    /// List user groups
+    #[command(alias = "ls")]
+    List(ListArgs),
+
+    /// Create a user group
+    Create {
+        /// Group name
+        name: String,
+
+        /// Per-client download limit in Kbps (0 or omitted: unlimited)
+        #[arg(long)]
+        down: Option<u32>,
+
+        /// Per-client upload limit in Kbps (0 or omitted: unlimited)
+        #[arg(long)]
+        up: Option<u32>,
+    },
+
+    /// Change a user group's name or limits
+    Update {
+        /// User group name or ID
+        group: String,
+
+        /// New name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Per-client download limit in Kbps (0: unlimited)
+        #[arg(long)]
+        down: Option<u32>,
+
+        /// Per-client upload limit in Kbps (0: unlimited)
+        #[arg(long)]
+        up: Option<u32>,
+    },
+
+    /// Delete a user group; its clients return to the default group
+    Delete {
+        /// User group name or ID
+        group: String,
+    },
 }
 
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -604,6 +999,14 @@ pub enum WifiCommand {
         #[arg(long)]
         force: bool,
     },
+
+    /// List neighboring (foreign) APs heard by our APs
+    #[command(alias = "rogue")]
+    Neighbors {
+        /// Only APs seen in the last N hours (controller default 24)
+        #[arg(long, value_name = "HOURS")]
+        within: Option<u32>,
+    },
 }
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -984,6 +1387,200 @@ pub enum DnsRecordType {
     Forward,
 }
 
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+//  PORT FORWARDS
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+#[derive(Debug, Args)]
+pub struct PortForwardsArgs {
+    #[command(subcommand)]
+    pub command: PortForwardsCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PortForwardsCommand {
+    /// List port-forward rules
+    #[command(alias = "ls")]
+    List(ListArgs),
+
+    /// Get a port-forward rule
+    Get {
+        /// Port-forward rule ID
+        id: String,
+    },
+
+    /// Create a port-forward rule
+    Create {
+        /// Rule name
+        #[arg(long, required_unless_present = "from_file")]
+        name: Option<String>,
+
+        /// External (WAN) port or range, e.g. 443 or 5000-5010
+        #[arg(long, required_unless_present = "from_file")]
+        wan_port: Option<String>,
+
+        /// Internal host to forward to
+        #[arg(long, required_unless_present = "from_file")]
+        forward_ip: Option<String>,
+
+        /// Internal port or range (default: same as --wan-port)
+        #[arg(long)]
+        forward_port: Option<String>,
+
+        /// Protocol
+        #[arg(long, value_enum, default_value = "tcp-udp")]
+        protocol: PortForwardProtocol,
+
+        /// WAN interface: wan, wan2, or both
+        #[arg(long, default_value = "wan")]
+        wan_interface: String,
+
+        /// Only allow traffic from this address or CIDR
+        #[arg(long)]
+        source: Option<String>,
+
+        /// Enable the rule (default: true)
+        #[arg(long, default_value = "true", action = clap::ArgAction::Set)]
+        enabled: bool,
+
+        /// Log forwarded traffic
+        #[arg(long)]
+        logging: bool,
+
+        /// Create from JSON file
+        #[arg(long, short = 'F', conflicts_with_all = &["name", "wan_port", "forward_ip"])]
+        from_file: Option<PathBuf>,
+    },
+
+    /// Update a port-forward rule
+    Update {
+        /// Port-forward rule ID
+        id: String,
+
+        /// Load changed fields from JSON file
+        #[arg(long, short = 'F')]
+        from_file: Option<PathBuf>,
+    },
+
+    /// Enable a port-forward rule
+    Enable {
+        /// Port-forward rule ID
+        id: String,
+    },
+
+    /// Disable a port-forward rule
+    Disable {
+        /// Port-forward rule ID
+        id: String,
+    },
+
+    /// Delete a port-forward rule
+    Delete {
+        /// Port-forward rule ID
+        id: String,
+    },
+}
+
+#[derive(Debug, Clone, ValueEnum)]
+pub enum PortForwardProtocol {
+    Tcp,
+    Udp,
+    TcpUdp,
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+//  ROUTES
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+#[derive(Debug, Args)]
+pub struct RoutesArgs {
+    #[command(subcommand)]
+    pub command: RoutesCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum RoutesCommand {
+    /// List static routes
+    #[command(alias = "ls")]
+    List(ListArgs),
+
+    /// Get a static route
+    Get {
+        /// Static route ID
+        id: String,
+    },
+
+    /// Create a static route
+    Create {
+        /// Route name
+        #[arg(long, required_unless_present = "from_file")]
+        name: Option<String>,
+
+        /// Destination network in CIDR notation, e.g. 10.50.0.0/16
+        #[arg(long, required_unless_present = "from_file")]
+        destination: Option<String>,
+
+        /// Route type
+        #[arg(long = "type", value_enum, default_value = "next-hop")]
+        route_type: StaticRouteType,
+
+        /// Gateway IP (next-hop routes)
+        #[arg(long)]
+        next_hop: Option<String>,
+
+        /// Egress interface, e.g. WAN1 (interface routes)
+        #[arg(long)]
+        interface: Option<String>,
+
+        /// Administrative distance (default: 1)
+        #[arg(long)]
+        distance: Option<u32>,
+
+        /// Enable the route (default: true)
+        #[arg(long, default_value = "true", action = clap::ArgAction::Set)]
+        enabled: bool,
+
+        /// Create from JSON file
+        #[arg(long, short = 'F', conflicts_with_all = &["name", "destination"])]
+        from_file: Option<PathBuf>,
+    },
+
+    /// Update a static route
+    Update {
+        /// Static route ID
+        id: String,
+
+        /// New destination network (CIDR)
+        #[arg(long)]
+        destination: Option<String>,
+
+        /// New gateway IP
+        #[arg(long)]
+        next_hop: Option<String>,
+
+        /// Enable or disable the route
+        #[arg(long, action = clap::ArgAction::Set)]
+        enabled: Option<bool>,
+
+        /// Load changed fields from JSON file
+        #[arg(long, short = 'F', conflicts_with_all = &["destination", "next_hop", "enabled"])]
+        from_file: Option<PathBuf>,
+    },
+
+    /// Delete a static route
+    Delete {
+        /// Static route ID
+        id: String,
+    },
+}
+
+#[derive(Debug, Clone, ValueEnum)]
+pub enum StaticRouteType {
+    NextHop,
+    Interface,
+    Blackhole,
+}
+
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 //  TRAFFIC MATCHING LISTS
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -1180,6 +1777,33 @@ pub enum SitesCommand {
     },
 }
 
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+//  SETTINGS
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+#[derive(Debug, Args)]
+pub struct SettingsArgs {
+    #[command(subcommand)]
+    pub command: SettingsCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SettingsCommand {
+    /// Show the common settings, or every key in one section (legacy API)
+    Get {
+        /// Section: ntp, country, snmp, syslog, auto_upgrade, ips, mgmt,
+        /// guest_access, or any other section key
+        section: Option<String>,
+    },
+
+    /// Change one or more settings (legacy API)
+    Set {
+        /// Changes as section.key=value, e.g. ntp.ntp_server_1=pool.ntp.org
+        #[arg(required = true, value_name = "SECTION.KEY=VALUE")]
+        assignments: Vec<String>,
+    },
+}
+
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 //  EVENTS
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -1246,6 +1870,39 @@ pub enum AlarmsCommand {
     ArchiveAll,
 }
 
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+//  THREATS
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+#[derive(Debug, Args)]
+pub struct ThreatsArgs {
+    #[command(subcommand)]
+    pub command: ThreatsCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ThreatsCommand {
+    /// List IDS/IPS detections (legacy API)
+    #[command(alias = "ls")]
+    List {
+        /// How far back to look: 30m, 6h, 2d, or an RFC3339 timestamp
+        #[arg(long, default_value = "24h")]
+        within: String,
+
+        /// Only show this signature category (case-insensitive substring)
+        #[arg(long)]
+        category: Option<String>,
+
+        /// Summarize by source IP instead of listing detections
+        #[arg(long)]
+        top_sources: bool,
+
+        /// Max results
+        #[arg(long, short = 'l', default_value = "100")]
+        limit: usize,
+    },
+}
+
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 //  STATS
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -1320,6 +1977,53 @@ pub enum DpiGroupBy {
     ByCat,
 }
 
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+//  HISTORY
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+#[derive(Debug, Args)]
+pub struct HistoryArgs {
+    #[command(subcommand)]
+    pub command: HistoryCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum HistoryCommand {
+    /// WAN throughput, latency and client count
+    Wan(HistoryRange),
+
+    /// Per-device CPU, memory and uplink throughput
+    Devices {
+        /// Only this device
+        #[arg(long)]
+        mac: Option<String>,
+
+        #[command(flatten)]
+        range: HistoryRange,
+    },
+
+    /// Per-client bandwidth and signal
+    Clients {
+        /// Only this client
+        #[arg(long)]
+        mac: Option<String>,
+
+        #[command(flatten)]
+        range: HistoryRange,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct HistoryRange {
+    /// Start: a duration back from now (30m, 6h, 2d) or RFC3339
+    #[arg(long, default_value = "1h")]
+    pub since: String,
+
+    /// End (RFC3339); defaults to now
+    #[arg(long)]
+    pub until: Option<String>,
+}
+
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 //  SYSTEM
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -1483,6 +2187,140 @@ pub enum WansCommand {
     List(ListArgs),
 }
 
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+//  PLAN / APPLY / EXPORT / IMPORT
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+#[derive(Debug, Args)]
+pub struct PlanArgs {
+    /// Desired-state file (YAML, TOML, or JSON)
+    #[arg(long, short = 'f', value_name = "FILE")]
+    pub file: PathBuf,
+
+    /// Delete user-defined resources missing from the file
+    #[arg(long)]
+    pub prune: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ApplyArgs {
+    #[command(flatten)]
+    pub plan: PlanArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct ExportArgs {
+    /// Write the snapshot to a file instead of stdout
+    #[arg(long, short = 'f', value_name = "FILE")]
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct ImportArgs {
+    /// Snapshot file (YAML, TOML, or JSON)
+    pub file: PathBuf,
+
+    /// Delete user-defined resources missing from the snapshot
+    #[arg(long)]
+    pub prune: bool,
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+//  FLEET
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+#[derive(Debug, Args)]
+pub struct FleetArgs {
+    #[command(subcommand)]
+    pub command: FleetCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum FleetCommand {
+    /// Connect to every profile and show health and device counts
+    Status {
+        /// Only include profiles carrying this tag (repeatable; all must match)
+        #[arg(long = "tag", short = 't', value_name = "TAG")]
+        tags: Vec<String>,
+
+        /// Maximum number of controllers contacted at once
+        #[arg(long, default_value = "8")]
+        concurrency: usize,
+    },
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+//  EXPORTER
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+#[derive(Debug, Args)]
+pub struct ExporterArgs {
+    /// Address to serve `/metrics` on
+    #[arg(long, default_value = "0.0.0.0:9130", value_name = "ADDR")]
+    pub listen: std::net::SocketAddr,
+
+    /// Seconds between full data refreshes
+    #[arg(long, default_value = "30")]
+    pub refresh_interval: u64,
+
+    /// Seconds between health, client and device stats polls
+    #[arg(long, default_value = "10")]
+    pub poll_interval: u64,
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+//  WATCH-ALERTS
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+#[derive(Debug, Args)]
+pub struct WatchAlertsArgs {
+    /// Rules file (.toml, .json or .yaml)
+    #[arg(long, value_name = "FILE")]
+    pub rules: PathBuf,
+
+    /// Seconds between alarm list polls
+    #[arg(long, default_value = "30")]
+    pub alarm_interval: u64,
+
+    /// Seconds between device state checks
+    #[arg(long, default_value = "15")]
+    pub device_interval: u64,
+
+    /// Print matching alerts without delivering them
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+//  CLOUD
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+#[derive(Debug, Args)]
+pub struct CloudArgs {
+    #[command(subcommand)]
+    pub command: CloudCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CloudCommand {
+    /// List the consoles the cloud API key can reach
+    Hosts,
+
+    /// List Network sites across all hosts
+    Sites {
+        /// Only show sites on this host
+        #[arg(long, value_name = "HOST_ID")]
+        host: Option<String>,
+    },
+
+    /// List devices across all hosts
+    Devices {
+        /// Only show devices on this host (repeatable)
+        #[arg(long = "host", value_name = "HOST_ID")]
+        hosts: Vec<String>,
+    },
+}
+
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 //  CONFIG
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -1525,6 +2363,13 @@ pub enum ConfigCommand {
         #[arg(long)]
         profile: Option<String>,
     },
+
+    /// Store a TOTP secret in the system keyring for two-factor login
+    SetTotp {
+        /// Profile name
+        #[arg(long)]
+        profile: Option<String>,
+    },
 }
 
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
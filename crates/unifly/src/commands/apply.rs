@@ -0,0 +1,244 @@
+//! Declarative `plan` / `apply` handlers, plus `export` / `import`,
+//! which round-trip a site through the same desired-state format.
+
+use std::path::Path;
+
+use owo_colors::{AnsiColors, OwoColorize};
+use unifly_core::Controller;
+use unifly_core::reconcile::{self, ChangeAction, DesiredState, Plan, PlannedChange};
+
+use crate::cli::{ApplyArgs, ExportArgs, GlobalOpts, ImportArgs, OutputFormat, PlanArgs};
+use crate::error::CliError;
+use crate::output;
+
+use super::util;
+
+// ── File loading ────────────────────────────────────────────────────
+
+/// Load a desired-state document, picking the format from the file extension.
+///
+/// `.toml` and `.json` are parsed as such; anything else is read as YAML.
+pub fn read_desired_state(path: &Path) -> Result<DesiredState, CliError> {
+    let contents = std::fs::read_to_string(path)?;
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+    let parsed = match ext.as_deref() {
+        Some("toml") => toml::from_str(&contents).map_err(|e| e.to_string()),
+        Some("json") => serde_json::from_str(&contents).map_err(|e| e.to_string()),
+        _ => serde_yaml::from_str(&contents).map_err(|e| e.to_string()),
+    };
+    parsed.map_err(|reason| CliError::Validation {
+        field: "file".into(),
+        reason: format!("{}: {reason}", path.display()),
+    })
+}
+
+/// Serialize a desired-state document in the format implied by `path`'s
+/// extension, falling back to the global output format for stdout.
+fn write_desired_state(
+    state: &DesiredState,
+    path: Option<&Path>,
+    global: &GlobalOpts,
+) -> Result<String, CliError> {
+    let ext = path
+        .and_then(Path::extension)
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+    let rendered = match (ext.as_deref(), &global.output) {
+        (Some("toml"), _) => toml::to_string_pretty(state).map_err(|e| CliError::Validation {
+            field: "file".into(),
+            reason: e.to_string(),
+        })?,
+        (Some("yaml" | "yml"), _) | (None, OutputFormat::Yaml) => output::render_yaml(state),
+        (None, OutputFormat::JsonCompact) => output::render_json_compact(state),
+        _ => output::render_json_pretty(state),
+    };
+    Ok(rendered)
+}
+
+fn load(args: &PlanArgs) -> Result<DesiredState, CliError> {
+    let mut desired = read_desired_state(&args.file)?;
+    desired.prune |= args.prune;
+    Ok(desired)
+}
+
+// ── Rendering ───────────────────────────────────────────────────────
+
+fn symbol(action: ChangeAction) -> (&'static str, AnsiColors) {
+    match action {
+        ChangeAction::Create => ("+", AnsiColors::Green),
+        ChangeAction::Update => ("~", AnsiColors::Yellow),
+        ChangeAction::Delete => ("-", AnsiColors::Red),
+    }
+}
+
+fn paint(text: &str, color: AnsiColors, enabled: bool) -> String {
+    if enabled {
+        text.color(color).to_string()
+    } else {
+        text.to_owned()
+    }
+}
+
+fn summary(plan: &Plan) -> String {
+    format!(
+        "Plan: {} to add, {} to change, {} to destroy.",
+        plan.count(ChangeAction::Create),
+        plan.count(ChangeAction::Update),
+        plan.count(ChangeAction::Delete),
+    )
+}
+
+/// Terraform-style human-readable plan.
+fn render_plan_text(plan: &Plan, color: bool) -> String {
+    if plan.is_empty() {
+        return "No changes. The site matches the desired state.".into();
+    }
+
+    let mut lines = Vec::new();
+    for change in &plan.changes {
+        let (sym, ansi) = symbol(change.action);
+        lines.push(paint(
+            &format!("  {sym} {} \"{}\"", change.kind, change.name),
+            ansi,
+            color,
+        ));
+        for field in &change.fields {
+            let line = match &field.old {
+                Some(old) => format!("      {sym} {} = {old} -> {}", field.field, field.new),
+                None => format!("      {sym} {} = {}", field.field, field.new),
+            };
+            lines.push(line);
+        }
+    }
+    lines.push(String::new());
+    lines.push(summary(plan));
+    lines.join("\n")
+}
+
+fn render_plan(plan: &Plan, global: &GlobalOpts) -> String {
+    match &global.output {
+        OutputFormat::Table => render_plan_text(plan, output::should_color(&global.color)),
+        OutputFormat::Json => output::render_json_pretty(plan),
+        OutputFormat::JsonCompact => output::render_json_compact(plan),
+        OutputFormat::Yaml => output::render_yaml(plan),
+        OutputFormat::Plain => plan
+            .changes
+            .iter()
+            .map(|c| format!("{:?} {} {}", c.action, c.kind, c.name).to_lowercase())
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+fn print_warnings(plan: &Plan, quiet: bool) {
+    if quiet {
+        return;
+    }
+    for warning in &plan.warnings {
+        eprintln!("Warning: {warning}");
+    }
+}
+
+fn progress_line(change: &PlannedChange) -> String {
+    let verb = match change.action {
+        ChangeAction::Create => "Creating",
+        ChangeAction::Update => "Updating",
+        ChangeAction::Delete => "Deleting",
+    };
+    format!("{verb} {} \"{}\"...", change.kind, change.name)
+}
+
+// ── Handlers ────────────────────────────────────────────────────────
+
+pub fn handle_plan(
+    controller: &Controller,
+    args: &PlanArgs,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let desired = load(args)?;
+    let plan = reconcile::plan(&desired, controller.store())?;
+    print_warnings(&plan, global.quiet);
+    output::print_output(&render_plan(&plan, global), global.quiet);
+    Ok(())
+}
+
+pub async fn handle_apply(
+    controller: &Controller,
+    args: ApplyArgs,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let desired = load(&args.plan)?;
+    converge(controller, &desired, global).await
+}
+
+pub fn handle_export(
+    controller: &Controller,
+    args: &ExportArgs,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let export = reconcile::export(controller.store());
+    if !global.quiet {
+        for warning in &export.warnings {
+            eprintln!("Warning: {warning}");
+        }
+    }
+
+    let rendered = write_desired_state(&export.state, args.file.as_deref(), global)?;
+    match &args.file {
+        Some(path) => {
+            std::fs::write(path, rendered)?;
+            if !global.quiet {
+                eprintln!("Snapshot written to {}", path.display());
+            }
+        }
+        None => println!("{rendered}"),
+    }
+    Ok(())
+}
+
+pub async fn handle_import(
+    controller: &Controller,
+    args: ImportArgs,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let mut desired = read_desired_state(&args.file)?;
+    desired.prune |= args.prune;
+    converge(controller, &desired, global).await
+}
+
+/// Show the plan for `desired`, confirm, then apply it.
+async fn converge(
+    controller: &Controller,
+    desired: &DesiredState,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let plan = reconcile::plan(desired, controller.store())?;
+    print_warnings(&plan, global.quiet);
+    output::print_output(&render_plan(&plan, global), global.quiet);
+
+    if plan.is_empty() {
+        return Ok(());
+    }
+    if !util::confirm(
+        &format!("Apply {} change(s)?", plan.changes.len()),
+        global.yes,
+    )? {
+        return Ok(());
+    }
+
+    let quiet = global.quiet;
+    let applied = reconcile::apply(controller, desired, |change| {
+        if !quiet {
+            eprintln!("{}", progress_line(change));
+        }
+    })
+    .await?;
+
+    if !global.quiet {
+        eprintln!("Apply complete: {applied} change(s) applied");
+    }
+    Ok(())
+}
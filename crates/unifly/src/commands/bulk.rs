@@ -0,0 +1,172 @@
+//! Bulk operations over `--selector` matches.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+use tabled::Tabled;
+use unifly_core::stream::Selector;
+use unifly_core::{Client, Command as CoreCommand, Controller, Device};
+
+use crate::cli::{BulkArgs, GlobalOpts};
+use crate::error::CliError;
+use crate::output;
+
+use super::util;
+
+/// One target of a bulk operation and the command to run on it.
+pub struct BulkTarget {
+    pub name: String,
+    pub mac: String,
+    pub command: CoreCommand,
+}
+
+/// Outcome for one target.
+#[derive(Serialize)]
+struct BulkOutcome {
+    name: String,
+    mac: String,
+    ok: bool,
+    result: String,
+}
+
+#[derive(Tabled)]
+struct BulkRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "MAC")]
+    mac: String,
+    #[tabled(rename = "Result")]
+    result: String,
+}
+
+impl From<&BulkOutcome> for BulkRow {
+    fn from(o: &BulkOutcome) -> Self {
+        Self {
+            name: o.name.clone(),
+            mac: o.mac.clone(),
+            result: o.result.clone(),
+        }
+    }
+}
+
+fn parse_selector(raw: &str) -> Result<Selector, CliError> {
+    raw.parse().map_err(|e| selector_error(&e))
+}
+
+fn selector_error(e: &unifly_core::CoreError) -> CliError {
+    CliError::Validation {
+        field: "selector".into(),
+        reason: e.to_string(),
+    }
+}
+
+/// Devices matching a selector expression such as `type=ap,tag=floor2`.
+pub async fn select_devices(
+    controller: &Controller,
+    raw: &str,
+) -> Result<Vec<Arc<Device>>, CliError> {
+    let selector = parse_selector(raw)?;
+    let mut tags = HashMap::new();
+    for tag in selector.tags() {
+        tags.insert(
+            tag.to_owned(),
+            util::device_tag_members(controller, tag).await?,
+        );
+    }
+    let filter = selector
+        .device_filter(|tag| tags.get(tag).cloned())
+        .map_err(|e| selector_error(&e))?;
+    Ok(controller
+        .devices_snapshot()
+        .iter()
+        .filter(|d| filter.matches(d))
+        .cloned()
+        .collect())
+}
+
+/// Clients matching a selector expression such as `ssid=Guest,signal<-75`.
+pub fn select_clients(controller: &Controller, raw: &str) -> Result<Vec<Arc<Client>>, CliError> {
+    let filter = parse_selector(raw)?
+        .client_filter()
+        .map_err(|e| selector_error(&e))?;
+    Ok(controller
+        .clients_snapshot()
+        .iter()
+        .filter(|c| filter.matches(c))
+        .cloned()
+        .collect())
+}
+
+/// Run `targets` with bounded concurrency and print one row per target.
+///
+/// `action` is the verb shown in prompts and the dry-run preview
+/// (e.g. "restart"). Fails with `BulkFailed` if any target failed.
+pub async fn run(
+    controller: &Controller,
+    targets: Vec<BulkTarget>,
+    action: &str,
+    args: &BulkArgs,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    if targets.is_empty() {
+        if !global.quiet {
+            eprintln!("Selector matched nothing");
+        }
+        return Ok(());
+    }
+
+    if args.dry_run {
+        let preview: Vec<BulkOutcome> = targets
+            .into_iter()
+            .map(|t| BulkOutcome {
+                name: t.name,
+                mac: t.mac,
+                ok: true,
+                result: format!("would {action}"),
+            })
+            .collect();
+        print_outcomes(&preview, global);
+        return Ok(());
+    }
+
+    let total = targets.len();
+    if !util::confirm(&format!("Run {action} on {total} target(s)?"), global.yes)? {
+        return Ok(());
+    }
+
+    let (labels, commands): (Vec<_>, Vec<_>) = targets
+        .into_iter()
+        .map(|t| ((t.name, t.mac), t.command))
+        .unzip();
+    let results = controller
+        .execute_many(commands, usize::from(args.concurrency))
+        .await?;
+    let outcomes: Vec<BulkOutcome> = labels
+        .into_iter()
+        .zip(results)
+        .map(|((name, mac), result)| BulkOutcome {
+            name,
+            mac,
+            ok: result.is_ok(),
+            result: result.map_or_else(|e| e.to_string(), |_| "ok".into()),
+        })
+        .collect();
+    print_outcomes(&outcomes, global);
+
+    let failed = outcomes.iter().filter(|o| !o.ok).count();
+    if failed > 0 {
+        return Err(CliError::BulkFailed { failed, total });
+    }
+    Ok(())
+}
+
+fn print_outcomes(outcomes: &[BulkOutcome], global: &GlobalOpts) {
+    let out = output::render_list(
+        &global.output,
+        outcomes,
+        |o| BulkRow::from(o),
+        |o| o.mac.clone(),
+    );
+    output::print_output(&out, global.quiet);
+}
@@ -1,15 +1,20 @@
 //! Client command handlers.
 
+use std::collections::HashSet;
+use std::net::Ipv4Addr;
 use std::sync::Arc;
 
+use chrono::Utc;
 use tabled::Tabled;
-use unifly_core::{Client, Command as CoreCommand, Controller, EntityId, MacAddress};
+use unifly_core::{
+    Client, ClientSession, ClientType, Command as CoreCommand, Controller, EntityId, MacAddress,
+};
 
-use crate::cli::{ClientsArgs, ClientsCommand, GlobalOpts};
+use crate::cli::{ClientsArgs, ClientsCommand, GlobalOpts, ListArgs};
 use crate::error::CliError;
 use crate::output;
 
-use super::util;
+use super::{bulk, util};
 
 // ── Table row ───────────────────────────────────────────────────────
 
@@ -50,6 +55,67 @@ impl From<&Arc<Client>> for ClientRow {
     }
 }
 
+#[derive(Tabled)]
+struct SessionRow {
+    #[tabled(rename = "Connected")]
+    connected: String,
+    #[tabled(rename = "Disconnected")]
+    disconnected: String,
+    #[tabled(rename = "Duration")]
+    duration: String,
+    #[tabled(rename = "Via")]
+    via: String,
+    #[tabled(rename = "IP")]
+    ip: String,
+    #[tabled(rename = "TX Bytes")]
+    tx_bytes: String,
+    #[tabled(rename = "RX Bytes")]
+    rx_bytes: String,
+}
+
+fn or_dash<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| "-".into(), |v| v.to_string())
+}
+
+fn fmt_duration(secs: u64) -> String {
+    let (h, m, s) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if h > 0 {
+        format!("{h}h {m:02}m")
+    } else {
+        format!("{m}m {s:02}s")
+    }
+}
+
+impl SessionRow {
+    /// `device_name` maps an AP or switch MAC to its display name.
+    fn new(s: &ClientSession, device_name: impl Fn(&MacAddress) -> String) -> Self {
+        let via = match s.client_type {
+            ClientType::Wired => match (&s.switch_mac, s.switch_port) {
+                (Some(mac), Some(port)) => format!("{} port {port}", device_name(mac)),
+                (Some(mac), None) => device_name(mac),
+                (None, _) => "-".into(),
+            },
+            _ => match (&s.ap_mac, &s.ssid) {
+                (Some(mac), Some(ssid)) => format!("{} ({ssid})", device_name(mac)),
+                (Some(mac), None) => device_name(mac),
+                (None, ssid) => or_dash(ssid.as_ref()),
+            },
+        };
+        Self {
+            connected: s.connected_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            disconnected: s.disconnected_at.map_or_else(
+                || "still connected".into(),
+                |t| t.format("%Y-%m-%d %H:%M:%S").to_string(),
+            ),
+            duration: s.duration_secs.map_or_else(|| "-".into(), fmt_duration),
+            via,
+            ip: or_dash(s.ip),
+            tx_bytes: or_dash(s.tx_bytes),
+            rx_bytes: or_dash(s.rx_bytes),
+        }
+    }
+}
+
 fn detail(c: &Arc<Client>) -> String {
     let mut lines = vec![
         format!("ID:        {}", c.id),
@@ -64,6 +130,15 @@ fn detail(c: &Arc<Client>) -> String {
         format!("Guest:     {}", c.is_guest),
         format!("Blocked:   {}", c.blocked),
     ];
+    if let Some(ip) = c.fixed_ip {
+        lines.push(format!("Fixed IP:  {ip}"));
+    }
+    if let Some(note) = &c.note {
+        lines.push(format!("Note:      {note}"));
+    }
+    if let Some(group) = &c.user_group {
+        lines.push(format!("Group:     {group}"));
+    }
     if let Some(ref w) = c.wireless {
         lines.push(format!("SSID:      {}", w.ssid.as_deref().unwrap_or("-")));
         if let Some(sig) = w.signal_dbm {
@@ -85,41 +160,15 @@ pub async fn handle(
     global: &GlobalOpts,
 ) -> Result<(), CliError> {
     match args.command {
-        ClientsCommand::List(list) => {
-            let all = controller.clients_snapshot();
-            let snap = util::apply_list_args(all.iter().cloned(), &list, |c, filter| {
-                util::matches_json_filter(c, filter)
-            });
-            let out = output::render_list(
-                &global.output,
-                &snap,
-                |c| ClientRow::from(c),
-                |c| c.id.to_string(),
-            );
-            output::print_output(&out, global.quiet);
-            Ok(())
-        }
+        ClientsCommand::List {
+            list,
+            include_offline,
+        } => list_clients(controller, &list, include_offline, global).await,
 
-        ClientsCommand::Get { client } => {
-            let snap = controller.clients_snapshot();
-            let found = snap
-                .iter()
-                .find(|c| c.id.to_string() == client || c.mac.to_string() == client);
-            match found {
-                Some(c) => {
-                    let out =
-                        output::render_single(&global.output, c, detail, |c| c.id.to_string());
-                    output::print_output(&out, global.quiet);
-                }
-                None => {
-                    return Err(CliError::NotFound {
-                        resource_type: "client".into(),
-                        identifier: client,
-                        list_command: "clients list".into(),
-                    });
-                }
-            }
-            Ok(())
+        ClientsCommand::Get { client } => get(controller, &client, global).await,
+
+        ClientsCommand::History { client, within } => {
+            history(controller, &client, &within, global).await
         }
 
         ClientsCommand::Authorize {
@@ -129,31 +178,21 @@ pub async fn handle(
             rx_limit_kbps,
             tx_limit_kbps,
         } => {
-            let client_id = EntityId::from(client);
-            controller
-                .execute(CoreCommand::AuthorizeGuest {
-                    client_id,
-                    time_limit_minutes: Some(minutes),
-                    data_limit_mb,
-                    rx_rate_kbps: rx_limit_kbps,
-                    tx_rate_kbps: tx_limit_kbps,
-                })
-                .await?;
-            if !global.quiet {
-                eprintln!("Guest authorized for {minutes} minutes");
-            }
-            Ok(())
+            let command = |client_id| CoreCommand::AuthorizeGuest {
+                client_id,
+                time_limit_minutes: Some(minutes),
+                data_limit_mb,
+                rx_rate_kbps: rx_limit_kbps,
+                tx_rate_kbps: tx_limit_kbps,
+            };
+            let done = format!("Guest authorized for {minutes} minutes");
+            guest_command(controller, &client, command, &done, global).await
         }
 
         ClientsCommand::Unauthorize { client } => {
-            let client_id = EntityId::from(client);
-            controller
-                .execute(CoreCommand::UnauthorizeGuest { client_id })
-                .await?;
-            if !global.quiet {
-                eprintln!("Guest authorization revoked");
-            }
-            Ok(())
+            let command = |client_id| CoreCommand::UnauthorizeGuest { client_id };
+            let done = "Guest authorization revoked";
+            guest_command(controller, &client, command, done, global).await
         }
 
         ClientsCommand::Block { mac } => {
@@ -176,7 +215,23 @@ pub async fn handle(
             Ok(())
         }
 
-        ClientsCommand::Kick { mac } => {
+        ClientsCommand::Kick { mac, bulk } => {
+            let Some(mac) = mac else {
+                let selector = bulk.selector.as_deref().unwrap_or_default();
+                let targets = bulk::select_clients(controller, selector)?
+                    .iter()
+                    .map(|c| bulk::BulkTarget {
+                        name: c
+                            .name
+                            .clone()
+                            .or_else(|| c.hostname.clone())
+                            .unwrap_or_default(),
+                        mac: c.mac.to_string(),
+                        command: CoreCommand::KickClient { mac: c.mac.clone() },
+                    })
+                    .collect();
+                return bulk::run(controller, targets, "kick", &bulk, global).await;
+            };
             let mac = MacAddress::new(&mac);
             controller.execute(CoreCommand::KickClient { mac }).await?;
             if !global.quiet {
@@ -201,5 +256,269 @@ pub async fn handle(
             }
             Ok(())
         }
+
+        ClientsCommand::Reserve { mac, ip, network } => {
+            reserve(controller, &mac, ip, &network, global).await
+        }
+
+        ClientsCommand::Unreserve { mac } => {
+            controller
+                .execute(CoreCommand::SetClientFixedIp {
+                    mac: MacAddress::new(&mac),
+                    ip: None,
+                    network_id: None,
+                })
+                .await?;
+            if !global.quiet {
+                eprintln!("Reservation released");
+            }
+            Ok(())
+        }
+
+        ClientsCommand::Alias { mac, name } => {
+            let cleared = name.is_none();
+            controller
+                .execute(CoreCommand::SetClientAlias {
+                    mac: MacAddress::new(&mac),
+                    alias: name,
+                })
+                .await?;
+            if !global.quiet {
+                eprintln!(
+                    "{}",
+                    if cleared {
+                        "Alias cleared"
+                    } else {
+                        "Alias set"
+                    }
+                );
+            }
+            Ok(())
+        }
+
+        ClientsCommand::Note { mac, note } => {
+            let cleared = note.is_none();
+            controller
+                .execute(CoreCommand::SetClientNote {
+                    mac: MacAddress::new(&mac),
+                    note,
+                })
+                .await?;
+            if !global.quiet {
+                eprintln!("{}", if cleared { "Note cleared" } else { "Note set" });
+            }
+            Ok(())
+        }
+
+        ClientsCommand::SetGroup { mac, group, .. } => {
+            set_group(controller, &mac, group.as_deref(), global).await
+        }
+    }
+}
+
+/// Connected clients, plus known offline ones when `include_offline`.
+async fn list_clients(
+    controller: &Controller,
+    list: &ListArgs,
+    include_offline: bool,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let mut all: Vec<Arc<Client>> = controller.clients_snapshot().iter().cloned().collect();
+    if include_offline {
+        let online: HashSet<MacAddress> = all.iter().map(|c| c.mac.clone()).collect();
+        let known = controller.list_known_clients(None).await?;
+        all.extend(
+            known
+                .into_iter()
+                .filter(|k| !online.contains(&k.mac))
+                .map(|k| Arc::new(Client::from(k))),
+        );
+    }
+    let snap = util::apply_list_args(all, list, util::matches_json_filter);
+    let out = output::render_site_list(
+        &global.output,
+        &snap,
+        util::site_names(controller).as_ref(),
+        |c| c.site_id.as_ref(),
+        |c| ClientRow::from(c),
+        |c| c.id.to_string(),
+    );
+    output::print_output(&out, global.quiet);
+    Ok(())
+}
+
+/// Details of a connected client, falling back to the known-client list.
+async fn get(controller: &Controller, client: &str, global: &GlobalOpts) -> Result<(), CliError> {
+    let mac = MacAddress::new(client);
+    let snap = controller.clients_snapshot();
+    let found = match snap
+        .iter()
+        .find(|c| c.id.to_string() == client || c.mac == mac)
+    {
+        Some(c) => c.as_ref().clone(),
+        None => util::find_known_client(controller, client)
+            .await
+            .map(Client::from)
+            .ok_or_else(|| CliError::NotFound {
+                resource_type: "client".into(),
+                identifier: client.into(),
+                list_command: "clients list --include-offline".into(),
+            })?,
+    };
+    let c = Arc::new(with_group_name(controller, &found).await);
+    let out = output::render_single(&global.output, &c, detail, |c| c.id.to_string());
+    output::print_output(&out, global.quiet);
+    Ok(())
+}
+
+/// When a client was last seen, then its sessions over `within`.
+async fn history(
+    controller: &Controller,
+    client: &str,
+    within: &str,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let now = Utc::now();
+    let start = util::parse_since("within", within, now)?;
+    let known = util::find_known_client(controller, client)
+        .await
+        .ok_or_else(|| CliError::NotFound {
+            resource_type: "client".into(),
+            identifier: client.into(),
+            list_command: "clients list --include-offline".into(),
+        })?;
+
+    if !global.quiet {
+        let name = known
+            .name
+            .as_deref()
+            .or(known.hostname.as_deref())
+            .unwrap_or("-");
+        let fmt = |t: Option<chrono::DateTime<Utc>>| {
+            t.map_or_else(|| "-".into(), |t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+        };
+        let connected = controller
+            .clients_snapshot()
+            .iter()
+            .any(|c| c.mac == known.mac);
+        eprintln!(
+            "{name} ({}): {}, first seen {}",
+            known.mac,
+            if connected {
+                "connected now".to_owned()
+            } else {
+                format!("last seen {}", fmt(known.last_seen))
+            },
+            fmt(known.first_seen),
+        );
+    }
+
+    let sessions = controller
+        .list_client_sessions(&known.mac, start, now)
+        .await?;
+    let devices = controller.devices_snapshot();
+    let device_name = |mac: &MacAddress| {
+        devices
+            .iter()
+            .find(|d| &d.mac == mac)
+            .and_then(|d| d.name.clone())
+            .unwrap_or_else(|| mac.to_string())
+    };
+    let out = output::render_list(
+        &global.output,
+        &sessions,
+        |s| SessionRow::new(s, device_name),
+        |s| s.connected_at.to_rfc3339(),
+    );
+    output::print_output(&out, global.quiet);
+    Ok(())
+}
+
+/// Run a guest (un)authorization for the client `client` resolves to.
+async fn guest_command(
+    controller: &Controller,
+    client: &str,
+    command: impl FnOnce(EntityId) -> CoreCommand,
+    done: &str,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let client_id = util::resolve_client_id(controller, client).await?;
+    controller.execute(command(client_id)).await?;
+    if !global.quiet {
+        eprintln!("{done}");
+    }
+    Ok(())
+}
+
+/// Reserve `ip` for a client on the network named or identified by `network`.
+async fn reserve(
+    controller: &Controller,
+    mac: &str,
+    ip: Ipv4Addr,
+    network: &str,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let networks = controller.networks_snapshot();
+    let net = networks
+        .iter()
+        .find(|n| n.id.to_string() == network || n.name.eq_ignore_ascii_case(network))
+        .ok_or_else(|| CliError::NotFound {
+            resource_type: "network".into(),
+            identifier: network.into(),
+            list_command: "networks list".into(),
+        })?;
+    controller
+        .execute(CoreCommand::SetClientFixedIp {
+            mac: MacAddress::new(mac),
+            ip: Some(ip),
+            network_id: Some(net.id.clone()),
+        })
+        .await?;
+    if !global.quiet {
+        eprintln!("Reserved {ip} for {mac} on {}", net.name);
+    }
+    Ok(())
+}
+
+/// Move a client into `group`, or back to the default group when `None`.
+async fn set_group(
+    controller: &Controller,
+    mac: &str,
+    group: Option<&str>,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let group = match group {
+        Some(g) => Some(util::resolve_user_group(controller, g).await?),
+        None => None,
+    };
+    controller
+        .execute(CoreCommand::AssignClientGroup {
+            mac: MacAddress::new(mac),
+            group_id: group.as_ref().map(|g| g.id.clone()),
+        })
+        .await?;
+    if !global.quiet {
+        match group {
+            Some(g) => eprintln!("Client moved to group {}", g.name),
+            None => eprintln!("Client moved to the default group"),
+        }
+    }
+    Ok(())
+}
+
+/// Copy of `client` with its user group name filled in.
+///
+/// Group names come from the Legacy API; without it, or for clients in
+/// the default group, the name is left unset.
+async fn with_group_name(controller: &Controller, client: &Client) -> Client {
+    let mut client = client.clone();
+    if let Some(id) = &client.user_group_id {
+        match controller.list_user_groups().await {
+            Ok(groups) => {
+                client.user_group = groups.into_iter().find(|g| &g.id == id).map(|g| g.name);
+            }
+            Err(e) => tracing::debug!(error = %e, "could not load user groups"),
+        }
     }
+    client
 }
@@ -0,0 +1,174 @@
+//! Cloud command handlers: Site Manager listings across every host.
+
+use tabled::Tabled;
+use unifly_core::cloud::{CloudDevice, CloudHost, CloudSite, SITE_MANAGER_URL, SiteManager};
+use unifly_core::{AuthCredentials, ControllerConfig};
+
+use crate::cli::{CloudArgs, CloudCommand, GlobalOpts};
+use crate::error::CliError;
+use crate::output;
+
+fn or_dash(value: Option<&String>) -> String {
+    value.cloned().unwrap_or_else(|| "-".into())
+}
+
+// ── Table rows ──────────────────────────────────────────────────────
+
+#[derive(Tabled)]
+struct HostRow {
+    #[tabled(rename = "Host ID")]
+    id: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Type")]
+    host_type: String,
+    #[tabled(rename = "IP")]
+    ip: String,
+    #[tabled(rename = "State")]
+    state: String,
+    #[tabled(rename = "Version")]
+    version: String,
+}
+
+impl From<&CloudHost> for HostRow {
+    fn from(h: &CloudHost) -> Self {
+        Self {
+            id: h.id.clone(),
+            name: or_dash(h.name.as_ref()),
+            host_type: or_dash(h.host_type.as_ref()),
+            ip: or_dash(h.ip_address.as_ref()),
+            state: or_dash(h.state.as_ref()),
+            version: or_dash(h.version.as_ref()),
+        }
+    }
+}
+
+#[derive(Tabled)]
+struct SiteRow {
+    #[tabled(rename = "Site ID")]
+    site_id: String,
+    #[tabled(rename = "Host ID")]
+    host_id: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Description")]
+    description: String,
+    #[tabled(rename = "Permission")]
+    permission: String,
+}
+
+impl From<&CloudSite> for SiteRow {
+    fn from(s: &CloudSite) -> Self {
+        Self {
+            site_id: s.site_id.clone(),
+            host_id: s.host_id.clone(),
+            name: or_dash(s.name.as_ref()),
+            description: or_dash(s.description.as_ref()),
+            permission: or_dash(s.permission.as_ref()),
+        }
+    }
+}
+
+#[derive(Tabled)]
+struct DeviceRow {
+    #[tabled(rename = "Host")]
+    host: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Model")]
+    model: String,
+    #[tabled(rename = "MAC")]
+    mac: String,
+    #[tabled(rename = "IP")]
+    ip: String,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Version")]
+    version: String,
+}
+
+impl From<&CloudDevice> for DeviceRow {
+    fn from(d: &CloudDevice) -> Self {
+        Self {
+            host: d.host_name.clone().unwrap_or_else(|| d.host_id.clone()),
+            name: or_dash(d.name.as_ref()),
+            model: or_dash(d.model.as_ref()),
+            mac: or_dash(d.mac.as_ref()),
+            ip: or_dash(d.ip.as_ref()),
+            status: or_dash(d.status.as_ref()),
+            version: or_dash(d.version.as_ref()),
+        }
+    }
+}
+
+// ── Handler ─────────────────────────────────────────────────────────
+
+/// Turn the resolved config into a Site Manager session.
+///
+/// Only a cloud profile's key is used, or a bare `--api-key` aimed at
+/// Site Manager itself; a local controller's key is never sent.
+fn site_manager(mut config: ControllerConfig) -> Result<SiteManager, CliError> {
+    let is_site_manager = config.url.as_str().trim_end_matches('/') == SITE_MANAGER_URL;
+    match config.auth {
+        AuthCredentials::Cloud { .. } => {}
+        AuthCredentials::ApiKey(api_key) if is_site_manager => {
+            config.auth = AuthCredentials::Cloud {
+                api_key,
+                host_id: String::new(),
+            };
+        }
+        _ => {
+            return Err(CliError::Validation {
+                field: "auth_mode".into(),
+                reason: format!(
+                    "cloud commands need a cloud profile (auth_mode = \"cloud\") or \
+                     --controller {SITE_MANAGER_URL} --api-key <KEY>"
+                ),
+            });
+        }
+    }
+    Ok(SiteManager::new(config)?)
+}
+
+pub async fn handle(
+    args: CloudArgs,
+    config: ControllerConfig,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let manager = site_manager(config)?;
+
+    let out = match args.command {
+        CloudCommand::Hosts => {
+            let hosts = manager.hosts().await?;
+            output::render_list(
+                &global.output,
+                &hosts,
+                |h| HostRow::from(h),
+                |h| h.id.clone(),
+            )
+        }
+        CloudCommand::Sites { host } => {
+            let mut sites = manager.sites().await?;
+            if let Some(host) = host {
+                sites.retain(|s| s.host_id == host);
+            }
+            output::render_list(
+                &global.output,
+                &sites,
+                |s| SiteRow::from(s),
+                |s| s.site_id.clone(),
+            )
+        }
+        CloudCommand::Devices { hosts } => {
+            let devices = manager.devices(&hosts).await?;
+            output::render_list(
+                &global.output,
+                &devices,
+                |d| DeviceRow::from(d),
+                |d| d.id.clone(),
+            )
+        }
+    };
+    output::print_output(&out, global.quiet);
+    Ok(())
+}
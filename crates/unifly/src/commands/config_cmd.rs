@@ -44,6 +44,9 @@ fn format_config_redacted(cfg: &Config) -> String {
         if let Some(ref u) = p.username {
             let _ = writeln!(out, "username = \"{u}\"");
         }
+        if let Some(ref h) = p.host_id {
+            let _ = writeln!(out, "host_id = \"{h}\"");
+        }
         if p.password.is_some() {
             let _ = writeln!(out, "password = \"****\"");
         }
@@ -56,6 +59,30 @@ fn format_config_redacted(cfg: &Config) -> String {
         if let Some(timeout) = p.timeout {
             let _ = writeln!(out, "timeout = {timeout}");
         }
+        if let Some(retries) = p.retries {
+            let _ = writeln!(out, "retries = {retries}");
+        }
+        if let Some(retry_writes) = p.retry_writes {
+            let _ = writeln!(out, "retry_writes = {retry_writes}");
+        }
+        if let Some(history) = p.history {
+            let _ = writeln!(out, "history = {history}");
+        }
+        if let Some(days) = p.history_retention_days {
+            let _ = writeln!(out, "history_retention_days = {days}");
+        }
+        if !p.tags.is_empty() {
+            let tags: Vec<_> = p.tags.iter().map(|t| format!("\"{t}\"")).collect();
+            let _ = writeln!(out, "tags = [{}]", tags.join(", "));
+        }
+        if !p.known_rogue_aps.is_empty() {
+            let aps: Vec<_> = p
+                .known_rogue_aps
+                .iter()
+                .map(|a| format!("\"{a}\""))
+                .collect();
+            let _ = writeln!(out, "known_rogue_aps = [{}]", aps.join(", "));
+        }
     }
 
     out
@@ -75,6 +102,28 @@ fn prompt_err(e: impl std::fmt::Display) -> CliError {
     }
 }
 
+/// Prompt for a secret without echo and store it in the system keyring.
+fn store_keyring_secret(key: &str, label: &str) -> Result<(), CliError> {
+    let secret = rpassword::prompt_password(label).map_err(prompt_err)?;
+    if secret.is_empty() {
+        return Err(CliError::Validation {
+            field: "secret".into(),
+            reason: "value cannot be empty".into(),
+        });
+    }
+    let entry = keyring::Entry::new("unifly", key).map_err(|e| CliError::Validation {
+        field: "keyring".into(),
+        reason: format!("failed to access keyring: {e}"),
+    })?;
+    entry
+        .set_password(&secret)
+        .map_err(|e| CliError::Validation {
+            field: "keyring".into(),
+            reason: format!("failed to store secret in keyring: {e}"),
+        })?;
+    Ok(())
+}
+
 /// Prompt for username and password, validating neither is empty.
 fn prompt_credentials() -> Result<(String, String), CliError> {
     let user: String = Input::new()
@@ -262,9 +311,16 @@ pub fn handle(args: ConfigArgs, global: &GlobalOpts) -> Result<(), CliError> {
                 api_key_env: None,
                 username,
                 password,
+                host_id: None,
                 ca_cert: None,
                 insecure: None,
                 timeout: None,
+                retries: None,
+                retry_writes: None,
+                history: None,
+                history_retention_days: None,
+                tags: Vec::new(),
+                known_rogue_aps: Vec::new(),
             };
 
             let mut profiles = HashMap::new();
@@ -312,19 +368,29 @@ pub fn handle(args: ConfigArgs, global: &GlobalOpts) -> Result<(), CliError> {
                     api_key_env: None,
                     username: None,
                     password: None,
+                    host_id: None,
                     ca_cert: None,
                     insecure: None,
                     timeout: None,
+                    retries: None,
+                    retry_writes: None,
+                    history: None,
+                    history_retention_days: None,
+                    tags: Vec::new(),
+                    known_rogue_aps: Vec::new(),
                 });
 
             match key.as_str() {
                 "controller" => profile.controller = value,
                 "site" => profile.site = value,
                 "auth_mode" | "auth-mode" => {
-                    if !matches!(value.as_str(), "integration" | "legacy" | "hybrid") {
+                    if !matches!(
+                        value.as_str(),
+                        "integration" | "legacy" | "hybrid" | "cloud"
+                    ) {
                         return Err(CliError::Validation {
                             field: "auth_mode".into(),
-                            reason: "must be 'integration', 'legacy', or 'hybrid'".into(),
+                            reason: "must be 'integration', 'legacy', 'hybrid', or 'cloud'".into(),
                         });
                     }
                     profile.auth_mode = value;
@@ -332,6 +398,7 @@ pub fn handle(args: ConfigArgs, global: &GlobalOpts) -> Result<(), CliError> {
                 "api_key" | "api-key" => profile.api_key = Some(value),
                 "api_key_env" | "api-key-env" => profile.api_key_env = Some(value),
                 "username" => profile.username = Some(value),
+                "host_id" | "host-id" => profile.host_id = Some(value),
                 "insecure" => {
                     profile.insecure = Some(value.parse().map_err(|_| CliError::Validation {
                         field: "insecure".into(),
@@ -344,13 +411,48 @@ pub fn handle(args: ConfigArgs, global: &GlobalOpts) -> Result<(), CliError> {
                         reason: "must be a number (seconds)".into(),
                     })?);
                 }
+                "retries" => {
+                    profile.retries = Some(value.parse().map_err(|_| CliError::Validation {
+                        field: "retries".into(),
+                        reason: "must be a number (0 disables retrying)".into(),
+                    })?);
+                }
+                "retry_writes" | "retry-writes" => {
+                    profile.retry_writes =
+                        Some(value.parse().map_err(|_| CliError::Validation {
+                            field: "retry_writes".into(),
+                            reason: "must be 'true' or 'false'".into(),
+                        })?);
+                }
+                "history" => {
+                    profile.history = Some(value.parse().map_err(|_| CliError::Validation {
+                        field: "history".into(),
+                        reason: "must be 'true' or 'false'".into(),
+                    })?);
+                }
+                "history_retention_days" | "history-retention-days" => {
+                    profile.history_retention_days =
+                        Some(value.parse().map_err(|_| CliError::Validation {
+                            field: "history_retention_days".into(),
+                            reason: "must be a number of days (0 keeps everything)".into(),
+                        })?);
+                }
                 "ca_cert" | "ca-cert" => profile.ca_cert = Some(value.into()),
+                "tags" => {
+                    profile.tags = value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|t| !t.is_empty())
+                        .map(String::from)
+                        .collect();
+                }
                 other => {
                     return Err(CliError::Validation {
                         field: other.into(),
                         reason: format!(
                             "unknown config key '{other}'. Valid keys: controller, site, \
-                             auth_mode, api_key, api_key_env, username, insecure, timeout, ca_cert"
+                             auth_mode, api_key, api_key_env, username, host_id, insecure, timeout, \
+                             retries, retry_writes, history, history_retention_days, ca_cert, tags"
                         ),
                     });
                 }
@@ -415,44 +517,53 @@ pub fn handle(args: ConfigArgs, global: &GlobalOpts) -> Result<(), CliError> {
                 }
             })?;
 
-            let store_secret = |key: &str, label: &str| -> Result<(), CliError> {
-                let secret = rpassword::prompt_password(label).map_err(prompt_err)?;
-                if secret.is_empty() {
-                    return Err(CliError::Validation {
-                        field: "secret".into(),
-                        reason: "value cannot be empty".into(),
-                    });
-                }
-                let entry =
-                    keyring::Entry::new("unifly", key).map_err(|e| CliError::Validation {
-                        field: "keyring".into(),
-                        reason: format!("failed to access keyring: {e}"),
-                    })?;
-                entry
-                    .set_password(&secret)
-                    .map_err(|e| CliError::Validation {
-                        field: "keyring".into(),
-                        reason: format!("failed to store secret in keyring: {e}"),
-                    })?;
-                Ok(())
-            };
-
             match prof.auth_mode.as_str() {
                 "hybrid" => {
                     // Hybrid needs both API key and password
-                    store_secret(&format!("{profile_name}/api-key"), "API key: ")?;
-                    store_secret(&format!("{profile_name}/password"), "Password: ")?;
+                    store_keyring_secret(&format!("{profile_name}/api-key"), "API key: ")?;
+                    store_keyring_secret(&format!("{profile_name}/password"), "Password: ")?;
                 }
                 "integration" => {
-                    store_secret(&format!("{profile_name}/api-key"), "API key: ")?;
+                    store_keyring_secret(&format!("{profile_name}/api-key"), "API key: ")?;
                 }
                 _ => {
-                    store_secret(&format!("{profile_name}/password"), "Password: ")?;
+                    store_keyring_secret(&format!("{profile_name}/password"), "Password: ")?;
                 }
             }
 
             eprintln!("✓ Secret(s) stored in system keyring for profile '{profile_name}'");
             Ok(())
         }
+
+        // ── SetTotp ─────────────────────────────────────────────────
+        ConfigCommand::SetTotp { profile } => {
+            let cfg = config::load_config_or_default();
+            let profile_name = profile.unwrap_or_else(|| config::active_profile_name(global, &cfg));
+
+            let prof = cfg.profiles.get(&profile_name).ok_or_else(|| {
+                let available: Vec<_> = cfg.profiles.keys().cloned().collect();
+                CliError::ProfileNotFound {
+                    name: profile_name.clone(),
+                    available: if available.is_empty() {
+                        "(none)".into()
+                    } else {
+                        available.join(", ")
+                    },
+                }
+            })?;
+            if prof.auth_mode == "integration" {
+                return Err(CliError::Validation {
+                    field: "auth_mode".into(),
+                    reason: "two-factor login applies to 'legacy' and 'hybrid' profiles".into(),
+                });
+            }
+
+            store_keyring_secret(
+                &format!("{profile_name}/totp-secret"),
+                "TOTP secret (base32): ",
+            )?;
+            eprintln!("✓ TOTP secret stored in system keyring for profile '{profile_name}'");
+            Ok(())
+        }
     }
 }
@@ -1,15 +1,26 @@
 //! Device command handlers.
 
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use tabled::Tabled;
-use unifly_core::{Command as CoreCommand, Controller, Device, MacAddress};
+use unifly_core::model::{
+    ChannelSpectrum, PoeMode as ModelPoeMode, Port, Radio, RadioBand as ModelRadioBand,
+    RadioChannel, TxPower,
+};
+use unifly_core::{
+    Command as CoreCommand, Controller, Device, DeviceType, MacAddress, PortProfile, SpectrumScan,
+    UpdateDeviceRadioRequest, UpdatePortRequest,
+};
 
-use crate::cli::{DevicesArgs, DevicesCommand, GlobalOpts};
+use crate::cli::{
+    BulkArgs, DevicePortsArgs, DevicePortsCommand, DevicesArgs, DevicesCommand, GlobalOpts,
+    PoeMode, RadioBand,
+};
 use crate::error::CliError;
 use crate::output;
 
-use super::util;
+use super::{bulk, util};
 
 // ── Table row ───────────────────────────────────────────────────────
 
@@ -53,6 +64,183 @@ struct DeviceTagRow {
     name: String,
 }
 
+#[derive(Tabled)]
+struct PortRow {
+    #[tabled(rename = "Port")]
+    index: u32,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Enabled")]
+    enabled: String,
+    #[tabled(rename = "State")]
+    state: String,
+    #[tabled(rename = "Speed")]
+    speed: String,
+    #[tabled(rename = "PoE")]
+    poe: String,
+    #[tabled(rename = "Profile")]
+    profile: String,
+    #[tabled(rename = "Native Network")]
+    native_network: String,
+}
+
+#[derive(Tabled)]
+struct PortProfileRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Forward")]
+    forward: String,
+    #[tabled(rename = "PoE")]
+    poe: String,
+}
+
+impl From<&PortProfile> for PortProfileRow {
+    fn from(p: &PortProfile) -> Self {
+        Self {
+            id: p.id.to_string(),
+            name: p.name.clone(),
+            forward: p.forward.clone().unwrap_or_default(),
+            poe: p.poe_mode.map(|m| m.to_string()).unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Tabled)]
+struct SpectrumRow {
+    #[tabled(rename = "Channel")]
+    channel: u32,
+    #[tabled(rename = "Freq")]
+    freq: String,
+    #[tabled(rename = "Width")]
+    width: String,
+    #[tabled(rename = "Utilization")]
+    utilization: String,
+    #[tabled(rename = "Interference")]
+    interference: String,
+    #[tabled(rename = "Sources")]
+    sources: String,
+}
+
+impl From<&ChannelSpectrum> for SpectrumRow {
+    fn from(c: &ChannelSpectrum) -> Self {
+        Self {
+            channel: c.channel,
+            freq: c
+                .frequency_ghz
+                .map(|f| format!("{f:.3} GHz"))
+                .unwrap_or_default(),
+            width: c.width_mhz.map(|w| format!("{w} MHz")).unwrap_or_default(),
+            utilization: c
+                .utilization_pct
+                .map(|u| format!("{u:.0}%"))
+                .unwrap_or_default(),
+            interference: c
+                .interference_pct
+                .map(|i| format!("{i:.0}%"))
+                .unwrap_or_default(),
+            sources: c.interference_types.join(", "),
+        }
+    }
+}
+
+/// How often `rf-scan --wait` polls for results, how long it waits for the
+/// scan to show as running, and when it gives up.
+const RF_SCAN_POLL: Duration = Duration::from_secs(5);
+const RF_SCAN_GRACE: Duration = Duration::from_secs(30);
+const RF_SCAN_TIMEOUT: Duration = Duration::from_secs(600);
+
+#[derive(Tabled)]
+struct RadioRow {
+    #[tabled(rename = "Band")]
+    band: String,
+    #[tabled(rename = "Channel")]
+    channel: String,
+    #[tabled(rename = "Width")]
+    width: String,
+    #[tabled(rename = "TX Power")]
+    tx_power: String,
+    #[tabled(rename = "Min RSSI")]
+    min_rssi: String,
+    #[tabled(rename = "Retries")]
+    retries: String,
+}
+
+impl From<&Radio> for RadioRow {
+    fn from(r: &Radio) -> Self {
+        let tx_power = match (r.tx_power, r.tx_power_dbm) {
+            (Some(TxPower::Custom(_)) | None, Some(dbm)) => format!("{dbm} dBm"),
+            (Some(setting), Some(dbm)) => format!("{setting} ({dbm} dBm)"),
+            (Some(setting), None) => setting.to_string(),
+            (None, None) => String::new(),
+        };
+        Self {
+            band: ModelRadioBand::from_frequency_ghz(r.frequency_ghz).to_string(),
+            channel: r.channel.map(|c| c.to_string()).unwrap_or_default(),
+            width: r
+                .channel_width_mhz
+                .map(|w| format!("{w} MHz"))
+                .unwrap_or_default(),
+            tx_power,
+            min_rssi: r
+                .min_rssi_dbm
+                .map_or_else(|| "off".into(), |v| format!("{v} dBm")),
+            retries: r
+                .tx_retries_pct
+                .map(|p| format!("{p:.1}%"))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+fn map_radio_band(b: RadioBand) -> ModelRadioBand {
+    match b {
+        RadioBand::Ghz2_4 => ModelRadioBand::Ghz2_4,
+        RadioBand::Ghz5 => ModelRadioBand::Ghz5,
+        RadioBand::Ghz6 => ModelRadioBand::Ghz6,
+    }
+}
+
+fn parse_radio_channel(raw: &str) -> Result<RadioChannel, CliError> {
+    if raw.eq_ignore_ascii_case("auto") {
+        return Ok(RadioChannel::Auto);
+    }
+    raw.parse()
+        .map(RadioChannel::Fixed)
+        .map_err(|_| CliError::Validation {
+            field: "channel".into(),
+            reason: format!("expected a channel number or \"auto\", got '{raw}'"),
+        })
+}
+
+fn parse_tx_power(raw: &str) -> Result<TxPower, CliError> {
+    match raw.to_ascii_lowercase().as_str() {
+        "auto" => Ok(TxPower::Auto),
+        "high" => Ok(TxPower::High),
+        "medium" => Ok(TxPower::Medium),
+        "low" => Ok(TxPower::Low),
+        other => other
+            .trim_end_matches("dbm")
+            .trim()
+            .parse()
+            .map(TxPower::Custom)
+            .map_err(|_| CliError::Validation {
+                field: "tx-power".into(),
+                reason: format!("expected auto, high, medium, low or dBm, got '{raw}'"),
+            }),
+    }
+}
+
+fn map_poe_mode(m: &PoeMode) -> ModelPoeMode {
+    match m {
+        PoeMode::Auto => ModelPoeMode::Auto,
+        PoeMode::Off => ModelPoeMode::Off,
+        PoeMode::Pasv24 => ModelPoeMode::Passive24V,
+        PoeMode::Passthrough => ModelPoeMode::Passthrough,
+    }
+}
+
 impl From<&Arc<Device>> for DeviceRow {
     fn from(d: &Arc<Device>) -> Self {
         Self {
@@ -152,9 +340,11 @@ pub async fn handle(
             let snap = util::apply_list_args(all.iter().cloned(), &list, |d, filter| {
                 util::matches_json_filter(d, filter)
             });
-            let out = output::render_list(
+            let out = output::render_site_list(
                 &global.output,
                 &snap,
+                util::site_names(controller).as_ref(),
+                |d| d.site_id.as_ref(),
                 |d| DeviceRow::from(d),
                 |d| d.id.to_string(),
             );
@@ -210,7 +400,10 @@ pub async fn handle(
             Ok(())
         }
 
-        DevicesCommand::Restart { device } => {
+        DevicesCommand::Restart { device, bulk } => {
+            let Some(device) = device else {
+                return restart_selected(controller, &bulk, global).await;
+            };
             let id = util::resolve_device_id(controller, &device)?;
             controller
                 .execute(CoreCommand::RestartDevice { id })
@@ -353,6 +546,74 @@ pub async fn handle(
             Ok(())
         }
 
+        DevicesCommand::RfScan { device, wait, last } => {
+            handle_rf_scan(controller, &device, wait, last, global).await
+        }
+
+        DevicesCommand::Radio {
+            device,
+            band,
+            channel,
+            width,
+            tx_power,
+            min_rssi,
+            no_min_rssi,
+        } => {
+            let Some(band) = band else {
+                let snap = controller.devices_snapshot();
+                let found = snap
+                    .iter()
+                    .find(|d| d.id.to_string() == device || d.mac.to_string() == device)
+                    .ok_or_else(|| CliError::NotFound {
+                        resource_type: "device".into(),
+                        identifier: device.clone(),
+                        list_command: "devices list".into(),
+                    })?;
+                let out = output::render_list(
+                    &global.output,
+                    &found.radios,
+                    |r| RadioRow::from(r),
+                    |r| ModelRadioBand::from_frequency_ghz(r.frequency_ghz).to_string(),
+                );
+                output::print_output(&out, global.quiet);
+                return Ok(());
+            };
+
+            let update = UpdateDeviceRadioRequest {
+                channel: channel.as_deref().map(parse_radio_channel).transpose()?,
+                channel_width_mhz: width,
+                tx_power: tx_power.as_deref().map(parse_tx_power).transpose()?,
+                min_rssi_dbm: min_rssi,
+                min_rssi_enabled: no_min_rssi.then_some(false),
+            };
+            if update.channel.is_none()
+                && update.channel_width_mhz.is_none()
+                && update.tx_power.is_none()
+                && update.min_rssi_dbm.is_none()
+                && update.min_rssi_enabled.is_none()
+            {
+                return Err(CliError::Validation {
+                    field: "radio".into(),
+                    reason: "nothing to change; pass --channel, --width, --tx-power or --min-rssi"
+                        .into(),
+                });
+            }
+
+            let device_id = util::resolve_device_id(controller, &device)?;
+            let band = map_radio_band(band);
+            controller
+                .execute(CoreCommand::UpdateDeviceRadio {
+                    device_id,
+                    band,
+                    update,
+                })
+                .await?;
+            if !global.quiet {
+                eprintln!("{band} radio updated");
+            }
+            Ok(())
+        }
+
         DevicesCommand::Tags(list) => {
             let tags =
                 util::apply_list_args(controller.list_device_tags().await?, &list, |v, filter| {
@@ -385,5 +646,260 @@ pub async fn handle(
             output::print_output(&out, global.quiet);
             Ok(())
         }
+
+        DevicesCommand::Ports(args) => handle_ports(controller, args, global).await,
+
+        DevicesCommand::PortProfiles(list) => {
+            let profiles = util::apply_list_args(
+                controller.list_port_profiles().await?,
+                &list,
+                util::matches_json_filter,
+            );
+            let out = output::render_list(
+                &global.output,
+                &profiles,
+                |p| PortProfileRow::from(p),
+                |p| p.id.to_string(),
+            );
+            output::print_output(&out, global.quiet);
+            Ok(())
+        }
+    }
+}
+
+// ── Spectrum scan ───────────────────────────────────────────────────
+
+async fn handle_rf_scan(
+    controller: &Controller,
+    device: &str,
+    wait: bool,
+    last: bool,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let snap = controller.devices_snapshot();
+    let ap = snap
+        .iter()
+        .find(|d| d.id.to_string() == device || d.mac.to_string() == device)
+        .ok_or_else(|| CliError::NotFound {
+            resource_type: "device".into(),
+            identifier: device.into(),
+            list_command: "devices list".into(),
+        })?;
+    if ap.device_type != DeviceType::AccessPoint {
+        return Err(CliError::Validation {
+            field: "device".into(),
+            reason: format!("{device} is not an access point"),
+        });
+    }
+    let mac = ap.mac.clone();
+    if !last {
+        let prompt = format!("Scan {device}? Its radios stop serving clients while the scan runs.");
+        if !util::confirm(&prompt, global.yes)? {
+            return Ok(());
+        }
+        controller
+            .execute(CoreCommand::SpectrumScan { mac: mac.clone() })
+            .await?;
+        if !wait {
+            if !global.quiet {
+                eprintln!("Spectrum scan started on {mac}");
+            }
+            return Ok(());
+        }
+        if !global.quiet {
+            eprintln!("Spectrum scan started on {mac}, waiting for results...");
+        }
+    }
+
+    let scan = if last {
+        controller.get_spectrum_scan(&mac).await?
+    } else {
+        Some(wait_for_spectrum_scan(controller, &mac).await?)
+    };
+    let channels = scan.map(|s| s.channels).unwrap_or_default();
+    let out = output::render_list(
+        &global.output,
+        &channels,
+        |c| SpectrumRow::from(c),
+        |c| c.channel.to_string(),
+    );
+    output::print_output(&out, global.quiet);
+    Ok(())
+}
+
+/// Restart every device matching `--selector`.
+async fn restart_selected(
+    controller: &Controller,
+    bulk: &BulkArgs,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let selector = bulk.selector.as_deref().unwrap_or_default();
+    let targets = bulk::select_devices(controller, selector)
+        .await?
+        .iter()
+        .map(|d| bulk::BulkTarget {
+            name: d.name.clone().unwrap_or_default(),
+            mac: d.mac.to_string(),
+            command: CoreCommand::RestartDevice { id: d.id.clone() },
+        })
+        .collect();
+    bulk::run(controller, targets, "restart", bulk, global).await
+}
+
+/// Poll until the AP reports its spectrum scan finished with results.
+async fn wait_for_spectrum_scan(
+    controller: &Controller,
+    mac: &MacAddress,
+) -> Result<SpectrumScan, CliError> {
+    let started = Instant::now();
+    // The controller may not flag the scan as running straight away, so a
+    // finished-looking record (possibly the previous scan) only counts once
+    // we have seen it run or the start-up grace period has passed.
+    let mut seen_running = false;
+    loop {
+        tokio::time::sleep(RF_SCAN_POLL).await;
+        if let Some(scan) = controller.get_spectrum_scan(mac).await? {
+            if scan.scanning {
+                seen_running = true;
+            } else if (seen_running || started.elapsed() >= RF_SCAN_GRACE)
+                && !scan.channels.is_empty()
+            {
+                return Ok(scan);
+            }
+        }
+        if started.elapsed() >= RF_SCAN_TIMEOUT {
+            return Err(CliError::Timeout {
+                seconds: RF_SCAN_TIMEOUT.as_secs(),
+            });
+        }
+    }
+}
+
+// ── Switch ports ────────────────────────────────────────────────────
+
+#[allow(clippy::too_many_lines)]
+async fn handle_ports(
+    controller: &Controller,
+    args: DevicePortsArgs,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let snap = controller.devices_snapshot();
+    let device = snap
+        .iter()
+        .find(|d| d.id.to_string() == args.device || d.mac.to_string() == args.device)
+        .ok_or_else(|| CliError::NotFound {
+            resource_type: "device".into(),
+            identifier: args.device.clone(),
+            list_command: "devices list".into(),
+        })?;
+
+    match args.command {
+        DevicePortsCommand::List(list) => {
+            let profiles = controller.list_port_profiles().await?;
+            let networks = controller.networks_by_legacy_id().await?;
+            let profile_name = |p: &Port| {
+                p.profile_id.as_ref().map_or_else(String::new, |id| {
+                    profiles
+                        .iter()
+                        .find(|pr| &pr.id == id)
+                        .map_or_else(|| id.to_string(), |pr| pr.name.clone())
+                })
+            };
+            let network_name = |p: &Port| {
+                p.native_network_id.as_ref().map_or_else(String::new, |id| {
+                    networks
+                        .get(id)
+                        .map_or_else(|| id.to_string(), |n| n.name.clone())
+                })
+            };
+
+            let ports = util::apply_list_args(device.ports.iter().cloned(), &list, |p, filter| {
+                util::matches_json_filter(p, filter)
+            });
+            let out = output::render_list(
+                &global.output,
+                &ports,
+                |p| PortRow {
+                    index: p.index,
+                    name: p.name.clone().unwrap_or_default(),
+                    enabled: if p.enabled { "yes" } else { "no" }.into(),
+                    state: format!("{:?}", p.state),
+                    speed: p.speed_mbps.map(|s| format!("{s}M")).unwrap_or_default(),
+                    poe: p.poe.as_ref().map_or_else(String::new, |poe| {
+                        poe.mode
+                            .map_or_else(|| poe.enabled.to_string(), |m| m.to_string())
+                    }),
+                    profile: profile_name(p),
+                    native_network: network_name(p),
+                },
+                |p| p.index.to_string(),
+            );
+            output::print_output(&out, global.quiet);
+            Ok(())
+        }
+
+        DevicePortsCommand::Set {
+            port,
+            name,
+            profile,
+            native_network,
+            enable,
+            disable,
+            poe,
+        } => {
+            let profile_id = match profile {
+                Some(profile) => {
+                    let profiles = controller.list_port_profiles().await?;
+                    let found = profiles
+                        .into_iter()
+                        .find(|p| {
+                            p.id.to_string() == profile || p.name.eq_ignore_ascii_case(&profile)
+                        })
+                        .ok_or_else(|| CliError::NotFound {
+                            resource_type: "port profile".into(),
+                            identifier: profile.clone(),
+                            list_command: "devices port-profiles".into(),
+                        })?;
+                    Some(found.id)
+                }
+                None => None,
+            };
+            let native_network_id = match native_network {
+                Some(network) => {
+                    let networks = controller.networks_snapshot();
+                    let found = networks
+                        .iter()
+                        .find(|n| {
+                            n.id.to_string() == network || n.name.eq_ignore_ascii_case(&network)
+                        })
+                        .ok_or_else(|| CliError::NotFound {
+                            resource_type: "network".into(),
+                            identifier: network.clone(),
+                            list_command: "networks list".into(),
+                        })?;
+                    Some(found.id.clone())
+                }
+                None => None,
+            };
+            let update = UpdatePortRequest {
+                name,
+                profile_id,
+                native_network_id,
+                enabled: (enable || disable).then_some(enable),
+                poe_mode: poe.as_ref().map(map_poe_mode),
+            };
+
+            controller
+                .execute(CoreCommand::UpdatePort {
+                    device_id: device.id.clone(),
+                    port_idx: port,
+                    update,
+                })
+                .await?;
+            if !global.quiet {
+                eprintln!("Port {port} updated");
+            }
+            Ok(())
+        }
     }
 }
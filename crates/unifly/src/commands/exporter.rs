@@ -0,0 +1,124 @@
+//! Exporter: serves Prometheus / OpenMetrics text from a live controller.
+//!
+//! A deliberately small HTTP/1.1 responder -- one request per connection,
+//! `GET /metrics` only -- so the CLI needs no web framework.
+
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use unifly_core::metrics::{ExpositionFormat, MetricsSnapshot};
+use unifly_core::{Controller, ControllerConfig};
+
+use crate::cli::ExporterArgs;
+use crate::error::CliError;
+
+/// Largest request head we read before giving up on a connection.
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+/// How long a scraper may take to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Enable the background refresh, polling and WebSocket tasks that
+/// one-shot CLI commands leave off.
+pub fn live_config(args: &ExporterArgs, config: ControllerConfig) -> ControllerConfig {
+    let poll = Duration::from_secs(args.poll_interval);
+    ControllerConfig {
+        refresh_interval_secs: args.refresh_interval,
+        websocket_enabled: true,
+        bandwidth_poll_interval: poll,
+        client_poll_interval: poll,
+        device_stats_poll_interval: poll,
+        ..config
+    }
+}
+
+/// Accept scrapes until Ctrl-C.
+pub async fn serve(args: &ExporterArgs, controller: &Controller) -> Result<(), CliError> {
+    let listener = TcpListener::bind(args.listen).await?;
+    eprintln!(
+        "Serving metrics on http://{}/metrics",
+        listener.local_addr()?
+    );
+
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            accepted = listener.accept() => {
+                let (stream, peer) = match accepted {
+                    Ok(conn) => conn,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "accept failed");
+                        continue;
+                    }
+                };
+                let controller = controller.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = respond(stream, &controller).await {
+                        tracing::debug!(%peer, error = %e, "scrape failed");
+                    }
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn respond(mut stream: TcpStream, controller: &Controller) -> std::io::Result<()> {
+    let head = tokio::time::timeout(READ_TIMEOUT, read_head(&mut stream))
+        .await
+        .map_err(|_| std::io::Error::from(std::io::ErrorKind::TimedOut))??;
+
+    let response = match parse_request(&head) {
+        Some(("GET", "/metrics", accept)) => {
+            let format = ExpositionFormat::from_accept(accept);
+            let body = MetricsSnapshot::capture(controller).render(format);
+            http_response("200 OK", format.content_type(), &body)
+        }
+        Some((_, "/metrics", _)) => {
+            http_response("405 Method Not Allowed", "text/plain", "GET only\n")
+        }
+        Some(_) => http_response("404 Not Found", "text/plain", "try /metrics\n"),
+        None => http_response("400 Bad Request", "text/plain", "bad request\n"),
+    };
+
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Read up to the blank line ending the request head.
+async fn read_head(stream: &mut TcpStream) -> std::io::Result<String> {
+    let mut buf = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+    while !buf.windows(4).any(|w| w == b"\r\n\r\n") && buf.len() < MAX_REQUEST_BYTES {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Method, path (without query) and `Accept` header of a request head.
+fn parse_request(head: &str) -> Option<(&str, &str, &str)> {
+    let mut lines = head.lines();
+    let mut parts = lines.next()?.split_whitespace();
+    let method = parts.next()?;
+    let target = parts.next()?;
+    let path = target.split('?').next().unwrap_or(target);
+
+    let accept = lines
+        .take_while(|line| !line.is_empty())
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("accept"))
+        .map_or("", |(_, value)| value.trim());
+
+    Some((method, path, accept))
+}
+
+fn http_response(status: &str, content_type: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
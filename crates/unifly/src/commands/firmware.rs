@@ -0,0 +1,261 @@
+//! Firmware command handlers.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tabled::Tabled;
+use unifly_core::firmware::{self, BatchState, BatchTracker, FirmwareStatus};
+use unifly_core::{Command as CoreCommand, Controller, Device, DeviceType};
+
+use crate::cli::{DeviceKind, FirmwareArgs, FirmwareCommand, GlobalOpts};
+use crate::error::CliError;
+use crate::output;
+
+use super::util;
+
+/// How often the device list is refreshed while a batch is upgrading.
+const ROLLOUT_POLL: Duration = Duration::from_secs(15);
+
+// ── Table rows ──────────────────────────────────────────────────────
+
+#[derive(Tabled)]
+struct FirmwareRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Model")]
+    model: String,
+    #[tabled(rename = "Type")]
+    device_type: String,
+    #[tabled(rename = "State")]
+    state: String,
+    #[tabled(rename = "Version")]
+    current: String,
+    #[tabled(rename = "Upgrade")]
+    upgradable: String,
+    #[tabled(rename = "Target")]
+    target: String,
+}
+
+impl From<&FirmwareStatus> for FirmwareRow {
+    fn from(f: &FirmwareStatus) -> Self {
+        Self {
+            name: f.name.clone().unwrap_or_else(|| f.mac.to_string()),
+            model: f.model.clone().unwrap_or_default(),
+            device_type: format!("{:?}", f.device_type),
+            state: format!("{:?}", f.state),
+            current: f.current_version.clone().unwrap_or_default(),
+            upgradable: if f.upgradable { "yes" } else { "no" }.into(),
+            target: f.target_version.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// One device in a planned rollout.
+#[derive(Serialize)]
+struct PlannedUpgrade {
+    batch: usize,
+    #[serde(flatten)]
+    device: FirmwareStatus,
+}
+
+#[derive(Tabled)]
+struct RolloutRow {
+    #[tabled(rename = "Batch")]
+    batch: usize,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Type")]
+    device_type: String,
+    #[tabled(rename = "Version")]
+    current: String,
+    #[tabled(rename = "Target")]
+    target: String,
+}
+
+impl From<&PlannedUpgrade> for RolloutRow {
+    fn from(p: &PlannedUpgrade) -> Self {
+        Self {
+            batch: p.batch,
+            name: p
+                .device
+                .name
+                .clone()
+                .unwrap_or_else(|| p.device.mac.to_string()),
+            device_type: format!("{:?}", p.device.device_type),
+            current: p.device.current_version.clone().unwrap_or_default(),
+            target: p.device.target_version.clone().unwrap_or_default(),
+        }
+    }
+}
+
+fn device_label(d: &Device) -> String {
+    d.name.clone().unwrap_or_else(|| d.mac.to_string())
+}
+
+fn map_device_kind(k: DeviceKind) -> DeviceType {
+    match k {
+        DeviceKind::Ap => DeviceType::AccessPoint,
+        DeviceKind::Switch => DeviceType::Switch,
+        DeviceKind::Gateway => DeviceType::Gateway,
+        DeviceKind::Other => DeviceType::Other,
+    }
+}
+
+// ── Handler ─────────────────────────────────────────────────────────
+
+pub async fn handle(
+    controller: &Controller,
+    args: FirmwareArgs,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    match args.command {
+        FirmwareCommand::Status { upgradable } => {
+            let statuses: Vec<FirmwareStatus> = controller
+                .devices_snapshot()
+                .iter()
+                .filter(|d| !upgradable || d.firmware_updatable)
+                .map(|d| FirmwareStatus::from(d.as_ref()))
+                .collect();
+            let out = output::render_list(
+                &global.output,
+                &statuses,
+                |f| FirmwareRow::from(f),
+                |f| f.mac.to_string(),
+            );
+            output::print_output(&out, global.quiet);
+            Ok(())
+        }
+
+        FirmwareCommand::Rollout {
+            device_type,
+            tag,
+            batch_size,
+            batch_timeout,
+            dry_run,
+        } => {
+            let tagged = match tag {
+                Some(ref tag) => Some(util::device_tag_members(controller, tag).await?),
+                None => None,
+            };
+            let selected: Vec<Arc<Device>> = controller
+                .devices_snapshot()
+                .iter()
+                .filter(|d| device_type.is_none_or(|k| d.device_type == map_device_kind(k)))
+                .filter(|d| tagged.as_ref().is_none_or(|ids| ids.contains(&d.id)))
+                .cloned()
+                .collect();
+            let batches = firmware::plan_rollout(&selected, usize::from(batch_size));
+            if batches.is_empty() {
+                if !global.quiet {
+                    eprintln!("No online devices with a firmware upgrade available");
+                }
+                return Ok(());
+            }
+
+            if dry_run {
+                let plan: Vec<PlannedUpgrade> = batches
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, batch)| {
+                        batch.iter().map(move |d| PlannedUpgrade {
+                            batch: i + 1,
+                            device: FirmwareStatus::from(d.as_ref()),
+                        })
+                    })
+                    .collect();
+                let out = output::render_list(
+                    &global.output,
+                    &plan,
+                    |p| RolloutRow::from(p),
+                    |p| p.device.mac.to_string(),
+                );
+                output::print_output(&out, global.quiet);
+                return Ok(());
+            }
+
+            let total: usize = batches.iter().map(Vec::len).sum();
+            let prompt = format!("Upgrade {total} device(s) in {} batch(es)?", batches.len());
+            if !util::confirm(&prompt, global.yes)? {
+                return Ok(());
+            }
+            run_rollout(
+                controller,
+                &batches,
+                Duration::from_secs(batch_timeout),
+                global,
+            )
+            .await
+        }
+    }
+}
+
+/// Upgrade each batch in turn and wait for it before starting the next.
+async fn run_rollout(
+    controller: &Controller,
+    batches: &[Vec<Arc<Device>>],
+    batch_timeout: Duration,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    for (i, batch) in batches.iter().enumerate() {
+        let names: Vec<String> = batch.iter().map(|d| device_label(d)).collect();
+        if !global.quiet {
+            eprintln!(
+                "Batch {}/{}: upgrading {}",
+                i + 1,
+                batches.len(),
+                names.join(", ")
+            );
+        }
+
+        let mut tracker = BatchTracker::new(batch);
+        for device in batch {
+            controller
+                .execute(CoreCommand::UpgradeDevice {
+                    mac: device.mac.clone(),
+                    firmware_url: None,
+                })
+                .await
+                .map_err(|e| CliError::RolloutAborted {
+                    reason: format!(
+                        "could not start the upgrade on {}: {e}",
+                        device_label(device)
+                    ),
+                })?;
+        }
+
+        let started = Instant::now();
+        loop {
+            tokio::time::sleep(ROLLOUT_POLL).await;
+            // Refreshes fail while a gateway reboots; keep waiting until the timeout.
+            if let Err(e) = controller.full_refresh().await {
+                tracing::debug!(error = %e, "refresh failed during rollout");
+            }
+            let snap = controller.devices_snapshot();
+            match tracker.observe(&snap) {
+                BatchState::Done => break,
+                BatchState::Failed { reason, .. } => {
+                    return Err(CliError::RolloutAborted { reason });
+                }
+                BatchState::InProgress if started.elapsed() >= batch_timeout => {
+                    return Err(CliError::RolloutAborted {
+                        reason: format!(
+                            "batch {} not back online after {}s (waiting on {})",
+                            i + 1,
+                            batch_timeout.as_secs(),
+                            tracker.waiting_on(&snap).join(", ")
+                        ),
+                    });
+                }
+                BatchState::InProgress => {}
+            }
+        }
+        if !global.quiet {
+            eprintln!("Batch {}/{} back online", i + 1, batches.len());
+        }
+    }
+    if !global.quiet {
+        eprintln!("Firmware rollout complete");
+    }
+    Ok(())
+}
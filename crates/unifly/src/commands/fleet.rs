@@ -0,0 +1,114 @@
+//! Fleet command handlers: one summary row per configured controller.
+
+use tabled::Tabled;
+use unifly_core::fleet::{self, ControllerStatus, FleetTarget};
+
+use crate::cli::{FleetArgs, FleetCommand, GlobalOpts};
+use crate::config;
+use crate::error::CliError;
+use crate::output;
+
+// ── Table row ───────────────────────────────────────────────────────
+
+#[derive(Tabled)]
+struct FleetRow {
+    #[tabled(rename = "Profile")]
+    name: String,
+    #[tabled(rename = "Site")]
+    site: String,
+    #[tabled(rename = "Health")]
+    health: String,
+    #[tabled(rename = "Online")]
+    online: String,
+    #[tabled(rename = "Offline")]
+    offline: String,
+    #[tabled(rename = "Subsystems")]
+    subsystems: String,
+    #[tabled(rename = "Error")]
+    error: String,
+}
+
+impl From<&ControllerStatus> for FleetRow {
+    fn from(s: &ControllerStatus) -> Self {
+        let health = if s.is_reachable() {
+            s.overall_health().unwrap_or("-").to_owned()
+        } else {
+            "unreachable".into()
+        };
+        let subsystems = s
+            .health
+            .iter()
+            .map(|h| format!("{}:{}", h.subsystem, h.status))
+            .collect::<Vec<_>>()
+            .join(" ");
+        Self {
+            name: s.name.clone(),
+            site: s.site.clone(),
+            health,
+            online: s.devices_online.to_string(),
+            offline: s.devices_offline.to_string(),
+            subsystems,
+            error: s.error.clone().unwrap_or_default(),
+        }
+    }
+}
+
+// ── Handler ─────────────────────────────────────────────────────────
+
+pub async fn handle(args: FleetArgs, global: &GlobalOpts) -> Result<(), CliError> {
+    match args.command {
+        FleetCommand::Status { tags, concurrency } => {
+            let cfg = config::load_config_or_default();
+            let selected = cfg.profiles_tagged(&tags);
+            if selected.is_empty() {
+                return Err(CliError::Validation {
+                    field: "tag".into(),
+                    reason: if tags.is_empty() {
+                        "no profiles configured. Run: unifi config init".into()
+                    } else {
+                        format!("no profile carries tag(s): {}", tags.join(", "))
+                    },
+                });
+            }
+
+            // Profiles that fail to resolve (bad URL, missing credentials)
+            // are reported alongside the ones that fail to connect.
+            let mut targets = Vec::new();
+            let mut failed = Vec::new();
+            for (name, profile) in selected {
+                match config::resolve_fleet_profile(profile, name, global) {
+                    Ok(config) => targets.push(FleetTarget {
+                        name: name.to_owned(),
+                        config,
+                    }),
+                    Err(e) => failed.push(ControllerStatus::unreachable(
+                        name,
+                        &profile.controller,
+                        &profile.site,
+                        e.to_string(),
+                    )),
+                }
+            }
+
+            let mut statuses = fleet::fleet_status(targets, concurrency).await;
+            statuses.extend(failed);
+            statuses.sort_by(|a, b| a.name.cmp(&b.name));
+
+            let unreachable = statuses.iter().filter(|s| !s.is_reachable()).count();
+            let out = output::render_list(
+                &global.output,
+                &statuses,
+                |s| FleetRow::from(s),
+                |s| s.name.clone(),
+            );
+            output::print_output(&out, global.quiet);
+            if unreachable > 0 && !global.quiet {
+                eprintln!(
+                    "{unreachable} of {} controller(s) could not be reached",
+                    statuses.len()
+                );
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! User group command handlers.
+
+use tabled::Tabled;
+use unifly_core::{
+    Command as CoreCommand, CommandResult, Controller, CreateUserGroupRequest,
+    UpdateUserGroupRequest, UserGroup,
+};
+
+use crate::cli::{GlobalOpts, GroupsArgs, GroupsCommand};
+use crate::error::CliError;
+use crate::output;
+
+use super::util;
+
+// ── Table row ───────────────────────────────────────────────────────
+
+#[derive(Tabled)]
+struct UserGroupRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Down")]
+    down: String,
+    #[tabled(rename = "Up")]
+    up: String,
+}
+
+fn fmt_limit(kbps: Option<u32>) -> String {
+    kbps.map_or_else(|| "unlimited".into(), |k| format!("{k} Kbps"))
+}
+
+impl From<&UserGroup> for UserGroupRow {
+    fn from(g: &UserGroup) -> Self {
+        Self {
+            id: g.id.to_string(),
+            name: if g.is_default {
+                format!("{} (default)", g.name)
+            } else {
+                g.name.clone()
+            },
+            down: fmt_limit(g.download_limit_kbps),
+            up: fmt_limit(g.upload_limit_kbps),
+        }
+    }
+}
+
+// ── Handler ─────────────────────────────────────────────────────────
+
+pub async fn handle(
+    controller: &Controller,
+    args: GroupsArgs,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    match args.command {
+        GroupsCommand::List(list) => {
+            let groups = controller.list_user_groups().await?;
+            let groups = util::apply_list_args(groups, &list, |g, filter| {
+                util::matches_json_filter(g, filter)
+            });
+            let out = output::render_list(
+                &global.output,
+                &groups,
+                |g| UserGroupRow::from(g),
+                |g| g.id.to_string(),
+            );
+            output::print_output(&out, global.quiet);
+            Ok(())
+        }
+
+        GroupsCommand::Create { name, down, up } => {
+            let result = controller
+                .execute(CoreCommand::CreateUserGroup(CreateUserGroupRequest {
+                    name,
+                    download_limit_kbps: down,
+                    upload_limit_kbps: up,
+                }))
+                .await?;
+            if let CommandResult::UserGroup(group) = result {
+                let out = output::render_single(
+                    &global.output,
+                    &group,
+                    |g| format!("Created user group {} ({})", g.name, g.id),
+                    |g| g.id.to_string(),
+                );
+                output::print_output(&out, global.quiet);
+            }
+            Ok(())
+        }
+
+        GroupsCommand::Update {
+            group,
+            name,
+            down,
+            up,
+        } => {
+            let existing = util::resolve_user_group(controller, &group).await?;
+            controller
+                .execute(CoreCommand::UpdateUserGroup {
+                    id: existing.id,
+                    update: UpdateUserGroupRequest {
+                        name,
+                        download_limit_kbps: down,
+                        upload_limit_kbps: up,
+                    },
+                })
+                .await?;
+            if !global.quiet {
+                eprintln!("User group updated");
+            }
+            Ok(())
+        }
+
+        GroupsCommand::Delete { group } => {
+            let existing = util::resolve_user_group(controller, &group).await?;
+            if existing.is_default {
+                return Err(CliError::Validation {
+                    field: "group".into(),
+                    reason: "the default user group cannot be deleted".into(),
+                });
+            }
+            if !util::confirm(&format!("Delete user group {}?", existing.name), global.yes)? {
+                return Ok(());
+            }
+            controller
+                .execute(CoreCommand::DeleteUserGroup { id: existing.id })
+                .await?;
+            if !global.quiet {
+                eprintln!("User group deleted");
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! History command handlers: read back what the stats recorder wrote.
+
+use chrono::{DateTime, Utc};
+use tabled::Tabled;
+use unifly_core::MacAddress;
+use unifly_core::history::{HistoryQuery, HistoryStore, Sample, SampleKind};
+
+use crate::cli::{GlobalOpts, HistoryArgs, HistoryCommand, HistoryRange};
+use crate::config;
+use crate::error::CliError;
+use crate::output;
+
+use super::util;
+
+fn or_dash<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| "-".into(), |v| v.to_string())
+}
+
+fn time(sample: &Sample) -> String {
+    sample.at.format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+fn mac(sample: &Sample) -> String {
+    or_dash(sample.mac.as_ref())
+}
+
+// ── Table rows ──────────────────────────────────────────────────────
+
+#[derive(Tabled)]
+struct WanRow {
+    #[tabled(rename = "Time (UTC)")]
+    time: String,
+    #[tabled(rename = "TX B/s")]
+    tx: String,
+    #[tabled(rename = "RX B/s")]
+    rx: String,
+    #[tabled(rename = "Latency ms")]
+    latency: String,
+    #[tabled(rename = "Clients")]
+    clients: String,
+}
+
+impl From<&Sample> for WanRow {
+    fn from(s: &Sample) -> Self {
+        Self {
+            time: time(s),
+            tx: or_dash(s.tx_bytes_per_sec),
+            rx: or_dash(s.rx_bytes_per_sec),
+            latency: or_dash(s.latency_ms),
+            clients: or_dash(s.clients),
+        }
+    }
+}
+
+#[derive(Tabled)]
+struct DeviceRow {
+    #[tabled(rename = "Time (UTC)")]
+    time: String,
+    #[tabled(rename = "MAC")]
+    mac: String,
+    #[tabled(rename = "CPU %")]
+    cpu: String,
+    #[tabled(rename = "Mem %")]
+    memory: String,
+    #[tabled(rename = "TX B/s")]
+    tx: String,
+    #[tabled(rename = "RX B/s")]
+    rx: String,
+    #[tabled(rename = "Clients")]
+    clients: String,
+}
+
+impl From<&Sample> for DeviceRow {
+    fn from(s: &Sample) -> Self {
+        Self {
+            time: time(s),
+            mac: mac(s),
+            cpu: or_dash(s.cpu_pct),
+            memory: or_dash(s.memory_pct),
+            tx: or_dash(s.tx_bytes_per_sec),
+            rx: or_dash(s.rx_bytes_per_sec),
+            clients: or_dash(s.clients),
+        }
+    }
+}
+
+#[derive(Tabled)]
+struct ClientRow {
+    #[tabled(rename = "Time (UTC)")]
+    time: String,
+    #[tabled(rename = "MAC")]
+    mac: String,
+    #[tabled(rename = "TX B/s")]
+    tx: String,
+    #[tabled(rename = "RX B/s")]
+    rx: String,
+    #[tabled(rename = "Signal dBm")]
+    signal: String,
+}
+
+impl From<&Sample> for ClientRow {
+    fn from(s: &Sample) -> Self {
+        Self {
+            time: time(s),
+            mac: mac(s),
+            tx: or_dash(s.tx_bytes_per_sec),
+            rx: or_dash(s.rx_bytes_per_sec),
+            signal: or_dash(s.signal_dbm),
+        }
+    }
+}
+
+fn query(
+    kind: SampleKind,
+    mac: Option<String>,
+    range: &HistoryRange,
+) -> Result<HistoryQuery, CliError> {
+    let until = range
+        .until
+        .as_deref()
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|at| at.with_timezone(&Utc))
+                .map_err(|_| CliError::Validation {
+                    field: "until".into(),
+                    reason: format!("invalid timestamp '{value}' (use RFC3339)"),
+                })
+        })
+        .transpose()?;
+    Ok(HistoryQuery {
+        kind,
+        mac: mac.map(MacAddress::new),
+        since: util::parse_since("since", &range.since, Utc::now())?,
+        until,
+    })
+}
+
+// ── Handler ─────────────────────────────────────────────────────────
+
+pub fn handle(args: HistoryArgs, global: &GlobalOpts) -> Result<(), CliError> {
+    let store = HistoryStore::new(config::active_history_dir(global));
+    let id = |s: &Sample| s.at.to_rfc3339();
+
+    let out = match args.command {
+        HistoryCommand::Wan(range) => {
+            let samples = store.query(&query(SampleKind::Wan, None, &range)?)?;
+            output::render_list(&global.output, &samples, |s| WanRow::from(s), id)
+        }
+        HistoryCommand::Devices { mac, range } => {
+            let samples = store.query(&query(SampleKind::Device, mac, &range)?)?;
+            output::render_list(&global.output, &samples, |s| DeviceRow::from(s), id)
+        }
+        HistoryCommand::Clients { mac, range } => {
+            let samples = store.query(&query(SampleKind::Client, mac, &range)?)?;
+            output::render_list(&global.output, &samples, |s| ClientRow::from(s), id)
+        }
+    };
+    output::print_output(&out, global.quiet);
+    Ok(())
+}
@@ -3,24 +3,37 @@
 pub mod acl;
 pub mod admin;
 pub mod alarms;
+pub mod apply;
+pub mod bulk;
 pub mod clients;
+pub mod cloud;
 pub mod config_cmd;
 pub mod countries;
 pub mod devices;
 pub mod dns;
 pub mod dpi;
 pub mod events;
+pub mod exporter;
 pub mod firewall;
+pub mod firmware;
+pub mod fleet;
+pub mod groups;
+pub mod history;
 pub mod hotspot;
 pub mod networks;
+pub mod port_forwards;
 pub mod radius;
+pub mod routes;
+pub mod settings;
 pub mod sites;
 pub mod stats;
 pub mod system;
+pub mod threats;
 pub mod traffic_lists;
 pub mod util;
 pub mod vpn;
 pub mod wans;
+pub mod watch_alerts;
 pub mod wifi;
 
 use unifly_core::Controller;
@@ -29,34 +42,69 @@ use crate::cli::{Command, GlobalOpts};
 use crate::error::CliError;
 
 /// Dispatch a controller-bound command to the appropriate handler.
-#[allow(clippy::future_not_send)]
+#[allow(clippy::future_not_send, clippy::cognitive_complexity)]
 pub async fn dispatch(
     cmd: Command,
     controller: &Controller,
     global: &GlobalOpts,
 ) -> Result<(), CliError> {
+    // Only these kinds are loaded for every site and tagged with their
+    // site; anything else would read an empty store.
+    if controller.is_all_sites()
+        && !matches!(
+            cmd,
+            Command::Clients(_)
+                | Command::Devices(_)
+                | Command::Networks(_)
+                | Command::Sites(_)
+                | Command::Wifi(_)
+        )
+    {
+        return Err(CliError::Validation {
+            field: "site".into(),
+            reason: "'all' only covers devices, clients, networks, wifi and sites; select one site"
+                .into(),
+        });
+    }
+
     match cmd {
         Command::Acl(args) => acl::handle(controller, args, global).await,
         Command::Admin(args) => admin::handle(controller, args, global).await,
         Command::Alarms(args) => alarms::handle(controller, args, global).await,
+        Command::Apply(args) => apply::handle_apply(controller, args, global).await,
         Command::Clients(args) => clients::handle(controller, args, global).await,
         Command::Countries => countries::handle(controller, global).await,
         Command::Devices(args) => devices::handle(controller, args, global).await,
         Command::Dns(args) => dns::handle(controller, args, global).await,
         Command::Dpi(args) => dpi::handle(controller, args, global).await,
         Command::Events(args) => events::handle(controller, args, global).await,
+        Command::Export(args) => apply::handle_export(controller, &args, global),
         Command::Firewall(args) => firewall::handle(controller, args, global).await,
+        Command::Firmware(args) => firmware::handle(controller, args, global).await,
+        Command::Groups(args) => groups::handle(controller, args, global).await,
         Command::Hotspot(args) => hotspot::handle(controller, args, global).await,
+        Command::Import(args) => apply::handle_import(controller, args, global).await,
         Command::Networks(args) => networks::handle(controller, args, global).await,
+        Command::Plan(args) => apply::handle_plan(controller, &args, global),
+        Command::PortForwards(args) => port_forwards::handle(controller, args, global).await,
         Command::Radius(args) => radius::handle(controller, args, global).await,
+        Command::Routes(args) => routes::handle(controller, args, global).await,
+        Command::Settings(args) => settings::handle(controller, args, global).await,
         Command::Sites(args) => sites::handle(controller, args, global).await,
         Command::Stats(args) => stats::handle(controller, args, global).await,
         Command::System(args) => system::handle(controller, args, global).await,
+        Command::Threats(args) => threats::handle(controller, args, global).await,
         Command::TrafficLists(args) => traffic_lists::handle(controller, args, global).await,
         Command::Vpn(args) => vpn::handle(controller, args, global).await,
         Command::Wans(args) => wans::handle(controller, args, global).await,
         Command::Wifi(args) => wifi::handle(controller, args, global).await,
-        // Config and Completions are handled before dispatch
-        Command::Config(_) | Command::Completions(_) => unreachable!(),
+        // Cloud, Config, Completions, Exporter, Fleet and History are handled before dispatch
+        Command::Cloud(_)
+        | Command::Config(_)
+        | Command::Completions(_)
+        | Command::Exporter(_)
+        | Command::Fleet(_)
+        | Command::History(_)
+        | Command::WatchAlerts(_) => unreachable!(),
     }
 }
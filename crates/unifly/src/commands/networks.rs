@@ -106,9 +106,11 @@ pub async fn handle(
             let snap = util::apply_list_args(all.iter().cloned(), &list, |n, filter| {
                 util::matches_json_filter(n, filter)
             });
-            let out = output::render_list(
+            let out = output::render_site_list(
                 &global.output,
                 &snap,
+                util::site_names(controller).as_ref(),
+                |n| n.site_id.as_ref(),
                 |n| NetworkRow::from(n),
                 |n| n.id.to_string(),
             );
@@ -0,0 +1,210 @@
+//! Port-forward rule command handlers.
+
+use std::sync::Arc;
+
+use tabled::Tabled;
+use unifly_core::model::{PortForward, PortForwardProtocol as ModelProtocol};
+use unifly_core::{
+    Command as CoreCommand, Controller, CreatePortForwardRequest, EntityId,
+    UpdatePortForwardRequest,
+};
+
+use crate::cli::{GlobalOpts, PortForwardProtocol, PortForwardsArgs, PortForwardsCommand};
+use crate::error::CliError;
+use crate::output;
+
+use super::util;
+
+fn map_protocol(p: &PortForwardProtocol) -> ModelProtocol {
+    match p {
+        PortForwardProtocol::Tcp => ModelProtocol::Tcp,
+        PortForwardProtocol::Udp => ModelProtocol::Udp,
+        PortForwardProtocol::TcpUdp => ModelProtocol::TcpUdp,
+    }
+}
+
+// ── Table row ───────────────────────────────────────────────────────
+
+#[derive(Tabled)]
+struct PortForwardRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Enabled")]
+    enabled: String,
+    #[tabled(rename = "Proto")]
+    protocol: String,
+    #[tabled(rename = "WAN Port")]
+    wan_port: String,
+    #[tabled(rename = "Forward To")]
+    forward: String,
+    #[tabled(rename = "Source")]
+    source: String,
+}
+
+impl From<&Arc<PortForward>> for PortForwardRow {
+    fn from(p: &Arc<PortForward>) -> Self {
+        Self {
+            id: p.id.to_string(),
+            name: p.name.clone(),
+            enabled: if p.enabled { "yes" } else { "no" }.into(),
+            protocol: p.protocol.to_string(),
+            wan_port: p.wan_port.clone(),
+            forward: format!("{}:{}", p.forward_ip, p.forward_port),
+            source: p.source_address.clone().unwrap_or_else(|| "any".into()),
+        }
+    }
+}
+
+fn detail(p: &Arc<PortForward>) -> String {
+    [
+        format!("ID:            {}", p.id),
+        format!("Name:          {}", p.name),
+        format!("Enabled:       {}", p.enabled),
+        format!("Protocol:      {}", p.protocol),
+        format!("WAN Interface: {}", p.wan_interface),
+        format!("WAN Port:      {}", p.wan_port),
+        format!("Forward IP:    {}", p.forward_ip),
+        format!("Forward Port:  {}", p.forward_port),
+        format!(
+            "Source:        {}",
+            p.source_address.as_deref().unwrap_or("any")
+        ),
+        format!("Logging:       {}", p.logging),
+    ]
+    .join("\n")
+}
+
+// ── Handler ─────────────────────────────────────────────────────────
+
+#[allow(clippy::too_many_lines)]
+pub async fn handle(
+    controller: &Controller,
+    args: PortForwardsArgs,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    match args.command {
+        PortForwardsCommand::List(list) => {
+            let all = controller.port_forwards_snapshot();
+            let snap = util::apply_list_args(all.iter().cloned(), &list, |p, filter| {
+                util::matches_json_filter(p, filter)
+            });
+            let out = output::render_list(
+                &global.output,
+                &snap,
+                |p| PortForwardRow::from(p),
+                |p| p.id.to_string(),
+            );
+            output::print_output(&out, global.quiet);
+            Ok(())
+        }
+
+        PortForwardsCommand::Get { id } => {
+            let snap = controller.port_forwards_snapshot();
+            let found = snap.iter().find(|p| p.id.to_string() == id);
+            match found {
+                Some(p) => {
+                    let out =
+                        output::render_single(&global.output, p, detail, |p| p.id.to_string());
+                    output::print_output(&out, global.quiet);
+                }
+                None => {
+                    return Err(CliError::NotFound {
+                        resource_type: "port forward".into(),
+                        identifier: id,
+                        list_command: "port-forwards list".into(),
+                    });
+                }
+            }
+            Ok(())
+        }
+
+        PortForwardsCommand::Create {
+            from_file,
+            name,
+            wan_port,
+            forward_ip,
+            forward_port,
+            protocol,
+            wan_interface,
+            source,
+            enabled,
+            logging,
+        } => {
+            let req = if let Some(ref path) = from_file {
+                serde_json::from_value(util::read_json_file(path)?)?
+            } else {
+                CreatePortForwardRequest {
+                    name: name.unwrap_or_default(),
+                    enabled,
+                    protocol: map_protocol(&protocol),
+                    wan_interface: Some(wan_interface),
+                    source_address: source,
+                    wan_port: wan_port.unwrap_or_default(),
+                    forward_ip: forward_ip.unwrap_or_default(),
+                    forward_port,
+                    logging,
+                }
+            };
+
+            controller
+                .execute(CoreCommand::CreatePortForward(req))
+                .await?;
+            if !global.quiet {
+                eprintln!("Port forward created");
+            }
+            Ok(())
+        }
+
+        PortForwardsCommand::Update { id, from_file } => {
+            let update = if let Some(ref path) = from_file {
+                serde_json::from_value(util::read_json_file(path)?)?
+            } else {
+                UpdatePortForwardRequest::default()
+            };
+            let eid = EntityId::from(id);
+            controller
+                .execute(CoreCommand::UpdatePortForward { id: eid, update })
+                .await?;
+            if !global.quiet {
+                eprintln!("Port forward updated");
+            }
+            Ok(())
+        }
+
+        PortForwardsCommand::Enable { id } => set_enabled(controller, id, true, global).await,
+        PortForwardsCommand::Disable { id } => set_enabled(controller, id, false, global).await,
+
+        PortForwardsCommand::Delete { id } => {
+            let eid = EntityId::from(id.clone());
+            if !util::confirm(&format!("Delete port forward {id}?"), global.yes)? {
+                return Ok(());
+            }
+            controller
+                .execute(CoreCommand::DeletePortForward { id: eid })
+                .await?;
+            if !global.quiet {
+                eprintln!("Port forward deleted");
+            }
+            Ok(())
+        }
+    }
+}
+
+async fn set_enabled(
+    controller: &Controller,
+    id: String,
+    enabled: bool,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let eid = EntityId::from(id);
+    controller
+        .execute(CoreCommand::SetPortForwardEnabled { id: eid, enabled })
+        .await?;
+    if !global.quiet {
+        let state = if enabled { "enabled" } else { "disabled" };
+        eprintln!("Port forward {state}");
+    }
+    Ok(())
+}
@@ -0,0 +1,203 @@
+//! Static route command handlers.
+
+use std::sync::Arc;
+
+use tabled::Tabled;
+use unifly_core::model::{StaticRoute, StaticRouteType as ModelRouteType};
+use unifly_core::{
+    Command as CoreCommand, Controller, CreateStaticRouteRequest, EntityId,
+    UpdateStaticRouteRequest,
+};
+
+use crate::cli::{GlobalOpts, RoutesArgs, RoutesCommand, StaticRouteType};
+use crate::error::CliError;
+use crate::output;
+
+use super::util;
+
+fn map_route_type(t: &StaticRouteType) -> ModelRouteType {
+    match t {
+        StaticRouteType::NextHop => ModelRouteType::NextHop,
+        StaticRouteType::Interface => ModelRouteType::Interface,
+        StaticRouteType::Blackhole => ModelRouteType::Blackhole,
+    }
+}
+
+/// Where matching traffic goes: the next hop, the interface, or nowhere.
+fn target(r: &StaticRoute) -> String {
+    match r.route_type {
+        ModelRouteType::NextHop => r.next_hop.clone().unwrap_or_default(),
+        ModelRouteType::Interface => r.interface.clone().unwrap_or_default(),
+        ModelRouteType::Blackhole => "drop".into(),
+    }
+}
+
+// ── Table row ───────────────────────────────────────────────────────
+
+#[derive(Tabled)]
+struct RouteRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Enabled")]
+    enabled: String,
+    #[tabled(rename = "Destination")]
+    destination: String,
+    #[tabled(rename = "Type")]
+    route_type: String,
+    #[tabled(rename = "Target")]
+    target: String,
+    #[tabled(rename = "Distance")]
+    distance: String,
+}
+
+impl From<&Arc<StaticRoute>> for RouteRow {
+    fn from(r: &Arc<StaticRoute>) -> Self {
+        Self {
+            id: r.id.to_string(),
+            name: r.name.clone(),
+            enabled: if r.enabled { "yes" } else { "no" }.into(),
+            destination: r.destination.clone(),
+            route_type: r.route_type.to_string(),
+            target: target(r),
+            distance: r.distance.map(|d| d.to_string()).unwrap_or_default(),
+        }
+    }
+}
+
+fn detail(r: &Arc<StaticRoute>) -> String {
+    [
+        format!("ID:          {}", r.id),
+        format!("Name:        {}", r.name),
+        format!("Enabled:     {}", r.enabled),
+        format!("Destination: {}", r.destination),
+        format!("Type:        {}", r.route_type),
+        format!("Target:      {}", target(r)),
+        format!(
+            "Distance:    {}",
+            r.distance.map_or_else(|| "-".into(), |d| d.to_string())
+        ),
+    ]
+    .join("\n")
+}
+
+// ── Handler ─────────────────────────────────────────────────────────
+
+#[allow(clippy::too_many_lines)]
+pub async fn handle(
+    controller: &Controller,
+    args: RoutesArgs,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    match args.command {
+        RoutesCommand::List(list) => {
+            let all = controller.static_routes_snapshot();
+            let snap = util::apply_list_args(all.iter().cloned(), &list, |r, filter| {
+                util::matches_json_filter(r, filter)
+            });
+            let out = output::render_list(
+                &global.output,
+                &snap,
+                |r| RouteRow::from(r),
+                |r| r.id.to_string(),
+            );
+            output::print_output(&out, global.quiet);
+            Ok(())
+        }
+
+        RoutesCommand::Get { id } => {
+            let snap = controller.static_routes_snapshot();
+            let found = snap.iter().find(|r| r.id.to_string() == id);
+            match found {
+                Some(r) => {
+                    let out =
+                        output::render_single(&global.output, r, detail, |r| r.id.to_string());
+                    output::print_output(&out, global.quiet);
+                }
+                None => {
+                    return Err(CliError::NotFound {
+                        resource_type: "static route".into(),
+                        identifier: id,
+                        list_command: "routes list".into(),
+                    });
+                }
+            }
+            Ok(())
+        }
+
+        RoutesCommand::Create {
+            from_file,
+            name,
+            destination,
+            route_type,
+            next_hop,
+            interface,
+            distance,
+            enabled,
+        } => {
+            let req = if let Some(ref path) = from_file {
+                serde_json::from_value(util::read_json_file(path)?)?
+            } else {
+                CreateStaticRouteRequest {
+                    name: name.unwrap_or_default(),
+                    enabled,
+                    route_type: map_route_type(&route_type),
+                    destination: destination.unwrap_or_default(),
+                    next_hop,
+                    interface,
+                    distance,
+                }
+            };
+
+            controller
+                .execute(CoreCommand::CreateStaticRoute(req))
+                .await?;
+            if !global.quiet {
+                eprintln!("Static route created");
+            }
+            Ok(())
+        }
+
+        RoutesCommand::Update {
+            id,
+            destination,
+            next_hop,
+            enabled,
+            from_file,
+        } => {
+            let update = if let Some(ref path) = from_file {
+                serde_json::from_value(util::read_json_file(path)?)?
+            } else {
+                UpdateStaticRouteRequest {
+                    destination,
+                    next_hop,
+                    enabled,
+                    ..Default::default()
+                }
+            };
+            let eid = EntityId::from(id);
+            controller
+                .execute(CoreCommand::UpdateStaticRoute { id: eid, update })
+                .await?;
+            if !global.quiet {
+                eprintln!("Static route updated");
+            }
+            Ok(())
+        }
+
+        RoutesCommand::Delete { id } => {
+            let eid = EntityId::from(id.clone());
+            if !util::confirm(&format!("Delete static route {id}?"), global.yes)? {
+                return Ok(());
+            }
+            controller
+                .execute(CoreCommand::DeleteStaticRoute { id: eid })
+                .await?;
+            if !global.quiet {
+                eprintln!("Static route deleted");
+            }
+            Ok(())
+        }
+    }
+}
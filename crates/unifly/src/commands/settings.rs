@@ -0,0 +1,223 @@
+//! Site settings command handlers.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use tabled::Tabled;
+use unifly_core::settings::{self, KNOWN_SETTINGS, SettingAssignment, SiteSettings};
+use unifly_core::{Command as CoreCommand, Controller};
+
+use crate::cli::{GlobalOpts, SettingsArgs, SettingsCommand};
+use crate::error::CliError;
+use crate::output;
+
+use super::util;
+
+// ── Table row ───────────────────────────────────────────────────────
+
+/// One setting value, with secrets masked.
+#[derive(Serialize)]
+struct SettingEntry {
+    section: String,
+    key: String,
+    value: Value,
+}
+
+#[derive(Tabled)]
+struct SettingRow {
+    #[tabled(rename = "Section")]
+    section: String,
+    #[tabled(rename = "Key")]
+    key: String,
+    #[tabled(rename = "Value")]
+    value: String,
+}
+
+impl From<&SettingEntry> for SettingRow {
+    fn from(e: &SettingEntry) -> Self {
+        Self {
+            section: e.section.clone(),
+            key: e.key.clone(),
+            value: fmt_value(&e.value),
+        }
+    }
+}
+
+fn fmt_value(v: &Value) -> String {
+    match v {
+        Value::String(s) => s.clone(),
+        Value::Null => "-".into(),
+        other => other.to_string(),
+    }
+}
+
+/// Passwords, keys and tokens, e.g. `x_mgmt_key`, `x_ssh_keys` or
+/// `x_api_token`. Flags such as `x_ssh_auth_password_enabled` are
+/// booleans and shown as-is.
+fn is_secret(key: &str) -> bool {
+    key.contains("password")
+        || key.contains("secret")
+        || key.contains("token")
+        || key.ends_with("_key")
+        || key.ends_with("_keys")
+        || key == "community"
+}
+
+fn entry(section: &str, key: &str, value: &Value) -> SettingEntry {
+    let hidden = is_secret(key)
+        && match value {
+            Value::String(s) => !s.is_empty(),
+            Value::Array(items) => !items.is_empty(),
+            Value::Object(fields) => !fields.is_empty(),
+            _ => false,
+        };
+    let value = if hidden {
+        Value::from("********")
+    } else {
+        value.clone()
+    };
+    SettingEntry {
+        section: section.to_owned(),
+        key: key.to_owned(),
+        value,
+    }
+}
+
+/// The typed settings present on this controller.
+fn known_entries(settings: &SiteSettings) -> Vec<SettingEntry> {
+    let sections = settings.sections();
+    KNOWN_SETTINGS
+        .iter()
+        .filter_map(|f| {
+            let value = sections.get(f.section)?.get(f.key)?;
+            Some(entry(f.section, f.key, value))
+        })
+        .collect()
+}
+
+// ── Handler ─────────────────────────────────────────────────────────
+
+pub async fn handle(
+    controller: &Controller,
+    args: SettingsArgs,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    match args.command {
+        SettingsCommand::Get { section } => {
+            let settings = controller.get_site_settings().await?;
+            let entries = if let Some(name) = section {
+                let fields = settings.section(&name).ok_or_else(|| CliError::NotFound {
+                    resource_type: "settings section".into(),
+                    identifier: name.clone(),
+                    list_command: "settings get".into(),
+                })?;
+                let key = settings::section_key(&name);
+                fields.iter().map(|(k, v)| entry(key, k, v)).collect()
+            } else {
+                if !global.quiet && !settings.other.is_empty() {
+                    let names: Vec<&str> = settings.other.keys().map(String::as_str).collect();
+                    eprintln!(
+                        "Other sections: {} (see `unifly settings get <section>`)",
+                        names.join(", ")
+                    );
+                }
+                known_entries(&settings)
+            };
+            let out = output::render_list(
+                &global.output,
+                &entries,
+                |e| SettingRow::from(e),
+                |e| format!("{}.{}", e.section, e.key),
+            );
+            output::print_output(&out, global.quiet);
+            Ok(())
+        }
+
+        SettingsCommand::Set { assignments } => {
+            let assignments = assignments
+                .iter()
+                .map(|a| a.parse::<SettingAssignment>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| validation_error(&e))?;
+            let settings = controller.get_site_settings().await?;
+            let sections = settings.sections();
+
+            let mut changes: BTreeMap<String, Map<String, Value>> = BTreeMap::new();
+            for a in &assignments {
+                let section = sections.get(&a.section).ok_or_else(|| CliError::NotFound {
+                    resource_type: "settings section".into(),
+                    identifier: a.section.clone(),
+                    list_command: "settings get".into(),
+                })?;
+                let current = section.get(&a.key);
+                let value = settings::parse_value(a, current).map_err(|e| validation_error(&e))?;
+                if !global.quiet {
+                    let old = current.map_or(Value::Null, |v| entry(&a.section, &a.key, v).value);
+                    let new = entry(&a.section, &a.key, &value).value;
+                    eprintln!(
+                        "{}.{}: {} -> {}",
+                        a.section,
+                        a.key,
+                        fmt_value(&old),
+                        fmt_value(&new)
+                    );
+                }
+                changes
+                    .entry(a.section.clone())
+                    .or_default()
+                    .insert(a.key.clone(), value);
+            }
+
+            let prompt = format!("Apply {} setting change(s)?", assignments.len());
+            if !util::confirm(&prompt, global.yes)? {
+                return Ok(());
+            }
+            for (section, changes) in changes {
+                controller
+                    .execute(CoreCommand::UpdateSiteSettings { section, changes })
+                    .await?;
+            }
+            if !global.quiet {
+                eprintln!("Settings updated");
+            }
+            Ok(())
+        }
+    }
+}
+
+fn validation_error(e: &unifly_core::CoreError) -> CliError {
+    CliError::Validation {
+        field: "setting".into(),
+        reason: e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{Value, entry};
+
+    #[test]
+    fn secrets_are_masked() {
+        let shown = |key: &str, value: Value| entry("mgmt", key, &value).value;
+
+        assert_eq!(shown("x_mgmt_key", json!("abc")), json!("********"));
+        assert_eq!(
+            shown("x_ssh_keys", json!([{ "key": "ssh-ed25519 AAAA" }])),
+            json!("********")
+        );
+        assert_eq!(shown("x_api_token", json!("t0k")), json!("********"));
+        assert_eq!(shown("x_ssh_password", json!("hunter2")), json!("********"));
+        assert_eq!(shown("community", json!("public")), json!("********"));
+        // Unset secrets and flags stay visible.
+        assert_eq!(shown("x_mgmt_key", json!("")), json!(""));
+        assert_eq!(shown("x_ssh_keys", json!([])), json!([]));
+        assert_eq!(
+            shown("x_ssh_auth_password_enabled", json!(true)),
+            json!(true)
+        );
+        assert_eq!(shown("x_ssh_username", json!("admin")), json!("admin"));
+    }
+}
@@ -0,0 +1,144 @@
+//! Threat (IDS/IPS) command handlers.
+
+use chrono::Utc;
+use tabled::Tabled;
+use unifly_core::{Controller, ThreatEvent, ThreatSource};
+
+use crate::cli::{GlobalOpts, ThreatsArgs, ThreatsCommand};
+use crate::error::CliError;
+use crate::output;
+
+use super::util;
+
+fn or_dash<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| "-".into(), |v| v.to_string())
+}
+
+fn endpoint(ip: Option<std::net::IpAddr>, port: Option<u16>, country: Option<&str>) -> String {
+    let addr = match (ip, port) {
+        (Some(ip), Some(port)) => format!("{ip}:{port}"),
+        (Some(ip), None) => ip.to_string(),
+        (None, _) => "-".into(),
+    };
+    match country {
+        Some(country) => format!("{addr} ({country})"),
+        None => addr,
+    }
+}
+
+fn action(blocked: bool) -> &'static str {
+    if blocked { "blocked" } else { "alert" }
+}
+
+// ── Table rows ──────────────────────────────────────────────────────
+
+#[derive(Tabled)]
+struct ThreatRow {
+    #[tabled(rename = "Time")]
+    time: String,
+    #[tabled(rename = "Action")]
+    action: &'static str,
+    #[tabled(rename = "Sev")]
+    severity: String,
+    #[tabled(rename = "Category")]
+    category: String,
+    #[tabled(rename = "Signature")]
+    signature: String,
+    #[tabled(rename = "Source")]
+    source: String,
+    #[tabled(rename = "Destination")]
+    destination: String,
+}
+
+impl From<&ThreatEvent> for ThreatRow {
+    fn from(t: &ThreatEvent) -> Self {
+        Self {
+            time: t.timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+            action: action(t.blocked),
+            severity: or_dash(t.severity),
+            category: or_dash(t.category.as_deref()),
+            signature: or_dash(t.signature.as_deref()),
+            source: endpoint(t.src_ip, t.src_port, t.src_country.as_deref()),
+            destination: endpoint(t.dst_ip, t.dst_port, t.dst_country.as_deref()),
+        }
+    }
+}
+
+#[derive(Tabled)]
+struct SourceRow {
+    #[tabled(rename = "Source")]
+    source: String,
+    #[tabled(rename = "Country")]
+    country: String,
+    #[tabled(rename = "Events")]
+    events: usize,
+    #[tabled(rename = "Blocked")]
+    blocked: usize,
+    #[tabled(rename = "Top category")]
+    top_category: String,
+    #[tabled(rename = "Last seen")]
+    last_seen: String,
+}
+
+impl From<&ThreatSource> for SourceRow {
+    fn from(s: &ThreatSource) -> Self {
+        Self {
+            source: s.src_ip.to_string(),
+            country: or_dash(s.country.as_deref()),
+            events: s.events,
+            blocked: s.blocked,
+            top_category: or_dash(s.top_category.as_deref()),
+            last_seen: s.last_seen.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
+
+// ── Handler ─────────────────────────────────────────────────────────
+
+pub async fn handle(
+    controller: &Controller,
+    args: ThreatsArgs,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    match args.command {
+        ThreatsCommand::List {
+            within,
+            category,
+            top_sources,
+            limit,
+        } => {
+            let now = Utc::now();
+            let start = util::parse_since("within", &within, now)?;
+            let mut events = controller.list_threat_events(start, now).await?;
+            if let Some(category) = category {
+                let needle = category.to_ascii_lowercase();
+                events.retain(|t| {
+                    t.category
+                        .as_deref()
+                        .is_some_and(|c| c.to_ascii_lowercase().contains(&needle))
+                });
+            }
+
+            let out = if top_sources {
+                let mut sources = ThreatSource::tally(&events);
+                sources.truncate(limit);
+                output::render_list(
+                    &global.output,
+                    &sources,
+                    |s| SourceRow::from(s),
+                    |s| s.src_ip.to_string(),
+                )
+            } else {
+                events.truncate(limit);
+                output::render_list(
+                    &global.output,
+                    &events,
+                    |t| ThreatRow::from(t),
+                    |t| t.id.to_string(),
+                )
+            };
+            output::print_output(&out, global.quiet);
+            Ok(())
+        }
+    }
+}
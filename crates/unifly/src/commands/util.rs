@@ -1,8 +1,10 @@
 //! Shared helpers for command handlers.
 
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
-use unifly_core::{Controller, EntityId, MacAddress};
+use chrono::{DateTime, Duration, Utc};
+use unifly_core::{Controller, EntityId, KnownClient, MacAddress, UserGroup};
 
 use crate::cli::ListArgs;
 use crate::error::CliError;
@@ -38,22 +40,138 @@ pub fn resolve_device_mac(
     Ok(MacAddress::new(identifier))
 }
 
-/// Resolve a client identifier (UUID or MAC) to an EntityId via snapshot lookup.
-#[allow(dead_code)]
-pub fn resolve_client_id(controller: &Controller, identifier: &str) -> Result<EntityId, CliError> {
+/// Resolve a client identifier (UUID or MAC) to an EntityId.
+///
+/// Connected clients come from the snapshot; offline clients and those
+/// that only hold a DHCP reservation come from the Legacy API's
+/// known-client list.
+pub async fn resolve_client_id(
+    controller: &Controller,
+    identifier: &str,
+) -> Result<EntityId, CliError> {
+    let mac = MacAddress::new(identifier);
     let snap = controller.clients_snapshot();
-    for client in snap.iter() {
-        if client.id.to_string() == identifier || client.mac.to_string() == identifier {
-            return Ok(client.id.clone());
+    if let Some(client) = snap
+        .iter()
+        .find(|c| c.id.to_string() == identifier || c.mac == mac)
+    {
+        return Ok(client.id.clone());
+    }
+    find_known_client(controller, identifier)
+        .await
+        .map(|k| k.id)
+        .ok_or_else(|| CliError::NotFound {
+            resource_type: "client".into(),
+            identifier: identifier.into(),
+            list_command: "clients list --include-offline".into(),
+        })
+}
+
+/// Look up a client the controller has seen, connected or not, by ID or
+/// MAC. `None` if it is unknown or the Legacy API is unavailable.
+pub async fn find_known_client(controller: &Controller, identifier: &str) -> Option<KnownClient> {
+    let mac = MacAddress::new(identifier);
+    match controller.list_known_clients(None).await {
+        Ok(known) => known
+            .into_iter()
+            .find(|k| k.id.to_string() == identifier || k.mac == mac),
+        Err(e) => {
+            tracing::debug!(error = %e, "could not load known clients");
+            None
         }
     }
-    Err(CliError::NotFound {
-        resource_type: "client".into(),
-        identifier: identifier.into(),
-        list_command: "clients list".into(),
+}
+
+/// IDs of the devices carrying a tag, matched by tag ID or name.
+///
+/// Tags come from the Integration API.
+pub async fn device_tag_members(
+    controller: &Controller,
+    tag: &str,
+) -> Result<HashSet<EntityId>, CliError> {
+    let tags = controller.list_device_tags().await?;
+    let found = tags
+        .iter()
+        .find(|v| {
+            let id = v.get("id").and_then(serde_json::Value::as_str);
+            let name = v.get("name").and_then(serde_json::Value::as_str);
+            id == Some(tag) || name.is_some_and(|n| n.eq_ignore_ascii_case(tag))
+        })
+        .ok_or_else(|| CliError::NotFound {
+            resource_type: "device tag".into(),
+            identifier: tag.into(),
+            list_command: "devices tags".into(),
+        })?;
+    Ok(found
+        .get("deviceIds")
+        .and_then(serde_json::Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(serde_json::Value::as_str)
+        .map(EntityId::from)
+        .collect())
+}
+
+/// Look up a user group by ID or (case-insensitive) name.
+pub async fn resolve_user_group(
+    controller: &Controller,
+    group: &str,
+) -> Result<UserGroup, CliError> {
+    controller
+        .list_user_groups()
+        .await?
+        .into_iter()
+        .find(|g| g.id.to_string() == group || g.name.eq_ignore_ascii_case(group))
+        .ok_or_else(|| CliError::NotFound {
+            resource_type: "user group".into(),
+            identifier: group.into(),
+            list_command: "groups list".into(),
+        })
+}
+
+/// Site display names keyed by ID, for the Site column of `--site all`
+/// listings. `None` when a single site is loaded.
+pub fn site_names(controller: &Controller) -> Option<HashMap<EntityId, String>> {
+    controller.is_all_sites().then(|| {
+        controller
+            .sites_snapshot()
+            .iter()
+            .map(|s| (s.id.clone(), s.name.clone()))
+            .collect()
     })
 }
 
+/// `30m`, `6h`, `2d` back from `now`, or an RFC3339 timestamp.
+pub fn parse_since(
+    field: &str,
+    value: &str,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>, CliError> {
+    let invalid = || CliError::Validation {
+        field: field.into(),
+        reason: format!("invalid start '{value}' (use e.g. 30m, 6h, 2d or RFC3339)"),
+    };
+
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    let (split, _) = value.char_indices().next_back().ok_or_else(invalid)?;
+    let (amount, unit) = value.split_at(split);
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    if amount <= 0 {
+        return Err(invalid());
+    }
+    let back = match unit {
+        "s" => Duration::try_seconds(amount),
+        "m" => Duration::try_minutes(amount),
+        "h" => Duration::try_hours(amount),
+        "d" => Duration::try_days(amount),
+        _ => None,
+    }
+    .ok_or_else(invalid)?;
+    now.checked_sub_signed(back).ok_or_else(invalid)
+}
+
 /// Prompt for confirmation, auto-approving if `--yes` was passed.
 pub fn confirm(message: &str, yes_flag: bool) -> Result<bool, CliError> {
     if yes_flag {
@@ -115,7 +233,7 @@ pub fn matches_json_filter<T: serde::Serialize>(item: &T, filter: &str) -> bool
 
 #[cfg(test)]
 mod tests {
-    use super::{apply_list_args, matches_json_filter};
+    use super::{apply_list_args, matches_json_filter, parse_since};
     use crate::cli::ListArgs;
 
     #[test]
@@ -149,4 +267,25 @@ mod tests {
         assert_eq!(filtered.len(), 1);
         assert_eq!(filtered[0]["name"], "beta");
     }
+
+    #[test]
+    fn parse_since_accepts_durations_and_rfc3339() {
+        let now = chrono::DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z")
+            .expect("valid timestamp")
+            .to_utc();
+        let ago = |v: &str| {
+            parse_since("within", v, now)
+                .map(|t| (now - t).num_minutes())
+                .ok()
+        };
+        assert_eq!(ago("30m"), Some(30));
+        assert_eq!(ago("24h"), Some(24 * 60));
+        assert_eq!(ago("2d"), Some(2 * 24 * 60));
+        assert_eq!(ago("2024-05-01T11:00:00Z"), Some(60));
+        assert_eq!(ago("soon"), None);
+        assert_eq!(ago("5w"), None);
+        assert_eq!(ago("-5m"), None);
+        assert_eq!(ago("0h"), None);
+        assert_eq!(ago("100000000d"), None);
+    }
 }
@@ -0,0 +1,117 @@
+//! Watch-alerts: evaluate alert rules against a live controller and
+//! deliver matches to the configured notifiers.
+
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::sync::broadcast::error::RecvError;
+use unifly_core::alerts::{Alert, AlertEngine, AlertRules, Notifiers};
+use unifly_core::{Controller, ControllerConfig};
+
+use crate::cli::{GlobalOpts, OutputFormat, WatchAlertsArgs};
+use crate::error::CliError;
+
+/// Load and validate a rules document, picking the format from the file
+/// extension the same way `apply` does.
+pub fn read_rules(path: &Path) -> Result<AlertRules, CliError> {
+    let contents = std::fs::read_to_string(path)?;
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+    let parsed: Result<AlertRules, String> = match ext.as_deref() {
+        Some("toml") => toml::from_str(&contents).map_err(|e| e.to_string()),
+        Some("json") => serde_json::from_str(&contents).map_err(|e| e.to_string()),
+        _ => serde_yaml::from_str(&contents).map_err(|e| e.to_string()),
+    };
+    let rules = parsed.map_err(|reason| CliError::Validation {
+        field: "rules".into(),
+        reason: format!("{}: {reason}", path.display()),
+    })?;
+    rules.validate()?;
+    Ok(rules)
+}
+
+/// Keep the WebSocket event stream and device refresh running.
+pub fn live_config(args: &WatchAlertsArgs, config: ControllerConfig) -> ControllerConfig {
+    ControllerConfig {
+        refresh_interval_secs: args.device_interval,
+        websocket_enabled: true,
+        ..config
+    }
+}
+
+fn print_alert(alert: &Alert, format: &OutputFormat) {
+    let line = match format {
+        OutputFormat::Json | OutputFormat::JsonCompact => {
+            serde_json::to_string(alert).unwrap_or_else(|_| alert.summary())
+        }
+        OutputFormat::Yaml => serde_yaml::to_string(alert).unwrap_or_else(|_| alert.summary()),
+        _ => format!(
+            "{}  {}",
+            alert.timestamp.format("%H:%M:%S"),
+            alert.summary()
+        ),
+    };
+    let _ = writeln!(io::stdout().lock(), "{line}");
+}
+
+/// Evaluate rules until Ctrl-C.
+pub async fn watch(
+    args: &WatchAlertsArgs,
+    rules: AlertRules,
+    controller: &Controller,
+    global: &GlobalOpts,
+) -> Result<(), CliError> {
+    let notifiers = Arc::new(Notifiers::new(&rules.notifiers)?);
+    let mut engine = AlertEngine::new(rules);
+    let mut events = controller.events();
+    let mut alarm_tick = tokio::time::interval(Duration::from_secs(args.alarm_interval.max(1)));
+    let mut device_tick = tokio::time::interval(Duration::from_secs(args.device_interval.max(1)));
+
+    if !global.quiet {
+        eprintln!("Watching for alerts (Ctrl-C to stop)");
+    }
+
+    loop {
+        let alerts = tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            result = events.recv() => match result {
+                Ok(event) => engine.on_event(&event, Utc::now()),
+                Err(RecvError::Closed) => break,
+                Err(RecvError::Lagged(n)) => {
+                    eprintln!("warning: skipped {n} events (too slow)");
+                    continue;
+                }
+            },
+            _ = alarm_tick.tick() => match controller.list_alarms().await {
+                Ok(alarms) => engine.on_alarms(&alarms, Utc::now()),
+                Err(e) => {
+                    eprintln!("warning: could not list alarms: {e}");
+                    continue;
+                }
+            },
+            _ = device_tick.tick() => {
+                engine.on_devices(&controller.devices_snapshot(), Utc::now())
+            }
+        };
+
+        for alert in alerts {
+            print_alert(&alert, &global.output);
+            if args.dry_run {
+                continue;
+            }
+            let notifiers = Arc::clone(&notifiers);
+            tokio::spawn(async move {
+                for (name, e) in notifiers.deliver(&alert).await {
+                    eprintln!("warning: notifier '{name}' failed: {e}");
+                }
+            });
+        }
+    }
+
+    Ok(())
+}
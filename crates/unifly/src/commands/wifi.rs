@@ -3,13 +3,14 @@
 use std::sync::Arc;
 
 use tabled::Tabled;
-use unifly_core::model::{WifiBroadcast, WifiSecurityMode};
+use unifly_core::model::{NeighborAp, WifiBroadcast, WifiSecurityMode};
 use unifly_core::{
     Command as CoreCommand, Controller, CreateWifiBroadcastRequest, EntityId,
     UpdateWifiBroadcastRequest,
 };
 
 use crate::cli::{GlobalOpts, WifiArgs, WifiBroadcastType, WifiCommand, WifiSecurity};
+use crate::config;
 use crate::error::CliError;
 use crate::output;
 
@@ -70,6 +71,49 @@ impl From<&Arc<WifiBroadcast>> for WifiRow {
     }
 }
 
+#[derive(Tabled)]
+struct NeighborRow {
+    #[tabled(rename = "BSSID")]
+    bssid: String,
+    #[tabled(rename = "SSID")]
+    ssid: String,
+    #[tabled(rename = "Channel")]
+    channel: String,
+    #[tabled(rename = "Band")]
+    band: String,
+    #[tabled(rename = "RSSI")]
+    rssi: String,
+    #[tabled(rename = "Signal")]
+    signal: String,
+    #[tabled(rename = "Heard By")]
+    heard_by: String,
+    #[tabled(rename = "Flags")]
+    flags: String,
+}
+
+fn neighbor_row(ap: &NeighborAp, heard_by: String, known: bool) -> NeighborRow {
+    let flags: Vec<&str> = [(ap.is_rogue, "wired"), (known, "known")]
+        .into_iter()
+        .filter_map(|(set, flag)| set.then_some(flag))
+        .collect();
+    NeighborRow {
+        bssid: ap.bssid.to_string(),
+        ssid: ap.ssid.clone().unwrap_or_else(|| "(hidden)".into()),
+        channel: ap.channel.map(|c| c.to_string()).unwrap_or_default(),
+        band: ap
+            .frequency_ghz
+            .map(|f| format!("{f}GHz"))
+            .unwrap_or_default(),
+        rssi: ap.rssi.map(|r| r.to_string()).unwrap_or_default(),
+        signal: ap
+            .signal_dbm
+            .map(|s| format!("{s} dBm"))
+            .unwrap_or_default(),
+        heard_by,
+        flags: flags.join(", "),
+    }
+}
+
 fn detail(w: &Arc<WifiBroadcast>) -> String {
     [
         format!("ID:         {}", w.id),
@@ -106,9 +150,11 @@ pub async fn handle(
             let snap = util::apply_list_args(all.iter().cloned(), &list, |w, filter| {
                 util::matches_json_filter(w, filter)
             });
-            let out = output::render_list(
+            let out = output::render_site_list(
                 &global.output,
                 &snap,
+                util::site_names(controller).as_ref(),
+                |w| w.site_id.as_ref(),
                 |w| WifiRow::from(w),
                 |w| w.id.to_string(),
             );
@@ -218,5 +264,38 @@ pub async fn handle(
             }
             Ok(())
         }
+
+        WifiCommand::Neighbors { within } => {
+            let mut aps = controller.list_neighbor_aps(within).await?;
+            aps.sort_by_key(|ap| std::cmp::Reverse(ap.signal_dbm.unwrap_or(i32::MIN)));
+
+            let cfg = config::load_config_or_default();
+            let profile = cfg.profiles.get(&config::active_profile_name(global, &cfg));
+            let devices = controller.devices_snapshot();
+            let heard_by = |ap: &NeighborAp| {
+                ap.detected_by
+                    .as_ref()
+                    .map(|mac| {
+                        devices
+                            .iter()
+                            .find(|d| d.mac == *mac)
+                            .and_then(|d| d.name.clone())
+                            .unwrap_or_else(|| mac.to_string())
+                    })
+                    .unwrap_or_default()
+            };
+
+            let out = output::render_list(
+                &global.output,
+                &aps,
+                |ap| {
+                    let known = profile.is_some_and(|p| p.is_known_rogue(&ap.bssid));
+                    neighbor_row(ap, heard_by(ap), known)
+                },
+                |ap| ap.bssid.to_string(),
+            );
+            output::print_output(&out, global.quiet);
+            Ok(())
+        }
     }
 }
@@ -3,11 +3,13 @@
 //! Re-exports the shared types and adds CLI-specific resolution that
 //! respects `GlobalOpts` flag overrides (--controller, --api-key, etc.).
 
+use std::path::PathBuf;
 use std::time::Duration;
 
 use secrecy::SecretString;
 
-use unifly_core::{AuthCredentials, ControllerConfig, TlsVerification};
+use unifly_core::history::HistoryConfig;
+use unifly_core::{AuthCredentials, ControllerConfig, TlsVerification, TwoFactor};
 
 use crate::cli::GlobalOpts;
 use crate::error::CliError;
@@ -20,6 +22,21 @@ pub use unifly_config::{
 
 // ── CLI-specific helpers ────────────────────────────────────────────
 
+/// History recorder settings of the active profile, when it records.
+pub fn active_history_config(global: &GlobalOpts) -> Option<HistoryConfig> {
+    let cfg = load_config_or_default();
+    let name = active_profile_name(global, &cfg);
+    cfg.profiles
+        .get(&name)
+        .and_then(|profile| unifly_config::history_config(profile, &name))
+}
+
+/// Where the active profile's history is (or would be) recorded.
+pub fn active_history_dir(global: &GlobalOpts) -> PathBuf {
+    let cfg = load_config_or_default();
+    unifly_config::history_dir(&active_profile_name(global, &cfg))
+}
+
 /// Resolve the active profile name from CLI flags and config.
 pub fn active_profile_name(global: &GlobalOpts, config: &Config) -> String {
     global
@@ -65,10 +82,16 @@ pub fn resolve_profile(
                 password,
             }
         }
+        "cloud" => AuthCredentials::Cloud {
+            api_key: resolve_api_key_with_flag(profile, profile_name, global)?,
+            host_id: profile.host_id.clone().unwrap_or_default(),
+        },
         other => {
             return Err(CliError::Validation {
                 field: "auth_mode".into(),
-                reason: format!("expected 'integration', 'legacy', or 'hybrid', got '{other}'"),
+                reason: format!(
+                    "expected 'integration', 'legacy', 'hybrid', or 'cloud', got '{other}'"
+                ),
             });
         }
     };
@@ -88,21 +111,54 @@ pub fn resolve_profile(
     // 5. Timeout
     let timeout = Duration::from_secs(global.timeout);
 
+    // 6. Second factor (flag > keyring TOTP secret)
+    let two_factor = match global.otp {
+        Some(ref code) => Some(TwoFactor::Code(SecretString::from(code.clone()))),
+        None => {
+            unifly_config::resolve_totp_secret(profile, profile_name).map(TwoFactor::TotpSecret)
+        }
+    };
+
     Ok(ControllerConfig {
         url,
         auth,
         site,
         tls,
         timeout,
+        retry: profile.retry_config(),
         refresh_interval_secs: 0,
         websocket_enabled: false,
         polling_interval_secs: 30,
         bandwidth_poll_interval: std::time::Duration::ZERO,
         client_poll_interval: std::time::Duration::ZERO,
         device_stats_poll_interval: std::time::Duration::ZERO,
+        two_factor,
+        session_cache: unifly_config::session_cache(profile, profile_name),
+        // Only long-running commands (the exporter) record history
+        history: None,
     })
 }
 
+/// Resolve a profile for a multi-controller command.
+///
+/// Same as [`resolve_profile`], except the flags that pin a single
+/// controller (`--controller`, `--api-key`, `--site`, `--otp`) are
+/// ignored: every profile keeps its own.
+pub fn resolve_fleet_profile(
+    profile: &Profile,
+    profile_name: &str,
+    global: &GlobalOpts,
+) -> Result<ControllerConfig, CliError> {
+    let unpinned = GlobalOpts {
+        controller: None,
+        api_key: None,
+        site: None,
+        otp: None,
+        ..global.clone()
+    };
+    resolve_profile(profile, profile_name, &unpinned)
+}
+
 /// Resolve API key with CLI flag override, then fall through to shared resolution.
 fn resolve_api_key_with_flag(
     profile: &Profile,
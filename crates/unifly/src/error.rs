@@ -64,6 +64,16 @@ pub enum CliError {
     )]
     AuthFailed { profile: String, message: String },
 
+    #[error("Two-factor authentication code required")]
+    #[diagnostic(
+        code(unifi::two_factor_required),
+        help(
+            "Pass a code with --otp <CODE>, or store the account's TOTP secret:\n\
+             unifi config set-totp --profile <NAME>"
+        )
+    )]
+    TwoFactorRequired,
+
     #[error("No credentials configured for profile '{profile}'")]
     #[diagnostic(
         code(unifi::no_credentials),
@@ -166,6 +176,24 @@ pub enum CliError {
     )]
     Timeout { seconds: u64 },
 
+    // ── Firmware rollout ─────────────────────────────────────────────
+    #[error("Firmware rollout aborted: {reason}")]
+    #[diagnostic(
+        code(unifi::rollout_aborted),
+        help(
+            "Devices from earlier batches keep their new firmware. Check the device, then rerun `unifly firmware rollout` to continue."
+        )
+    )]
+    RolloutAborted { reason: String },
+
+    // ── Bulk operations ──────────────────────────────────────────────
+    #[error("{failed} of {total} targets failed")]
+    #[diagnostic(
+        code(unifi::bulk_failed),
+        help("See the result table for each target's error.")
+    )]
+    BulkFailed { failed: usize, total: usize },
+
     // ── IO / Serialization ────────────────────────────────────────────
     #[error(transparent)]
     Io(#[from] std::io::Error),
@@ -191,7 +219,9 @@ impl CliError {
     pub fn exit_code(&self) -> i32 {
         match self {
             Self::ConnectionFailed { .. } | Self::TlsError { .. } => exit_code::CONNECTION,
-            Self::AuthFailed { .. } | Self::NoCredentials { .. } => exit_code::AUTH,
+            Self::AuthFailed { .. } | Self::TwoFactorRequired | Self::NoCredentials { .. } => {
+                exit_code::AUTH
+            }
             Self::NotFound { .. } => exit_code::NOT_FOUND,
             Self::Conflict { .. } => exit_code::CONFLICT,
             Self::Timeout { .. } => exit_code::TIMEOUT,
@@ -217,6 +247,8 @@ impl From<CoreError> for CliError {
                 message,
             },
 
+            CoreError::TwoFactorRequired => CliError::TwoFactorRequired,
+
             CoreError::ControllerDisconnected => CliError::ConnectionFailed {
                 url: "(disconnected)".into(),
                 source: "Controller connection was lost".into(),
@@ -14,10 +14,12 @@ mod config;
 mod error;
 mod output;
 
+use std::io::IsTerminal;
+
 use clap::Parser;
 use tracing_subscriber::EnvFilter;
 
-use unifly_core::Controller;
+use unifly_core::{Controller, ControllerConfig, CoreError, TwoFactor};
 
 use crate::cli::{Cli, Command};
 use crate::error::CliError;
@@ -60,6 +62,42 @@ async fn run(cli: Cli) -> Result<(), CliError> {
         // Config commands don't need a controller connection
         Command::Config(args) => commands::config_cmd::handle(args, &cli.global),
 
+        // Cloud commands talk to Site Manager, not a controller
+        Command::Cloud(args) => {
+            let config = build_controller_config(&cli.global)?;
+            commands::cloud::handle(args, config, &cli.global).await
+        }
+
+        // The exporter keeps one live connection until interrupted
+        Command::Exporter(args) => {
+            let config = ControllerConfig {
+                history: config::active_history_config(&cli.global),
+                ..commands::exporter::live_config(&args, build_controller_config(&cli.global)?)
+            };
+            let controller = connect(config).await?;
+            let result = commands::exporter::serve(&args, &controller).await;
+            controller.disconnect().await;
+            result
+        }
+
+        Command::WatchAlerts(args) => {
+            // Reject a bad rules file before connecting
+            let rules = commands::watch_alerts::read_rules(&args.rules)?;
+            let config =
+                commands::watch_alerts::live_config(&args, build_controller_config(&cli.global)?);
+            let controller = connect(config).await?;
+            let result =
+                commands::watch_alerts::watch(&args, rules, &controller, &cli.global).await;
+            controller.disconnect().await;
+            result
+        }
+
+        // History reads what the recorder wrote; no connection needed
+        Command::History(args) => commands::history::handle(args, &cli.global),
+
+        // Fleet commands open their own connection per profile
+        Command::Fleet(args) => commands::fleet::handle(args, &cli.global).await,
+
         // Shell completions generation
         Command::Completions(args) => {
             use clap::CommandFactory;
@@ -73,8 +111,7 @@ async fn run(cli: Cli) -> Result<(), CliError> {
         // All other commands require a controller connection
         cmd => {
             let controller_config = build_controller_config(&cli.global)?;
-            let controller = Controller::new(controller_config);
-            controller.connect().await.map_err(CliError::from)?;
+            let controller = connect(controller_config).await?;
 
             tracing::debug!(command = ?cmd, "dispatching command");
             let result = commands::dispatch(cmd, &controller, &cli.global).await;
@@ -85,6 +122,28 @@ async fn run(cli: Cli) -> Result<(), CliError> {
     }
 }
 
+/// Connect to the controller, prompting once for a two-factor code when
+/// the account requires one and stdin is a terminal.
+async fn connect(config: ControllerConfig) -> Result<Controller, CliError> {
+    let controller = Controller::new(config.clone());
+    match controller.connect().await {
+        Ok(()) => Ok(controller),
+        Err(CoreError::TwoFactorRequired) if std::io::stdin().is_terminal() => {
+            let code: String = dialoguer::Input::new()
+                .with_prompt("Two-factor code")
+                .interact_text()
+                .map_err(|e| CliError::Io(std::io::Error::other(e)))?;
+            let controller = Controller::new(ControllerConfig {
+                two_factor: Some(TwoFactor::Code(code.trim().to_owned().into())),
+                ..config
+            });
+            controller.connect().await?;
+            Ok(controller)
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
 /// Build a `ControllerConfig` from the config file, profile, and CLI overrides.
 fn build_controller_config(
     global: &cli::GlobalOpts,
@@ -130,11 +189,15 @@ fn build_controller_config(
         site: global.site.clone().unwrap_or_else(|| "default".into()),
         tls,
         timeout: std::time::Duration::from_secs(global.timeout),
+        retry: unifly_core::RetryConfig::default(),
         refresh_interval_secs: 0,
         websocket_enabled: false,
         polling_interval_secs: 30,
         bandwidth_poll_interval: std::time::Duration::ZERO,
         client_poll_interval: std::time::Duration::ZERO,
         device_stats_poll_interval: std::time::Duration::ZERO,
+        two_factor: None,
+        session_cache: None,
+        history: None,
     })
 }
@@ -3,16 +3,18 @@
 //! Renders data in the format selected by `--output`. Table uses `tabled`,
 //! structured formats use serde, plain emits one identifier per line.
 
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::io::{self, IsTerminal, Write};
 
 use tabled::{Table, Tabled, settings::Style};
+use unifly_core::EntityId;
 
 use crate::cli::{ColorMode, OutputFormat};
 
 // ── Color helpers (SilkCircuit palette) ──────────────────────────────
 
 /// Determine whether color output should be enabled.
-#[allow(dead_code)]
 pub fn should_color(mode: &ColorMode) -> bool {
     match mode {
         ColorMode::Always => true,
@@ -51,6 +53,60 @@ where
     }
 }
 
+/// Render a list like [`render_list`], prefixing each table row with a
+/// `Site` column when `site_names` is given (`--site all`).
+///
+/// Structured formats are unchanged: entities already carry `site_id`.
+pub fn render_site_list<T, R>(
+    format: &OutputFormat,
+    data: &[T],
+    site_names: Option<&HashMap<EntityId, String>>,
+    site_of: impl Fn(&T) -> Option<&EntityId>,
+    to_row: impl Fn(&T) -> R,
+    id_fn: impl Fn(&T) -> String,
+) -> String
+where
+    T: serde::Serialize,
+    R: Tabled,
+{
+    let Some(names) = site_names else {
+        return render_list(format, data, to_row, id_fn);
+    };
+    render_list(
+        format,
+        data,
+        |item| SiteRow {
+            site: site_of(item)
+                .map(|id| names.get(id).cloned().unwrap_or_else(|| id.to_string()))
+                .unwrap_or_default(),
+            row: to_row(item),
+        },
+        id_fn,
+    )
+}
+
+/// A table row with a leading `Site` column.
+struct SiteRow<R> {
+    site: String,
+    row: R,
+}
+
+impl<R: Tabled> Tabled for SiteRow<R> {
+    const LENGTH: usize = R::LENGTH + 1;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        let mut fields = vec![Cow::Borrowed(self.site.as_str())];
+        fields.extend(self.row.fields());
+        fields
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        let mut headers = vec![Cow::Borrowed("Site")];
+        headers.extend(R::headers());
+        headers
+    }
+}
+
 /// Render a single serde-serializable item in the chosen format.
 ///
 /// Table rendering uses a custom `detail_fn` that returns a pre-formatted string,
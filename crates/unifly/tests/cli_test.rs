@@ -301,6 +301,50 @@ fn test_acl_create_command_parses_with_required_flags() {
         );
 }
 
+#[test]
+fn test_clients_reserve_rejects_invalid_ip() {
+    let output = unifly_cmd()
+        .args([
+            "clients",
+            "reserve",
+            "aa:bb:cc:dd:ee:ff",
+            "--ip",
+            "192.168.1.300",
+            "--network",
+            "LAN",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let text = combined_output(&output);
+    assert!(
+        text.contains("--ip"),
+        "Expected error about the --ip value:\n{text}"
+    );
+}
+
+#[test]
+fn test_clients_reserve_command_parses_with_required_flags() {
+    unifly_cmd()
+        .args([
+            "clients",
+            "reserve",
+            "aa:bb:cc:dd:ee:ff",
+            "--ip",
+            "192.168.1.50",
+            "--network",
+            "LAN",
+        ])
+        .assert()
+        .failure()
+        .stderr(
+            predicate::str::contains("config")
+                .or(predicate::str::contains("Configuration"))
+                .or(predicate::str::contains("controller"))
+                .or(predicate::str::contains("profile")),
+        );
+}
+
 #[test]
 fn test_firewall_policy_create_requires_zone_flags() {
     let output = unifly_cmd()
@@ -346,6 +390,46 @@ fn test_firewall_policy_create_command_parses_with_required_flags() {
         );
 }
 
+#[test]
+fn test_plan_requires_file() {
+    let output = unifly_cmd().args(["plan"]).output().unwrap();
+    assert!(!output.status.success(), "Expected failure without --file");
+    let text = combined_output(&output);
+    assert!(
+        text.contains("--file"),
+        "Expected error about missing --file:\n{text}"
+    );
+}
+
+#[test]
+fn test_apply_command_parses() {
+    unifly_cmd()
+        .args(["apply", "-f", "site.yaml", "--prune"])
+        .assert()
+        .failure()
+        .stderr(
+            predicate::str::contains("config")
+                .or(predicate::str::contains("Configuration"))
+                .or(predicate::str::contains("controller"))
+                .or(predicate::str::contains("profile")),
+        );
+}
+
+#[test]
+fn test_export_import_commands_parse() {
+    for args in [
+        &["export", "--site", "default"][..],
+        &["import", "snapshot.json", "--site", "branch"][..],
+    ] {
+        unifly_cmd().args(args).assert().failure().stderr(
+            predicate::str::contains("config")
+                .or(predicate::str::contains("Configuration"))
+                .or(predicate::str::contains("controller"))
+                .or(predicate::str::contains("profile")),
+        );
+    }
+}
+
 // ── Subcommand help discovery ───────────────────────────────────────
 
 #[test]
@@ -386,6 +470,165 @@ fn test_firewall_subcommands_exist() {
         .stdout(predicate::str::contains("policies").and(predicate::str::contains("zones")));
 }
 
+#[test]
+fn test_port_forwards_subcommands_exist() {
+    unifly_cmd()
+        .args(["port-forwards", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("list")
+                .and(predicate::str::contains("create"))
+                .and(predicate::str::contains("enable"))
+                .and(predicate::str::contains("disable"))
+                .and(predicate::str::contains("delete")),
+        );
+}
+
+#[test]
+fn test_routes_subcommands_exist() {
+    unifly_cmd()
+        .args(["routes", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("list")
+                .and(predicate::str::contains("create"))
+                .and(predicate::str::contains("update"))
+                .and(predicate::str::contains("delete")),
+        );
+}
+
+#[test]
+fn test_devices_ports_subcommands_exist() {
+    unifly_cmd()
+        .args(["devices", "ports", "aa:bb:cc:dd:ee:ff", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("list").and(predicate::str::contains("set")));
+}
+
+#[test]
+fn test_devices_ports_set_rejects_enable_with_disable() {
+    let output = unifly_cmd()
+        .args([
+            "devices",
+            "ports",
+            "aa:bb:cc:dd:ee:ff",
+            "set",
+            "3",
+            "--enable",
+            "--disable",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let text = combined_output(&output);
+    assert!(
+        text.contains("cannot be used with"),
+        "Expected a conflict error:\n{text}"
+    );
+}
+
+#[test]
+fn test_bulk_selector_conflicts_with_single_target() {
+    let output = unifly_cmd()
+        .args([
+            "devices",
+            "restart",
+            "aa:bb:cc:dd:ee:ff",
+            "--selector",
+            "type=ap",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let text = combined_output(&output);
+    assert!(
+        text.contains("cannot be used with"),
+        "Expected a conflict error:\n{text}"
+    );
+
+    unifly_cmd()
+        .args(["clients", "kick", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("--selector")
+                .and(predicate::str::contains("--dry-run"))
+                .and(predicate::str::contains("--concurrency")),
+        );
+}
+
+#[test]
+fn test_client_history_and_offline_flags() {
+    let output = unifly_cmd().args(["clients", "history"]).output().unwrap();
+    assert!(!output.status.success());
+
+    unifly_cmd()
+        .args(["clients", "history", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--within"));
+
+    unifly_cmd()
+        .args(["clients", "list", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--include-offline"));
+}
+
+#[test]
+fn test_client_set_group_requires_group_or_default() {
+    let output = unifly_cmd()
+        .args(["clients", "set-group", "aa:bb:cc:dd:ee:ff"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let output = unifly_cmd()
+        .args([
+            "clients",
+            "set-group",
+            "aa:bb:cc:dd:ee:ff",
+            "Kids",
+            "--default",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let text = combined_output(&output);
+    assert!(
+        text.contains("cannot be used with"),
+        "Expected a conflict error:\n{text}"
+    );
+}
+
+#[test]
+fn test_settings_set_requires_assignment() {
+    let output = unifly_cmd().args(["settings", "set"]).output().unwrap();
+    assert!(!output.status.success());
+
+    unifly_cmd()
+        .args(["settings", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("get").and(predicate::str::contains("set")));
+}
+
+#[test]
+fn test_threats_list_flags() {
+    unifly_cmd()
+        .args(["threats", "list", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("--within")
+                .and(predicate::str::contains("--top-sources"))
+                .and(predicate::str::contains("--category")),
+        );
+}
+
 #[test]
 fn test_config_subcommands_exist() {
     unifly_cmd()
@@ -398,3 +641,94 @@ fn test_config_subcommands_exist() {
                 .and(predicate::str::contains("profiles")),
         );
 }
+
+#[test]
+fn test_fleet_status_accepts_tags() {
+    unifly_cmd()
+        .args(["fleet", "status", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--tag").and(predicate::str::contains("--concurrency")));
+}
+
+#[test]
+fn test_cloud_subcommands_exist() {
+    unifly_cmd()
+        .args(["cloud", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("hosts")
+                .and(predicate::str::contains("sites"))
+                .and(predicate::str::contains("devices")),
+        );
+}
+
+#[test]
+fn test_cloud_rejects_local_api_key() {
+    unifly_cmd()
+        .args([
+            "--controller",
+            "https://192.168.1.1",
+            "--api-key",
+            "local-key",
+            "cloud",
+            "hosts",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cloud profile"));
+}
+
+#[test]
+fn test_exporter_command_exists() {
+    unifly_cmd()
+        .args(["exporter", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("--listen")
+                .and(predicate::str::contains("0.0.0.0:9130"))
+                .and(predicate::str::contains("--poll-interval")),
+        );
+}
+
+#[test]
+fn test_history_subcommands_exist() {
+    unifly_cmd()
+        .args(["history", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("wan")
+                .and(predicate::str::contains("devices"))
+                .and(predicate::str::contains("clients")),
+        );
+}
+
+#[test]
+fn test_watch_alerts_command_exists() {
+    unifly_cmd()
+        .args(["watch-alerts", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("--rules")
+                .and(predicate::str::contains("--alarm-interval"))
+                .and(predicate::str::contains("--dry-run")),
+        );
+}
+
+#[test]
+fn test_two_factor_options_exist() {
+    unifly_cmd()
+        .args(["config", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("set-totp"));
+    unifly_cmd()
+        .args(["devices", "list", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--otp"));
+}